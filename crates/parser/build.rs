@@ -1,7 +1,7 @@
 use std::{
     fs::{self, File},
     io::BufReader,
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use serde::{Deserialize, Serialize};
@@ -45,29 +45,39 @@ fn main() {
         env!("CARGO_MANIFEST_DIR")
     );
 
-    let dir: PathBuf = ["tree-sitter-kotlin", "src"].iter().collect();
+    let mut tera = Tera::new("templates/*").unwrap();
+    tera.register_filter("camel_case", tera_text_filters::camel_case);
 
+    let kotlin_dir: PathBuf = ["tree-sitter-kotlin", "src"].iter().collect();
     cc::Build::new()
-        .include(&dir)
+        .include(&kotlin_dir)
         .flag("-Wno-unused-variable")
         .flag("-Wno-unused-but-set-variable")
-        .file(dir.join("parser.c"))
-        .file(dir.join("scanner.c"))
+        .file(kotlin_dir.join("parser.c"))
+        .file(kotlin_dir.join("scanner.c"))
         .compile("tree-sitter-kotlin");
+    generate_nodes(&tera, &kotlin_dir, "LANGUAGE", "src/node.rs");
+
+    let java_dir: PathBuf = ["tree-sitter-java", "src"].iter().collect();
+    cc::Build::new()
+        .include(&java_dir)
+        .flag("-Wno-unused-variable")
+        .flag("-Wno-unused-but-set-variable")
+        .file(java_dir.join("parser.c"))
+        .compile("tree-sitter-java");
+    generate_nodes(&tera, &java_dir, "JAVA_LANGUAGE", "src/java_node.rs");
+}
 
+/// Generates the typed nodes of the grammar in `grammar_dir`. `language` is the static of
+/// `lib.rs` holding the tree-sitter language
+fn generate_nodes(tera: &Tera, grammar_dir: &Path, language: &str, out_file: &str) {
     // Open the file in read-only mode with buffer.
-    let file = File::open(dir.join("node-types.json")).unwrap();
+    let file = File::open(grammar_dir.join("node-types.json")).unwrap();
     let reader = BufReader::new(file);
     let nodes: Vec<NodeType> = serde_json::from_reader(reader).unwrap();
 
-    let mut tera = Tera::new("templates/*").unwrap();
-    tera.register_filter("camel_case", tera_text_filters::camel_case);
-
-    let nodes_source = tera
-        .render(
-            "node.rs",
-            &Context::from_serialize(NodeTypes { nodes }).unwrap(),
-        )
-        .unwrap();
-    fs::write("src/node.rs", nodes_source).unwrap();
+    let mut context = Context::from_serialize(NodeTypes { nodes }).unwrap();
+    context.insert("language", language);
+    let nodes_source = tera.render("node.rs", &context).unwrap();
+    fs::write(out_file, nodes_source).unwrap();
 }
@@ -0,0 +1,650 @@
+// src/nodes.rs is autogeneratd from templates/nodes.rs
+
+use crop::Rope;
+use tree_sitter::Node;
+use stdx::{TextRange, WithTR};
+use lazy_static::lazy_static;
+
+use crate::text_of;
+
+// for w/e reasons looking up the child by field_name doesn't work
+// so we filter on kind
+lazy_static!{
+    pub static ref AnnotationTypeDeclarationId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("annotation_type_declaration", true);
+}
+pub struct AnnotationTypeDeclaration<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> AnnotationTypeDeclaration<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for AnnotationTypeDeclaration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref ClassDeclarationId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("class_declaration", true);
+}
+pub struct ClassDeclaration<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> ClassDeclaration<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for ClassDeclaration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref ConstantDeclarationId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("constant_declaration", true);
+}
+pub struct ConstantDeclaration<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> ConstantDeclaration<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for ConstantDeclaration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref ConstructorDeclarationId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("constructor_declaration", true);
+}
+pub struct ConstructorDeclaration<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> ConstructorDeclaration<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for ConstructorDeclaration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref EnumBodyDeclarationsId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("enum_body_declarations", true);
+}
+pub struct EnumBodyDeclarations<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> EnumBodyDeclarations<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for EnumBodyDeclarations<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref EnumConstantId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("enum_constant", true);
+}
+pub struct EnumConstant<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> EnumConstant<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for EnumConstant<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref EnumDeclarationId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("enum_declaration", true);
+}
+pub struct EnumDeclaration<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> EnumDeclaration<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for EnumDeclaration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref ExtendsInterfacesId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("extends_interfaces", true);
+}
+pub struct ExtendsInterfaces<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> ExtendsInterfaces<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for ExtendsInterfaces<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref FieldDeclarationId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("field_declaration", true);
+}
+pub struct FieldDeclaration<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> FieldDeclaration<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for FieldDeclaration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref FormalParameterId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("formal_parameter", true);
+}
+pub struct FormalParameter<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> FormalParameter<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for FormalParameter<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref IdentifierId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("identifier", true);
+}
+pub struct Identifier<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> Identifier<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for Identifier<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref InterfaceDeclarationId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("interface_declaration", true);
+}
+pub struct InterfaceDeclaration<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> InterfaceDeclaration<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for InterfaceDeclaration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref MethodDeclarationId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("method_declaration", true);
+}
+pub struct MethodDeclaration<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> MethodDeclaration<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for MethodDeclaration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref ModifiersId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("modifiers", true);
+}
+pub struct Modifiers<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> Modifiers<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for Modifiers<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref PackageDeclarationId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("package_declaration", true);
+}
+pub struct PackageDeclaration<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> PackageDeclaration<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for PackageDeclaration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref RecordDeclarationId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("record_declaration", true);
+}
+pub struct RecordDeclaration<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> RecordDeclaration<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for RecordDeclaration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref ScopedIdentifierId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("scoped_identifier", true);
+}
+pub struct ScopedIdentifier<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> ScopedIdentifier<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for ScopedIdentifier<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref SpreadParameterId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("spread_parameter", true);
+}
+pub struct SpreadParameter<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> SpreadParameter<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for SpreadParameter<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref SuperInterfacesId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("super_interfaces", true);
+}
+pub struct SuperInterfaces<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> SuperInterfaces<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for SuperInterfaces<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref SuperclassId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("superclass", true);
+}
+pub struct Superclass<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> Superclass<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for Superclass<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref TypeListId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("type_list", true);
+}
+pub struct TypeList<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> TypeList<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for TypeList<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}lazy_static!{
+    pub static ref VariableDeclaratorId: u16 = crate::JAVA_LANGUAGE.id_for_node_kind("variable_declarator", true);
+}
+pub struct VariableDeclarator<'a> {
+    pub node: Node<'a>,
+    pub source: &'a Rope,
+}
+
+impl<'a> VariableDeclarator<'a> {
+    pub fn new(node: Node<'a>, source: &'a Rope) -> Self {
+        Self{node, source}
+    }
+
+    pub fn text(&self) -> String {
+        text_of(&self.node, self.source)
+    }
+
+    pub fn text_tr(&self) -> WithTR<String> {
+        WithTR::new(self.text_range(), self.text())
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        self.node.byte_range().try_into().unwrap()
+    }}
+
+impl<'a> std::fmt::Debug for VariableDeclarator<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
\ No newline at end of file
@@ -10,12 +10,14 @@ use tree_sitter::{Node, Point};
 extern crate derive_new;
 
 mod ast_util;
+pub mod java_node;
 pub mod node;
 
 pub use ast_util::*;
 
 extern "C" {
     fn tree_sitter_kotlin() -> Language;
+    fn tree_sitter_java() -> Language;
 }
 use std::path::Path;
 
@@ -24,14 +26,24 @@ use tree_sitter::{Language, Parser, Tree};
 
 lazy_static! {
     static ref LANGUAGE: Language = unsafe { tree_sitter_kotlin() };
+    static ref JAVA_LANGUAGE: Language = unsafe { tree_sitter_java() };
 }
 
 pub fn parse(rope: &Rope, old_tree: Option<&Tree>) -> Option<Tree> {
+    parse_with_language(*LANGUAGE, rope, old_tree)
+}
+
+/// Parses java sources. Node kinds are in [java_node]
+pub fn parse_java(rope: &Rope, old_tree: Option<&Tree>) -> Option<Tree> {
+    parse_with_language(*JAVA_LANGUAGE, rope, old_tree)
+}
+
+fn parse_with_language(language: Language, rope: &Rope, old_tree: Option<&Tree>) -> Option<Tree> {
     // LANGUAGE.id_for_node_kind(kind, named)
     let mut parser = Parser::new();
-    parser.set_language(*LANGUAGE).unwrap();
+    parser.set_language(language).unwrap();
     let empty_bytes: &[u8] = &[];
-    let newline_bytes: &[u8] = &[b'\n'];
+    let newline_bytes: &[u8] = b"\n";
     let mut all_text = String::new();
     let tree = parser.parse_with(
         &mut |_byte: usize, position: Point| -> &[u8] {
@@ -95,7 +107,7 @@ pub fn parse(rope: &Rope, old_tree: Option<&Tree>) -> Option<Tree> {
 }
 
 pub fn text_of(node: &Node<'_>, rope: &Rope) -> String {
-    return rope.byte_slice(node.byte_range()).to_string();
+    rope.byte_slice(node.byte_range()).to_string()
 }
 
 pub fn is_utf8_multibyte_char(byte: u8) -> bool {
@@ -13,7 +13,7 @@ use crate::text_of;
 {% if node.named -%}
 
 lazy_static!{
-    pub static ref {{node.type | camel_case}}Id: u16 = crate::LANGUAGE.id_for_node_kind("{{node.type}}", true);
+    pub static ref {{node.type | camel_case}}Id: u16 = crate::{{language}}.id_for_node_kind("{{node.type}}", true);
 }
 pub struct {{node.type | camel_case}}<'a> {
     pub node: Node<'a>,
@@ -38,24 +38,24 @@ impl<'a> {{node.type | camel_case }}<'a> {
     }
 
     {%- for child in node.children.types %}
-    pub fn find_{{ child.type }}(&self) -> Option<{{child.type | camel_case }}> {
+    pub fn find_{{ child.type }}(&self) -> Option<{{child.type | camel_case }}<'_>> {
         let mut cursor = self.node.walk();
         let x = self
             .node
             .children(&mut cursor)
             .filter(|n| n.kind() == "{{child.type}}")
-            .map(|n| {{child.type | camel_case }}::new(n.clone(), &self.source))
+            .map(|n| {{child.type | camel_case }}::new(n, self.source))
             .next();
         x
     }
 
-    pub fn find_all_{{ child.type }}(&self) -> Vec<{{child.type | camel_case }}> {
+    pub fn find_all_{{ child.type }}(&self) -> Vec<{{child.type | camel_case }}<'_>> {
         let mut cursor = self.node.walk();
         let x = self
             .node
             .children(&mut cursor)
             .filter(|n| n.kind() == "{{child.type}}")
-            .map(|n| {{child.type | camel_case }}::new(n.clone(), &self.source))
+            .map(|n| {{child.type | camel_case }}::new(n, self.source))
             .collect::<Vec<_>>();
         x
     }
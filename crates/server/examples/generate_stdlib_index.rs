@@ -0,0 +1,26 @@
+//! Generates the stubs of the bundled stdlib index (see `server::stdlib`)
+//!
+//! `cargo run --example generate_stdlib_index -- <kotlin-stdlib.jar> [<output dir>]`
+
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let jar = PathBuf::from(
+        args.next()
+            .ok_or_else(|| anyhow!("Usage: generate_stdlib_index <jar> [<output dir>]"))?,
+    );
+    let output_dir = args
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("stdlib"));
+
+    std::fs::create_dir_all(&output_dir)?;
+    for (file_name, content) in server::stdlib::generate_stubs(&jar)? {
+        println!("Writing {} ({} bytes)", file_name, content.len());
+        std::fs::write(output_dir.join(file_name), content)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,199 @@
+//! Cancellation of requests computed on outdated files. Every change of a file increases its
+//! revision. Requests remember the revisions when they start and stop with [ContentModified] at
+//! their next [CancellationToken::check], once the revision changed.
+//!
+//! Requests run on a blocking thread by [run_cancellable], so that changes are processed while
+//! they are computed. `$/cancelRequest` is handled by tower-lsp, which drops the pending request.
+//! Dropping it cancels its token, so that the blocking computation stops as well.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use parking_lot::Mutex;
+use tracing::debug;
+
+/// Error of requests, whose file changed while they were computed. Reported to the client as
+/// `ContentModified`
+#[derive(Debug)]
+pub struct ContentModified;
+
+impl std::fmt::Display for ContentModified {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Content modified")
+    }
+}
+
+impl std::error::Error for ContentModified {}
+
+/// Error of requests, which got dropped before they finished, e.G. by `$/cancelRequest`
+#[derive(Debug)]
+pub struct RequestCancelled;
+
+impl std::fmt::Display for RequestCancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Request cancelled")
+    }
+}
+
+impl std::error::Error for RequestCancelled {}
+
+/// The number of changes of each project file
+#[derive(Debug, Default, Clone)]
+pub struct FileRevisions(Arc<Mutex<Revisions>>);
+
+#[derive(Debug, Default)]
+struct Revisions {
+    /// Changes of all files
+    total: u64,
+    by_file: HashMap<PathBuf, u64>,
+}
+
+impl FileRevisions {
+    /// Marks the file at `path` as changed. Called before the change is applied, so that
+    /// requests reading the file stop early
+    pub fn increment(&self, path: &Path) {
+        let mut revisions = self.0.lock();
+        revisions.total += 1;
+        *revisions.by_file.entry(path.to_owned()).or_default() += 1;
+    }
+
+    /// A token cancelled by the next change of the file at `path`
+    pub fn token_of_file(&self, path: &Path) -> CancellationToken {
+        let revision = self.0.lock().by_file.get(path).copied().unwrap_or_default();
+        CancellationToken {
+            revisions: self.clone(),
+            path: Some(path.to_owned()),
+            revision,
+            dropped: Arc::default(),
+        }
+    }
+
+    /// A token cancelled by the next change of any file
+    pub fn token_of_project(&self) -> CancellationToken {
+        let revision = self.0.lock().total;
+        CancellationToken {
+            revisions: self.clone(),
+            path: None,
+            revision,
+            dropped: Arc::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CancellationToken {
+    revisions: FileRevisions,
+    /// None if any file cancels the token
+    path: Option<PathBuf>,
+    revision: u64,
+    /// Set once the request got dropped
+    dropped: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// A token, which is only cancelled by dropping its request
+    pub fn none() -> Self {
+        FileRevisions::default().token_of_project()
+    }
+
+    fn is_modified(&self) -> bool {
+        let revisions = self.revisions.0.lock();
+        let current = match &self.path {
+            Some(path) => revisions.by_file.get(path).copied().unwrap_or_default(),
+            None => revisions.total,
+        };
+        current != self.revision
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.dropped.load(Ordering::Relaxed) || self.is_modified()
+    }
+
+    /// Fails with [ContentModified] or [RequestCancelled], if the token is cancelled
+    pub fn check(&self) -> anyhow::Result<()> {
+        if self.dropped.load(Ordering::Relaxed) {
+            debug!("Cancelling request on {:?}, as it got dropped", self.path);
+            return Err(RequestCancelled.into());
+        }
+        if self.is_modified() {
+            debug!("Cancelling request on {:?}, as it changed", self.path);
+            return Err(ContentModified.into());
+        }
+        Ok(())
+    }
+}
+
+/// Cancels the token of a request, once the request is dropped
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Runs the synchronous request handler `handle` on a blocking thread, so that changes of files
+/// are processed and cancel `cancel` meanwhile. Dropping the returned future cancels it as well
+pub async fn run_cancellable<T: Send + 'static>(
+    cancel: CancellationToken,
+    handle: impl FnOnce(&CancellationToken) -> anyhow::Result<T> + Send + 'static,
+) -> anyhow::Result<T> {
+    let _cancel_on_drop = CancelOnDrop(cancel.dropped.clone());
+    tokio::task::spawn_blocking(move || handle(&cancel)).await?
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn running_requests_stop_once_their_file_changes() {
+    let revisions = FileRevisions::default();
+    let a = Path::new("/a.kt");
+    let (started, is_started) = tokio::sync::oneshot::channel();
+    let request = tokio::spawn(run_cancellable(
+        revisions.token_of_file(a),
+        move |cancel| -> anyhow::Result<()> {
+            let _ = started.send(());
+            loop {
+                cancel.check()?;
+                std::thread::yield_now();
+            }
+        },
+    ));
+    is_started.await.unwrap();
+
+    revisions.increment(Path::new("/b.kt"));
+    assert!(!request.is_finished());
+    revisions.increment(a);
+    let result = tokio::time::timeout(std::time::Duration::from_secs(10), request)
+        .await
+        .expect("Request did not stop")
+        .unwrap();
+    assert!(result.unwrap_err().is::<ContentModified>());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn running_requests_stop_once_they_are_dropped() {
+    let (started, is_started) = tokio::sync::oneshot::channel();
+    let (stopped, is_stopped) = tokio::sync::oneshot::channel();
+    let request = tokio::spawn(run_cancellable(CancellationToken::none(), move |cancel| {
+        let _ = started.send(());
+        while !cancel.is_cancelled() {
+            std::thread::yield_now();
+        }
+        let _ = stopped.send(cancel.check().unwrap_err());
+        Ok(())
+    }));
+    is_started.await.unwrap();
+
+    // As tower-lsp does on `$/cancelRequest`
+    request.abort();
+    let stopped_with = tokio::time::timeout(std::time::Duration::from_secs(10), is_stopped)
+        .await
+        .expect("Request did not stop")
+        .unwrap();
+    assert!(stopped_with.is::<RequestCancelled>());
+}
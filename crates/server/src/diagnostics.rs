@@ -0,0 +1,147 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+
+use itertools::Itertools;
+use stdx::{new_arc_lock, AMtx};
+use tower_lsp::lsp_types::{Diagnostic, Url};
+use tracing::{debug, trace, warn};
+
+use crate::{
+    cancellation::{run_cancellable, CancellationToken, FileRevisions},
+    kserver::ClientI,
+    multiplatform::MissingActuals,
+    package_index, resolve,
+    scope::GScopes,
+    scope::{script_scope, GScopesData},
+};
+
+/// How long diagnostics wait for further changes, before they are published. Typing does not
+/// recompute them on every keystroke
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Returns the diagnostics of all files. Files without diagnostics are not part of the result.
+/// Fails with [crate::cancellation::ContentModified], once `cancel` is cancelled
+pub fn project_diagnostics(
+    scopes: &GScopesData,
+    missing_actuals: &MissingActuals,
+    cancel: &CancellationToken,
+) -> anyhow::Result<HashMap<PathBuf, Vec<Diagnostic>>> {
+    let mut diagnostics = HashMap::new();
+    missing_actuals.add_to(&mut diagnostics);
+    cancel.check()?;
+    resolve::add_resolution_diagnostics(scopes, &mut diagnostics, cancel)?;
+    package_index::add_package_mismatch_diagnostics(scopes, &mut diagnostics);
+    script_scope::add_script_syntax_diagnostics(scopes, &mut diagnostics);
+    Ok(diagnostics)
+}
+
+/// Computes and sends the diagnostics of the whole project to the client
+#[derive(Clone)]
+pub struct DiagnosticsPublisher {
+    scopes: GScopes,
+    client: Arc<dyn ClientI>,
+    file_revisions: FileRevisions,
+    /// Files for which non empty diagnostics got published last time
+    published: AMtx<HashSet<PathBuf>>,
+    /// Files changed since diagnostics got computed last time
+    changed: AMtx<HashSet<PathBuf>>,
+    missing_actuals: AMtx<MissingActuals>,
+}
+
+impl DiagnosticsPublisher {
+    pub fn new(scopes: GScopes, client: Arc<dyn ClientI>, file_revisions: FileRevisions) -> Self {
+        DiagnosticsPublisher {
+            scopes,
+            client,
+            file_revisions,
+            published: new_arc_lock(HashSet::new()),
+            changed: new_arc_lock(HashSet::new()),
+            missing_actuals: new_arc_lock(MissingActuals::default()),
+        }
+    }
+
+    /// Computes the diagnostics of all files and publishes them, e.G. once the project got
+    /// imported
+    pub async fn publish(&self) {
+        self.publish_with(true).await;
+    }
+
+    /// Publishes the diagnostics after the file at `path` changed. They are published once no
+    /// further change follows for a moment, and only cross file diagnostics of the changed files
+    /// are recomputed
+    pub fn file_changed(&self, path: PathBuf) {
+        self.changed.lock().insert(path);
+        let cancel = self.file_revisions.token_of_project();
+        let publisher = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(DEBOUNCE).await;
+            if cancel.is_cancelled() {
+                trace!("Not publishing diagnostics, as files changed meanwhile");
+                return;
+            }
+            publisher.publish_with(false).await;
+        });
+    }
+
+    async fn publish_with(&self, is_whole_project: bool) {
+        // Diagnostics of outdated files are not published. The change publishes new ones
+        let cancel = self.file_revisions.token_of_project();
+        let scopes = self.scopes.clone();
+        let changed = self.changed.clone();
+        let missing_actuals = self.missing_actuals.clone();
+        let diagnostics = run_cancellable(cancel, move |cancel| {
+            // Updates are applied in order, each to a newer snapshot than the one before
+            let mut missing_actuals = missing_actuals.lock();
+            let scopes = scopes.snapshot();
+            let changed = std::mem::take(&mut *changed.lock());
+            if is_whole_project {
+                *missing_actuals = MissingActuals::of_project(&scopes);
+            } else {
+                for path in changed {
+                    missing_actuals.file_changed(&scopes, &path);
+                }
+            }
+            project_diagnostics(&scopes, &missing_actuals, cancel)
+        })
+        .await;
+        let Ok(diagnostics) = diagnostics else {
+            debug!("Not publishing diagnostics, as files changed meanwhile");
+            return;
+        };
+
+        // Files which had diagnostics before, but have none now, have to be cleared
+        let cleared = {
+            let mut published = self.published.lock();
+            let cleared = published
+                .iter()
+                .filter(|path| !diagnostics.contains_key(*path))
+                .cloned()
+                .collect_vec();
+            *published = diagnostics.keys().cloned().collect();
+            cleared
+        };
+
+        let all = cleared
+            .into_iter()
+            .map(|path| (path, vec![]))
+            .chain(diagnostics);
+        for (path, file_diagnostics) in all {
+            let Ok(uri) = Url::from_file_path(&path) else {
+                warn!("Can't publish diagnostics for {}", path.display());
+                continue;
+            };
+            trace!(
+                "Publishing {} diagnostics for {}",
+                file_diagnostics.len(),
+                uri
+            );
+            self.client
+                .publish_diagnostics(uri, file_diagnostics, None)
+                .await;
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Opt-in self-check of the incremental scope updates, enabled by `--verify-incremental` or
+//! `KLS_VERIFY_INCREMENTAL=1`. After every change the scopes of the file are rebuilt from its text
+//! and compared with the incrementally updated ones. Mismatches are logged together with the edits
+//! leading to them, starting at the last text whose scopes matched, so that they can be reproduced.
+//! The incremental scopes are kept, so that the check does not change what it observes.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use serde_json::json;
+use stdx::TextRange;
+use tower_lsp::lsp_types::{DidChangeTextDocumentParams, TextDocumentContentChangeEvent};
+use tracing::{debug, error};
+
+use crate::scope::{GSFile, SKind};
+
+pub const VERIFY_INCREMENTAL_ENV: &str = "KLS_VERIFY_INCREMENTAL";
+
+#[derive(Debug, Default)]
+pub struct IncrementalCheck {
+    edit_logs: Mutex<HashMap<PathBuf, EditLog>>,
+}
+
+/// The edits of a file since its scopes last matched a rebuild
+#[derive(Debug, Serialize)]
+struct EditLog {
+    text: String,
+    edits: Vec<RecordedEdit>,
+}
+
+/// The content changes of one `didChange` notification
+#[derive(Debug, Serialize)]
+struct RecordedEdit {
+    version: i32,
+    content_changes: Vec<TextDocumentContentChangeEvent>,
+}
+
+impl IncrementalCheck {
+    /// Enabled, if `KLS_VERIFY_INCREMENTAL` is set to anything but `0`
+    pub fn from_env() -> Option<Self> {
+        std::env::var_os(VERIFY_INCREMENTAL_ENV)
+            .filter(|value| !value.is_empty() && value != "0")
+            .map(|_| IncrementalCheck::default())
+    }
+
+    /// Records the changes of `notification`. Called before they are applied to `s_file`
+    pub fn record(&self, s_file: &GSFile, notification: &DidChangeTextDocumentParams) {
+        let mut edit_logs = self.edit_logs.lock();
+        let edit_log = edit_logs
+            .entry(s_file.path.clone())
+            .or_insert_with(|| EditLog {
+                text: s_file.text.to_string(),
+                edits: vec![],
+            });
+        edit_log.edits.push(RecordedEdit {
+            version: notification.text_document.version,
+            content_changes: notification.content_changes.clone(),
+        });
+    }
+
+    /// Compares the scopes of `s_file` with the ones rebuilt from its text. On a mismatch the
+    /// edits since the last match are logged and returned as report. They are kept until the
+    /// scopes match again, as later edits start from the mismatching scopes. `s_file` is left as
+    /// it is
+    pub fn verify(&self, s_file: &GSFile) -> anyhow::Result<Option<serde_json::Value>> {
+        let rebuilt = GSFile::build(s_file.path.clone(), s_file.text.clone())?;
+        let mut edit_logs = self.edit_logs.lock();
+        if scope_entries(s_file) == scope_entries(&rebuilt) {
+            debug!("Incremental scopes of {} match", s_file.path.display());
+            edit_logs.remove(&s_file.path);
+            return Ok(None);
+        }
+        let edit_log = edit_logs.get(&s_file.path);
+
+        let incremental_scopes = scope_lines(s_file);
+        let rebuilt_scopes = scope_lines(&rebuilt);
+        let first_mismatch = incremental_scopes
+            .iter()
+            .zip(&rebuilt_scopes)
+            .position(|(incremental, rebuilt)| incremental != rebuilt)
+            .unwrap_or(incremental_scopes.len().min(rebuilt_scopes.len()));
+        let report = json!({
+            "path": s_file.path,
+            "edit_log": edit_log,
+            "first_mismatch": {
+                "line": first_mismatch,
+                "incremental": incremental_scopes.get(first_mismatch),
+                "rebuilt": rebuilt_scopes.get(first_mismatch),
+            },
+            "incremental_scopes": incremental_scopes,
+            "rebuilt_scopes": rebuilt_scopes,
+        });
+        drop(edit_logs);
+        error!(
+            "Incremental scopes of {} differ from a rebuild: {}",
+            s_file.path.display(),
+            report
+        );
+        Ok(Some(report))
+    }
+}
+
+/// The depth, range and kind of each scope of `s_file` in tree order
+fn scope_entries(s_file: &GSFile) -> Vec<(usize, TextRange, &SKind)> {
+    let mut entries = vec![];
+    for root_node in &s_file.root_nodes {
+        let depth = root_node.ancestors(&s_file.scopes).count();
+        for scope_id in root_node.descendants(&s_file.scopes) {
+            let scope = s_file.scopes[scope_id].get();
+            let indent = scope_id.ancestors(&s_file.scopes).count() - depth;
+            entries.push((indent, scope.range, &scope.kind));
+        }
+    }
+    entries
+}
+
+/// One line for each scope of `s_file`, indented by its depth
+fn scope_lines(s_file: &GSFile) -> Vec<String> {
+    scope_entries(s_file)
+        .into_iter()
+        .map(|(indent, range, kind)| format!("{}{} {:?}", "  ".repeat(indent), range, kind))
+        .collect()
+}
+
+#[test]
+fn mismatches_report_the_edits_since_the_last_match() {
+    use crop::Rope;
+    use tower_lsp::lsp_types::{Position, Range, Url, VersionedTextDocumentIdentifier};
+
+    let path = PathBuf::from("/a.kt");
+    let file = |text: &str| GSFile::build(path.clone(), Rope::from(text)).unwrap();
+    // Replaces the function name
+    let rename = |version: i32, name: &str| DidChangeTextDocumentParams {
+        text_document: VersionedTextDocumentIdentifier::new(
+            Url::from_file_path(&path).unwrap(),
+            version,
+        ),
+        content_changes: vec![TextDocumentContentChangeEvent {
+            range: Some(Range::new(Position::new(0, 4), Position::new(0, 5))),
+            range_length: None,
+            text: name.to_string(),
+        }],
+    };
+    let check = IncrementalCheck::default();
+
+    let original = file("fun a() = 1\n");
+    check.record(&original, &rename(1, "b"));
+    check.record(&original, &rename(2, "c"));
+    // Scopes, which missed both renames
+    let mut stale = original.clone();
+    stale.text = Rope::from("fun c() = 1\n");
+    let report = check
+        .verify(&stale)
+        .unwrap()
+        .expect("The scopes should differ");
+    assert_eq!(report["edit_log"]["text"], "fun a() = 1\n");
+    assert_eq!(report["edit_log"]["edits"][0]["version"], 1);
+    assert_eq!(report["edit_log"]["edits"][1]["version"], 2);
+    assert_eq!(stale.text.to_string(), "fun c() = 1\n");
+
+    // The log continues from the last match, as the next change edits the mismatching scopes
+    check.record(&stale, &rename(3, "d"));
+    let mut stale = original.clone();
+    stale.text = Rope::from("fun d() = 1\n");
+    let report = check
+        .verify(&stale)
+        .unwrap()
+        .expect("The scopes should still differ");
+    assert_eq!(report["edit_log"]["text"], "fun a() = 1\n");
+    assert_eq!(report["edit_log"]["edits"][2]["version"], 3);
+
+    // The log restarts at the text of the next change once the scopes match again
+    assert!(check.verify(&file("fun d() = 1\n")).unwrap().is_none());
+    assert!(check.edit_logs.lock().is_empty());
+    let renamed = file("fun d() = 1\n");
+    check.record(&renamed, &rename(4, "e"));
+    assert_eq!(check.edit_logs.lock()[&path].text, "fun d() = 1\n");
+}
@@ -0,0 +1,247 @@
+//! On disk cache of the declarations of the project files. Files, which did not change since the
+//! cache got written, are indexed from the cache on startup, so that their declarations resolve
+//! before all files are parsed. Only the package and the symbols of a file are cached. Unchanged
+//! files are still parsed after all changed ones: requests and diagnostics work on the syntax tree
+//! of a file, which cannot be serialized, so caching scope trees would not save the parsing
+
+use std::{
+    fs::Metadata,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use serde::{Deserialize, Serialize};
+use stdx::TextRange;
+use tracing::{debug, warn};
+
+use crate::{
+    scope::{
+        decl_id::{DeclKind, DeclSegment},
+        fun_decl_scope::Visibility,
+        DeclId,
+    },
+    symbol_index::{Symbol, SymbolKind, SymbolOrigin},
+};
+
+/// Version of the cache format. Has to be increased whenever the format or the indexed
+/// declarations change, so that caches of older kls versions are discarded
+pub const FORMAT_VERSION: u32 = 2;
+
+/// Overrides the directory caches are written to
+pub const CACHE_DIR_ENV: &str = "KLS_CACHE_DIR";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexCache {
+    version: u32,
+    files: im::HashMap<PathBuf, CachedFile>,
+}
+
+/// The declarations of a file, as it was on disk when it got indexed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFile {
+    stamp: FileStamp,
+    package: String,
+    symbols: Vec<CachedSymbol>,
+}
+
+/// Identifies the content of a file by its modification time and size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileStamp {
+    modified_millis: u128,
+    len: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedSymbol {
+    name: String,
+    container: String,
+    kind: SymbolKind,
+    visibility: Visibility,
+    /// The segments of the [DeclId] within the file
+    segments: Vec<DeclSegment>,
+    range: (u32, u32),
+    is_synthetic: bool,
+}
+
+impl FileStamp {
+    pub fn of(metadata: &Metadata) -> Option<Self> {
+        let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        Some(FileStamp {
+            modified_millis: modified.as_millis(),
+            len: metadata.len(),
+        })
+    }
+}
+
+impl Default for IndexCache {
+    fn default() -> Self {
+        IndexCache {
+            version: FORMAT_VERSION,
+            files: im::HashMap::new(),
+        }
+    }
+}
+
+impl IndexCache {
+    /// Reads the cache at `path`. Missing, unreadable or outdated caches are empty
+    pub fn load(path: &Path) -> Self {
+        let content = match std::fs::read(path) {
+            Ok(content) => content,
+            Err(e) => {
+                debug!("No index cache at {}: {}", path.display(), e);
+                return IndexCache::default();
+            }
+        };
+        match serde_json::from_slice::<IndexCache>(&content) {
+            Ok(cache) if cache.version == FORMAT_VERSION => {
+                debug!(
+                    "Loaded index cache of {} files from {}",
+                    cache.files.len(),
+                    path.display()
+                );
+                cache
+            }
+            Ok(cache) => {
+                debug!(
+                    "Discarding index cache {} of version {}",
+                    path.display(),
+                    cache.version
+                );
+                IndexCache::default()
+            }
+            Err(e) => {
+                warn!("Discarding invalid index cache {}: {}", path.display(), e);
+                IndexCache::default()
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        // Written next to the cache first, so that an interrupted write keeps the old cache
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec(self)?)?;
+        std::fs::rename(&tmp_path, path)?;
+        debug!(
+            "Saved index cache of {} files to {}",
+            self.files.len(),
+            path.display()
+        );
+        Ok(())
+    }
+
+    /// The package and symbols of the file at `path`, if it did not change since it got cached
+    pub fn fresh_symbols(&self, path: &Path, stamp: FileStamp) -> Option<(String, Vec<Symbol>)> {
+        let cached = self
+            .files
+            .get(path)
+            .filter(|cached| cached.stamp == stamp)?;
+        let symbols = cached
+            .symbols
+            .iter()
+            .map(|symbol| Symbol {
+                name: symbol.name.clone(),
+                container: symbol.container.clone(),
+                kind: symbol.kind,
+                visibility: symbol.visibility,
+                origin: SymbolOrigin::Source {
+                    id: DeclId {
+                        path: path.to_owned(),
+                        segments: symbol.segments.clone(),
+                    },
+                    range: TextRange::new(symbol.range.0, symbol.range.1),
+                },
+                is_synthetic: symbol.is_synthetic,
+            })
+            .collect();
+        Some((cached.package.clone(), symbols))
+    }
+
+    /// Caches the symbols of the file at `path`, whose content on disk is identified by `stamp`
+    pub fn set_file(&mut self, path: &Path, stamp: FileStamp, package: &str, symbols: &[Symbol]) {
+        let symbols = symbols
+            .iter()
+            .filter_map(|symbol| match &symbol.origin {
+                SymbolOrigin::Source { id, range } => Some(CachedSymbol {
+                    name: symbol.name.clone(),
+                    container: symbol.container.clone(),
+                    kind: symbol.kind,
+                    visibility: symbol.visibility,
+                    segments: id.segments.clone(),
+                    range: (range.start, range.end),
+                    is_synthetic: symbol.is_synthetic,
+                }),
+                SymbolOrigin::Library(_) => None,
+            })
+            .collect();
+        self.files.insert(
+            path.to_owned(),
+            CachedFile {
+                stamp,
+                package: package.to_string(),
+                symbols,
+            },
+        );
+    }
+
+    /// Removes files, which are no longer part of the project
+    pub fn retain_files(&mut self, is_project_file: impl Fn(&Path) -> bool) {
+        self.files.retain(|path, _| is_project_file(path));
+    }
+}
+
+/// The path of the cache of the project at `root_dir`. Caches are written to `$KLS_CACHE_DIR`,
+/// `$XDG_CACHE_HOME/kls` or `~/.cache/kls`
+pub fn cache_path(root_dir: &Path) -> Option<PathBuf> {
+    let dir = std::env::var_os(CACHE_DIR_ENV)
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("XDG_CACHE_HOME").map(|dir| PathBuf::from(dir).join("kls")))
+        .or_else(|| std::env::var_os("HOME").map(|dir| PathBuf::from(dir).join(".cache/kls")))?;
+    // One cache per project, e.G. `%home%user%app.json` for `/home/user/app`
+    let name = root_dir
+        .to_string_lossy()
+        .replace(std::path::MAIN_SEPARATOR, "%");
+    Some(dir.join(format!("{}.json", name)))
+}
+
+#[test]
+fn cached_symbols_are_fresh_until_the_file_changes() {
+    let path = Path::new("/p/src/main/kotlin/App.kt");
+    let stamp = FileStamp {
+        modified_millis: 1,
+        len: 10,
+    };
+    let symbol = Symbol {
+        name: "App".to_string(),
+        container: "example".to_string(),
+        kind: SymbolKind::Class,
+        visibility: Visibility::Internal,
+        origin: SymbolOrigin::Source {
+            id: DeclId::file(path).child(DeclSegment {
+                kind: DeclKind::Class,
+                name: "App".to_string(),
+                disambiguator: 0,
+            }),
+            range: TextRange::new(17, 26),
+        },
+        is_synthetic: false,
+    };
+    let mut cache = IndexCache::default();
+    cache.set_file(path, stamp, "example", &[symbol]);
+
+    let cache: IndexCache = serde_json::from_slice(&serde_json::to_vec(&cache).unwrap()).unwrap();
+    let (package, symbols) = cache.fresh_symbols(path, stamp).unwrap();
+    assert_eq!(package, "example");
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols[0].fq_name(), "example.App");
+    assert_eq!(symbols[0].visibility, Visibility::Internal);
+    let SymbolOrigin::Source { id, .. } = &symbols[0].origin else {
+        panic!("{:?} is not declared in a source file", symbols[0]);
+    };
+    assert_eq!(id.to_string(), "/p/src/main/kotlin/App.kt/Class App");
+
+    let changed = FileStamp { len: 11, ..stamp };
+    assert!(cache.fresh_symbols(path, changed).is_none());
+}
@@ -14,14 +14,30 @@ use tower_lsp::{async_trait, lsp_types::*, Client, LanguageServer};
 use tracing::{debug, error, info, trace};
 use walkdir::WalkDir;
 
+use crate::cancellation::{
+    run_cancellable, CancellationToken, ContentModified, FileRevisions, RequestCancelled,
+};
+use crate::diagnostics::DiagnosticsPublisher;
+use crate::incremental_check::IncrementalCheck;
+use crate::index_cache;
+use crate::library::sources::is_library_uri;
 use crate::project::ProjectI;
+use crate::request_handler::code_action_handler::CodeActionHandler;
+use crate::request_handler::completion_handler::CompletionHandler;
 use crate::request_handler::did_change_text_document_handler::DidChangeTextDocumentHandler;
+use crate::request_handler::goto_definition_handler::GotoDefinitionHandler;
+use crate::request_handler::hover_handler::HoverHandler;
+use crate::request_handler::library_file_handler::{
+    LibraryFileContentHandler, LibraryFileContentRequest,
+};
 use crate::request_handler::print_scopes_handler::{PrintScopesHandler, PrintScopesRequest};
 use crate::scope::*;
+use crate::to_file_path;
 
 #[async_trait]
 pub trait ClientI: Send + Sync {
     async fn log_message(&self, ty: MessageType, msg: String);
+    async fn publish_diagnostics(&self, uri: Url, diags: Vec<Diagnostic>, version: Option<i32>);
 }
 
 #[async_trait]
@@ -29,6 +45,10 @@ impl ClientI for Client {
     async fn log_message(&self, ty: MessageType, msg: String) {
         self.log_message(ty, msg).await;
     }
+
+    async fn publish_diagnostics(&self, uri: Url, diags: Vec<Diagnostic>, version: Option<i32>) {
+        self.publish_diagnostics(uri, diags, version).await;
+    }
 }
 
 pub struct KServer {
@@ -38,21 +58,71 @@ pub struct KServer {
     pub background_tasks: AMtx<Vec<JoinHandle<()>>>,
 
     pub scopes: GScopes,
+    pub diagnostics: DiagnosticsPublisher,
+    /// Changes of the files, which cancel requests computed on their previous content
+    pub file_revisions: FileRevisions,
+    /// Compares incremental scope updates with a rebuild. None unless enabled
+    pub incremental_check: Option<IncrementalCheck>,
 }
 
 impl KServer {
     pub fn new(client: Arc<dyn ClientI>) -> Self {
+        let scopes = GScopes::new();
+        let file_revisions = FileRevisions::default();
         KServer {
+            diagnostics: DiagnosticsPublisher::new(
+                scopes.clone(),
+                client.clone(),
+                file_revisions.clone(),
+            ),
             client,
             root_dir: new_arc_rw_lock(None),
             background_tasks: new_arc_lock(vec![]),
-            scopes: GScopes::new(),
+            scopes,
+            file_revisions,
+            incremental_check: IncrementalCheck::from_env(),
+        }
+    }
+
+    /// A token cancelled by the next change of the file of `uri`. Read only library files never
+    /// change
+    pub fn cancellation_token(&self, uri: &Url) -> CancellationToken {
+        match to_file_path(uri) {
+            Ok(path) if !is_library_uri(uri) => self.file_revisions.token_of_file(&path),
+            _ => CancellationToken::none(),
         }
     }
 
+    /// Runs `handle` on the scopes of a blocking thread. It is cancelled by changes of the file of
+    /// `uri` or by dropping the request
+    async fn run_request<T: Send + 'static>(
+        &self,
+        uri: &Url,
+        handle: impl FnOnce(&GScopes, &CancellationToken) -> anyhow::Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let scopes = self.scopes.clone();
+        let cancel = self.cancellation_token(uri);
+        map_result(run_cancellable(cancel, move |cancel| handle(&scopes, cancel)).await)
+    }
+
     /// Custom request
     pub async fn print_scopes(&self, request: PrintScopesRequest) -> Result<String> {
-        map_result(PrintScopesHandler::new(&self, &request).handle())
+        let scopes = self.scopes.clone();
+        map_result(
+            run_cancellable(CancellationToken::none(), move |_| {
+                PrintScopesHandler::new(&scopes, &request).handle()
+            })
+            .await,
+        )
+    }
+
+    /// Custom request. Returns the content of a read only `kls-jar:` or `kls-stdlib:` file
+    pub async fn library_file_content(&self, request: LibraryFileContentRequest) -> Result<String> {
+        let uri = request.uri.clone();
+        self.run_request(&uri, move |scopes, _| {
+            LibraryFileContentHandler::new(scopes, &request).handle()
+        })
+        .await
     }
 }
 
@@ -63,9 +133,57 @@ impl LanguageServer for KServer {
     async fn did_change(&self, notification: DidChangeTextDocumentParams) {
         // TODO synchronize notification. See
         // https://github.com/ebkalderon/tower-lsp/issues/284
-        if let Err(e) = DidChangeTextDocumentHandler::new(&self, &notification).handle() {
+        // Changes are applied without yielding, so that they keep their order. Files pending to be
+        // parsed are read from disk though, which must not block the executor
+        let result = tokio::task::block_in_place(|| {
+            DidChangeTextDocumentHandler::new(self, &notification).handle()
+        });
+        if let Err(e) = result {
             error!("{}", e);
         }
+        let uri = &notification.text_document.uri;
+        if let Some(path) = to_file_path(uri).ok().filter(|_| !is_library_uri(uri)) {
+            self.diagnostics.file_changed(path);
+        }
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let uri = params
+            .text_document_position_params
+            .text_document
+            .uri
+            .clone();
+        self.run_request(&uri, move |scopes, cancel| {
+            GotoDefinitionHandler::new(scopes, &params, cancel).handle()
+        })
+        .await
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let uri = params
+            .text_document_position_params
+            .text_document
+            .uri
+            .clone();
+        self.run_request(&uri, move |scopes, cancel| {
+            HoverHandler::new(scopes, &params, cancel).handle()
+        })
+        .await
+    }
+
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let uri = params.text_document_position.text_document.uri.clone();
+        self.run_request(&uri, move |scopes, cancel| {
+            CompletionHandler::new(scopes, &params, cancel).handle()
+        })
+        .await
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        map_result(CodeActionHandler::new(&params).handle())
     }
 
     async fn initialize(&self, init_params: InitializeParams) -> Result<InitializeResult> {
@@ -90,8 +208,13 @@ impl LanguageServer for KServer {
         {
             let scopes = self.scopes.clone();
             let client = self.client.clone();
+            let diagnostics = self.diagnostics.clone();
+            let cache_path = index_cache::cache_path(self.root_dir.read().as_ref().unwrap());
             self.background_tasks.lock().push(tokio::spawn(async move {
-                if let Err(e) = scopes.add_scopes_from_project_recursive(root_project).await {
+                if let Err(e) = scopes
+                    .add_scopes_from_project_recursive(root_project, cache_path)
+                    .await
+                {
                     client
                         .log_message(
                             MessageType::ERROR,
@@ -99,6 +222,7 @@ impl LanguageServer for KServer {
                         )
                         .await
                 }
+                diagnostics.publish().await;
             }));
         }
 
@@ -131,9 +255,14 @@ impl LanguageServer for KServer {
                 //         ..Default::default()
                 //     },
                 // )),
-                completion_provider: None,
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec![".".to_string()]),
+                    ..Default::default()
+                }),
                 // definition: Some(GotoCapability::default()),
-                definition_provider: None,
+                definition_provider: Some(OneOf::Left(true)),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
                 references_provider: None,
                 rename_provider: None,
                 // workspace: Some(WorkspaceServerCapabilities {
@@ -178,6 +307,14 @@ fn root_dir_of(init_params: &InitializeParams) -> Result<PathBuf> {
 }
 
 fn map_err<T>(err: anyhow::Error) -> tower_lsp::jsonrpc::Result<T> {
+    if err.is::<ContentModified>() {
+        debug!("Request cancelled, as its file changed");
+        return Err(tower_lsp::jsonrpc::Error::content_modified());
+    }
+    if err.is::<RequestCancelled>() {
+        debug!("Request cancelled");
+        return Err(tower_lsp::jsonrpc::Error::request_cancelled());
+    }
     error!("KServer caught error: {}", err);
     Err(tower_lsp::jsonrpc::Error::invalid_params(err.to_string()))
 }
@@ -1,4 +1,3 @@
-#![feature(strict_overflow_ops)]
 // #![feature(iter_intersperse)]
 // #![feature(entry_insert)]
 #![allow(dead_code)]
@@ -12,12 +11,23 @@ use tower_lsp::lsp_types::Url;
 #[macro_use]
 extern crate derive_new;
 
+pub mod cancellation;
+pub mod diagnostics;
+pub mod incremental_check;
+pub mod index_cache;
 pub mod kserver;
+pub mod library;
+pub mod multiplatform;
+pub mod package_index;
 pub mod project;
+pub mod query;
 pub mod range_util;
 pub mod request_handler;
+pub mod resolve;
 pub mod scope;
 pub mod scope_builder;
+pub mod stdlib;
+pub mod symbol_index;
 
 /// [Url::to_file_path] does not check, for the scheme, so we do manually
 fn to_file_path(uri: &Url) -> anyhow::Result<PathBuf> {
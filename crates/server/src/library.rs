@@ -0,0 +1,494 @@
+//! Indexing of library jars. Class files are read and, if compiled by kotlin, their
+//! `kotlin.Metadata` is decoded to retrieve the kotlin view on the declarations.
+
+pub mod class_file;
+pub mod kotlin_metadata;
+mod protobuf;
+pub mod sources;
+pub mod stub_parser;
+
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use itertools::Itertools;
+use tracing::{debug, trace};
+
+use crate::{
+    scope::fun_decl_scope::Visibility,
+    symbol_index::{Symbol, SymbolKind, SymbolOrigin},
+};
+
+use self::{
+    class_file::{ClassFile, MemberInfo},
+    kotlin_metadata::{
+        flags, KmBuiltins, KmClass, KmClassKind, KmClassifier, KmFunction, KmPackage, KmProperty,
+        KmType, KmValueParameter, KmVisibility, KotlinMetadata,
+    },
+};
+
+/// A declaration read from a library
+#[derive(Debug)]
+pub struct LibrarySymbol {
+    pub origin: LibraryOrigin,
+    /// Internal name of the class file declaring the symbol (e.G. `kotlin/collections/CollectionsKt`)
+    pub class_name: String,
+    /// Name of the source file the class was compiled from
+    pub source_file: Option<String>,
+    pub decl: LibraryDecl,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LibraryOrigin {
+    Jar(PathBuf),
+    /// The stdlib index shipped with kls (see [crate::stdlib])
+    BundledStdlib,
+}
+
+#[derive(Debug)]
+pub enum LibraryDecl {
+    Class(KmClass),
+    Function(KmFunction),
+    Property(KmProperty),
+}
+
+impl LibraryDecl {
+    pub fn flags(&self) -> i32 {
+        match self {
+            LibraryDecl::Class(c) => c.flags,
+            LibraryDecl::Function(fun) => fun.flags,
+            LibraryDecl::Property(p) => p.flags,
+        }
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        match flags::visibility(self.flags()) {
+            KmVisibility::Internal => Visibility::Internal,
+            KmVisibility::Protected => Visibility::Protected,
+            KmVisibility::Private | KmVisibility::PrivateToThis | KmVisibility::Local => {
+                Visibility::Private
+            }
+            KmVisibility::Public => Visibility::Public,
+        }
+    }
+}
+
+impl std::fmt::Display for LibraryDecl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LibraryDecl::Class(c) => write!(f, "{}", c),
+            LibraryDecl::Function(fun) => write!(f, "{}", fun),
+            LibraryDecl::Property(p) => write!(f, "{}", p),
+        }
+    }
+}
+
+/// Reads all class files of the jar at `jar_path` and returns the declared symbols
+pub fn index_jar(jar_path: &Path) -> anyhow::Result<Vec<Symbol>> {
+    debug!("Indexing jar {}", jar_path.display());
+    let mut archive = zip::ZipArchive::new(BufReader::new(File::open(jar_path)?))?;
+    let origin = LibraryOrigin::Jar(jar_path.to_owned());
+
+    let mut symbols = vec![];
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let entry_name = entry.name().to_string();
+        let is_class_file =
+            entry_name.ends_with(".class") && !entry_name.ends_with("module-info.class");
+        let is_builtins_file = entry_name.ends_with(".kotlin_builtins");
+        if !is_class_file && !is_builtins_file {
+            continue;
+        }
+
+        let mut bytes = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut bytes)?;
+        let result = if is_class_file {
+            symbols_of_class_file(&origin, &bytes)
+        } else {
+            symbols_of_builtins_file(&origin, &entry_name, &bytes)
+        };
+        match result {
+            Ok(mut file_symbols) => symbols.append(&mut file_symbols),
+            Err(e) => debug!("Skipping {}: {}", entry_name, e),
+        }
+    }
+
+    debug!(
+        "Indexed {} symbols of {}",
+        symbols.len(),
+        jar_path.display()
+    );
+    Ok(symbols)
+}
+
+/// Creates the symbols of the declarations of a single class file
+pub(crate) struct SymbolFactory<'a> {
+    pub origin: &'a LibraryOrigin,
+    pub class_name: String,
+    pub source_file: Option<String>,
+}
+
+impl<'a> SymbolFactory<'a> {
+    fn symbol(
+        &self,
+        name: String,
+        container: String,
+        kind: SymbolKind,
+        decl: LibraryDecl,
+    ) -> Symbol {
+        Symbol {
+            name,
+            container,
+            kind,
+            visibility: decl.visibility(),
+            origin: SymbolOrigin::Library(Arc::new(LibrarySymbol {
+                origin: self.origin.clone(),
+                class_name: self.class_name.clone(),
+                source_file: self.source_file.clone(),
+                decl,
+            })),
+            is_synthetic: false,
+        }
+    }
+
+    /// Returns the symbols of the class and its members
+    pub fn class_symbols(&self, km_class: KmClass) -> Vec<Symbol> {
+        let class_fq_name = km_class.name.replace('/', ".");
+        let (container, name) = class_fq_name
+            .rsplit_once('.')
+            .map_or((String::new(), class_fq_name.clone()), |(c, n)| {
+                (c.to_string(), n.to_string())
+            });
+
+        let mut symbols = vec![];
+        for function in km_class
+            .functions
+            .iter()
+            .filter(|f| is_member_visible(f.flags))
+        {
+            symbols.push(self.symbol(
+                function.name.clone(),
+                class_fq_name.clone(),
+                SymbolKind::Function,
+                LibraryDecl::Function(function.clone()),
+            ));
+        }
+        for property in km_class
+            .properties
+            .iter()
+            .filter(|p| is_member_visible(p.flags))
+        {
+            symbols.push(self.symbol(
+                property.name.clone(),
+                class_fq_name.clone(),
+                SymbolKind::Property,
+                LibraryDecl::Property(property.clone()),
+            ));
+        }
+        symbols.push(self.symbol(
+            name,
+            container,
+            SymbolKind::Class,
+            LibraryDecl::Class(km_class),
+        ));
+        symbols
+    }
+
+    /// Returns the symbols of the top level declarations of `package`
+    pub fn package_symbols(&self, package: &str, km_package: KmPackage) -> Vec<Symbol> {
+        let functions = km_package
+            .functions
+            .into_iter()
+            .filter(|f| is_member_visible(f.flags))
+            .map(|function| {
+                self.symbol(
+                    function.name.clone(),
+                    package.to_string(),
+                    SymbolKind::Function,
+                    LibraryDecl::Function(function),
+                )
+            });
+        let properties = km_package
+            .properties
+            .into_iter()
+            .filter(|p| is_member_visible(p.flags))
+            .map(|property| {
+                self.symbol(
+                    property.name.clone(),
+                    package.to_string(),
+                    SymbolKind::Property,
+                    LibraryDecl::Property(property),
+                )
+            });
+        functions.chain(properties).collect()
+    }
+}
+
+fn symbols_of_class_file(origin: &LibraryOrigin, bytes: &[u8]) -> anyhow::Result<Vec<Symbol>> {
+    let class_file = ClassFile::parse(bytes)?;
+    trace!("Indexing class {}", class_file.this_class);
+
+    let factory = SymbolFactory {
+        origin,
+        class_name: class_file.this_class.clone(),
+        source_file: class_file.source_file.clone(),
+    };
+
+    let symbols = match KotlinMetadata::of(&class_file)? {
+        Some(KotlinMetadata::Class(km_class)) => {
+            if !is_visible(km_class.flags) {
+                return Ok(vec![]);
+            }
+            factory.class_symbols(km_class)
+        }
+        Some(KotlinMetadata::Package(km_package)) => {
+            let package = class_file
+                .annotation(kotlin_metadata::METADATA_DESCRIPTOR)
+                .and_then(|a| a.element("pn"))
+                .and_then(|pn| pn.as_string())
+                .filter(|pn| !pn.is_empty())
+                .unwrap_or_else(|| class_file.package());
+            factory.package_symbols(&package, km_package)
+        }
+        Some(KotlinMetadata::Other) => vec![],
+        None => {
+            if !class_file.is_public() || is_anonymous_class(&class_file.this_class) {
+                return Ok(vec![]);
+            }
+            factory.class_symbols(java_class_to_km(&class_file))
+        }
+    };
+
+    Ok(symbols)
+}
+
+/// `entry_name` is e.G. `kotlin/collections/collections.kotlin_builtins`
+fn symbols_of_builtins_file(
+    origin: &LibraryOrigin,
+    entry_name: &str,
+    bytes: &[u8],
+) -> anyhow::Result<Vec<Symbol>> {
+    let builtins = KmBuiltins::decode(bytes)?;
+    let package = entry_name
+        .rsplit_once('/')
+        .map_or(String::new(), |(dir, _)| dir.replace('/', "."));
+
+    let mut symbols = SymbolFactory {
+        origin,
+        class_name: entry_name.trim_end_matches(".kotlin_builtins").to_string(),
+        source_file: None,
+    }
+    .package_symbols(&package, builtins.package);
+    for km_class in builtins.classes.into_iter().filter(|c| is_visible(c.flags)) {
+        symbols.extend(
+            SymbolFactory {
+                origin,
+                class_name: km_class.name.clone(),
+                source_file: None,
+            }
+            .class_symbols(km_class),
+        );
+    }
+    Ok(symbols)
+}
+
+/// Returns the types referenced by the signature of `function`
+pub(crate) fn function_types(function: &mut KmFunction) -> Vec<&mut KmType> {
+    function
+        .type_parameters
+        .iter_mut()
+        .flat_map(|tp| tp.upper_bounds.iter_mut())
+        .chain(function.receiver_type.iter_mut())
+        .chain(
+            function.value_parameters.iter_mut().flat_map(|p| {
+                std::iter::once(&mut p.type_).chain(p.vararg_element_type.iter_mut())
+            }),
+        )
+        .chain(std::iter::once(&mut function.return_type))
+        .collect()
+}
+
+/// Returns the types referenced by the signature of `property`
+pub(crate) fn property_types(property: &mut KmProperty) -> Vec<&mut KmType> {
+    property
+        .type_parameters
+        .iter_mut()
+        .flat_map(|tp| tp.upper_bounds.iter_mut())
+        .chain(property.receiver_type.iter_mut())
+        .chain(std::iter::once(&mut property.return_type))
+        .collect()
+}
+
+fn is_visible(flags: i32) -> bool {
+    !matches!(
+        flags::visibility(flags),
+        KmVisibility::Private | KmVisibility::PrivateToThis | KmVisibility::Local
+    )
+}
+
+fn is_member_visible(flags: i32) -> bool {
+    is_visible(flags) && !flags::is_not_declared(flags)
+}
+
+/// Anonymous and local classes are named `Outer$1` / `Outer$1Local`
+fn is_anonymous_class(internal_name: &str) -> bool {
+    internal_name
+        .rsplit('$')
+        .next()
+        .is_some_and(|last| last.starts_with(|c: char| c.is_ascii_digit()))
+        && internal_name.contains('$')
+}
+
+/// Converts a java class to its kotlin view. Types of java declarations are platform types,
+/// so nullability is unknown and they are treated as not nullable
+fn java_class_to_km(class_file: &ClassFile) -> KmClass {
+    use class_file::*;
+
+    // See Flags.java of the kotlin compiler for the bit layout
+    const PUBLIC_VISIBILITY: i32 = 3 << 1;
+    let kind = if class_file.access_flags & ACC_ANNOTATION != 0 {
+        4
+    } else if class_file.access_flags & ACC_INTERFACE != 0 {
+        1
+    } else if class_file.access_flags & ACC_ENUM != 0 {
+        2
+    } else {
+        0
+    };
+    let modality = if class_file.access_flags & ACC_ABSTRACT != 0 {
+        2 << 4
+    } else {
+        0
+    };
+
+    let is_visible_member = |m: &&MemberInfo| {
+        m.access_flags & (ACC_PUBLIC | ACC_PROTECTED) != 0
+            && m.access_flags & (ACC_SYNTHETIC | ACC_BRIDGE) == 0
+    };
+
+    let functions = class_file
+        .methods
+        .iter()
+        .filter(is_visible_member)
+        .filter(|m| !m.name.starts_with('<'))
+        .filter_map(|m| {
+            let (parameter_types, return_type) = parse_method_descriptor(&m.descriptor)?;
+            Some(KmFunction {
+                name: m.name.clone(),
+                flags: PUBLIC_VISIBILITY,
+                type_parameters: vec![],
+                receiver_type: None,
+                value_parameters: parameter_types
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, type_)| KmValueParameter {
+                        name: format!("p{}", i),
+                        flags: 0,
+                        type_,
+                        vararg_element_type: None,
+                    })
+                    .collect(),
+                return_type,
+            })
+        })
+        .collect_vec();
+
+    let properties = class_file
+        .fields
+        .iter()
+        .filter(is_visible_member)
+        .filter_map(|f| {
+            let (return_type, _) = parse_field_descriptor(&f.descriptor)?;
+            Some(KmProperty {
+                name: f.name.clone(),
+                flags: PUBLIC_VISIBILITY | (1 << 8), // var
+                type_parameters: vec![],
+                receiver_type: None,
+                return_type,
+            })
+        })
+        .collect_vec();
+
+    KmClass {
+        name: class_file.this_class.replace('$', "."),
+        flags: PUBLIC_VISIBILITY | modality | (kind << 6),
+        type_parameters: vec![],
+        supertypes: class_file
+            .super_class
+            .iter()
+            .chain(class_file.interfaces.iter())
+            .filter(|name| *name != "java/lang/Object")
+            .map(|name| class_type(name.replace('$', ".")))
+            .collect(),
+        constructors: vec![],
+        functions,
+        properties,
+        enum_entries: vec![],
+        companion_object: None,
+    }
+}
+
+fn class_type(name: String) -> KmType {
+    KmType {
+        classifier: KmClassifier::Class(name),
+        arguments: vec![],
+        nullable: false,
+        flags: 0,
+    }
+}
+
+/// Parses a method descriptor like `(ILjava/lang/String;)V`
+fn parse_method_descriptor(descriptor: &str) -> Option<(Vec<KmType>, KmType)> {
+    let mut rest = descriptor.strip_prefix('(')?;
+    let mut parameters = vec![];
+    while !rest.starts_with(')') {
+        let (type_, remaining) = parse_field_descriptor(rest)?;
+        parameters.push(type_);
+        rest = remaining;
+    }
+    let (return_type, _) = parse_field_descriptor(&rest[1..])?;
+    Some((parameters, return_type))
+}
+
+/// Parses the first type of `descriptor`. Returns the type and the remaining descriptor
+fn parse_field_descriptor(descriptor: &str) -> Option<(KmType, &str)> {
+    let primitive = |name: &str| Some(class_type(format!("kotlin/{}", name)));
+    let (type_, rest) = match descriptor.chars().next()? {
+        'V' => (primitive("Unit")?, &descriptor[1..]),
+        'Z' => (primitive("Boolean")?, &descriptor[1..]),
+        'B' => (primitive("Byte")?, &descriptor[1..]),
+        'C' => (primitive("Char")?, &descriptor[1..]),
+        'S' => (primitive("Short")?, &descriptor[1..]),
+        'I' => (primitive("Int")?, &descriptor[1..]),
+        'J' => (primitive("Long")?, &descriptor[1..]),
+        'F' => (primitive("Float")?, &descriptor[1..]),
+        'D' => (primitive("Double")?, &descriptor[1..]),
+        'L' => {
+            let end = descriptor.find(';')?;
+            let name = match &descriptor[1..end] {
+                "java/lang/Object" => "kotlin/Any".to_string(),
+                "java/lang/String" => "kotlin/String".to_string(),
+                name => name.replace('$', "."),
+            };
+            (class_type(name), &descriptor[end + 1..])
+        }
+        '[' => {
+            let (element, rest) = parse_field_descriptor(&descriptor[1..])?;
+            let array = KmType {
+                classifier: KmClassifier::Class("kotlin/Array".to_string()),
+                arguments: vec![kotlin_metadata::KmTypeProjection::Projection {
+                    variance: kotlin_metadata::KmVariance::Invariant,
+                    type_: element,
+                }],
+                nullable: false,
+                flags: 0,
+            };
+            (array, rest)
+        }
+        _ => return None,
+    };
+    Some((type_, rest))
+}
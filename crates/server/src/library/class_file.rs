@@ -0,0 +1,413 @@
+//! Minimal reader for jvm class files. Only the parts required for indexing are read:
+//! The constant pool, class / member names and descriptors and annotations.
+//! See https://docs.oracle.com/javase/specs/jvms/se21/html/jvms-4.html
+
+use anyhow::{anyhow, bail, ensure};
+
+pub const ACC_PUBLIC: u16 = 0x0001;
+pub const ACC_PRIVATE: u16 = 0x0002;
+pub const ACC_PROTECTED: u16 = 0x0004;
+pub const ACC_STATIC: u16 = 0x0008;
+pub const ACC_BRIDGE: u16 = 0x0040;
+pub const ACC_INTERFACE: u16 = 0x0200;
+pub const ACC_ABSTRACT: u16 = 0x0400;
+pub const ACC_SYNTHETIC: u16 = 0x1000;
+pub const ACC_ANNOTATION: u16 = 0x2000;
+pub const ACC_ENUM: u16 = 0x4000;
+
+#[derive(Debug)]
+pub struct ClassFile {
+    pub access_flags: u16,
+    /// Internal name (e.G. `kotlin/collections/CollectionsKt`)
+    pub this_class: String,
+    pub super_class: Option<String>,
+    pub interfaces: Vec<String>,
+    pub fields: Vec<MemberInfo>,
+    pub methods: Vec<MemberInfo>,
+    pub annotations: Vec<Annotation>,
+    /// Name of the source file the class was compiled from (e.G. `Collections.kt`)
+    pub source_file: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct MemberInfo {
+    pub access_flags: u16,
+    pub name: String,
+    pub descriptor: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Annotation {
+    /// Field descriptor of the annotation type (e.G. `Lkotlin/Metadata;`)
+    pub type_descriptor: String,
+    pub elements: Vec<(String, ElementValue)>,
+}
+
+impl Annotation {
+    pub fn element(&self, name: &str) -> Option<&ElementValue> {
+        self.elements
+            .iter()
+            .find(|(element_name, _)| element_name == name)
+            .map(|(_, value)| value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ElementValue {
+    Int(i32),
+    Long(i64),
+    /// Strings are kept as utf16 code units, as strings within annotations may
+    /// contain arbitrary (binary) chars (see `kotlin.Metadata.d1`)
+    String(Vec<u16>),
+    Enum {
+        type_descriptor: String,
+        name: String,
+    },
+    Class(String),
+    Annotation(Annotation),
+    Array(Vec<ElementValue>),
+    /// Float / Double / other constants, which are not needed
+    Other,
+}
+
+impl ElementValue {
+    pub fn as_int(&self) -> Option<i32> {
+        match self {
+            ElementValue::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_string(&self) -> Option<String> {
+        match self {
+            ElementValue::String(v) => Some(String::from_utf16_lossy(v)),
+            _ => None,
+        }
+    }
+
+    pub fn as_utf16_array(&self) -> Vec<&[u16]> {
+        match self {
+            ElementValue::Array(values) => values
+                .iter()
+                .filter_map(|v| match v {
+                    ElementValue::String(s) => Some(s.as_slice()),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn as_int_array(&self) -> Vec<i32> {
+        match self {
+            ElementValue::Array(values) => values.iter().filter_map(|v| v.as_int()).collect(),
+            _ => vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Constant {
+    Unusable,
+    Utf8(Vec<u16>),
+    Integer(i32),
+    Long(i64),
+    Class { name_index: u16 },
+    Other,
+}
+
+impl ClassFile {
+    pub fn parse(bytes: &[u8]) -> anyhow::Result<ClassFile> {
+        let mut reader = ByteReader { bytes, pos: 0 };
+        ensure!(
+            reader.u4()? == 0xCAFEBABE,
+            "Not a class file (invalid magic)"
+        );
+        let _minor = reader.u2()?;
+        let _major = reader.u2()?;
+
+        let constant_pool = read_constant_pool(&mut reader)?;
+        let pool = ConstantPool(constant_pool);
+
+        let access_flags = reader.u2()?;
+        let this_class = pool.class_name(reader.u2()?)?;
+        let super_class_index = reader.u2()?;
+        let super_class = if super_class_index == 0 {
+            None
+        } else {
+            Some(pool.class_name(super_class_index)?)
+        };
+
+        let interfaces_count = reader.u2()?;
+        let mut interfaces = Vec::with_capacity(interfaces_count as usize);
+        for _ in 0..interfaces_count {
+            interfaces.push(pool.class_name(reader.u2()?)?);
+        }
+
+        let fields = read_members(&mut reader, &pool)?;
+        let methods = read_members(&mut reader, &pool)?;
+
+        let mut annotations = vec![];
+        let mut source_file = None;
+        let attributes_count = reader.u2()?;
+        for _ in 0..attributes_count {
+            let name = pool.utf8(reader.u2()?)?;
+            let length = reader.u4()? as usize;
+            let attribute_bytes = reader.bytes(length)?;
+            if name == "RuntimeVisibleAnnotations" {
+                let mut attribute_reader = ByteReader {
+                    bytes: attribute_bytes,
+                    pos: 0,
+                };
+                let count = attribute_reader.u2()?;
+                for _ in 0..count {
+                    annotations.push(read_annotation(&mut attribute_reader, &pool)?);
+                }
+            } else if name == "SourceFile" && attribute_bytes.len() == 2 {
+                let index = u16::from_be_bytes([attribute_bytes[0], attribute_bytes[1]]);
+                source_file = Some(pool.utf8(index)?);
+            }
+        }
+
+        Ok(ClassFile {
+            access_flags,
+            this_class,
+            super_class,
+            interfaces,
+            fields,
+            methods,
+            annotations,
+            source_file,
+        })
+    }
+
+    pub fn annotation(&self, type_descriptor: &str) -> Option<&Annotation> {
+        self.annotations
+            .iter()
+            .find(|a| a.type_descriptor == type_descriptor)
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.access_flags & ACC_PUBLIC != 0
+    }
+
+    /// The package of the class in dot notation (e.G. `kotlin.collections`)
+    pub fn package(&self) -> String {
+        match self.this_class.rfind('/') {
+            Some(i) => self.this_class[..i].replace('/', "."),
+            None => String::new(),
+        }
+    }
+}
+
+fn read_constant_pool(reader: &mut ByteReader) -> anyhow::Result<Vec<Constant>> {
+    let count = reader.u2()? as usize;
+    let mut constants = Vec::with_capacity(count);
+    // index 0 is not used
+    constants.push(Constant::Unusable);
+
+    while constants.len() < count {
+        let tag = reader.u1()?;
+        let constant = match tag {
+            1 => {
+                let length = reader.u2()? as usize;
+                Constant::Utf8(decode_modified_utf8(reader.bytes(length)?))
+            }
+            3 => Constant::Integer(reader.u4()? as i32),
+            4 => {
+                reader.u4()?;
+                Constant::Other
+            }
+            5 => Constant::Long(((reader.u4()? as u64) << 32 | reader.u4()? as u64) as i64),
+            6 => {
+                reader.u4()?;
+                reader.u4()?;
+                Constant::Other
+            }
+            7 => Constant::Class {
+                name_index: reader.u2()?,
+            },
+            8 | 16 | 19 | 20 => {
+                reader.u2()?;
+                Constant::Other
+            }
+            9 | 10 | 11 | 12 | 17 | 18 => {
+                reader.u4()?;
+                Constant::Other
+            }
+            15 => {
+                reader.u1()?;
+                reader.u2()?;
+                Constant::Other
+            }
+            _ => bail!("Unknown constant pool tag {}", tag),
+        };
+        let takes_two_slots = matches!(tag, 5 | 6);
+        constants.push(constant);
+        if takes_two_slots {
+            constants.push(Constant::Unusable);
+        }
+    }
+
+    Ok(constants)
+}
+
+fn read_members(reader: &mut ByteReader, pool: &ConstantPool) -> anyhow::Result<Vec<MemberInfo>> {
+    let count = reader.u2()?;
+    let mut members = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let access_flags = reader.u2()?;
+        let name = pool.utf8(reader.u2()?)?;
+        let descriptor = pool.utf8(reader.u2()?)?;
+        let attributes_count = reader.u2()?;
+        for _ in 0..attributes_count {
+            reader.u2()?;
+            let length = reader.u4()? as usize;
+            reader.bytes(length)?;
+        }
+        members.push(MemberInfo {
+            access_flags,
+            name,
+            descriptor,
+        });
+    }
+    Ok(members)
+}
+
+fn read_annotation(reader: &mut ByteReader, pool: &ConstantPool) -> anyhow::Result<Annotation> {
+    let type_descriptor = pool.utf8(reader.u2()?)?;
+    let pairs = reader.u2()?;
+    let mut elements = Vec::with_capacity(pairs as usize);
+    for _ in 0..pairs {
+        let name = pool.utf8(reader.u2()?)?;
+        elements.push((name, read_element_value(reader, pool)?));
+    }
+    Ok(Annotation {
+        type_descriptor,
+        elements,
+    })
+}
+
+fn read_element_value(
+    reader: &mut ByteReader,
+    pool: &ConstantPool,
+) -> anyhow::Result<ElementValue> {
+    let tag = reader.u1()?;
+    let value = match tag {
+        b'B' | b'C' | b'I' | b'S' | b'Z' => match pool.get(reader.u2()?)? {
+            Constant::Integer(v) => ElementValue::Int(*v),
+            c => bail!("Expected integer constant, got {:?}", c),
+        },
+        b'J' => match pool.get(reader.u2()?)? {
+            Constant::Long(v) => ElementValue::Long(*v),
+            c => bail!("Expected long constant, got {:?}", c),
+        },
+        b'D' | b'F' => {
+            reader.u2()?;
+            ElementValue::Other
+        }
+        b's' => ElementValue::String(pool.utf16(reader.u2()?)?.to_vec()),
+        b'e' => ElementValue::Enum {
+            type_descriptor: pool.utf8(reader.u2()?)?,
+            name: pool.utf8(reader.u2()?)?,
+        },
+        b'c' => ElementValue::Class(pool.utf8(reader.u2()?)?),
+        b'@' => ElementValue::Annotation(read_annotation(reader, pool)?),
+        b'[' => {
+            let count = reader.u2()?;
+            let mut values = Vec::with_capacity(count as usize);
+            for _ in 0..count {
+                values.push(read_element_value(reader, pool)?);
+            }
+            ElementValue::Array(values)
+        }
+        _ => bail!("Unknown element value tag {}", tag),
+    };
+    Ok(value)
+}
+
+/// Decodes the "modified utf8" used by class files into utf16 code units
+/// See https://docs.oracle.com/javase/specs/jvms/se21/html/jvms-4.html#jvms-4.4.7
+fn decode_modified_utf8(bytes: &[u8]) -> Vec<u16> {
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i] as u16;
+        if b & 0x80 == 0 {
+            result.push(b);
+            i += 1;
+        } else if b & 0xE0 == 0xC0 && i + 1 < bytes.len() {
+            result.push(((b & 0x1F) << 6) | (bytes[i + 1] as u16 & 0x3F));
+            i += 2;
+        } else if b & 0xF0 == 0xE0 && i + 2 < bytes.len() {
+            result.push(
+                ((b & 0x0F) << 12)
+                    | ((bytes[i + 1] as u16 & 0x3F) << 6)
+                    | (bytes[i + 2] as u16 & 0x3F),
+            );
+            i += 3;
+        } else {
+            // Invalid encoding. Keep the byte, so that offsets stay intact
+            result.push(b);
+            i += 1;
+        }
+    }
+    result
+}
+
+struct ConstantPool(Vec<Constant>);
+
+impl ConstantPool {
+    fn get(&self, index: u16) -> anyhow::Result<&Constant> {
+        self.0
+            .get(index as usize)
+            .ok_or_else(|| anyhow!("Constant pool index {} out of bounds", index))
+    }
+
+    fn utf16(&self, index: u16) -> anyhow::Result<&[u16]> {
+        match self.get(index)? {
+            Constant::Utf8(v) => Ok(v),
+            c => bail!("Expected utf8 constant at {}, got {:?}", index, c),
+        }
+    }
+
+    fn utf8(&self, index: u16) -> anyhow::Result<String> {
+        Ok(String::from_utf16_lossy(self.utf16(index)?))
+    }
+
+    fn class_name(&self, index: u16) -> anyhow::Result<String> {
+        match self.get(index)? {
+            Constant::Class { name_index } => self.utf8(*name_index),
+            c => bail!("Expected class constant at {}, got {:?}", index, c),
+        }
+    }
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn bytes(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        let end = self.pos + len;
+        ensure!(end <= self.bytes.len(), "Unexpected end of class file");
+        let result = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(result)
+    }
+
+    fn u1(&mut self) -> anyhow::Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u2(&mut self) -> anyhow::Result<u16> {
+        let b = self.bytes(2)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn u4(&mut self) -> anyhow::Result<u32> {
+        let b = self.bytes(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+}
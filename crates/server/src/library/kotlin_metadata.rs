@@ -0,0 +1,1049 @@
+//! Decoding of the `kotlin.Metadata` annotation, which the kotlin compiler adds to every class file.
+//! It preserves kotlin specific information (nullability, extension receivers, default arguments,
+//! top level declarations, ...) which is not representable in plain jvm class files.
+//!
+//! The `d1` array contains protobuf messages (see `metadata.proto` and `jvm_metadata.proto` of
+//! the kotlin compiler), `d2` the strings referenced by the messages.
+
+use std::fmt;
+
+use anyhow::{anyhow, bail, ensure};
+use itertools::Itertools;
+
+use super::class_file::{Annotation, ClassFile};
+use super::protobuf::Message;
+
+pub const METADATA_DESCRIPTOR: &str = "Lkotlin/Metadata;";
+
+/// Strings not stored in `d2`, but referenced by index
+const PREDEFINED_STRINGS: [&str; 44] = [
+    "kotlin/Any",
+    "kotlin/Nothing",
+    "kotlin/Unit",
+    "kotlin/Throwable",
+    "kotlin/Number",
+    "kotlin/Byte",
+    "kotlin/Double",
+    "kotlin/Float",
+    "kotlin/Int",
+    "kotlin/Long",
+    "kotlin/Short",
+    "kotlin/Boolean",
+    "kotlin/Char",
+    "kotlin/CharSequence",
+    "kotlin/String",
+    "kotlin/Comparable",
+    "kotlin/Enum",
+    "kotlin/Array",
+    "kotlin/ByteArray",
+    "kotlin/DoubleArray",
+    "kotlin/FloatArray",
+    "kotlin/IntArray",
+    "kotlin/LongArray",
+    "kotlin/ShortArray",
+    "kotlin/BooleanArray",
+    "kotlin/CharArray",
+    "kotlin/Cloneable",
+    "kotlin/Annotation",
+    "kotlin/collections/Iterable",
+    "kotlin/collections/MutableIterable",
+    "kotlin/collections/Collection",
+    "kotlin/collections/MutableCollection",
+    "kotlin/collections/List",
+    "kotlin/collections/MutableList",
+    "kotlin/collections/Set",
+    "kotlin/collections/MutableSet",
+    "kotlin/collections/Map",
+    "kotlin/collections/MutableMap",
+    "kotlin/collections/Map.Entry",
+    "kotlin/collections/MutableMap.MutableEntry",
+    "kotlin/collections/Iterator",
+    "kotlin/collections/MutableIterator",
+    "kotlin/collections/ListIterator",
+    "kotlin/collections/MutableListIterator",
+];
+
+/// The decoded content of a `kotlin.Metadata` annotation
+#[derive(Debug, Clone)]
+pub enum KotlinMetadata {
+    Class(KmClass),
+    /// A file facade (`k = 2`) or a part of a multi file facade (`k = 5`)
+    Package(KmPackage),
+    /// Synthetic classes (lambdas, ...) and multi file facades. They declare nothing themselves
+    Other,
+}
+
+#[derive(Debug, Clone)]
+pub struct KmClass {
+    /// Class name in the form `a/b/Outer.Inner`
+    pub name: String,
+    pub flags: i32,
+    pub type_parameters: Vec<KmTypeParameter>,
+    pub supertypes: Vec<KmType>,
+    pub constructors: Vec<KmConstructor>,
+    pub functions: Vec<KmFunction>,
+    pub properties: Vec<KmProperty>,
+    pub enum_entries: Vec<String>,
+    pub companion_object: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct KmPackage {
+    pub functions: Vec<KmFunction>,
+    pub properties: Vec<KmProperty>,
+}
+
+#[derive(Debug, Clone)]
+pub struct KmConstructor {
+    pub flags: i32,
+    pub value_parameters: Vec<KmValueParameter>,
+}
+
+#[derive(Debug, Clone)]
+pub struct KmFunction {
+    pub name: String,
+    pub flags: i32,
+    pub type_parameters: Vec<KmTypeParameter>,
+    pub receiver_type: Option<KmType>,
+    pub value_parameters: Vec<KmValueParameter>,
+    pub return_type: KmType,
+}
+
+#[derive(Debug, Clone)]
+pub struct KmProperty {
+    pub name: String,
+    pub flags: i32,
+    pub type_parameters: Vec<KmTypeParameter>,
+    pub receiver_type: Option<KmType>,
+    pub return_type: KmType,
+}
+
+#[derive(Debug, Clone)]
+pub struct KmValueParameter {
+    pub name: String,
+    pub flags: i32,
+    pub type_: KmType,
+    pub vararg_element_type: Option<KmType>,
+}
+
+#[derive(Debug, Clone)]
+pub struct KmTypeParameter {
+    pub id: i32,
+    pub name: String,
+    pub variance: KmVariance,
+    pub reified: bool,
+    pub upper_bounds: Vec<KmType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KmType {
+    pub classifier: KmClassifier,
+    pub arguments: Vec<KmTypeProjection>,
+    pub nullable: bool,
+    pub flags: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KmClassifier {
+    /// Class name in the form `a/b/Outer.Inner`
+    Class(String),
+    TypeParameter(String),
+    TypeAlias(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KmTypeProjection {
+    Star,
+    Projection { variance: KmVariance, type_: KmType },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KmVariance {
+    In,
+    Out,
+    Invariant,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KmVisibility {
+    Internal,
+    Private,
+    Protected,
+    Public,
+    PrivateToThis,
+    Local,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KmClassKind {
+    Class,
+    Interface,
+    EnumClass,
+    EnumEntry,
+    AnnotationClass,
+    Object,
+    CompanionObject,
+}
+
+/// Accessors for the flags of declarations. See `Flags.java` of the kotlin compiler
+pub mod flags {
+    use super::{KmClassKind, KmVisibility};
+
+    fn bits(flags: i32, offset: u32, count: u32) -> i32 {
+        (flags >> offset) & ((1 << count) - 1)
+    }
+
+    fn bit(flags: i32, offset: u32) -> bool {
+        bits(flags, offset, 1) == 1
+    }
+
+    pub fn visibility(flags: i32) -> KmVisibility {
+        match bits(flags, 1, 3) {
+            0 => KmVisibility::Internal,
+            1 => KmVisibility::Private,
+            2 => KmVisibility::Protected,
+            4 => KmVisibility::PrivateToThis,
+            5 => KmVisibility::Local,
+            _ => KmVisibility::Public,
+        }
+    }
+
+    pub fn class_kind(flags: i32) -> KmClassKind {
+        match bits(flags, 6, 3) {
+            1 => KmClassKind::Interface,
+            2 => KmClassKind::EnumClass,
+            3 => KmClassKind::EnumEntry,
+            4 => KmClassKind::AnnotationClass,
+            5 => KmClassKind::Object,
+            6 => KmClassKind::CompanionObject,
+            _ => KmClassKind::Class,
+        }
+    }
+
+    pub fn is_data_class(flags: i32) -> bool {
+        bit(flags, 10)
+    }
+
+    /// Member kind of functions and properties is `FAKE_OVERRIDE`, `DELEGATION` or `SYNTHESIZED`
+    pub fn is_not_declared(flags: i32) -> bool {
+        bits(flags, 6, 2) != 0
+    }
+
+    pub fn is_operator(flags: i32) -> bool {
+        bit(flags, 8)
+    }
+
+    pub fn is_infix(flags: i32) -> bool {
+        bit(flags, 9)
+    }
+
+    pub fn is_inline(flags: i32) -> bool {
+        bit(flags, 10)
+    }
+
+    pub fn is_suspend(flags: i32) -> bool {
+        bit(flags, 13)
+    }
+
+    pub fn is_var(flags: i32) -> bool {
+        bit(flags, 8)
+    }
+
+    pub fn is_const(flags: i32) -> bool {
+        bit(flags, 11)
+    }
+
+    pub fn declares_default_value(flags: i32) -> bool {
+        bit(flags, 1)
+    }
+
+    pub fn is_suspend_type(flags: i32) -> bool {
+        bit(flags, 0)
+    }
+}
+
+impl KotlinMetadata {
+    /// Reads the metadata of `class_file`. Returns None for classes not compiled by kotlin
+    pub fn of(class_file: &ClassFile) -> anyhow::Result<Option<KotlinMetadata>> {
+        let Some(annotation) = class_file.annotation(METADATA_DESCRIPTOR) else {
+            return Ok(None);
+        };
+        Ok(Some(Self::decode(annotation)?))
+    }
+
+    fn decode(annotation: &Annotation) -> anyhow::Result<KotlinMetadata> {
+        let kind = annotation
+            .element("k")
+            .and_then(|k| k.as_int())
+            .unwrap_or(1);
+        if !matches!(kind, 1 | 2 | 5) {
+            return Ok(KotlinMetadata::Other);
+        }
+
+        let d1 = annotation
+            .element("d1")
+            .map(|d1| d1.as_utf16_array())
+            .unwrap_or_default();
+        let d2 = annotation
+            .element("d2")
+            .map(|d2| {
+                d2.as_utf16_array()
+                    .into_iter()
+                    .map(String::from_utf16_lossy)
+                    .collect_vec()
+            })
+            .unwrap_or_default();
+
+        let bytes = decode_bytes(&d1);
+        let (string_table_types, rest) = Message::parse_delimited(&bytes)?;
+        let strings = NameResolver::new(&string_table_types, d2)?;
+        let message = Message::parse(rest)?;
+
+        if kind == 1 {
+            Ok(KotlinMetadata::Class(decode_class(&message, &strings)?))
+        } else {
+            Ok(KotlinMetadata::Package(decode_package(&message, &strings)?))
+        }
+    }
+}
+
+/// The declarations of a `.kotlin_builtins` file. Builtins (e.G. `kotlin.String`,
+/// `kotlin.collections.List`) are mapped to java classes, so no class files exist for them
+#[derive(Debug, Clone, Default)]
+pub struct KmBuiltins {
+    pub classes: Vec<KmClass>,
+    pub package: KmPackage,
+}
+
+impl KmBuiltins {
+    /// Decodes the content of a `.kotlin_builtins` file. See `BuiltInsBinaryVersion.kt` and
+    /// `BuiltInsProtoBuf` of the kotlin compiler
+    pub fn decode(bytes: &[u8]) -> anyhow::Result<KmBuiltins> {
+        let version_len = bytes
+            .get(..4)
+            .ok_or_else(|| anyhow!("Builtins file without version"))?;
+        let version_len = u32::from_be_bytes(version_len.try_into()?) as usize;
+        let fragment = bytes
+            .get(4 + 4 * version_len..)
+            .ok_or_else(|| anyhow!("Builtins file without package fragment"))?;
+
+        let fragment = Message::parse(fragment)?;
+        let strings = NameResolver::new_builtins(
+            &fragment.message(1)?.unwrap_or_default(),
+            &fragment.message(2)?.unwrap_or_default(),
+        )?;
+
+        Ok(KmBuiltins {
+            classes: decode_all(&fragment.messages(4)?, |c| decode_class(c, &strings)),
+            package: match fragment.message(3)? {
+                Some(package) => decode_package(&package, &strings)?,
+                None => KmPackage::default(),
+            },
+        })
+    }
+}
+
+/// Decodes the strings of `d1` into bytes. See `BitEncoding.java` of the kotlin compiler
+pub fn decode_bytes(data: &[&[u16]]) -> Vec<u8> {
+    const UTF8_MODE_MARKER: u16 = 0;
+    const EIGHT_TO_SEVEN_MODE_MARKER: u16 = 0xFFFF;
+
+    let first = data.first().and_then(|s| s.first()).copied();
+    let chars = data.iter().flat_map(|s| s.iter().copied());
+
+    if first == Some(UTF8_MODE_MARKER) {
+        return chars.skip(1).map(|c| c as u8).collect();
+    }
+
+    let skip = if first == Some(EIGHT_TO_SEVEN_MODE_MARKER) {
+        1
+    } else {
+        0
+    };
+    let bytes = chars
+        .skip(skip)
+        .map(|c| ((c as u8 as u32 + 0x7F) & 0x7F) as u8)
+        .collect_vec();
+    decode_7_to_8(&bytes)
+}
+
+fn decode_7_to_8(data: &[u8]) -> Vec<u8> {
+    let result_len = 7 * data.len() / 8;
+    let mut result = Vec::with_capacity(result_len);
+    let mut byte_index = 0;
+    let mut bit = 0;
+    for _ in 0..result_len {
+        let first_part = (data[byte_index] as u32) >> bit;
+        byte_index += 1;
+        let second_part = (data[byte_index] as u32 & ((1 << (bit + 1)) - 1)) << (7 - bit);
+        result.push((first_part + second_part) as u8);
+        if bit == 6 {
+            byte_index += 1;
+            bit = 0;
+        } else {
+            bit += 1;
+        }
+    }
+    result
+}
+
+/// Resolves string ids of the protobuf messages
+enum NameResolver {
+    /// Names of class files. See `JvmNameResolverBase.kt` of the kotlin compiler
+    Jvm {
+        /// One record per string
+        records: Vec<StringRecord>,
+        strings: Vec<String>,
+    },
+    /// Names of `.kotlin_builtins` files. See `NameResolverImpl.kt` of the kotlin compiler
+    Builtins {
+        strings: Vec<String>,
+        qualified_names: Vec<QualifiedName>,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct QualifiedName {
+    parent: i32,
+    short_name: i32,
+    is_class: bool,
+}
+
+#[derive(Debug, Clone)]
+struct StringRecord {
+    predefined_index: Option<i32>,
+    string: Option<String>,
+    operation: i32,
+    substring_index: Vec<i32>,
+    replace_char: Vec<i32>,
+}
+
+impl NameResolver {
+    fn new(types: &Message, strings: Vec<String>) -> anyhow::Result<Self> {
+        let mut records = vec![];
+        for record in types.messages(1)? {
+            // Records describe the strings by index, so there is at most one for each string
+            let range = usize::try_from(record.int32(1).unwrap_or(1))?;
+            ensure!(
+                range <= strings.len().saturating_sub(records.len()),
+                "String record range {} exceeds the {} strings",
+                range,
+                strings.len()
+            );
+            let string_record = StringRecord {
+                predefined_index: record.int32(2),
+                string: record.string(6),
+                operation: record.int32(3).unwrap_or(0),
+                substring_index: record.int32s(4)?,
+                replace_char: record.int32s(5)?,
+            };
+            records.extend(std::iter::repeat_n(string_record, range));
+        }
+        Ok(NameResolver::Jvm { records, strings })
+    }
+
+    fn new_builtins(strings: &Message, qualified_names: &Message) -> anyhow::Result<Self> {
+        let strings = strings.strings(1);
+        let qualified_names = qualified_names
+            .messages(1)?
+            .iter()
+            .map(|name| QualifiedName {
+                parent: name.int32(1).unwrap_or(-1),
+                short_name: name.int32(2).unwrap_or_default(),
+                // Kind CLASS = 0, PACKAGE = 1 (default), LOCAL = 2
+                is_class: name.int32(3).unwrap_or(1) != 1,
+            })
+            .collect_vec();
+        Ok(NameResolver::Builtins {
+            strings,
+            qualified_names,
+        })
+    }
+
+    fn string(&self, index: i32) -> anyhow::Result<String> {
+        let (records, strings) = match self {
+            NameResolver::Jvm { records, strings } => (records, strings),
+            NameResolver::Builtins { strings, .. } => {
+                return strings
+                    .get(usize::try_from(index)?)
+                    .cloned()
+                    .ok_or_else(|| anyhow!("String index {} out of bounds", index))
+            }
+        };
+        let index = usize::try_from(index)?;
+        let record = records.get(index);
+
+        let mut string = match record {
+            Some(StringRecord {
+                string: Some(string),
+                ..
+            }) => string.clone(),
+            Some(StringRecord {
+                predefined_index: Some(i),
+                ..
+            }) if (*i as usize) < PREDEFINED_STRINGS.len() => {
+                PREDEFINED_STRINGS[*i as usize].to_string()
+            }
+            _ => strings
+                .get(index)
+                .cloned()
+                .ok_or_else(|| anyhow!("String index {} out of bounds", index))?,
+        };
+        let Some(record) = record else {
+            return Ok(string);
+        };
+
+        if let [begin, end, ..] = record.substring_index[..] {
+            let chars = string.chars().collect_vec();
+            if 0 <= begin && begin <= end && (end as usize) <= chars.len() {
+                string = chars[begin as usize..end as usize].iter().collect();
+            }
+        }
+        if let [from, to, ..] = record.replace_char[..] {
+            if let (Some(from), Some(to)) = (char::from_u32(from as u32), char::from_u32(to as u32))
+            {
+                string = string.replace(from, &to.to_string());
+            }
+        }
+        match record.operation {
+            // INTERNAL_TO_CLASS_ID
+            1 => string = string.replace('$', "."),
+            // DESC_TO_CLASS_ID
+            2 => {
+                let class_id = string
+                    .strip_prefix('L')
+                    .and_then(|string| string.strip_suffix(';'))
+                    .unwrap_or(&string);
+                string = class_id.replace('$', ".");
+            }
+            _ => {}
+        }
+        Ok(string)
+    }
+}
+
+impl NameResolver {
+    /// Returns the class name in the form `a/b/Outer.Inner`
+    fn class_name(&self, index: i32) -> anyhow::Result<String> {
+        let NameResolver::Builtins {
+            qualified_names, ..
+        } = self
+        else {
+            return self.string(index);
+        };
+
+        let mut package = vec![];
+        let mut classes = vec![];
+        let mut current = index;
+        while current >= 0 {
+            // Each name is visited at most once, unless the parents form a cycle
+            ensure!(
+                package.len() + classes.len() < qualified_names.len(),
+                "Qualified name {} has cyclic parents",
+                index
+            );
+            let name = qualified_names
+                .get(usize::try_from(current)?)
+                .ok_or_else(|| anyhow!("Qualified name index {} out of bounds", current))?;
+            let short_name = self.string(name.short_name)?;
+            if name.is_class {
+                classes.push(short_name);
+            } else {
+                package.push(short_name);
+            }
+            current = name.parent;
+        }
+        package.reverse();
+        classes.reverse();
+
+        let classes = classes.join(".");
+        if package.is_empty() {
+            Ok(classes)
+        } else {
+            Ok(format!("{}/{}", package.join("/"), classes))
+        }
+    }
+}
+
+/// Context required to decode the declarations within a class or package
+#[derive(Clone)]
+struct DecodeContext<'a> {
+    strings: &'a NameResolver,
+    type_table: Vec<Message<'a>>,
+    first_nullable: i32,
+    type_parameters: Vec<(i32, String)>,
+}
+
+impl<'a> DecodeContext<'a> {
+    fn new(strings: &'a NameResolver, owner: &Message<'a>) -> anyhow::Result<Self> {
+        let mut context = DecodeContext {
+            strings,
+            type_table: vec![],
+            first_nullable: -1,
+            type_parameters: vec![],
+        };
+        context.set_type_table(owner)?;
+        Ok(context)
+    }
+
+    fn set_type_table(&mut self, owner: &Message<'a>) -> anyhow::Result<()> {
+        if let Some(type_table) = owner.message(30)? {
+            self.type_table = type_table.messages(1)?;
+            self.first_nullable = type_table.int32(2).unwrap_or(-1);
+        }
+        Ok(())
+    }
+
+    /// Returns a context, which additionally knows the type parameters declared by `owner`
+    fn with_type_parameters(
+        &self,
+        owner: &Message<'a>,
+        field: u32,
+    ) -> anyhow::Result<(Self, Vec<KmTypeParameter>)> {
+        let mut context = self.clone();
+        context.set_type_table(owner)?;
+        let raw = owner.messages(field)?;
+        for type_parameter in &raw {
+            if let (Some(id), Some(name)) = (type_parameter.int32(1), type_parameter.int32(2)) {
+                context
+                    .type_parameters
+                    .push((id, context.strings.string(name)?));
+            }
+        }
+        let type_parameters = raw
+            .iter()
+            .map(|t| context.type_parameter(t))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok((context, type_parameters))
+    }
+
+    fn type_parameter(&self, message: &Message<'a>) -> anyhow::Result<KmTypeParameter> {
+        let id = message.int32(1).unwrap_or_default();
+        let name = self.strings.string(message.int32(2).unwrap_or_default())?;
+        let mut upper_bounds = message
+            .messages(5)?
+            .iter()
+            .map(|t| self.type_(t, false))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        for id in message.int32s(6)? {
+            upper_bounds.push(self.type_by_id(id)?);
+        }
+        Ok(KmTypeParameter {
+            id,
+            name,
+            variance: variance_of(message.int32(4).unwrap_or(2)),
+            reified: message.bool(3),
+            upper_bounds,
+        })
+    }
+
+    /// Reads the type stored either inline in `inline_field` or by reference in `id_field`
+    fn type_field(
+        &self,
+        message: &Message<'a>,
+        inline_field: u32,
+        id_field: u32,
+    ) -> anyhow::Result<Option<KmType>> {
+        if let Some(t) = message.message(inline_field)? {
+            return Ok(Some(self.type_(&t, false)?));
+        }
+        match message.int32(id_field) {
+            Some(id) => Ok(Some(self.type_by_id(id)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn type_by_id(&self, id: i32) -> anyhow::Result<KmType> {
+        let t = self
+            .type_table
+            .get(usize::try_from(id)?)
+            .ok_or_else(|| anyhow!("Type id {} not in type table", id))?;
+        self.type_(t, self.first_nullable >= 0 && id >= self.first_nullable)
+    }
+
+    fn type_(&self, message: &Message<'a>, nullable_by_table: bool) -> anyhow::Result<KmType> {
+        let classifier = if let Some(class_name) = message.int32(6) {
+            KmClassifier::Class(self.strings.class_name(class_name)?)
+        } else if let Some(id) = message.int32(7) {
+            let name = self
+                .type_parameters
+                .iter()
+                .find(|(tp_id, _)| *tp_id == id)
+                .map_or_else(|| format!("T{}", id), |(_, name)| name.clone());
+            KmClassifier::TypeParameter(name)
+        } else if let Some(name) = message.int32(9) {
+            KmClassifier::TypeParameter(self.strings.string(name)?)
+        } else if let Some(name) = message.int32(12) {
+            KmClassifier::TypeAlias(self.strings.class_name(name)?)
+        } else {
+            bail!("Type without classifier")
+        };
+
+        let mut arguments = vec![];
+        for argument in message.messages(2)? {
+            let projection = argument.int32(1).unwrap_or(2);
+            if projection == 3 {
+                arguments.push(KmTypeProjection::Star);
+                continue;
+            }
+            let type_ = self
+                .type_field(&argument, 2, 3)?
+                .ok_or_else(|| anyhow!("Type argument without type"))?;
+            arguments.push(KmTypeProjection::Projection {
+                variance: variance_of(projection),
+                type_,
+            });
+        }
+
+        Ok(KmType {
+            classifier,
+            arguments,
+            nullable: message.bool(3) || nullable_by_table,
+            flags: message.int32(1).unwrap_or_default(),
+        })
+    }
+
+    fn function(&self, message: &Message<'a>) -> anyhow::Result<KmFunction> {
+        let (context, type_parameters) = self.with_type_parameters(message, 4)?;
+        Ok(KmFunction {
+            name: context
+                .strings
+                .string(message.int32(2).unwrap_or_default())?,
+            flags: message.int32(9).unwrap_or(6),
+            type_parameters,
+            receiver_type: context.type_field(message, 5, 8)?,
+            value_parameters: context.value_parameters(message, 6)?,
+            return_type: context
+                .type_field(message, 3, 7)?
+                .ok_or_else(|| anyhow!("Function without return type"))?,
+        })
+    }
+
+    fn property(&self, message: &Message<'a>) -> anyhow::Result<KmProperty> {
+        let (context, type_parameters) = self.with_type_parameters(message, 4)?;
+        Ok(KmProperty {
+            name: context
+                .strings
+                .string(message.int32(2).unwrap_or_default())?,
+            flags: message.int32(11).unwrap_or(518),
+            type_parameters,
+            receiver_type: context.type_field(message, 5, 10)?,
+            return_type: context
+                .type_field(message, 3, 9)?
+                .ok_or_else(|| anyhow!("Property without type"))?,
+        })
+    }
+
+    fn value_parameters(
+        &self,
+        message: &Message<'a>,
+        field: u32,
+    ) -> anyhow::Result<Vec<KmValueParameter>> {
+        message
+            .messages(field)?
+            .iter()
+            .map(|parameter| {
+                Ok(KmValueParameter {
+                    name: self
+                        .strings
+                        .string(parameter.int32(2).unwrap_or_default())?,
+                    flags: parameter.int32(1).unwrap_or_default(),
+                    type_: self
+                        .type_field(parameter, 3, 5)?
+                        .ok_or_else(|| anyhow!("Parameter without type"))?,
+                    vararg_element_type: self.type_field(parameter, 4, 6)?,
+                })
+            })
+            .collect()
+    }
+}
+
+fn decode_class(message: &Message, strings: &NameResolver) -> anyhow::Result<KmClass> {
+    let base_context = DecodeContext::new(strings, message)?;
+    let (context, type_parameters) = base_context.with_type_parameters(message, 5)?;
+
+    let mut supertypes = message
+        .messages(6)?
+        .iter()
+        .map(|t| context.type_(t, false))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    for id in message.int32s(2)? {
+        supertypes.push(context.type_by_id(id)?);
+    }
+
+    let constructors = message
+        .messages(8)?
+        .iter()
+        .map(|c| {
+            Ok(KmConstructor {
+                flags: c.int32(1).unwrap_or(6),
+                value_parameters: context.value_parameters(c, 2)?,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let enum_entries = message
+        .messages(13)?
+        .iter()
+        .filter_map(|e| e.int32(1))
+        .map(|name| strings.string(name))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(KmClass {
+        name: strings.class_name(
+            message
+                .int32(3)
+                .ok_or_else(|| anyhow!("Class without fq_name"))?,
+        )?,
+        flags: message.int32(1).unwrap_or(6),
+        type_parameters,
+        supertypes,
+        constructors,
+        functions: decode_all(&message.messages(9)?, |f| context.function(f)),
+        properties: decode_all(&message.messages(10)?, |p| context.property(p)),
+        enum_entries,
+        companion_object: message
+            .int32(4)
+            .map(|name| strings.string(name))
+            .transpose()?,
+    })
+}
+
+fn decode_package(message: &Message, strings: &NameResolver) -> anyhow::Result<KmPackage> {
+    let context = DecodeContext::new(strings, message)?;
+    Ok(KmPackage {
+        functions: decode_all(&message.messages(3)?, |f| context.function(f)),
+        properties: decode_all(&message.messages(4)?, |p| context.property(p)),
+    })
+}
+
+/// Decodes all declarations. Declarations which can't be decoded are skipped, so that a single
+/// unsupported declaration does not hide the whole class
+fn decode_all<'a, T>(
+    messages: &[Message<'a>],
+    decode: impl Fn(&Message<'a>) -> anyhow::Result<T>,
+) -> Vec<T> {
+    messages
+        .iter()
+        .filter_map(|m| match decode(m) {
+            Ok(v) => Some(v),
+            Err(e) => {
+                tracing::debug!("Skipping declaration which can't be decoded: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
+fn variance_of(value: i32) -> KmVariance {
+    match value {
+        0 => KmVariance::In,
+        1 => KmVariance::Out,
+        _ => KmVariance::Invariant,
+    }
+}
+
+/// Returns the simple (not package qualified) name of a class name like `a/b/Outer.Inner`
+pub fn simple_class_name(class_name: &str) -> &str {
+    class_name
+        .rsplit_once('/')
+        .map_or(class_name, |(_, name)| name)
+}
+
+impl fmt::Display for KmType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.classifier {
+            KmClassifier::Class(name) | KmClassifier::TypeAlias(name) => {
+                write!(f, "{}", simple_class_name(name))?
+            }
+            KmClassifier::TypeParameter(name) => write!(f, "{}", name)?,
+        }
+        if !self.arguments.is_empty() {
+            write!(f, "<{}>", self.arguments.iter().join(", "))?;
+        }
+        if self.nullable {
+            write!(f, "?")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for KmTypeProjection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KmTypeProjection::Star => write!(f, "*"),
+            KmTypeProjection::Projection { variance, type_ } => match variance {
+                KmVariance::In => write!(f, "in {}", type_),
+                KmVariance::Out => write!(f, "out {}", type_),
+                KmVariance::Invariant => write!(f, "{}", type_),
+            },
+        }
+    }
+}
+
+impl fmt::Display for KmTypeParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.reified {
+            write!(f, "reified ")?;
+        }
+        match self.variance {
+            KmVariance::In => write!(f, "in ")?,
+            KmVariance::Out => write!(f, "out ")?,
+            KmVariance::Invariant => {}
+        }
+        write!(f, "{}", self.name)?;
+        if let [bound] = &self.upper_bounds[..] {
+            write!(f, " : {}", bound)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for KmValueParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.vararg_element_type {
+            Some(element_type) => write!(f, "vararg {}: {}", self.name, element_type)?,
+            None => write!(f, "{}: {}", self.name, self.type_)?,
+        }
+        if flags::declares_default_value(self.flags) {
+            write!(f, " = ...")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for KmFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if flags::is_suspend(self.flags) {
+            write!(f, "suspend ")?;
+        }
+        if flags::is_inline(self.flags) {
+            write!(f, "inline ")?;
+        }
+        if flags::is_infix(self.flags) {
+            write!(f, "infix ")?;
+        }
+        if flags::is_operator(self.flags) {
+            write!(f, "operator ")?;
+        }
+        write!(f, "fun ")?;
+        if !self.type_parameters.is_empty() {
+            write!(f, "<{}> ", self.type_parameters.iter().join(", "))?;
+        }
+        if let Some(receiver) = &self.receiver_type {
+            write!(f, "{}.", receiver)?;
+        }
+        write!(
+            f,
+            "{}({}): {}",
+            self.name,
+            self.value_parameters.iter().join(", "),
+            self.return_type
+        )
+    }
+}
+
+impl fmt::Display for KmProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if flags::is_const(self.flags) {
+            write!(f, "const ")?;
+        }
+        write!(
+            f,
+            "{} ",
+            if flags::is_var(self.flags) {
+                "var"
+            } else {
+                "val"
+            }
+        )?;
+        if !self.type_parameters.is_empty() {
+            write!(f, "<{}> ", self.type_parameters.iter().join(", "))?;
+        }
+        if let Some(receiver) = &self.receiver_type {
+            write!(f, "{}.", receiver)?;
+        }
+        write!(f, "{}: {}", self.name, self.return_type)
+    }
+}
+
+impl fmt::Display for KmClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match flags::class_kind(self.flags) {
+            KmClassKind::Class if flags::is_data_class(self.flags) => "data class",
+            KmClassKind::Class => "class",
+            KmClassKind::Interface => "interface",
+            KmClassKind::EnumClass => "enum class",
+            KmClassKind::EnumEntry => "enum entry",
+            KmClassKind::AnnotationClass => "annotation class",
+            KmClassKind::Object => "object",
+            KmClassKind::CompanionObject => "companion object",
+        };
+        write!(f, "{} {}", kind, simple_class_name(&self.name))?;
+        if !self.type_parameters.is_empty() {
+            write!(f, "<{}>", self.type_parameters.iter().join(", "))?;
+        }
+        if !self.supertypes.is_empty() {
+            write!(f, " : {}", self.supertypes.iter().join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn decode_bytes_in_utf8_mode_drops_the_marker() {
+    let data: Vec<u16> = vec![0, 0x0A, 0x02, 0xFF];
+    assert_eq!(decode_bytes(&[&data]), vec![0x0A, 0x02, 0xFF]);
+}
+
+#[test]
+fn decode_bytes_in_7_to_8_mode_combines_bits() {
+    // 0xFF 0x01 encoded with 7 bits per char, each char shifted by 1 (see BitEncoding.java)
+    let encoded: Vec<u16> = vec![0x80, 0x04, 0x01];
+    assert_eq!(decode_bytes(&[&encoded]), vec![0xFF, 0x01]);
+}
+
+#[test]
+fn string_records_beyond_the_strings_are_errors() {
+    // A record of range i32::MAX
+    let bytes = [0x0A, 0x06, 0x08, 0xFF, 0xFF, 0xFF, 0xFF, 0x07];
+    let types = Message::parse(&bytes).unwrap();
+    assert!(NameResolver::new(&types, vec!["a".to_string()]).is_err());
+}
+
+#[test]
+fn cyclic_qualified_names_are_errors() {
+    let resolver = NameResolver::Builtins {
+        strings: vec!["a".to_string(), "B".to_string()],
+        qualified_names: vec![
+            QualifiedName {
+                parent: 1,
+                short_name: 0,
+                is_class: false,
+            },
+            QualifiedName {
+                parent: 0,
+                short_name: 1,
+                is_class: true,
+            },
+        ],
+    };
+    assert!(resolver.class_name(1).is_err());
+}
+
+#[test]
+fn descriptors_are_class_ids() {
+    let record = |string: &str| StringRecord {
+        predefined_index: None,
+        string: Some(string.to_string()),
+        operation: 2,
+        substring_index: vec![],
+        replace_char: vec![],
+    };
+    let resolver = NameResolver::Jvm {
+        records: vec![record("La/Outer$Inner;"), record("La/Ä")],
+        strings: vec![],
+    };
+    assert_eq!(resolver.string(0).unwrap(), "a/Outer.Inner");
+    assert_eq!(resolver.string(1).unwrap(), "La/Ä");
+}
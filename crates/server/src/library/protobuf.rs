@@ -0,0 +1,173 @@
+//! Minimal protobuf decoder. Messages are decoded into a flat list of fields, which are then
+//! interpreted by the caller according to the schema (see [super::kotlin_metadata]).
+
+use anyhow::{anyhow, bail};
+
+#[derive(Debug, Clone, Copy)]
+pub enum FieldValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Fixed32(u32),
+    Bytes(&'a [u8]),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Message<'a> {
+    fields: Vec<(u32, FieldValue<'a>)>,
+}
+
+impl<'a> Message<'a> {
+    pub fn parse(bytes: &'a [u8]) -> anyhow::Result<Self> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let mut fields = vec![];
+        while !reader.is_at_end() {
+            fields.push(reader.field()?);
+        }
+        Ok(Message { fields })
+    }
+
+    /// Parses a message prefixed by its varint encoded length. Returns the message and the
+    /// remaining bytes
+    pub fn parse_delimited(bytes: &'a [u8]) -> anyhow::Result<(Self, &'a [u8])> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let len = reader.varint()? as usize;
+        let message_bytes = reader.bytes(len)?;
+        Ok((Message::parse(message_bytes)?, &bytes[reader.pos..]))
+    }
+
+    pub fn has(&self, field: u32) -> bool {
+        self.fields.iter().any(|(n, _)| *n == field)
+    }
+
+    /// Returns the last value of the int32 `field`, as protobuf does for non repeated fields
+    pub fn int32(&self, field: u32) -> Option<i32> {
+        self.values(field).rev().find_map(|v| match v {
+            FieldValue::Varint(v) => Some(*v as i64 as i32),
+            _ => None,
+        })
+    }
+
+    pub fn bool(&self, field: u32) -> bool {
+        self.int32(field).is_some_and(|v| v != 0)
+    }
+
+    pub fn string(&self, field: u32) -> Option<String> {
+        self.values(field).rev().find_map(|v| match v {
+            FieldValue::Bytes(b) => Some(String::from_utf8_lossy(b).to_string()),
+            _ => None,
+        })
+    }
+
+    /// Returns all values of the repeated string `field`
+    pub fn strings(&self, field: u32) -> Vec<String> {
+        self.values(field)
+            .filter_map(|v| match v {
+                FieldValue::Bytes(b) => Some(String::from_utf8_lossy(b).to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns all values of the repeated int32 `field`. Handles packed and unpacked encodings
+    pub fn int32s(&self, field: u32) -> anyhow::Result<Vec<i32>> {
+        let mut result = vec![];
+        for value in self.values(field) {
+            match value {
+                FieldValue::Varint(v) => result.push(*v as i64 as i32),
+                FieldValue::Bytes(bytes) => {
+                    let mut reader = Reader { bytes, pos: 0 };
+                    while !reader.is_at_end() {
+                        result.push(reader.varint()? as i64 as i32);
+                    }
+                }
+                v => bail!("Expected int32 for field {}, got {:?}", field, v),
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn message(&self, field: u32) -> anyhow::Result<Option<Message<'a>>> {
+        Ok(self.messages(field)?.pop())
+    }
+
+    pub fn messages(&self, field: u32) -> anyhow::Result<Vec<Message<'a>>> {
+        self.values(field)
+            .map(|v| match v {
+                FieldValue::Bytes(bytes) => Message::parse(bytes),
+                v => bail!("Expected message for field {}, got {:?}", field, v),
+            })
+            .collect()
+    }
+
+    fn values(&self, field: u32) -> impl DoubleEndedIterator<Item = &FieldValue<'a>> {
+        self.fields
+            .iter()
+            .filter(move |(n, _)| *n == field)
+            .map(|(_, v)| v)
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_at_end(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn bytes(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| anyhow!("Unexpected end of protobuf message"))?;
+        let result = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(result)
+    }
+
+    fn varint(&mut self) -> anyhow::Result<u64> {
+        let mut result = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.bytes(1)?[0];
+            result |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+        bail!("Varint is too long")
+    }
+
+    fn field(&mut self) -> anyhow::Result<(u32, FieldValue<'a>)> {
+        let key = self.varint()?;
+        let field = (key >> 3) as u32;
+        let value = match key & 0x7 {
+            0 => FieldValue::Varint(self.varint()?),
+            1 => {
+                let b = self.bytes(8)?;
+                FieldValue::Fixed64(u64::from_le_bytes(b.try_into().unwrap()))
+            }
+            2 => {
+                let len = usize::try_from(self.varint()?)?;
+                FieldValue::Bytes(self.bytes(len)?)
+            }
+            5 => {
+                let b = self.bytes(4)?;
+                FieldValue::Fixed32(u32::from_le_bytes(b.try_into().unwrap()))
+            }
+            wire_type => bail!("Unsupported protobuf wire type {}", wire_type),
+        };
+        Ok((field, value))
+    }
+}
+
+#[test]
+fn lengths_beyond_the_message_are_errors() {
+    // Field 1 of length u64::MAX
+    let bytes = [
+        0x0A, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01,
+    ];
+    assert!(Message::parse(&bytes).is_err());
+}
@@ -0,0 +1,245 @@
+//! Sources of library declarations. Sources are read from the `*-sources.jar` next to the
+//! library jar. If no sources exist, a stub is generated from the indexed declarations.
+//! Both are served to the client with the `kls-jar:` uri scheme (see [JarEntryUri]).
+
+use std::{
+    fmt::Write,
+    fs::File,
+    io::{BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, ensure};
+use itertools::Itertools;
+use tower_lsp::lsp_types::Url;
+use tracing::debug;
+
+use crate::{
+    stdlib,
+    symbol_index::{Symbol, SymbolIndex, SymbolOrigin},
+};
+
+use super::{LibraryDecl, LibraryOrigin, LibrarySymbol};
+
+pub const KLS_JAR_SCHEME: &str = "kls-jar";
+
+/// Whether `uri` names a read only library file, served by kls
+pub fn is_library_uri(uri: &Url) -> bool {
+    uri.scheme() == KLS_JAR_SCHEME || uri.scheme() == stdlib::KLS_STDLIB_SCHEME
+}
+
+/// An entry of a jar, addressed by an uri like `kls-jar:/path/to/lib-sources.jar!/a/b/File.kt`.
+/// If `jar` is not a sources jar, `entry` names the generated stub of a class file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JarEntryUri {
+    pub jar: PathBuf,
+    pub entry: String,
+}
+
+impl JarEntryUri {
+    pub fn parse(uri: &Url) -> anyhow::Result<Self> {
+        ensure!(
+            uri.scheme() == KLS_JAR_SCHEME,
+            "Expected a {} uri, got {}",
+            KLS_JAR_SCHEME,
+            uri
+        );
+        let (jar, entry) = uri
+            .path()
+            .split_once("!/")
+            .ok_or_else(|| anyhow!("Uri {} does not name a jar entry", uri))?;
+        // Reuse the percent decoding of file uris
+        let jar = Url::parse(&format!("file://{}", jar))?
+            .to_file_path()
+            .map_err(|_| anyhow!("Invalid jar path in {}", uri))?;
+
+        Ok(JarEntryUri {
+            jar,
+            entry: entry.to_string(),
+        })
+    }
+
+    pub fn to_uri(&self) -> anyhow::Result<Url> {
+        let jar = Url::from_file_path(&self.jar)
+            .map_err(|_| anyhow!("Jar path {} is not absolute", self.jar.display()))?;
+        Ok(Url::parse(&format!(
+            "{}:{}!/{}",
+            KLS_JAR_SCHEME,
+            jar.path(),
+            self.entry
+        ))?)
+    }
+
+    fn is_stub(&self) -> bool {
+        !is_sources_jar(&self.jar)
+    }
+
+    /// Reads the source file from the sources jar or generates the stub
+    pub fn read_content(&self, index: &SymbolIndex) -> anyhow::Result<String> {
+        if self.is_stub() {
+            let class_name = self
+                .entry
+                .strip_suffix(".kt")
+                .ok_or_else(|| anyhow!("Invalid stub entry {}", self.entry))?;
+            return Ok(decompiled_stub(index, &self.jar, class_name));
+        }
+
+        let mut archive = zip::ZipArchive::new(BufReader::new(File::open(&self.jar)?))?;
+        let mut entry = archive.by_name(&self.entry)?;
+        let mut content = String::with_capacity(entry.size() as usize);
+        entry.read_to_string(&mut content)?;
+        Ok(content)
+    }
+}
+
+/// Returns the uri of the file declaring `symbol`. For jars the sources jar is preferred, with a
+/// fallback to a stub
+pub fn source_uri_of(symbol: &LibrarySymbol) -> anyhow::Result<Url> {
+    match &symbol.origin {
+        LibraryOrigin::Jar(jar) => sources_jar_entry_of(symbol, jar)
+            .unwrap_or_else(|| JarEntryUri {
+                jar: jar.clone(),
+                entry: format!("{}.kt", stub_class_name(&symbol.class_name)),
+            })
+            .to_uri(),
+        LibraryOrigin::BundledStdlib => stdlib::file_uri(&symbol.class_name),
+    }
+}
+
+fn sources_jar_entry_of(symbol: &LibrarySymbol, jar: &Path) -> Option<JarEntryUri> {
+    let source_file = symbol.source_file.as_ref()?;
+    let sources_jar = sources_jar_of(jar)?;
+    let archive = zip::ZipArchive::new(BufReader::new(File::open(&sources_jar).ok()?)).ok()?;
+
+    let package_dir = symbol
+        .class_name
+        .rsplit_once('/')
+        .map_or(String::new(), |(dir, _)| format!("{}/", dir));
+    let expected_entry = format!("{}{}", package_dir, source_file);
+    // Multiplatform libraries put their sources in source set directories (e.G. `jvmMain/`),
+    // which are not part of the package path
+    let entry = archive
+        .file_names()
+        .sorted_by_key(|name| {
+            if *name == expected_entry {
+                0
+            } else if name.ends_with(&format!("/{}", expected_entry)) {
+                1
+            } else if name.ends_with(&format!("/{}", source_file)) {
+                2
+            } else {
+                3
+            }
+        })
+        .find(|name| name.ends_with(source_file.as_str()))?
+        .to_string();
+
+    debug!(
+        "Found source of {} in {}",
+        symbol.class_name,
+        sources_jar.display()
+    );
+    Some(JarEntryUri {
+        jar: sources_jar,
+        entry,
+    })
+}
+
+fn is_sources_jar(jar: &Path) -> bool {
+    jar.file_name()
+        .is_some_and(|name| name.to_string_lossy().ends_with("-sources.jar"))
+}
+
+/// Returns the `*-sources.jar` belonging to `jar`. It is either placed next to the jar or, as done
+/// by the gradle cache, in a sibling directory
+fn sources_jar_of(jar: &Path) -> Option<PathBuf> {
+    let file_name = format!("{}-sources.jar", jar.file_stem()?.to_string_lossy());
+    let dir = jar.parent()?;
+
+    let next_to_jar = dir.join(&file_name);
+    if next_to_jar.is_file() {
+        return Some(next_to_jar);
+    }
+
+    std::fs::read_dir(dir.parent()?)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|sibling| sibling.path().join(&file_name))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Multi file facade parts (`CollectionsKt__CollectionsKt`) are shown in a stub of their facade
+fn stub_class_name(class_name: &str) -> &str {
+    class_name.split("__").next().unwrap_or(class_name)
+}
+
+/// Generates kotlin source code declaring all indexed declarations of the class file `class_name`
+fn decompiled_stub(index: &SymbolIndex, jar: &Path, class_name: &str) -> String {
+    let is_of_class = |symbol: &&Symbol| match &symbol.origin {
+        SymbolOrigin::Library(l) => {
+            matches!(&l.origin, LibraryOrigin::Jar(j) if j == jar)
+                && stub_class_name(&l.class_name) == class_name
+        }
+        SymbolOrigin::Source { .. } => false,
+    };
+    let package = class_name
+        .rsplit_once('/')
+        .map_or(String::new(), |(package, _)| package.replace('/', "."));
+    // Nested classes are declared within their outer class. Class files name them `Outer$Inner`,
+    // builtins `Outer.Inner`
+    let outer_class = class_name
+        .rsplit_once(['$', '.'])
+        .map(|(outer, _)| outer.replace(['/', '$'], "."));
+
+    let mut stub = String::new();
+    if !package.is_empty() {
+        let _ = writeln!(stub, "package {}\n", package);
+    }
+    let _ = writeln!(
+        stub,
+        "// Generated from {}. No sources available\n",
+        jar.display()
+    );
+
+    let containers = std::iter::once(package).chain(outer_class).collect_vec();
+    for symbol in containers
+        .iter()
+        .flat_map(|container| index.symbols_in(container))
+        .filter(is_of_class)
+    {
+        let SymbolOrigin::Library(library_symbol) = &symbol.origin else {
+            continue;
+        };
+        match &library_symbol.decl {
+            LibraryDecl::Class(km_class) => {
+                let _ = writeln!(stub, "{} {{", km_class);
+                for member in index
+                    .symbols_in(&symbol.fq_name())
+                    .iter()
+                    .filter(is_of_class)
+                {
+                    if let SymbolOrigin::Library(member) = &member.origin {
+                        let _ = writeln!(stub, "    {}", member.decl);
+                    }
+                }
+                let _ = writeln!(stub, "}}\n");
+            }
+            decl => {
+                let _ = writeln!(stub, "{}\n", decl);
+            }
+        }
+    }
+
+    stub
+}
+
+#[test]
+fn jar_entry_uri_round_trips() {
+    let jar_entry = JarEntryUri {
+        jar: PathBuf::from("/libs/my lib-sources.jar"),
+        entry: "a/b/File.kt".to_string(),
+    };
+    let uri = jar_entry.to_uri().unwrap();
+    assert_eq!(uri.scheme(), KLS_JAR_SCHEME);
+    assert_eq!(JarEntryUri::parse(&uri).unwrap(), jar_entry);
+}
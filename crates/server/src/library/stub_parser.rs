@@ -0,0 +1,505 @@
+//! Parser of declaration stubs, as written by the [std::fmt::Display] implementations of
+//! [super::kotlin_metadata]. Used to read the bundled stdlib index (see [crate::stdlib]).
+//!
+//! A stub contains one declaration per line. Class members are nested within `{` `}`
+//! ```text
+//! package kotlin.collections
+//!
+//! interface List<out E> : Collection<E> {
+//!     operator fun get(index: Int): E
+//! }
+//! fun <T> listOf(vararg elements: T): List<T>
+//! ```
+
+use anyhow::{anyhow, bail, ensure};
+
+use super::kotlin_metadata::{
+    KmClass, KmClassifier, KmFunction, KmPackage, KmProperty, KmType, KmTypeParameter,
+    KmTypeProjection, KmValueParameter, KmVariance,
+};
+
+/// The declarations of a stub file. Type names are not yet qualified, so classifiers contain the
+/// names as written (e.G. `List` or `Map.Entry`)
+#[derive(Debug, Default)]
+pub struct KmStub {
+    /// The package in dot notation
+    pub package: String,
+    pub classes: Vec<KmClass>,
+    pub package_decls: KmPackage,
+}
+
+// See `Flags.java` of the kotlin compiler for the bit layout
+const PUBLIC: i32 = 3 << 1;
+const DECLARES_DEFAULT_VALUE: i32 = 1 << 1;
+
+pub fn parse_stub(text: &str) -> anyhow::Result<KmStub> {
+    let mut stub = KmStub::default();
+    // Classes whose body is not yet closed. The innermost class is last
+    let mut open_classes: Vec<KmClass> = vec![];
+
+    for (line_number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        let result = (|| -> anyhow::Result<()> {
+            if let Some(package) = line.strip_prefix("package ") {
+                stub.package = package.trim().to_string();
+            } else if line == "}" {
+                let class = open_classes
+                    .pop()
+                    .ok_or_else(|| anyhow!("Unmatched `}}`"))?;
+                stub.classes.push(class);
+            } else {
+                let mut parser = DeclParser {
+                    text: line,
+                    pos: 0,
+                    type_parameters: open_classes
+                        .iter()
+                        .flat_map(|c| c.type_parameters.iter().map(|tp| tp.name.clone()))
+                        .collect(),
+                };
+                match parser.declaration()? {
+                    Decl::Class(mut class, has_body) => {
+                        if let Some(outer) = open_classes.last() {
+                            class.name = format!("{}.{}", outer.name, class.name);
+                        }
+                        if has_body {
+                            open_classes.push(class);
+                        } else {
+                            stub.classes.push(class);
+                        }
+                    }
+                    Decl::Function(function) => match open_classes.last_mut() {
+                        Some(class) => class.functions.push(function),
+                        None => stub.package_decls.functions.push(function),
+                    },
+                    Decl::Property(property) => match open_classes.last_mut() {
+                        Some(class) => class.properties.push(property),
+                        None => stub.package_decls.properties.push(property),
+                    },
+                }
+            }
+            Ok(())
+        })();
+        if let Err(e) = result {
+            bail!("Line {}: {} ({})", line_number + 1, e, line);
+        }
+    }
+
+    ensure!(open_classes.is_empty(), "Class body is not closed");
+    Ok(stub)
+}
+
+enum Decl {
+    /// The class and whether it has a body
+    Class(KmClass, bool),
+    Function(KmFunction),
+    Property(KmProperty),
+}
+
+struct DeclParser<'a> {
+    text: &'a str,
+    pos: usize,
+    /// Type parameters in scope. Types with these names are type parameters, not classes
+    type_parameters: Vec<String>,
+}
+
+impl<'a> DeclParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `token`, if the remaining text starts with it
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes the keyword `word`, if the remaining text starts with it
+    fn eat_word(&mut self, word: &str) -> bool {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let is_word = rest.starts_with(word)
+            && !rest[word.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_');
+        if is_word {
+            self.pos += word.len();
+        }
+        is_word
+    }
+
+    fn expect(&mut self, token: &str) -> anyhow::Result<()> {
+        ensure!(self.eat(token), "Expected `{}` at `{}`", token, self.rest());
+        Ok(())
+    }
+
+    fn ident(&mut self) -> anyhow::Result<String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = if let Some(quoted) = rest.strip_prefix('`') {
+            quoted
+                .find('`')
+                .map(|end| end + 2)
+                .ok_or_else(|| anyhow!("Unclosed `"))?
+        } else {
+            rest.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len())
+        };
+        ensure!(len > 0, "Expected identifier at `{}`", rest);
+        self.pos += len;
+        Ok(rest[..len].trim_matches('`').to_string())
+    }
+
+    fn declaration(&mut self) -> anyhow::Result<Decl> {
+        let mut flags = PUBLIC;
+        loop {
+            // See `Flags.java` of the kotlin compiler
+            let modifier = [
+                ("suspend", 1 << 13),
+                ("inline", 1 << 10),
+                ("infix", 1 << 9),
+                ("operator", 1 << 8),
+                ("const", 1 << 11),
+            ]
+            .into_iter()
+            .find(|(word, _)| self.eat_word(word));
+            match modifier {
+                Some((_, flag)) => flags |= flag,
+                None => break,
+            }
+        }
+
+        if self.eat_word("fun") {
+            return Ok(Decl::Function(self.function(flags)?));
+        }
+        if self.eat_word("val") {
+            return Ok(Decl::Property(self.property(flags)?));
+        }
+        if self.eat_word("var") {
+            return Ok(Decl::Property(self.property(flags | (1 << 8))?));
+        }
+
+        let (kind, data) = if self.eat_word("data") {
+            self.expect("class")?;
+            (0, true)
+        } else if self.eat_word("enum") {
+            if self.eat_word("entry") {
+                (3, false)
+            } else {
+                self.expect("class")?;
+                (2, false)
+            }
+        } else if self.eat_word("annotation") {
+            self.expect("class")?;
+            (4, false)
+        } else if self.eat_word("companion") {
+            self.expect("object")?;
+            (6, false)
+        } else if self.eat_word("object") {
+            (5, false)
+        } else if self.eat_word("interface") {
+            (1, false)
+        } else if self.eat_word("class") {
+            (0, false)
+        } else {
+            bail!("Unknown declaration")
+        };
+        let class_flags = PUBLIC | (kind << 6) | if data { 1 << 10 } else { 0 };
+        let (class, has_body) = self.class(class_flags)?;
+        Ok(Decl::Class(class, has_body))
+    }
+
+    fn class(&mut self, flags: i32) -> anyhow::Result<(KmClass, bool)> {
+        let name = self.ident()?;
+        let type_parameters = self.type_parameters()?;
+
+        let mut supertypes = vec![];
+        if self.eat(":") {
+            loop {
+                supertypes.push(self.type_()?);
+                if !self.eat(",") {
+                    break;
+                }
+            }
+        }
+        let has_body = self.eat("{");
+
+        Ok((
+            KmClass {
+                name,
+                flags,
+                type_parameters,
+                supertypes,
+                constructors: vec![],
+                functions: vec![],
+                properties: vec![],
+                enum_entries: vec![],
+                companion_object: None,
+            },
+            has_body,
+        ))
+    }
+
+    fn type_parameters(&mut self) -> anyhow::Result<Vec<KmTypeParameter>> {
+        if !self.eat("<") {
+            return Ok(vec![]);
+        }
+        let mut type_parameters = vec![];
+        loop {
+            let reified = self.eat_word("reified");
+            let variance = self.variance();
+            let name = self.ident()?;
+            self.type_parameters.push(name.clone());
+            let upper_bounds = if self.eat(":") {
+                vec![self.type_()?]
+            } else {
+                vec![]
+            };
+            type_parameters.push(KmTypeParameter {
+                id: type_parameters.len() as i32,
+                name,
+                variance,
+                reified,
+                upper_bounds,
+            });
+            if !self.eat(",") {
+                break;
+            }
+        }
+        self.expect(">")?;
+        Ok(type_parameters)
+    }
+
+    fn variance(&mut self) -> KmVariance {
+        if self.eat_word("in") {
+            KmVariance::In
+        } else if self.eat_word("out") {
+            KmVariance::Out
+        } else {
+            KmVariance::Invariant
+        }
+    }
+
+    /// Parses `[<type parameters>] [receiver.]name`
+    fn receiver_and_name(
+        &mut self,
+    ) -> anyhow::Result<(Vec<KmTypeParameter>, Option<KmType>, String)> {
+        let type_parameters = self.type_parameters()?;
+        let type_ = self.type_()?;
+
+        if self.eat(".") {
+            // The type ended with type arguments or `?`, so it must be the receiver
+            return Ok((type_parameters, Some(type_), self.ident()?));
+        }
+
+        // A type without arguments: `Receiver.name` or just `name`
+        ensure!(
+            type_.arguments.is_empty() && !type_.nullable,
+            "Expected name after `{}`",
+            self.rest()
+        );
+        let (KmClassifier::Class(path) | KmClassifier::TypeParameter(path)) = type_.classifier
+        else {
+            bail!("Unexpected type alias")
+        };
+        match path.rsplit_once('.') {
+            Some((receiver, name)) => {
+                let receiver = self.named_type(receiver.to_string(), vec![], false);
+                Ok((type_parameters, Some(receiver), name.to_string()))
+            }
+            None => Ok((type_parameters, None, path)),
+        }
+    }
+
+    fn function(&mut self, flags: i32) -> anyhow::Result<KmFunction> {
+        let (type_parameters, receiver_type, name) = self.receiver_and_name()?;
+
+        self.expect("(")?;
+        let mut value_parameters = vec![];
+        if !self.eat(")") {
+            loop {
+                value_parameters.push(self.value_parameter()?);
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.expect(")")?;
+        }
+        self.expect(":")?;
+        let return_type = self.type_()?;
+
+        Ok(KmFunction {
+            name,
+            flags,
+            type_parameters,
+            receiver_type,
+            value_parameters,
+            return_type,
+        })
+    }
+
+    fn value_parameter(&mut self) -> anyhow::Result<KmValueParameter> {
+        let is_vararg = self.eat_word("vararg");
+        let name = self.ident()?;
+        self.expect(":")?;
+        let type_ = self.type_()?;
+        let flags = if self.eat("=") {
+            self.expect("...")?;
+            DECLARES_DEFAULT_VALUE
+        } else {
+            0
+        };
+
+        Ok(if is_vararg {
+            KmValueParameter {
+                name,
+                flags,
+                type_: KmType {
+                    classifier: KmClassifier::Class("Array".to_string()),
+                    arguments: vec![KmTypeProjection::Projection {
+                        variance: KmVariance::Out,
+                        type_: type_.clone(),
+                    }],
+                    nullable: false,
+                    flags: 0,
+                },
+                vararg_element_type: Some(type_),
+            }
+        } else {
+            KmValueParameter {
+                name,
+                flags,
+                type_,
+                vararg_element_type: None,
+            }
+        })
+    }
+
+    fn property(&mut self, flags: i32) -> anyhow::Result<KmProperty> {
+        let (type_parameters, receiver_type, name) = self.receiver_and_name()?;
+        self.expect(":")?;
+        let return_type = self.type_()?;
+
+        Ok(KmProperty {
+            name,
+            flags,
+            type_parameters,
+            receiver_type,
+            return_type,
+        })
+    }
+
+    /// Parses a type like `Map.Entry<out K, *>?`
+    fn type_(&mut self) -> anyhow::Result<KmType> {
+        let mut name = self.ident()?;
+        // Qualified names. A `.` followed by a lower case identifier could be the name of a
+        // function with receiver, which is resolved by the caller
+        while self.rest().starts_with('.')
+            && self.text[self.pos + 1..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+        {
+            self.pos += 1;
+            name = format!("{}.{}", name, self.ident()?);
+        }
+
+        let mut arguments = vec![];
+        if self.rest().starts_with('<') {
+            self.pos += 1;
+            loop {
+                if self.eat("*") {
+                    arguments.push(KmTypeProjection::Star);
+                } else {
+                    let variance = self.variance();
+                    arguments.push(KmTypeProjection::Projection {
+                        variance,
+                        type_: self.type_()?,
+                    });
+                }
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.expect(">")?;
+        }
+        let nullable = self.rest().starts_with('?');
+        if nullable {
+            self.pos += 1;
+        }
+
+        Ok(self.named_type(name, arguments, nullable))
+    }
+
+    fn named_type(&self, name: String, arguments: Vec<KmTypeProjection>, nullable: bool) -> KmType {
+        let classifier = if self.type_parameters.contains(&name) {
+            KmClassifier::TypeParameter(name)
+        } else {
+            KmClassifier::Class(name)
+        };
+        KmType {
+            classifier,
+            arguments,
+            nullable,
+            flags: 0,
+        }
+    }
+}
+
+#[test]
+fn parse_stub_round_trips_declarations() {
+    let text = "package kotlin.collections
+
+interface List<out E> : Collection<E> {
+    operator fun get(index: Int): E
+    val size: Int
+}
+fun <T> listOf(vararg elements: T): List<T>
+inline fun <T> Iterable<T>.forEach(action: Function1<T, Unit>): Unit
+fun <K, V> Map.Entry<K, V>.toPair(): Pair<K, V>
+fun CharSequence.isBlank(): Boolean
+val <T> List<T>.lastIndex: Int
+fun joinToString(separator: CharSequence = ...): String
+";
+    let stub = parse_stub(text).unwrap();
+    assert_eq!(stub.package, "kotlin.collections");
+
+    let list = &stub.classes[0];
+    assert_eq!(list.to_string(), "interface List<out E> : Collection<E>");
+    assert_eq!(
+        list.functions[0].to_string(),
+        "operator fun get(index: Int): E"
+    );
+    assert_eq!(
+        list.functions[0].return_type.classifier,
+        KmClassifier::TypeParameter("E".to_string())
+    );
+    assert_eq!(list.properties[0].to_string(), "val size: Int");
+
+    let expected = text.lines().skip(6).collect::<Vec<_>>();
+    let actual = stub
+        .package_decls
+        .functions
+        .iter()
+        .map(|f| f.to_string())
+        .chain(stub.package_decls.properties.iter().map(|p| p.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actual,
+        [
+            expected[0],
+            expected[1],
+            expected[2],
+            expected[3],
+            expected[5],
+            expected[4]
+        ]
+    );
+}
@@ -0,0 +1,289 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use indextree::NodeId;
+use itertools::Itertools;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Location, Range, Url};
+use tracing::{debug, warn};
+use tree_sitter::Node;
+
+use crate::{
+    range_util::text_range_to_lsp_range,
+    scope::{fun_decl_scope::MultiplatformModifier, GSFile, GScopesData, SKind, Scope},
+    symbol_index::SymbolKind,
+};
+
+/// A top level function, class or property marked with `expect` or `actual`
+#[derive(Debug, Clone)]
+pub struct MultiplatformDecl {
+    pub package: Option<String>,
+    pub name: String,
+    pub kind: SymbolKind,
+    pub modifier: MultiplatformModifier,
+    pub path: PathBuf,
+    pub range: Range,
+}
+
+impl MultiplatformDecl {
+    fn of(s_file: &GSFile, scope: &Scope) -> Option<Self> {
+        let (name, kind, modifier) = match &scope.kind {
+            SKind::FunDecl(s_fun_decl) => (
+                s_fun_decl.ident.clone()?,
+                SymbolKind::Function,
+                s_fun_decl.multiplatform?,
+            ),
+            SKind::Class(s_class) => (
+                s_class.ident.clone()?,
+                SymbolKind::Class,
+                s_class.multiplatform?,
+            ),
+            SKind::Property(s_property) => (
+                s_property.ident.clone()?,
+                SymbolKind::Property,
+                s_property.multiplatform?,
+            ),
+            _ => return None,
+        };
+
+        Some(MultiplatformDecl {
+            package: s_file.package().map(str::to_string),
+            name,
+            kind,
+            modifier,
+            path: s_file.path.clone(),
+            range: text_range_to_lsp_range(&s_file.text, scope.range),
+        })
+    }
+
+    /// Returns the declaration, if `byte` is on the name of a multiplatform declaration
+    pub fn at(s_file: &GSFile, byte: u32) -> Option<Self> {
+        let node = s_file
+            .ast
+            .root_node()
+            .descendant_for_byte_range(byte as usize, byte as usize)?;
+        if !is_declaration_name(&node) {
+            return None;
+        }
+        let scope_id = s_file.scope_at_byte(byte)?;
+        Self::of(s_file, s_file.scopes[scope_id].get())
+    }
+
+    pub fn location(&self) -> Option<Location> {
+        Url::from_file_path(&self.path)
+            .ok()
+            .map(|uri| Location::new(uri, self.range))
+    }
+
+    fn is_counterpart_of(&self, other: &MultiplatformDecl) -> bool {
+        self.modifier != other.modifier && self.has_name_of(other)
+    }
+
+    /// Whether both declare the same kind of declaration with the same fully qualified name
+    fn has_name_of(&self, other: &MultiplatformDecl) -> bool {
+        self.kind == other.kind && self.package == other.package && self.name == other.name
+    }
+}
+
+/// Returns the `actual` declarations of an `expect` declaration or
+/// the `expect` declaration of an `actual` declaration
+pub fn counterparts_of(
+    scopes: &GScopesData,
+    source_set_id: NodeId,
+    decl: &MultiplatformDecl,
+) -> Vec<MultiplatformDecl> {
+    let source_set_ids = match decl.modifier {
+        MultiplatformModifier::Expect => scopes.refining_source_sets(source_set_id),
+        MultiplatformModifier::Actual => scopes.depends_on_closure(source_set_id),
+    };
+
+    decls_of(scopes, &source_set_ids)
+        .into_iter()
+        .filter(|other| other.is_counterpart_of(decl))
+        .collect()
+}
+
+/// The diagnostics of `expect` declarations, which have no `actual` declaration for one of the
+/// configured targets. Changes of a file only recheck the declarations of the file and their
+/// counterparts
+#[derive(Debug, Default, Clone)]
+pub struct MissingActuals {
+    /// The multiplatform declarations and the source set of each file, as of the last update
+    decls: HashMap<PathBuf, (NodeId, Vec<MultiplatformDecl>)>,
+    /// The diagnostics of the `expect` declarations of each file
+    diagnostics: HashMap<PathBuf, Vec<Diagnostic>>,
+}
+
+impl MissingActuals {
+    /// Checks the `expect` declarations of all files
+    pub fn of_project(scopes: &GScopesData) -> Self {
+        let mut missing_actuals = MissingActuals {
+            decls: scopes
+                .files
+                .iter()
+                .map(|(path, project_file)| {
+                    let decls = decls_of_file(&project_file.s_file);
+                    (path.clone(), (project_file.source_set_id, decls))
+                })
+                .filter(|(_, (_, decls))| !decls.is_empty())
+                .collect(),
+            diagnostics: HashMap::new(),
+        };
+        let paths = missing_actuals.decls.keys().cloned().collect_vec();
+        for path in paths {
+            missing_actuals.check_file(scopes, &path);
+        }
+        missing_actuals
+    }
+
+    /// Rechecks the `expect` declarations of the file at `path` and the ones, which are
+    /// counterparts of its declarations before or after the change
+    pub fn file_changed(&mut self, scopes: &GScopesData, path: &Path) {
+        let mut names = self
+            .decls
+            .remove(path)
+            .map(|(_, decls)| decls)
+            .unwrap_or_default();
+        if let Some(project_file) = scopes.files.get(path) {
+            let decls = decls_of_file(&project_file.s_file);
+            names.extend(decls.iter().cloned());
+            if !decls.is_empty() {
+                self.decls
+                    .insert(path.to_owned(), (project_file.source_set_id, decls));
+            }
+        }
+
+        let affected = self
+            .decls
+            .iter()
+            .filter(|(other, (_, decls))| {
+                *other == path
+                    || decls.iter().any(|decl| {
+                        decl.modifier == MultiplatformModifier::Expect
+                            && names.iter().any(|name| name.has_name_of(decl))
+                    })
+            })
+            .map(|(other, _)| other.clone())
+            .collect_vec();
+        debug!(
+            "Checking expect declarations of {} files after a change of {}",
+            affected.len(),
+            path.display()
+        );
+        self.diagnostics.remove(path);
+        for path in affected {
+            self.check_file(scopes, &path);
+        }
+    }
+
+    /// Adds the diagnostics of all files to `diagnostics`
+    pub fn add_to(&self, diagnostics: &mut HashMap<PathBuf, Vec<Diagnostic>>) {
+        for (path, file_diagnostics) in &self.diagnostics {
+            diagnostics
+                .entry(path.clone())
+                .or_default()
+                .extend(file_diagnostics.iter().cloned());
+        }
+    }
+
+    /// Replaces the diagnostics of the `expect` declarations of the file at `path`
+    fn check_file(&mut self, scopes: &GScopesData, path: &Path) {
+        let Some((source_set_id, decls)) = self.decls.get(path) else {
+            self.diagnostics.remove(path);
+            return;
+        };
+        let expects = decls
+            .iter()
+            .filter(|decl| decl.modifier == MultiplatformModifier::Expect)
+            .collect_vec();
+        let mut file_diagnostics = vec![];
+        if !expects.is_empty() {
+            let common_source_set_ids = scopes.depends_on_closure(*source_set_id);
+            for target_id in scopes.refining_source_sets(*source_set_id) {
+                let Some(target) = scopes.scopes[target_id]
+                    .get()
+                    .read()
+                    .kind
+                    .as_source_set()
+                    .and_then(|s| s.target().map(str::to_string))
+                else {
+                    continue;
+                };
+
+                let actual_source_set_ids = scopes
+                    .depends_on_closure(target_id)
+                    .into_iter()
+                    .filter(|id| !common_source_set_ids.contains(id))
+                    .collect_vec();
+                let actuals = self
+                    .decls
+                    .values()
+                    .filter(|(id, _)| actual_source_set_ids.contains(id))
+                    .flat_map(|(_, decls)| decls)
+                    .collect_vec();
+
+                for expect in &expects {
+                    if actuals
+                        .iter()
+                        .any(|actual| actual.is_counterpart_of(expect))
+                    {
+                        continue;
+                    }
+                    debug!("expect {} has no actual for target {}", expect.name, target);
+                    file_diagnostics.push(Diagnostic {
+                        range: expect.range,
+                        severity: Some(DiagnosticSeverity::ERROR),
+                        source: Some("kls".to_string()),
+                        message: format!(
+                            "expect {} has no actual declaration for target {}",
+                            expect.name, target
+                        ),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+        if file_diagnostics.is_empty() {
+            self.diagnostics.remove(path);
+        } else {
+            self.diagnostics.insert(path.to_owned(), file_diagnostics);
+        }
+    }
+}
+
+/// Returns all multiplatform declarations of the files within the `source_set_ids`
+fn decls_of(scopes: &GScopesData, source_set_ids: &[NodeId]) -> Vec<MultiplatformDecl> {
+    scopes
+        .files
+        .values()
+        .filter(|project_file| source_set_ids.contains(&project_file.source_set_id))
+        .flat_map(|project_file| decls_of_file(&project_file.s_file))
+        .collect()
+}
+
+/// Returns the multiplatform declarations of `s_file`
+fn decls_of_file(s_file: &GSFile) -> Vec<MultiplatformDecl> {
+    s_file
+        .root_nodes
+        .iter()
+        .filter_map(|n| MultiplatformDecl::of(s_file, s_file.scopes[*n].get()))
+        .collect()
+}
+
+/// Whether `node` is the name of a function, class, object or property declaration
+fn is_declaration_name(node: &Node) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    if node.kind_id() == *parser::node::TypeIdentifierId {
+        return parent.kind_id() == *parser::node::ClassDeclarationId
+            || parent.kind_id() == *parser::node::ObjectDeclarationId;
+    }
+    node.kind_id() == *parser::node::SimpleIdentifierId
+        && (parent.kind_id() == *parser::node::FunctionDeclarationId
+            || (parent.kind_id() == *parser::node::VariableDeclarationId
+                && parent.parent().is_some_and(|grandparent| {
+                    grandparent.kind_id() == *parser::node::PropertyDeclarationId
+                })))
+}
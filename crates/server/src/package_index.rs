@@ -0,0 +1,184 @@
+//! Packages declared by the package headers of the project files
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    path::{Path, PathBuf},
+};
+
+use stdx::TextRange;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use tracing::debug;
+
+use crate::{
+    range_util::text_range_to_lsp_range,
+    scope::{GSFile, GScopesData, SourceLanguage},
+};
+
+/// Code of the diagnostic reported for a package, which does not match the directory of its
+/// file. The expected package is passed as `data`
+pub const PACKAGE_MISMATCH_CODE: &str = "package-mismatch";
+
+/// Index of the files of each package. Files without package header are part of the root
+/// package `""`. Clones share their maps until they change
+#[derive(Debug, Default, Clone)]
+pub struct PackageIndex {
+    files_by_package: im::OrdMap<String, BTreeSet<PathBuf>>,
+    package_of_file: im::HashMap<PathBuf, String>,
+}
+
+impl PackageIndex {
+    /// Sets the package of the file at `path` to `package`, as declared by its package header
+    pub fn set_file_package(&mut self, path: &Path, package: &str) {
+        if self.package_of(path) == Some(package) {
+            return;
+        }
+        self.remove_file(path);
+        debug!("{} is part of package {:?}", path.display(), package);
+        self.files_by_package
+            .entry(package.to_string())
+            .or_default()
+            .insert(path.to_owned());
+        self.package_of_file
+            .insert(path.to_owned(), package.to_string());
+    }
+
+    pub fn remove_file(&mut self, path: &Path) {
+        let Some(package) = self.package_of_file.remove(path) else {
+            return;
+        };
+        if let Some(files) = self.files_by_package.get_mut(&package) {
+            files.remove(path);
+            if files.is_empty() {
+                self.files_by_package.remove(&package);
+            }
+        }
+    }
+
+    pub fn package_of(&self, path: &Path) -> Option<&str> {
+        self.package_of_file.get(path).map(|p| p.as_str())
+    }
+
+    /// The files declaring `package`. Files of sub packages are not included
+    pub fn files_of(&self, package: &str) -> impl Iterator<Item = &PathBuf> {
+        self.files_by_package.get(package).into_iter().flatten()
+    }
+
+    pub fn packages(&self) -> impl Iterator<Item = &str> {
+        self.files_by_package.keys().map(|p| p.as_str())
+    }
+}
+
+/// The package expected for the file at `path` in the source directory `src_dir`, e.G.
+/// `com.example` for `src/main/kotlin/com/example/App.kt`. None if the file is not within
+/// `src_dir`
+pub fn expected_package(src_dir: &Path, path: &Path) -> Option<String> {
+    let dir = path.parent()?.strip_prefix(src_dir).ok()?;
+    let segments = dir
+        .components()
+        .map(|c| c.as_os_str().to_str())
+        .collect::<Option<Vec<_>>>()?;
+    Some(segments.join("."))
+}
+
+/// Whether `package` matches the directory of its file, whose expected package is `expected`.
+/// As recommended for kotlin, a common root package might be omitted from the directories
+pub fn matches_directory(package: &str, expected: &str) -> bool {
+    expected.is_empty()
+        || package == expected
+        || package
+            .strip_suffix(expected)
+            .is_some_and(|root| root.ends_with('.'))
+}
+
+/// Adds a diagnostic for every kotlin file, whose package does not match its directory relative
+/// to the source directory of its source set
+pub fn add_package_mismatch_diagnostics(
+    scopes: &GScopesData,
+    diagnostics: &mut HashMap<PathBuf, Vec<Diagnostic>>,
+) {
+    for (path, project_file) in scopes.files.iter() {
+        if SourceLanguage::of_path(path) != SourceLanguage::Kotlin {
+            continue;
+        }
+        let expected = {
+            let r_source_set = scopes.scopes[project_file.source_set_id].get().read();
+            let Some(s_source_set) = r_source_set.kind.as_source_set() else {
+                continue;
+            };
+            let src_dir = s_source_set
+                .project_root_dir
+                .join(&s_source_set.data.src_dir);
+            expected_package(&src_dir, path)
+        };
+        let Some(expected) = expected else {
+            continue;
+        };
+
+        let s_file = &project_file.s_file;
+        let package = s_file.package().unwrap_or_default();
+        if matches_directory(package, &expected) {
+            continue;
+        }
+        debug!(
+            "Package {:?} of {} does not match its directory",
+            package,
+            path.display()
+        );
+        // Files without package header are reported at their start
+        let range = package_identifier_range(s_file)
+            .map(|range| text_range_to_lsp_range(&s_file.text, range))
+            .unwrap_or_else(|| Range::new(Position::new(0, 0), Position::new(0, 0)));
+        diagnostics
+            .entry(path.clone())
+            .or_default()
+            .push(Diagnostic {
+                range,
+                severity: Some(DiagnosticSeverity::WARNING),
+                code: Some(NumberOrString::String(PACKAGE_MISMATCH_CODE.to_string())),
+                source: Some("kls".to_string()),
+                message: format!(
+                    "Package '{}' does not match the directory. Expected '{}'",
+                    package, expected
+                ),
+                data: Some(serde_json::Value::String(expected)),
+                ..Default::default()
+            });
+    }
+}
+
+/// The range of the package name within the package header
+fn package_identifier_range(s_file: &GSFile) -> Option<TextRange> {
+    let header = s_file.package_header_range()?;
+    let node = s_file
+        .ast
+        .root_node()
+        .descendant_for_byte_range(header.start as usize, header.end as usize)?;
+    let mut cursor = node.walk();
+    let identifier = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind_id() == *parser::node::IdentifierId)?;
+    TextRange::try_from(identifier.byte_range()).ok()
+}
+
+#[test]
+fn package_follows_directory_relative_to_src_dir() {
+    let src_dir = Path::new("/p/src/main/kotlin");
+    assert_eq!(
+        expected_package(src_dir, Path::new("/p/src/main/kotlin/com/example/App.kt")),
+        Some("com.example".to_string())
+    );
+    assert_eq!(
+        expected_package(src_dir, Path::new("/p/src/main/kotlin/App.kt")),
+        Some(String::new())
+    );
+    assert_eq!(
+        expected_package(src_dir, Path::new("/p/build/App.kt")),
+        None
+    );
+
+    assert!(matches_directory("com.example", "com.example"));
+    assert!(matches_directory("com.example.ui", "ui"));
+    assert!(matches_directory("com.example", ""));
+    assert!(!matches_directory("com.example", "example.ui"));
+    assert!(!matches_directory("com.myui", "ui"));
+}
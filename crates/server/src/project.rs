@@ -30,6 +30,18 @@ pub struct PProject {
     pub name: String,
     pub root_dir: PathBuf,
     pub source_sets: Vec<PSourceSet>,
+    /// Definitions of the scripts of the project. Gradle scripts have a default definition
+    #[serde(default)]
+    pub script_definitions: Vec<PScriptDefinition>,
+}
+
+/// The implicit receivers of the scripts, whose file name ends with `file_suffix`, e.G.
+/// `org.gradle.api.Project` for `.gradle.kts`. Their members are accessible without qualifier
+#[derive(Deserialize, Debug, Clone)]
+pub struct PScriptDefinition {
+    pub file_suffix: String,
+    /// Fully qualified class names, innermost first
+    pub implicit_receivers: Vec<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -38,6 +50,14 @@ pub struct PSourceSet {
     /// Relative to the project [PProject::root_dir]
     pub src_dir: PathBuf,
     pub dependencies: Vec<PDependency>,
+    /// Names of the source sets this source set refines (kotlin multiplatform `dependsOn`).
+    /// E.G. `jvmMain` depends on `commonMain`
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// The platform this source set is compiled for (e.G. `jvm`, `ios`).
+    /// Only set for source sets which are leafs of the multiplatform hierarchy
+    #[serde(default)]
+    pub target: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -45,6 +65,10 @@ pub struct PDependency {
     pub kind: PDependencyKind,
     pub name: String,
     pub visibility: PDependencyVisibilty,
+    /// Path to the jar of a [PDependencyKind::Jar] dependency. Relative paths are resolved
+    /// against the project [PProject::root_dir]
+    #[serde(default)]
+    pub path: Option<PathBuf>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -57,4 +81,6 @@ pub enum PDependencyVisibilty {
 pub enum PDependencyKind {
     SourceSet,
     Project,
+    /// A library jar. Its class files are indexed, but never edited
+    Jar,
 }
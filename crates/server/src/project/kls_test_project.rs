@@ -30,6 +30,7 @@ impl ProjectI for KlsTestProject {
             name: self.defs.name.clone(),
             root_dir: self.defs.root_dir.clone(),
             source_sets: self.defs.source_sets.clone(),
+            script_definitions: self.defs.script_definitions.clone(),
         })
     }
 }
@@ -40,4 +41,6 @@ struct KlsTestProjectDefs {
     id: i32,
     root_dir: PathBuf,
     source_sets: Vec<PSourceSet>,
+    #[serde(default)]
+    script_definitions: Vec<PScriptDefinition>,
 }
@@ -0,0 +1,283 @@
+//! Demand driven, memoized queries. Results are computed when requested and reused as long as
+//! none of the [Input]s they depend on changed.
+//!
+//! Inputs form the chain file text -> AST -> file scopes -> declarations -> resolution. Only the
+//! resolution is a memoized query. The AST and scopes of a file are not: the did change handler
+//! updates them incrementally from the previous syntax tree, which recomputing them on demand
+//! could not reuse, and swaps in the new file under [Input::File]. The package and symbol indices
+//! are updated along with it. Other files only depend on the declarations of a file, keyed by
+//! their package or class under [Input::Declarations]. The declarations a query depends on are
+//! recorded while it computes, as the [SymbolIndex](crate::symbol_index::SymbolIndex) reports each
+//! container it is read from (see [record_read]). Declarations are cut off early: they only change,
+//! if the [declarations_fingerprint] of a file differs after an edit. Edits within function bodies
+//! therefore do not invalidate the resolution within other files, and edits of declarations only
+//! invalidate the files reading their package or class. There is no type inference yet, so there
+//! are no type queries either.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use indextree::NodeId;
+use parking_lot::Mutex;
+use tracing::trace;
+
+use crate::scope::{GSFile, SKind};
+
+pub type Revision = u64;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Input {
+    /// The text of a project file and everything derived from it within the file
+    File(PathBuf),
+    /// The declarations of all files and libraries directly within a package or class, e.G.
+    /// `Declarations("a.b")` for the top level declarations of package `a.b`
+    Declarations(String),
+    /// Which packages are declared at all
+    Packages,
+}
+
+/// The revision each input last changed at
+#[derive(Debug, Default, Clone)]
+pub struct Revisions {
+    current: Revision,
+    changed_at: HashMap<Input, Revision>,
+    /// The [declarations_fingerprint] of each file
+    fingerprints: HashMap<PathBuf, u64>,
+}
+
+impl Revisions {
+    pub fn current(&self) -> Revision {
+        self.current
+    }
+
+    pub fn changed_at(&self, input: &Input) -> Revision {
+        self.changed_at.get(input).copied().unwrap_or_default()
+    }
+
+    /// Marks `input` as changed in a new revision
+    pub fn bump(&mut self, input: Input) {
+        self.bump_all([input]);
+    }
+
+    /// Marks all of `inputs` as changed in a single new revision
+    pub fn bump_all(&mut self, inputs: impl IntoIterator<Item = Input>) {
+        self.current += 1;
+        for input in inputs {
+            trace!("{:?} changed in revision {}", input, self.current);
+            self.changed_at.insert(input, self.current);
+        }
+    }
+
+    /// Marks the file at `path` as changed. Its `declarations` (e.G. the packages and classes it
+    /// declares before and after the change) only count as changed, if `fingerprint` differs from
+    /// the previous one. Files of unknown fingerprint always change them
+    pub fn file_changed(
+        &mut self,
+        path: &Path,
+        fingerprint: Option<u64>,
+        declarations: impl IntoIterator<Item = Input>,
+    ) {
+        self.bump(Input::File(path.to_owned()));
+        let previous = match fingerprint {
+            Some(fingerprint) => self.fingerprints.insert(path.to_owned(), fingerprint),
+            None => self.fingerprints.remove(path),
+        };
+        if fingerprint.is_none() || previous != fingerprint {
+            self.bump_all(declarations);
+        }
+    }
+}
+
+thread_local! {
+    /// The inputs read by each query computing on this thread, innermost last
+    static READS: RefCell<Vec<HashSet<Input>>> = const { RefCell::new(vec![]) };
+}
+
+/// Records that the query computing on this thread read `input`. `input` is only created, if a
+/// query is computing
+pub fn record_read(input: impl FnOnce() -> Input) {
+    READS.with(|reads| {
+        if let Some(frame) = reads.borrow_mut().last_mut() {
+            frame.insert(input());
+        }
+    });
+}
+
+/// Runs `compute` and returns the inputs it read. They are also read by the enclosing query
+fn recording_reads<V>(compute: impl FnOnce() -> V) -> (V, HashSet<Input>) {
+    /// Pops the frame of `compute`, even if it panics
+    struct Frame;
+    impl Drop for Frame {
+        fn drop(&mut self) {
+            READS.with(|reads| reads.borrow_mut().pop());
+        }
+    }
+
+    READS.with(|reads| reads.borrow_mut().push(HashSet::new()));
+    let frame = Frame;
+    let value = compute();
+    let read = READS.with(|reads| std::mem::take(reads.borrow_mut().last_mut().unwrap()));
+    drop(frame);
+    for input in &read {
+        record_read(|| input.clone());
+    }
+    (value, read)
+}
+
+/// The memoized results of a query for each key
+#[derive(Debug)]
+pub struct QueryCache<K, V> {
+    memos: Mutex<HashMap<K, Memo<V>>>,
+}
+
+#[derive(Debug)]
+struct Memo<V> {
+    value: Arc<V>,
+    computed_at: Revision,
+    dependencies: Vec<Input>,
+    /// The inputs read while computing `value`
+    read: HashSet<Input>,
+}
+
+impl<K, V> Default for QueryCache<K, V> {
+    fn default() -> Self {
+        QueryCache {
+            memos: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone + std::fmt::Debug, V> QueryCache<K, V> {
+    /// Returns the result for `key`. It is computed by `compute`, unless a result memoized
+    /// before is still valid, as none of `dependencies` and none of the inputs read by the
+    /// computation changed since
+    pub fn get(
+        &self,
+        revisions: &Revisions,
+        key: &K,
+        dependencies: Vec<Input>,
+        compute: impl FnOnce() -> V,
+    ) -> Arc<V> {
+        if let Some(memo) = self.memos.lock().get(key) {
+            let is_valid = memo.dependencies == dependencies
+                && dependencies
+                    .iter()
+                    .chain(&memo.read)
+                    .all(|input| revisions.changed_at(input) <= memo.computed_at);
+            if is_valid {
+                trace!(
+                    "Reusing result for {:?} of revision {}",
+                    key,
+                    memo.computed_at
+                );
+                for input in &memo.read {
+                    record_read(|| input.clone());
+                }
+                return memo.value.clone();
+            }
+        }
+
+        // Computed without holding the lock, as queries might depend on other queries
+        let (value, read) = recording_reads(compute);
+        let value = Arc::new(value);
+        self.memos.lock().insert(
+            key.clone(),
+            Memo {
+                value: value.clone(),
+                computed_at: revisions.current(),
+                dependencies,
+                read,
+            },
+        );
+        value
+    }
+}
+
+/// A hash of the declarations of `s_file`, which are visible to other files. Ranges and function
+/// bodies are not part of it
+pub fn declarations_fingerprint(s_file: &GSFile) -> u64 {
+    fn hash_declaration(s_file: &GSFile, node_id: NodeId, hasher: &mut DefaultHasher) {
+        let kind = &s_file.scopes[node_id].get().kind;
+        match kind {
+            SKind::Block(_) | SKind::LocalBinding(_) => return,
+            _ => kind.hash(hasher),
+        }
+        // Children of functions are local declarations
+        if kind.is_fun_decl() || kind.is_java_method() {
+            return;
+        }
+        for child in node_id.children(&s_file.scopes) {
+            hash_declaration(s_file, child, hasher);
+        }
+    }
+
+    let mut hasher = DefaultHasher::new();
+    s_file.package().hash(&mut hasher);
+    for root_node in &s_file.root_nodes {
+        hash_declaration(s_file, *root_node, &mut hasher);
+    }
+    hasher.finish()
+}
+
+#[test]
+fn results_are_reused_until_a_dependency_changes() {
+    let a = Path::new("/a.kt");
+    let b = Path::new("/b.kt");
+    let declarations_of = |container: &str| [Input::Declarations(container.to_string())];
+    let mut revisions = Revisions::default();
+    revisions.file_changed(a, Some(1), declarations_of("a"));
+    revisions.file_changed(b, Some(2), declarations_of("b"));
+    let cache = QueryCache::<PathBuf, usize>::default();
+    let computations = std::cell::Cell::new(0);
+    let get = |revisions: &Revisions| {
+        cache.get(
+            revisions,
+            &b.to_owned(),
+            vec![Input::File(b.to_owned())],
+            || {
+                record_read(|| Input::Declarations("a".to_string()));
+                computations.replace(computations.get() + 1) + 1
+            },
+        )
+    };
+
+    assert_eq!(*get(&revisions), 1);
+    assert_eq!(*get(&revisions), 1);
+    // An edit of a function body of another file keeps its declarations
+    revisions.file_changed(a, Some(1), declarations_of("a"));
+    assert_eq!(*get(&revisions), 1);
+    revisions.file_changed(a, Some(3), declarations_of("a"));
+    assert_eq!(*get(&revisions), 2);
+    revisions.file_changed(b, Some(2), declarations_of("b"));
+    assert_eq!(*get(&revisions), 3);
+}
+
+#[test]
+fn results_are_reused_if_unread_declarations_change() {
+    let a = Path::new("/a.kt");
+    let mut revisions = Revisions::default();
+    let cache = QueryCache::<PathBuf, usize>::default();
+    let computations = std::cell::Cell::new(0);
+    let get = |revisions: &Revisions| {
+        cache.get(
+            revisions,
+            &a.to_owned(),
+            vec![Input::File(a.to_owned())],
+            || {
+                record_read(|| Input::Declarations("b".to_string()));
+                computations.replace(computations.get() + 1) + 1
+            },
+        )
+    };
+
+    assert_eq!(*get(&revisions), 1);
+    revisions.file_changed(Path::new("/c.kt"), None, [Input::Declarations("c".into())]);
+    assert_eq!(*get(&revisions), 1);
+    revisions.file_changed(Path::new("/b.kt"), None, [Input::Declarations("b".into())]);
+    assert_eq!(*get(&revisions), 2);
+}
@@ -24,6 +24,21 @@ pub fn lsp_pos_to_byte_pos(rope: &Rope, point: &tower_lsp::lsp_types::Position)
     rope.byte_of_line(point.line as usize) as u32 + point.character
 }
 
+pub fn byte_pos_to_lsp_pos(rope: &Rope, byte: u32) -> tower_lsp::lsp_types::Position {
+    let line = rope.line_of_byte(byte as usize);
+    tower_lsp::lsp_types::Position {
+        line: line as u32,
+        character: byte - rope.byte_of_line(line) as u32,
+    }
+}
+
+pub fn text_range_to_lsp_range(rope: &Rope, range: TextRange) -> tower_lsp::lsp_types::Range {
+    tower_lsp::lsp_types::Range {
+        start: byte_pos_to_lsp_pos(rope, range.start),
+        end: byte_pos_to_lsp_pos(rope, range.end),
+    }
+}
+
 pub fn ts_point_of(rope: &Rope, byte_offset: usize) -> tree_sitter::Point {
     let row = rope.line_of_byte(byte_offset);
     let col = byte_offset - rope.byte_of_line(row);
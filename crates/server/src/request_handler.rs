@@ -1,2 +1,7 @@
 pub mod print_scopes_handler;
+pub mod code_action_handler;
+pub mod completion_handler;
 pub mod did_change_text_document_handler;
+pub mod goto_definition_handler;
+pub mod hover_handler;
+pub mod library_file_handler;
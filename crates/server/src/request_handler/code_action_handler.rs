@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
+    Diagnostic, NumberOrString, TextEdit, WorkspaceEdit,
+};
+use tracing::debug;
+
+use crate::package_index::PACKAGE_MISMATCH_CODE;
+
+#[derive(new)]
+pub struct CodeActionHandler<'a> {
+    params: &'a CodeActionParams,
+}
+
+impl<'a> CodeActionHandler<'a> {
+    pub fn handle(&self) -> anyhow::Result<Option<CodeActionResponse>> {
+        let actions = self
+            .params
+            .context
+            .diagnostics
+            .iter()
+            .filter_map(|diagnostic| self.quick_fix_of(diagnostic))
+            .map(CodeActionOrCommand::CodeAction)
+            .collect::<Vec<_>>();
+        debug!("Found {} code actions", actions.len());
+
+        Ok(Some(actions))
+    }
+
+    /// Replaces a package, which does not match the directory of its file, by the expected one
+    fn quick_fix_of(&self, diagnostic: &Diagnostic) -> Option<CodeAction> {
+        if diagnostic.code != Some(NumberOrString::String(PACKAGE_MISMATCH_CODE.to_string())) {
+            return None;
+        }
+        let expected = diagnostic.data.as_ref()?.as_str()?;
+        if expected.is_empty() {
+            return None;
+        }
+        // The range is the package name or, if the file has no package header, empty
+        let new_text = if diagnostic.range.start == diagnostic.range.end {
+            format!("package {}\n\n", expected)
+        } else {
+            expected.to_string()
+        };
+        let edit = TextEdit::new(diagnostic.range, new_text);
+        let uri = self.params.text_document.uri.clone();
+
+        Some(CodeAction {
+            title: format!("Change the package to '{}'", expected),
+            kind: Some(CodeActionKind::QUICKFIX),
+            diagnostics: Some(vec![diagnostic.clone()]),
+            edit: Some(WorkspaceEdit {
+                changes: Some(HashMap::from([(uri, vec![edit])])),
+                ..Default::default()
+            }),
+            is_preferred: Some(true),
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{
+    cancellation::CancellationToken,
+    library::LibraryDecl,
+    range_util::lsp_pos_to_byte_pos,
+    resolve,
+    scope::{GSFile, GScopes, GScopesData, SImport},
+    stdlib,
+    symbol_index::{Symbol, SymbolIndex, SymbolKind, SymbolOrigin},
+};
+use itertools::Itertools;
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse,
+};
+use tracing::debug;
+
+#[derive(new)]
+pub struct CompletionHandler<'a> {
+    scopes: &'a GScopes,
+    params: &'a CompletionParams,
+    cancel: &'a CancellationToken,
+}
+
+impl<'a> CompletionHandler<'a> {
+    pub fn handle(&self) -> anyhow::Result<Option<CompletionResponse>> {
+        let position = &self.params.text_document_position;
+        let cancel = self.cancel;
+        let s_file = self.scopes.file_of_uri(&position.text_document.uri)?;
+
+        let r_scopes = self.scopes.snapshot();
+        let s_file = &*s_file;
+        let byte = lsp_pos_to_byte_pos(&s_file.text, &position.position) as usize;
+        let text = s_file.text.byte_slice(..byte).to_string();
+
+        let prefix_start = text
+            .rfind(|c: char| !is_identifier_char(c))
+            .map_or(0, |i| i + 1);
+        let prefix = &text[prefix_start..];
+        let qualifier = text[..prefix_start].strip_suffix('.').map(|before_dot| {
+            let start = before_dot
+                .rfind(|c: char| !(is_identifier_char(c) || c == '.'))
+                .map_or(0, |i| i + 1);
+            &before_dot[start..]
+        });
+
+        let (locals, candidates) = match qualifier {
+            Some(qualifier) => (
+                vec![],
+                qualified_candidates(&r_scopes, s_file, qualifier, byte as u32),
+            ),
+            None => (
+                local_candidates(s_file, byte as u32),
+                visible_candidates(
+                    &r_scopes.symbols,
+                    s_file,
+                    &resolve::script_containers(&r_scopes, s_file),
+                ),
+            ),
+        };
+        cancel.check()?;
+        // Declarations, which are not accessible here (e.G. `private` ones of other files)
+        let candidates = candidates.into_iter().filter(|(_, symbol)| {
+            resolve::inaccessible_reason(&r_scopes, s_file, byte as u32, symbol).is_none()
+        });
+        // Packages are only completed within imports
+        let is_import = text[text.rfind('\n').map_or(0, |i| i + 1)..]
+            .trim_start()
+            .starts_with("import ");
+        let packages = if is_import {
+            let project_packages = r_scopes.packages.packages();
+            sub_packages(
+                project_packages.chain(r_scopes.symbols.packages()),
+                qualifier.unwrap_or_default(),
+            )
+        } else {
+            vec![]
+        };
+        // Locals shadow the declarations of the index
+        let items = locals
+            .iter()
+            .map(|(label, symbol)| (label.clone(), symbol))
+            .chain(candidates)
+            .filter(|(label, _)| label.starts_with(prefix))
+            .map(|(label, symbol)| completion_item(label, symbol))
+            .chain(
+                packages
+                    .into_iter()
+                    .filter(|segment| segment.starts_with(prefix))
+                    .map(|segment| CompletionItem {
+                        label: segment,
+                        kind: Some(CompletionItemKind::MODULE),
+                        ..Default::default()
+                    }),
+            )
+            .unique_by(|item| (item.label.clone(), item.detail.clone()))
+            .collect_vec();
+        cancel.check()?;
+        debug!(
+            "Found {} completions for {:?} (qualifier {:?})",
+            items.len(),
+            prefix,
+            qualifier
+        );
+
+        Ok(Some(CompletionResponse::Array(items)))
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Parameters and local declarations visible at `byte`
+fn local_candidates(s_file: &GSFile, byte: u32) -> Vec<(String, Symbol)> {
+    s_file
+        .locals_at(byte)
+        .iter()
+        .map(|local| (local.name.to_string(), resolve::local_symbol(s_file, local)))
+        .collect()
+}
+
+/// Declarations accessible by their simple name: the ones of the same package, imported or
+/// default imported, and the members of `script_containers`. Returns them with the name they are
+/// accessible by
+fn visible_candidates<'i>(
+    index: &'i SymbolIndex,
+    s_file: &GSFile,
+    script_containers: &[String],
+) -> Vec<(String, &'i Symbol)> {
+    let imports = resolve::imports_of(s_file);
+    let aliased = imports
+        .iter()
+        .filter(|import| import.alias.is_some())
+        .map(|import| import.fq_name.as_str())
+        .collect_vec();
+    let explicitly_imported = imports
+        .iter()
+        .filter(|import| !import.is_wildcard)
+        .flat_map(|import: &&SImport| {
+            index.lookup(&import.fq_name).into_iter().map(|symbol| {
+                let label = import.visible_name().unwrap_or(&symbol.name).to_string();
+                (label, symbol)
+            })
+        });
+    let packages = std::iter::once(s_file.package().unwrap_or_default())
+        .chain(script_containers.iter().map(String::as_str))
+        .chain(
+            imports
+                .iter()
+                .filter(|import| import.is_wildcard)
+                .map(|import| import.fq_name.as_str()),
+        )
+        .chain(stdlib::DEFAULT_IMPORTS.iter().copied())
+        .collect_vec();
+
+    explicitly_imported
+        .chain(
+            packages
+                .into_iter()
+                .flat_map(|p| index.symbols_in(p))
+                // Aliased declarations are only accessible by their alias
+                .filter(|symbol| !aliased.contains(&symbol.fq_name().as_str()))
+                .map(|symbol| (symbol.name.clone(), symbol)),
+        )
+        // Extensions can not be called without a receiver
+        .filter(|(_, symbol)| !is_extension(symbol))
+        .collect()
+}
+
+/// Declarations of the package or class `qualifier`. A simple class name (or type alias) is
+/// resolved first. Members of the class of a local `qualifier` are completed too
+fn qualified_candidates<'i>(
+    scopes: &'i GScopesData,
+    s_file: &GSFile,
+    qualifier: &str,
+    byte: u32,
+) -> Vec<(String, &'i Symbol)> {
+    let mut containers = vec![qualifier.to_string()];
+    if !qualifier.contains('.') {
+        let imports = resolve::imports_of(s_file);
+        containers.extend(resolve::classes_named(scopes, s_file, &imports, qualifier));
+        containers.extend(resolve::classes_of_local(
+            scopes, s_file, &imports, qualifier, byte,
+        ));
+    }
+    containers
+        .iter()
+        .flat_map(|container| scopes.symbols.symbols_in(container))
+        .filter(|symbol| !is_extension(symbol))
+        .map(|symbol| (symbol.name.clone(), symbol))
+        .collect()
+}
+
+/// The names of the direct sub packages of `parent` (e.G. `collections` of `kotlin`)
+fn sub_packages<'p>(packages: impl Iterator<Item = &'p str>, parent: &str) -> Vec<String> {
+    packages
+        .filter_map(|package| {
+            let rest = if parent.is_empty() {
+                package
+            } else {
+                package.strip_prefix(parent)?.strip_prefix('.')?
+            };
+            rest.split('.').next().filter(|segment| !segment.is_empty())
+        })
+        .unique()
+        .map(|segment| segment.to_string())
+        .collect()
+}
+
+fn is_extension(symbol: &Symbol) -> bool {
+    match &symbol.origin {
+        SymbolOrigin::Library(l) => match &l.decl {
+            LibraryDecl::Function(function) => function.receiver_type.is_some(),
+            LibraryDecl::Property(property) => property.receiver_type.is_some(),
+            LibraryDecl::Class(_) => false,
+        },
+        SymbolOrigin::Source { .. } => false,
+    }
+}
+
+fn completion_item(label: String, symbol: &Symbol) -> CompletionItem {
+    CompletionItem {
+        label,
+        kind: Some(match symbol.kind {
+            SymbolKind::Class | SymbolKind::TypeAlias => CompletionItemKind::CLASS,
+            SymbolKind::Function => CompletionItemKind::FUNCTION,
+            SymbolKind::Property => CompletionItemKind::PROPERTY,
+        }),
+        detail: match &symbol.origin {
+            SymbolOrigin::Library(l) => Some(l.decl.to_string()),
+            SymbolOrigin::Source { .. } => None,
+        },
+        ..Default::default()
+    }
+}
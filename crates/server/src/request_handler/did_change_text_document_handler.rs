@@ -1,4 +1,5 @@
 use crate::{
+    library::sources::is_library_uri,
     range_util::*,
     scope::GSFile,
     scope_builder::{ChangedRange, ScopeBuilder, UpsertOrDelete},
@@ -7,11 +8,11 @@ use crop::Rope;
 use stdx::TextRange;
 use tap::Tap;
 use tower_lsp::lsp_types::{DidChangeTextDocumentParams, Range};
-use tracing::{debug, info, instrument::WithSubscriber, trace};
+use tracing::{debug, info, instrument::WithSubscriber, trace, warn};
 use tree_sitter::{InputEdit, Point, Tree};
 
 use crate::{kserver::KServer, to_file_path};
-use anyhow::{anyhow, bail};
+use anyhow::{anyhow, bail, ensure};
 
 #[derive(new)]
 pub struct DidChangeTextDocumentHandler<'a> {
@@ -46,29 +47,28 @@ enum ByteEditOffsets {
 
 impl<'a> DidChangeTextDocumentHandler<'a> {
     pub fn handle(&self) -> anyhow::Result<()> {
-        let file_path = to_file_path(&self.notification.text_document.uri)?;
-
-        let s_file = {
-            let r_scopes = self.server.scopes.0.read();
-            let s_f_node_id = r_scopes.file_nodes.get(&file_path).ok_or_else(|| {
-                anyhow!(
-                    "File {} is not registered in file_nodes. Not handling the change request",
-                    file_path.display()
-                )
-            })?;
+        let uri = &self.notification.text_document.uri;
+        ensure!(
+            !is_library_uri(uri),
+            "Library file {} is read only. Not handling the change request",
+            uri
+        );
+        let file_path = to_file_path(uri)?;
+        // Requests on the old content stop early
+        self.server.file_revisions.increment(&file_path);
 
-            r_scopes
-                .scopes
-                .get(*s_f_node_id)
-                .ok_or_else(|| anyhow!("Registered file_node {} is not in scopes", s_f_node_id))?
-                .get()
-                .clone()
-        };
+        self.server
+            .scopes
+            .update_file(&file_path, |s_file| self.update_file(s_file))
+    }
 
-        let mut w_s_file = s_file.write();
-        let s_file = w_s_file.kind.as_file_mut().unwrap();
+    /// Applies the edits to `s_file`
+    fn update_file(&self, s_file: &mut GSFile) -> anyhow::Result<()> {
         trace!("Buffer before edits:\n{}", s_file.text.to_string());
         trace!("Tree before edits:\n{}", s_file.ast.root_node().to_sexp());
+        if let Some(check) = &self.server.incremental_check {
+            check.record(s_file, self.notification);
+        }
 
         let (changed_ranges, new_ast) = self.edit_rope(s_file)?;
         // from now on everything is a NewRange
@@ -76,11 +76,28 @@ impl<'a> DidChangeTextDocumentHandler<'a> {
         trace!("Buffer after edits:\n{}", s_file.text.to_string());
         trace!("Tree after edits:\n{}", new_ast.root_node().to_sexp());
 
-        for changed_range in changed_ranges {
-            // TODO handle result
-            ScopeBuilder::new(s_file, changed_range).update_scopes(&new_ast)?;
-        }
+        let updated = changed_ranges.into_iter().try_for_each(|changed_range| {
+            ScopeBuilder::new(s_file, changed_range).update_scopes(&new_ast)
+        });
+        // The text and tree are kept in any case, as the editor applied the change already
         s_file.ast = new_ast;
+        if let Err(e) = updated {
+            warn!(
+                "Rebuilding the scopes of {}, as updating them failed: {}",
+                s_file.path.display(),
+                e
+            );
+            s_file.rebuild_scopes()?;
+        }
+        if let Some(check) = &self.server.incremental_check {
+            if let Err(e) = check.verify(s_file) {
+                warn!(
+                    "Verifying the scopes of {} failed: {}",
+                    s_file.path.display(),
+                    e
+                );
+            }
+        }
 
         Ok(())
     }
@@ -134,7 +151,10 @@ impl<'a> DidChangeTextDocumentHandler<'a> {
         }
 
         // Update the tree
-        let new_ast = parser::parse(&s_file.text, Some(&s_file.ast)).expect("No tree returned");
+        let new_ast = s_file
+            .language()
+            .parse(&s_file.text, Some(&s_file.ast))
+            .expect("No tree returned");
         // store changed_ranges for later
         let mut changed_ranges: Vec<_> = s_file
             .ast // the ranges are NewRange
@@ -0,0 +1,80 @@
+use crate::{
+    cancellation::CancellationToken,
+    multiplatform::{self, MultiplatformDecl},
+    range_util::lsp_pos_to_byte_pos,
+    resolve,
+    scope::GScopes,
+    to_file_path,
+};
+use anyhow::anyhow;
+use itertools::Itertools;
+use tower_lsp::lsp_types::{GotoDefinitionParams, GotoDefinitionResponse};
+use tracing::{debug, warn};
+
+#[derive(new)]
+pub struct GotoDefinitionHandler<'a> {
+    scopes: &'a GScopes,
+    params: &'a GotoDefinitionParams,
+    cancel: &'a CancellationToken,
+}
+
+impl<'a> GotoDefinitionHandler<'a> {
+    pub fn handle(&self) -> anyhow::Result<Option<GotoDefinitionResponse>> {
+        let position = &self.params.text_document_position_params;
+        let cancel = self.cancel;
+        let s_file = self.scopes.file_of_uri(&position.text_document.uri)?;
+        let r_scopes = self.scopes.snapshot();
+
+        let (decl, symbols, package) = {
+            let byte = lsp_pos_to_byte_pos(&s_file.text, &position.position);
+            (
+                MultiplatformDecl::at(&s_file, byte).filter(|_| !s_file.is_read_only()),
+                resolve::symbols_at(&r_scopes, &s_file, byte),
+                resolve::package_name_at(&s_file, byte),
+            )
+        };
+        cancel.check()?;
+
+        if let Some(decl) = decl {
+            let file_path = to_file_path(&position.text_document.uri)?;
+            let source_set_id = r_scopes
+                .files
+                .get(&file_path)
+                .ok_or_else(|| anyhow!("File {} is not registered in files", file_path.display()))?
+                .source_set_id;
+
+            let locations = multiplatform::counterparts_of(&r_scopes, source_set_id, &decl)
+                .iter()
+                .filter_map(MultiplatformDecl::location)
+                .collect_vec();
+            debug!("Found {} counterparts of {}", locations.len(), decl.name);
+
+            return Ok(Some(GotoDefinitionResponse::Array(locations)));
+        }
+
+        if let Some(package) = package.filter(|_| symbols.is_empty()) {
+            let locations = resolve::package_locations(self.scopes, &package);
+            debug!("Found {} files of package {}", locations.len(), package);
+            if !locations.is_empty() {
+                return Ok(Some(GotoDefinitionResponse::Array(locations)));
+            }
+        }
+
+        if symbols.is_empty() {
+            debug!("No definition found at {:?}", position.position);
+            return Ok(None);
+        }
+
+        let mut locations = vec![];
+        for symbol in &symbols {
+            cancel.check()?;
+            match resolve::location_of(self.scopes, symbol) {
+                Ok(location) => locations.push(location),
+                Err(e) => warn!("Could not locate {}: {}", symbol.fq_name(), e),
+            }
+        }
+        debug!("Found {} definitions", locations.len());
+
+        Ok(Some(GotoDefinitionResponse::Array(locations)))
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    cancellation::CancellationToken,
+    range_util::lsp_pos_to_byte_pos,
+    resolve,
+    scope::{java_scope, GScopes, SKind},
+    symbol_index::{Symbol, SymbolKind, SymbolOrigin},
+};
+use tower_lsp::lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind};
+use tracing::debug;
+
+#[derive(new)]
+pub struct HoverHandler<'a> {
+    scopes: &'a GScopes,
+    params: &'a HoverParams,
+    cancel: &'a CancellationToken,
+}
+
+impl<'a> HoverHandler<'a> {
+    pub fn handle(&self) -> anyhow::Result<Option<Hover>> {
+        let position = &self.params.text_document_position_params;
+        let cancel = self.cancel;
+        let s_file = self.scopes.file_of_uri(&position.text_document.uri)?;
+
+        let symbols = {
+            let r_scopes = self.scopes.snapshot();
+            let byte = lsp_pos_to_byte_pos(&s_file.text, &position.position);
+            resolve::symbols_at(&r_scopes, &s_file, byte)
+        };
+        cancel.check()?;
+
+        let mut signatures = vec![];
+        for symbol in &symbols {
+            cancel.check()?;
+            signatures.extend(self.signature_of(symbol));
+        }
+        if signatures.is_empty() {
+            debug!("Nothing to hover at {:?}", position.position);
+            return Ok(None);
+        }
+
+        let mut value = format!("```kotlin\n{}\n```", signatures.join("\n"));
+        if let Some(container) = symbols.first().map(|s| &s.container) {
+            if !container.is_empty() {
+                value += &format!("\n\n`{}`", container);
+            }
+        }
+
+        Ok(Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range: None,
+        }))
+    }
+
+    fn signature_of(&self, symbol: &Symbol) -> Option<String> {
+        match &symbol.origin {
+            SymbolOrigin::Library(library_symbol) => Some(library_symbol.decl.to_string()),
+            SymbolOrigin::Source { id, .. } => {
+                let s_file = self.scopes.project_file(&id.path).ok()?;
+                let scope_id = id.scope_in(&s_file)?;
+                match &s_file.scopes[scope_id].get().kind {
+                    SKind::FunDecl(s_fun_decl) if symbol.kind == SymbolKind::Property => s_fun_decl
+                        .parameters
+                        .iter()
+                        .find(|p| p.ident.as_ref() == Some(&symbol.name))
+                        .map(|p| p.to_string()),
+                    SKind::FunDecl(s_fun_decl) => Some(s_fun_decl.signature()),
+                    SKind::Property(s_property) => Some(s_property.to_string()),
+                    // Constructor properties and synthetic members share the range of the class
+                    SKind::Class(s_class) if s_class.ident.as_ref() != Some(&symbol.name) => {
+                        s_class
+                            .constructor_parameters
+                            .iter()
+                            .filter(|_| symbol.kind == SymbolKind::Property)
+                            .find(|p| p.parameter.ident.as_ref() == Some(&symbol.name))
+                            .map(|p| p.to_string())
+                            .or_else(|| {
+                                s_class
+                                    .synthetic_members()
+                                    .into_iter()
+                                    .find(|m| m.name == symbol.name && m.kind == symbol.kind)
+                                    .map(|m| m.signature)
+                            })
+                    }
+                    SKind::Class(s_class) => Some(s_class.to_string()),
+                    SKind::EnumEntry(s_enum_entry) => Some(s_enum_entry.to_string()),
+                    SKind::TypeAlias(s_type_alias) => Some(s_type_alias.to_string()),
+                    SKind::JavaMethod(_) if symbol.kind == SymbolKind::Property => Some(
+                        java_scope::synthetic_property_signature(&s_file, scope_id, &symbol.name),
+                    ),
+                    SKind::JavaClass(s_java_class) => Some(s_java_class.to_string()),
+                    SKind::JavaMethod(s_java_method) => Some(s_java_method.to_string()),
+                    // Declarators of one field declaration share its range
+                    SKind::JavaField(_) => s_file.scopes[scope_id]
+                        .parent()
+                        .into_iter()
+                        .flat_map(|class_id| class_id.children(&s_file.scopes))
+                        .filter_map(|n| s_file.scopes[n].get().kind.as_java_field())
+                        .find(|field| field.ident == symbol.name)
+                        .map(|field| field.to_string()),
+                    SKind::LocalBinding(s_local_binding) => Some(s_local_binding.to_string()),
+                    SKind::PackageHeader { .. } | SKind::Import(_) | SKind::Block(_) => None,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types::Url;
+
+use crate::scope::GScopes;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LibraryFileContentRequest {
+    /// A `kls-jar:` or `kls-stdlib:` uri, as returned by e.G. goto definition
+    pub uri: Url,
+}
+
+#[derive(new)]
+pub struct LibraryFileContentHandler<'a> {
+    scopes: &'a GScopes,
+    request: &'a LibraryFileContentRequest,
+}
+
+impl<'a> LibraryFileContentHandler<'a> {
+    pub fn handle(&self) -> anyhow::Result<String> {
+        let s_file = self.scopes.library_file(&self.request.uri)?;
+        Ok(s_file.text.to_string())
+    }
+}
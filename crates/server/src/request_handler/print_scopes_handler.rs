@@ -1,7 +1,6 @@
 use serde::{Deserialize, Serialize};
 use tree_sitter::Node;
 
-use crate::kserver::KServer;
 use parser::*;
 use tracing::debug;
 
@@ -24,19 +23,45 @@ pub struct PrintAstOptions {
 
 #[derive(new)]
 pub struct PrintScopesHandler<'a> {
-    server: &'a KServer,
+    scopes: &'a GScopes,
     request: &'a PrintScopesRequest,
 }
 
 impl<'a> PrintScopesHandler<'a> {
     pub fn handle(&self) -> anyhow::Result<String> {
-        let r_scopes = self.server.scopes.0.read();
-        let printer =
-            ScopeDebugPrettyPrint::new(&r_scopes.project_nodes[0], &r_scopes.scopes, self.request);
+        let scopes = self.scopes.snapshot();
+        let mut arena = Arena::new();
+        let root = print_tree(&scopes, scopes.project_nodes[0], &mut arena);
+        let printer = ScopeDebugPrettyPrint::new(&root, &arena, self.request);
         Ok(format!("{:?}", printer))
     }
 }
 
+/// A node of the printed tree. Files are not part of the arena of the global scopes
+enum PrintNode {
+    Global(ARwLock<GScope>),
+    File(Arc<GSFile>),
+}
+
+/// Copies the global scope `id` and its descendants into `arena`. The files of source sets are
+/// appended to them, ordered by path
+fn print_tree(scopes: &GScopesData, id: NodeId, arena: &mut Arena<PrintNode>) -> NodeId {
+    let node_id = arena.new_node(PrintNode::Global(scopes.scopes[id].get().clone()));
+    for child in id.children(&scopes.scopes) {
+        let child_id = print_tree(scopes, child, arena);
+        node_id.append(child_id, arena);
+    }
+    let files = scopes
+        .files
+        .values()
+        .filter(|project_file| project_file.source_set_id == id)
+        .sorted_by(|a, b| a.s_file.path.cmp(&b.s_file.path));
+    for project_file in files {
+        node_id.append_value(PrintNode::File(project_file.s_file.clone()), arena);
+    }
+    node_id
+}
+
 impl<'a, P: Printable> ScopeDebugPrettyPrint<'a, P> {
     /// Creates a new `DebugPrettyPrint` object for the node.
     #[inline]
@@ -53,10 +78,18 @@ trait Printable {
     fn print(&self, request: &PrintScopesRequest) -> String;
 }
 
-impl Printable for ARwLock<GScope> {
+impl Printable for PrintNode {
     fn print(&self, request: &PrintScopesRequest) -> String {
-        let r_scope = self.read();
-        match &r_scope.kind {
+        match self {
+            PrintNode::Global(scope) => scope.read().print(request),
+            PrintNode::File(s_file) => s_file.print(request),
+        }
+    }
+}
+
+impl Printable for GScope {
+    fn print(&self, _: &PrintScopesRequest) -> String {
+        match &self.kind {
             GSKind::Project(project) => format!("Project {}", project.data.name.clone()),
 
             GSKind::SourceSet(source_set) => {
@@ -73,65 +106,68 @@ impl Printable for ARwLock<GScope> {
                 }
                 result
             }
+        }
+    }
+}
 
-            GSKind::File(s_file) => {
-                let file_path = match &request.trim_from_file_paths {
-                    Some(prefix) => s_file
-                        .path
-                        .strip_prefix(prefix)
-                        .expect("Could not strip passed prefix from file")
-                        .display()
-                        .to_string(),
-                    None => s_file.path.display().to_string(),
-                };
-
-                let mut result = format!("File ({})\n", file_path);
-
-                if request.print_file_contents && !s_file.text.is_empty() {
-                    result += &format!("{}", s_file.text);
+impl Printable for GSFile {
+    fn print(&self, request: &PrintScopesRequest) -> String {
+        let s_file = self;
+        let file_path = match &request.trim_from_file_paths {
+            Some(prefix) => s_file
+                .path
+                .strip_prefix(prefix)
+                .expect("Could not strip passed prefix from file")
+                .display()
+                .to_string(),
+            None => s_file.path.display().to_string(),
+        };
 
-                    if request.print_ast.is_some() || request.print_scopes {
-                        result += "=============\n"
-                    }
-                }
+        let mut result = format!("File ({})\n", file_path);
 
-                if let Some(print_ast_options) = &request.print_ast {
-                    if print_ast_options.print_ast {
-                        let mut tree = "".to_string();
-                        dfs_descend(&s_file.ast.root_node(), 0, &mut |node, depth| {
-                            if node.kind_id() == *parser::node::SourceFileId {
-                                tree += &format!("{}\n", node.kind());
-                            } else {
-                                tree += &format!(
-                                    "{}{} {}-{} ({})\n",
-                                    " ".repeat(depth * 2),
-                                    node.kind(),
-                                    node.start_position(),
-                                    node.end_position(),
-                                    parser::text_of(node, &s_file.text),
-                                );
-                            }
-                        });
-                        result += &tree;
-                    }
+        if request.print_file_contents && !s_file.text.is_empty() {
+            result += &format!("{}", s_file.text);
 
-                    if request.print_scopes {
-                        result += "=============\n"
-                    }
-                };
+            if request.print_ast.is_some() || request.print_scopes {
+                result += "=============\n"
+            }
+        }
 
-                if request.print_scopes {
-                    for root_node in &s_file.root_nodes {
-                        result += &format!(
-                            "{:?}\n",
-                            ScopeDebugPrettyPrint::new(root_node, &s_file.scopes, request)
+        if let Some(print_ast_options) = &request.print_ast {
+            if print_ast_options.print_ast {
+                let mut tree = "".to_string();
+                dfs_descend(&s_file.ast.root_node(), 0, &mut |node, depth| {
+                    if depth == 0 {
+                        tree += &format!("{}\n", node.kind());
+                    } else {
+                        tree += &format!(
+                            "{}{} {}-{} ({})\n",
+                            " ".repeat(depth * 2),
+                            node.kind(),
+                            node.start_position(),
+                            node.end_position(),
+                            parser::text_of(node, &s_file.text),
                         );
                     }
-                }
+                });
+                result += &tree;
+            }
 
-                result
+            if request.print_scopes {
+                result += "=============\n"
+            }
+        };
+
+        if request.print_scopes {
+            for root_node in &s_file.root_nodes {
+                result += &format!(
+                    "{:?}\n",
+                    ScopeDebugPrettyPrint::new(root_node, &s_file.scopes, request)
+                );
             }
         }
+
+        result
     }
 }
 
@@ -139,10 +175,24 @@ impl Printable for Scope {
     fn print(&self, _: &PrintScopesRequest) -> String {
         let data = match &self.kind {
             SKind::PackageHeader { ident } => format!("package {}", ident),
+            SKind::Import(s_import) => format!("{}", s_import),
             SKind::FunDecl(s_fun_decl) => format!("{}", s_fun_decl),
+            SKind::Property(s_property) => format!("{}", s_property),
+            SKind::Class(s_class) => format!("{}", s_class),
+            SKind::EnumEntry(s_enum_entry) => format!("{}", s_enum_entry),
+            SKind::TypeAlias(s_type_alias) => format!("{}", s_type_alias),
+            SKind::Block(block_kind) => format!("{}", block_kind),
+            SKind::LocalBinding(s_local_binding) => format!("{}", s_local_binding),
+            SKind::JavaClass(s_java_class) => format!("{}", s_java_class),
+            SKind::JavaMethod(s_java_method) => format!("{}", s_java_method),
+            SKind::JavaField(s_java_field) => format!("{}", s_java_field),
         };
 
-        format!("({}) {}", self.range, data)
+        if self.is_recovered {
+            format!("({}) {} (recovered)", self.range, data)
+        } else {
+            format!("({}) {}", self.range, data)
+        }
     }
 }
 
@@ -161,7 +211,7 @@ use crate::scope::*;
 use core::fmt::{self, Write as _};
 use indextree::*;
 use itertools::Itertools;
-use std::{fmt::Debug, path::PathBuf, vec::Vec};
+use std::{fmt::Debug, path::PathBuf, sync::Arc, vec::Vec};
 use stdx::ARwLock;
 
 //use crate::dynamic::hierarchy::traverse::{DepthFirstTraverser, DftEvent};
@@ -392,7 +442,7 @@ impl<'a, P: Printable> fmt::Debug for ScopeDebugPrettyPrint<'a, P> {
         }
 
         // Print the descendants.
-        while let Some(id) = prepare_next_node_printing(&mut writer, &mut traverser, &self.arena)? {
+        while let Some(id) = prepare_next_node_printing(&mut writer, &mut traverser, self.arena)? {
             let data = self.arena[id].get();
             writer.write_str(&data.print(self.request))?;
         }
@@ -404,14 +454,13 @@ impl<'a, P: Printable> fmt::Debug for ScopeDebugPrettyPrint<'a, P> {
 /// Prepares printing of next node.
 ///
 /// Internally, this searches next node open and adjust indent level and prefix.
-fn prepare_next_node_printing<'a, T, P: Printable>(
+fn prepare_next_node_printing<T, P: Printable>(
     writer: &mut IndentWriter<'_, '_>,
     traverser: &mut Traverse<'_, T>,
     arena: &Arena<P>,
 ) -> Result<Option<NodeId>, fmt::Error> {
-    // Not using `for ev in traverser` in order to access to `traverser`
-    // directly in the loop.
-    while let Some(ev) = traverser.next() {
+    // Iterating `by_ref` in order to continue with `traverser` for the next node.
+    for ev in traverser.by_ref() {
         let id = match ev {
             NodeEdge::Start(id) => id,
             NodeEdge::End(_) => {
@@ -0,0 +1,1049 @@
+//! Resolution of references to declarations of the [SymbolIndex]
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+use stdx::TextRange;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Location, Range};
+use tracing::{debug, trace};
+use tree_sitter::Node;
+
+use crate::{
+    cancellation::CancellationToken,
+    library::{
+        kotlin_metadata::{KmClassifier, KmValueParameter},
+        sources::source_uri_of,
+        LibraryDecl, LibrarySymbol,
+    },
+    query::Input,
+    range_util::text_range_to_lsp_range,
+    scope::{
+        class_scope::component_name,
+        decl_id::DeclKind,
+        fun_decl_scope::{Type_, Visibility},
+        local_scope::Local,
+        DeclId, GSFile, GScopes, GScopesData, SClass, SImport, SKind, SourceLanguage,
+    },
+    stdlib,
+    symbol_index::{Symbol, SymbolIndex, SymbolKind, SymbolOrigin},
+};
+
+/// Returns the imports of `s_file`
+pub fn imports_of(s_file: &GSFile) -> Vec<&SImport> {
+    s_file
+        .root_nodes
+        .iter()
+        .filter_map(|n| s_file.scopes[*n].get().kind.as_import())
+        .collect()
+}
+
+/// Packages, which are not part of the index. Imports of them are not reported as unresolved
+const UNINDEXED_PACKAGES: &[&str] = &["java", "javax", "jdk", "sun"];
+
+/// Adds the diagnostics of imports and references of each project file, which do not resolve to
+/// an accessible declaration. They are recomputed only for files, whose text or the declarations
+/// they read (e.G. of an imported package) changed
+pub fn add_resolution_diagnostics(
+    scopes: &GScopesData,
+    diagnostics: &mut HashMap<PathBuf, Vec<Diagnostic>>,
+    cancel: &CancellationToken,
+) -> anyhow::Result<()> {
+    for (path, project_file) in scopes.files.iter() {
+        cancel.check()?;
+        let dependencies = vec![Input::File(path.clone())];
+        let file_diagnostics =
+            scopes
+                .resolution_diagnostics
+                .get(&scopes.revisions, path, dependencies, || {
+                    let s_file = &project_file.s_file;
+                    let mut file_diagnostics = unresolved_import_diagnostics(scopes, s_file);
+                    file_diagnostics.extend(inaccessible_reference_diagnostics(scopes, s_file));
+                    file_diagnostics
+                });
+        if !file_diagnostics.is_empty() {
+            diagnostics
+                .entry(path.clone())
+                .or_default()
+                .extend(file_diagnostics.iter().cloned());
+        }
+    }
+    Ok(())
+}
+
+/// Diagnostics for the imports of `s_file`, which resolve to no declaration
+fn unresolved_import_diagnostics(scopes: &GScopesData, s_file: &GSFile) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    for import_id in &s_file.root_nodes {
+        let scope = s_file.scopes[*import_id].get();
+        let Some(s_import) = scope.kind.as_import() else {
+            continue;
+        };
+        if is_resolved(&scopes.symbols, s_import) {
+            continue;
+        }
+        debug!(
+            "Import {} of {} is unresolved",
+            s_import.fq_name,
+            s_file.path.display()
+        );
+        diagnostics.push(Diagnostic {
+            range: text_range_to_lsp_range(&s_file.text, scope.range),
+            severity: Some(DiagnosticSeverity::ERROR),
+            source: Some("kls".to_string()),
+            message: format!("Unresolved import {}", s_import.fq_name),
+            ..Default::default()
+        });
+    }
+    diagnostics
+}
+
+fn is_resolved(index: &SymbolIndex, s_import: &SImport) -> bool {
+    let root_package = s_import.fq_name.split('.').next().unwrap_or_default();
+    if UNINDEXED_PACKAGES.contains(&root_package) {
+        return true;
+    }
+    if s_import.is_wildcard {
+        index.has_package(&s_import.fq_name) || !index.symbols_in(&s_import.fq_name).is_empty()
+    } else {
+        !index.lookup(&s_import.fq_name).is_empty()
+    }
+}
+
+/// Diagnostics for the references within `s_file`, which only resolve to declarations not
+/// accessible there
+fn inaccessible_reference_diagnostics(scopes: &GScopesData, s_file: &GSFile) -> Vec<Diagnostic> {
+    if s_file.language() != SourceLanguage::Kotlin {
+        return vec![];
+    }
+    let imports = imports_of(s_file);
+    let mut references = vec![];
+    parser::bfs_descend(&s_file.ast.root_node(), |node| {
+        if is_reference(scopes, s_file, &imports, node) {
+            references.extend(TextRange::try_from(node.byte_range()).ok());
+        }
+        node.kind_id() != *parser::node::PackageHeaderId
+    });
+
+    let mut diagnostics = vec![];
+    for reference in references {
+        let byte = reference.start;
+        // The reason of the innermost declaration skipped as inaccessible
+        let first_reason = RefCell::new(None);
+        let is_accessible = |symbol: &Symbol| {
+            let Some(reason) = inaccessible_reason(scopes, s_file, byte, symbol) else {
+                return true;
+            };
+            first_reason.borrow_mut().get_or_insert(reason);
+            false
+        };
+        if !resolve_at(scopes, s_file, byte, &is_accessible).is_empty() {
+            continue;
+        }
+        let Some(reason) = first_reason.into_inner() else {
+            continue;
+        };
+        debug!("{} at {} of {}", reason, byte, s_file.path.display());
+        diagnostics.push(Diagnostic {
+            range: text_range_to_lsp_range(&s_file.text, reference),
+            severity: Some(DiagnosticSeverity::ERROR),
+            source: Some("kls".to_string()),
+            message: reason,
+            ..Default::default()
+        });
+    }
+    diagnostics
+}
+
+/// Whether `node` is an identifier referencing a declaration. Names of declarations and named
+/// arguments are no references, neither are members of receivers of unknown class
+fn is_reference(scopes: &GScopesData, s_file: &GSFile, imports: &[&SImport], node: &Node) -> bool {
+    if node.kind_id() != *parser::node::SimpleIdentifierId
+        && node.kind_id() != *parser::node::TypeIdentifierId
+    {
+        return false;
+    }
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    let parent_kind = parent.kind_id();
+    let is_declaration_name = parent_kind == *parser::node::ClassDeclarationId
+        || parent_kind == *parser::node::ObjectDeclarationId
+        || parent_kind == *parser::node::FunctionDeclarationId
+        || parent_kind == *parser::node::VariableDeclarationId
+        || parent_kind == *parser::node::TypeAliasId
+        || parent_kind == *parser::node::ParameterId
+        || parent_kind == *parser::node::ClassParameterId
+        || parent_kind == *parser::node::ParameterWithOptionalTypeId
+        || parent_kind == *parser::node::TypeParameterId
+        || parent_kind == *parser::node::EnumEntryId
+        || parent_kind == *parser::node::ImportAliasId;
+    if is_declaration_name {
+        return false;
+    }
+    if parent_kind == *parser::node::ValueArgumentId
+        && node.next_sibling().is_some_and(|next| next.kind() == "=")
+    {
+        return false;
+    }
+    // Only the first segment of `a.B` is resolved by its simple name
+    let mut previous = node.prev_named_sibling();
+    while let Some(sibling) = previous {
+        if sibling.kind_id() == *parser::node::TypeIdentifierId {
+            return false;
+        }
+        previous = sibling.prev_named_sibling();
+    }
+    navigation_receiver(node).is_none()
+        || class_qualifier_of(scopes, s_file, imports, node).is_some()
+}
+
+/// Returns the declarations referenced by the identifier at `byte`. Declarations, which are not
+/// accessible at `byte` (e.G. `private` ones of other files), are not returned
+pub fn symbols_at(scopes: &GScopesData, s_file: &GSFile, byte: u32) -> Vec<Symbol> {
+    let is_accessible =
+        |symbol: &Symbol| inaccessible_reason(scopes, s_file, byte, symbol).is_none();
+    resolve_at(scopes, s_file, byte, &is_accessible)
+}
+
+/// Resolves the identifier at `byte` to the declarations satisfying `is_accessible`
+fn resolve_at(
+    scopes: &GScopesData,
+    s_file: &GSFile,
+    byte: u32,
+    is_accessible: &dyn Fn(&Symbol) -> bool,
+) -> Vec<Symbol> {
+    let index = &scopes.symbols;
+    // References within java files are not resolved
+    if s_file.language() != SourceLanguage::Kotlin {
+        return vec![];
+    }
+    let Some(node) = s_file
+        .ast
+        .root_node()
+        .descendant_for_byte_range(byte as usize, byte as usize)
+    else {
+        return vec![];
+    };
+    if node.kind_id() != *parser::node::SimpleIdentifierId
+        && node.kind_id() != *parser::node::TypeIdentifierId
+    {
+        trace!("No identifier at byte {}, but {}", byte, node.kind());
+        return vec![];
+    }
+
+    if node
+        .parent()
+        .and_then(|identifier| identifier.parent())
+        .is_some_and(|header| header.kind_id() == *parser::node::PackageHeaderId)
+    {
+        // Packages are no declarations of the index. See [package_name_at]
+        return vec![];
+    }
+    if let Some(fq_name) = imported_name_up_to(s_file, &node) {
+        debug!("Resolving import {}", fq_name);
+        return index
+            .lookup(&fq_name)
+            .into_iter()
+            .filter(|symbol| is_accessible(symbol))
+            .cloned()
+            .collect();
+    }
+
+    let name = parser::text_of(&node, &s_file.text);
+    let imports = imports_of(s_file);
+    if let Some(class_fq_names) = class_qualifier_of(scopes, s_file, &imports, &node) {
+        debug!("Resolving {} as member of {:?}", name, class_fq_names);
+        return class_fq_names
+            .iter()
+            .flat_map(|class_fq_name| members_named(index, class_fq_name, &name, is_accessible))
+            .collect();
+    }
+    if navigation_receiver(&node).is_some() {
+        // Unrelated declarations of the same name must not be found instead of the member
+        debug!(
+            "Not resolving {}, the class of its receiver is not known",
+            name
+        );
+        return vec![];
+    }
+    if let Some(components) = destructured_components_of(scopes, s_file, &imports, &node) {
+        debug!("Resolved {} to {} components", name, components.len());
+        return components
+            .into_iter()
+            .filter(|symbol| is_accessible(symbol))
+            .collect();
+    }
+    if let Some(local) = s_file
+        .locals_at(byte)
+        .iter()
+        .find(|local| local.name == name)
+    {
+        debug!("Resolved {} to a local declaration", name);
+        return vec![local_symbol(s_file, local)];
+    }
+    for class_fq_name in enclosing_class_containers(scopes, s_file, &imports, byte) {
+        let members = members_named(index, &class_fq_name, &name, is_accessible);
+        if !members.is_empty() {
+            debug!("Resolved {} to a member of {}", name, class_fq_name);
+            return members;
+        }
+    }
+    resolve_name(index, s_file, &imports, &name, is_accessible)
+}
+
+/// If `node` is a variable of a destructuring declaration, e.G. `b` of `val (a, b) = pair`,
+/// returns the `componentN` functions binding it. None if the destructured class is not known
+fn destructured_components_of(
+    scopes: &GScopesData,
+    s_file: &GSFile,
+    imports: &[&SImport],
+    node: &Node,
+) -> Option<Vec<Symbol>> {
+    let variable = node.parent()?;
+    if variable.kind_id() != *parser::node::VariableDeclarationId {
+        return None;
+    }
+    let declaration = variable.parent()?;
+    if declaration.kind_id() != *parser::node::MultiVariableDeclarationId {
+        return None;
+    }
+    // Variables of loops and lambdas destructure elements of unknown type
+    let property = declaration.parent()?;
+    if property.kind_id() != *parser::node::PropertyDeclarationId {
+        return None;
+    }
+    let mut cursor = declaration.walk();
+    let index = declaration
+        .named_children(&mut cursor)
+        .filter(|child| child.kind_id() == *parser::node::VariableDeclarationId)
+        .position(|child| child.id() == variable.id())?;
+    let initializer = initializer_of(&property)?;
+    let classes = classes_of_expression(scopes, s_file, imports, &initializer);
+    if classes.is_empty() {
+        return None;
+    }
+    let component = component_name(index);
+    Some(
+        classes
+            .iter()
+            .flat_map(|class_fq_name| {
+                members_named(&scopes.symbols, class_fq_name, &component, &|_| true)
+            })
+            .collect(),
+    )
+}
+
+/// The expression following `=` of a property declaration
+fn initializer_of<'a>(property: &Node<'a>) -> Option<Node<'a>> {
+    let mut cursor = property.walk();
+    let children = property.children(&mut cursor).collect_vec();
+    let assignment = children.iter().position(|child| child.kind() == "=")?;
+    children[assignment + 1..]
+        .iter()
+        .find(|child| child.is_named())
+        .copied()
+}
+
+/// The fully qualified names of the classes `expression` evaluates to. Only constructor calls and
+/// locals, whose type is declared or follows from their initializer, are known
+fn classes_of_expression(
+    scopes: &GScopesData,
+    s_file: &GSFile,
+    imports: &[&SImport],
+    expression: &Node,
+) -> Vec<String> {
+    if expression.kind_id() == *parser::node::CallExpressionId {
+        return match expression.named_child(0) {
+            Some(callee) if callee.kind_id() == *parser::node::SimpleIdentifierId => classes_named(
+                scopes,
+                s_file,
+                imports,
+                &parser::text_of(&callee, &s_file.text),
+            ),
+            _ => vec![],
+        };
+    }
+    if expression.kind_id() != *parser::node::SimpleIdentifierId {
+        return vec![];
+    }
+    let name = parser::text_of(expression, &s_file.text);
+    classes_of_local(
+        scopes,
+        s_file,
+        imports,
+        &name,
+        expression.start_byte() as u32,
+    )
+}
+
+/// The fully qualified names of the classes of the local `name` visible at `byte`. Empty if
+/// there is no such local or its class is not known
+pub fn classes_of_local(
+    scopes: &GScopesData,
+    s_file: &GSFile,
+    imports: &[&SImport],
+    name: &str,
+    byte: u32,
+) -> Vec<String> {
+    let locals = s_file.locals_at(byte);
+    let Some(local) = locals.iter().find(|local| local.name == name) else {
+        return vec![];
+    };
+    let scope = s_file.scopes[local.scope_id].get();
+    let type_ = match &scope.kind {
+        SKind::FunDecl(s_fun_decl) if local.kind == SymbolKind::Property => s_fun_decl
+            .parameters
+            .iter()
+            .find(|parameter| parameter.ident.as_deref() == Some(local.name))
+            .and_then(|parameter| parameter.type_.clone()),
+        SKind::LocalBinding(s_local_binding) => s_local_binding.type_.clone(),
+        SKind::Property(s_property) if s_property.type_.is_some() => s_property.type_.clone(),
+        SKind::Property(_) => {
+            // The initializer precedes the property, so that resolving it terminates
+            let initializer = s_file
+                .ast
+                .root_node()
+                .descendant_for_byte_range(scope.range.start as usize, scope.range.end as usize)
+                .filter(|property| property.kind_id() == *parser::node::PropertyDeclarationId)
+                .and_then(|property| initializer_of(&property));
+            return initializer.map_or(vec![], |initializer| {
+                classes_of_expression(scopes, s_file, imports, &initializer)
+            });
+        }
+        _ => None,
+    };
+    type_
+        .and_then(|type_| type_.classifier_name())
+        .map_or(vec![], |name| classes_named(scopes, s_file, imports, &name))
+}
+
+fn members_named(
+    index: &SymbolIndex,
+    class_fq_name: &str,
+    name: &str,
+    is_accessible: &dyn Fn(&Symbol) -> bool,
+) -> Vec<Symbol> {
+    index
+        .symbols_in(class_fq_name)
+        .iter()
+        .filter(|symbol| symbol.name == name && is_accessible(symbol))
+        .cloned()
+        .collect()
+}
+
+/// The receiver of the navigation `node` is the member of, e.G. `a` for `x` of `a.x`
+fn navigation_receiver<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    let suffix = node.parent()?;
+    if suffix.kind_id() != *parser::node::NavigationSuffixId {
+        return None;
+    }
+    suffix.prev_named_sibling()
+}
+
+/// If `node` is the member of a navigation on a class name (e.G. `x` of `Registry.x`), on a
+/// local of a known class or on `this`, returns the fully qualified names of the class. Type
+/// aliases resolve to the aliased class
+fn class_qualifier_of(
+    scopes: &GScopesData,
+    s_file: &GSFile,
+    imports: &[&SImport],
+    node: &Node,
+) -> Option<Vec<String>> {
+    let receiver = navigation_receiver(node)?;
+    if receiver.kind_id() == *parser::node::ThisExpressionId {
+        let classes =
+            enclosing_class_containers(scopes, s_file, imports, receiver.start_byte() as u32);
+        return (!classes.is_empty()).then_some(classes);
+    }
+    if receiver.kind_id() != *parser::node::SimpleIdentifierId {
+        return None;
+    }
+    let receiver_name = parser::text_of(&receiver, &s_file.text);
+    let receiver_byte = receiver.start_byte() as u32;
+    let classes = if s_file
+        .locals_at(receiver_byte)
+        .iter()
+        .any(|local| local.name == receiver_name)
+    {
+        classes_of_local(scopes, s_file, imports, &receiver_name, receiver_byte)
+    } else {
+        classes_named(scopes, s_file, imports, &receiver_name)
+    };
+    (!classes.is_empty()).then_some(classes)
+}
+
+/// The fully qualified names of the classes the unqualified `name` resolves to within `s_file`.
+/// Type aliases resolve to the aliased class
+pub fn classes_named(
+    scopes: &GScopesData,
+    s_file: &GSFile,
+    imports: &[&SImport],
+    name: &str,
+) -> Vec<String> {
+    let symbols = resolve_name(&scopes.symbols, s_file, imports, name, &|_| true);
+    class_names_of(scopes, s_file, symbols)
+}
+
+/// The fully qualified names of the classes among `symbols`. Type aliases are expanded
+fn class_names_of(scopes: &GScopesData, s_file: &GSFile, symbols: Vec<Symbol>) -> Vec<String> {
+    symbols
+        .iter()
+        .flat_map(|symbol| match symbol.kind {
+            SymbolKind::TypeAlias => expand_type_alias(scopes, s_file, symbol),
+            _ => vec![symbol.clone()],
+        })
+        .filter(|symbol| symbol.kind == SymbolKind::Class)
+        .map(|symbol| symbol.fq_name())
+        .unique()
+        .collect()
+}
+
+/// Aliases of aliases are followed up to this depth
+const MAX_TYPE_ALIAS_DEPTH: usize = 8;
+
+/// The declarations the type alias `symbol` resolves to. `s_file` is the file currently read
+pub fn expand_type_alias(scopes: &GScopesData, s_file: &GSFile, symbol: &Symbol) -> Vec<Symbol> {
+    let mut symbols = vec![symbol.clone()];
+    for _ in 0..MAX_TYPE_ALIAS_DEPTH {
+        if symbols.iter().all(|s| s.kind != SymbolKind::TypeAlias) {
+            break;
+        }
+        symbols = symbols
+            .iter()
+            .flat_map(|s| match s.kind {
+                SymbolKind::TypeAlias => type_alias_target(scopes, s_file, s),
+                _ => vec![s.clone()],
+            })
+            .collect();
+    }
+    symbols.retain(|s| s.kind != SymbolKind::TypeAlias);
+    symbols
+}
+
+/// Resolves the target of the type alias `symbol` within the file declaring it
+fn type_alias_target(scopes: &GScopesData, s_file: &GSFile, symbol: &Symbol) -> Vec<Symbol> {
+    let SymbolOrigin::Source { id, .. } = &symbol.origin else {
+        return vec![];
+    };
+    let target_in = |declaring: &GSFile| {
+        let Some(name) = id
+            .scope_in(declaring)
+            .and_then(|scope_id| declaring.scopes[scope_id].get().kind.as_type_alias())
+            .and_then(|s_type_alias| s_type_alias.target.as_ref()?.classifier_name())
+        else {
+            return vec![];
+        };
+        let imports = imports_of(declaring);
+        let symbols = resolve_name(&scopes.symbols, declaring, &imports, &name, &|_| true);
+        if symbols.is_empty() {
+            // Fully qualified, e.G. `kotlin.collections.List`
+            scopes.symbols.lookup(&name).into_iter().cloned().collect()
+        } else {
+            symbols
+        }
+    };
+
+    if id.path == s_file.path {
+        return target_in(s_file);
+    }
+    scopes
+        .project_file(&id.path)
+        .map_or(vec![], |declaring| target_in(&declaring))
+}
+
+/// The classes enclosing `byte`, innermost first, with their fully qualified name
+fn enclosing_classes(s_file: &GSFile, byte: u32) -> Vec<(String, &SClass)> {
+    let Some(innermost) = s_file.scope_at_byte(byte) else {
+        return vec![];
+    };
+    let classes = innermost
+        .ancestors(&s_file.scopes)
+        .filter_map(|ancestor| s_file.scopes[ancestor].get().kind.as_class())
+        .filter(|s_class| s_class.ident.is_some())
+        .collect_vec();
+    let top_level_container = s_file.top_level_container();
+    (0..classes.len())
+        .map(|i| {
+            let fq_name = classes[i..].iter().rev().fold(
+                top_level_container.clone(),
+                |container, s_class| {
+                    qualified(&container, s_class.ident.as_deref().unwrap_or_default())
+                },
+            );
+            (fq_name, classes[i])
+        })
+        .collect()
+}
+
+/// The containers of the members accessible without qualifier at `byte`: the enclosing classes
+/// and their supertypes, innermost first. The implicit receivers of scripts are the
+/// outermost
+fn enclosing_class_containers(
+    scopes: &GScopesData,
+    s_file: &GSFile,
+    imports: &[&SImport],
+    byte: u32,
+) -> Vec<String> {
+    enclosing_classes(s_file, byte)
+        .into_iter()
+        .flat_map(|(fq_name, s_class)| {
+            std::iter::once(fq_name).chain(supertypes_of(scopes, s_file, imports, s_class))
+        })
+        .chain(script_containers(scopes, s_file))
+        .unique()
+        .collect()
+}
+
+/// The containers of the members accessible without qualifier anywhere in the script `s_file`:
+/// the script class and its implicit receivers. Empty for other files
+pub fn script_containers(scopes: &GScopesData, s_file: &GSFile) -> Vec<String> {
+    if !s_file.is_script() {
+        return vec![];
+    }
+    std::iter::once(s_file.top_level_container())
+        .chain(scopes.script_receivers(&s_file.path))
+        .collect()
+}
+
+/// The fully qualified names of the supertypes of `s_class` as far as they can be resolved,
+/// including the supertypes of supertypes
+fn supertypes_of(
+    scopes: &GScopesData,
+    s_file: &GSFile,
+    imports: &[&SImport],
+    s_class: &SClass,
+) -> Vec<String> {
+    let mut supertypes = resolve_supertypes(scopes, s_file, imports, &s_class.supertypes);
+    let mut visited = supertypes.iter().cloned().collect::<HashSet<_>>();
+    let mut i = 0;
+    while i < supertypes.len() {
+        for supertype in supertypes_of_class(scopes, &supertypes[i]) {
+            if visited.insert(supertype.clone()) {
+                supertypes.push(supertype);
+            }
+        }
+        i += 1;
+    }
+    supertypes
+}
+
+/// The direct supertypes of the class `fq_name`, resolved within the file declaring it
+fn supertypes_of_class(scopes: &GScopesData, fq_name: &str) -> Vec<String> {
+    scopes
+        .symbols
+        .lookup(fq_name)
+        .into_iter()
+        .filter(|symbol| symbol.kind == SymbolKind::Class)
+        .flat_map(|symbol| match &symbol.origin {
+            SymbolOrigin::Source { id, .. } => {
+                let Ok(declaring) = scopes.project_file(&id.path) else {
+                    return vec![];
+                };
+                let Some(scope_id) = id.scope_in(&declaring) else {
+                    return vec![];
+                };
+                let supertypes = match &declaring.scopes[scope_id].get().kind {
+                    SKind::Class(s_class) => &s_class.supertypes,
+                    SKind::JavaClass(s_java_class) => &s_java_class.supertypes,
+                    _ => return vec![],
+                };
+                resolve_supertypes(scopes, &declaring, &imports_of(&declaring), supertypes)
+            }
+            SymbolOrigin::Library(l) => match &l.decl {
+                LibraryDecl::Class(km_class) => km_class
+                    .supertypes
+                    .iter()
+                    .filter_map(|supertype| match &supertype.classifier {
+                        KmClassifier::Class(name) => Some(name.replace('/', ".")),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            },
+        })
+        .collect()
+}
+
+/// The fully qualified names of `supertypes` as far as they can be resolved within `s_file`
+fn resolve_supertypes(
+    scopes: &GScopesData,
+    s_file: &GSFile,
+    imports: &[&SImport],
+    supertypes: &[Type_],
+) -> Vec<String> {
+    supertypes
+        .iter()
+        .filter_map(Type_::classifier_name)
+        .flat_map(|name| {
+            if name.contains('.') {
+                vec![name]
+            } else {
+                classes_named(scopes, s_file, imports, &name)
+            }
+        })
+        .collect()
+}
+
+/// Why `symbol` is not accessible at `byte` of `s_file`, e.G. "it is private in file a.kt".
+/// None if it is accessible
+pub fn inaccessible_reason(
+    scopes: &GScopesData,
+    s_file: &GSFile,
+    byte: u32,
+    symbol: &Symbol,
+) -> Option<String> {
+    // Libraries are only checked against the declarations they ship with
+    if symbol.visibility == Visibility::Public || s_file.is_read_only() {
+        return None;
+    }
+    let index = &scopes.symbols;
+    let path = match &symbol.origin {
+        SymbolOrigin::Source { id, .. } => Some(&id.path),
+        SymbolOrigin::Library(_) => None,
+    };
+    let is_member = index
+        .lookup(&symbol.container)
+        .iter()
+        .any(|container| container.kind == SymbolKind::Class);
+    let enclosing = || {
+        enclosing_classes(s_file, byte)
+            .into_iter()
+            .map(|(fq_name, _)| fq_name)
+            .collect_vec()
+    };
+
+    let reason = match symbol.visibility {
+        Visibility::Public => return None,
+        Visibility::Private if is_member => {
+            if enclosing().contains(&symbol.container) {
+                return None;
+            }
+            format!("it is private in '{}'", symbol.container)
+        }
+        Visibility::Private => match path {
+            Some(path) if *path == s_file.path => return None,
+            Some(path) => format!(
+                "it is private in file {}",
+                path.file_name().unwrap_or_default().to_string_lossy()
+            ),
+            None => "it is private in its library".to_string(),
+        },
+        Visibility::Internal => match path {
+            Some(path) => {
+                let project = scopes.project_of_file(path);
+                if project.is_some() && project == scopes.project_of_file(&s_file.path) {
+                    return None;
+                }
+                match project.and_then(|project| scopes.project_name(project)) {
+                    Some(name) => format!("it is internal in module {}", name),
+                    None => "it is internal in another module".to_string(),
+                }
+            }
+            None => "it is internal in its library".to_string(),
+        },
+        Visibility::Protected => {
+            let imports = imports_of(s_file);
+            let is_subclass =
+                enclosing_classes(s_file, byte)
+                    .into_iter()
+                    .any(|(fq_name, s_class)| {
+                        fq_name == symbol.container
+                            || supertypes_of(scopes, s_file, &imports, s_class)
+                                .contains(&symbol.container)
+                    });
+            if is_subclass {
+                return None;
+            }
+            format!("it is protected in '{}'", symbol.container)
+        }
+    };
+    Some(format!("Cannot access '{}': {}", symbol.name, reason))
+}
+
+/// A symbol for the local declaration `local`. Locals are not part of the [SymbolIndex]
+pub fn local_symbol(s_file: &GSFile, local: &Local) -> Symbol {
+    Symbol {
+        name: local.name.to_string(),
+        container: String::new(),
+        kind: local.kind,
+        visibility: Visibility::Public,
+        origin: SymbolOrigin::Source {
+            id: DeclId::of(s_file, local.scope_id).unwrap_or_else(|| DeclId::file(&s_file.path)),
+            range: s_file.scopes[local.scope_id].get().range,
+        },
+        is_synthetic: false,
+    }
+}
+
+/// If `node` is a segment of an import, returns the imported name up to and including `node`
+fn imported_name_up_to(s_file: &GSFile, node: &Node) -> Option<String> {
+    header_name_up_to(s_file, node, *parser::node::ImportHeaderId)
+}
+
+/// If `node` is a segment of the name of a header of kind `header_kind_id` (e.G. an import),
+/// returns the name up to and including `node`
+fn header_name_up_to(s_file: &GSFile, node: &Node, header_kind_id: u16) -> Option<String> {
+    let identifier = node.parent()?;
+    if identifier.kind_id() != *parser::node::IdentifierId
+        || identifier.parent()?.kind_id() != header_kind_id
+    {
+        return None;
+    }
+    let segment_end = node.end_byte() - identifier.start_byte();
+    let identifier_text = parser::text_of(&identifier, &s_file.text);
+    Some(
+        identifier_text
+            .get(..segment_end)?
+            .split_whitespace()
+            .join(""),
+    )
+}
+
+/// If the identifier at `byte` is a segment of the package header or of an import, returns the
+/// package named up to and including the segment
+pub fn package_name_at(s_file: &GSFile, byte: u32) -> Option<String> {
+    let node = s_file
+        .ast
+        .root_node()
+        .descendant_for_byte_range(byte as usize, byte as usize)?;
+    if node.kind_id() != *parser::node::SimpleIdentifierId {
+        return None;
+    }
+    header_name_up_to(s_file, &node, *parser::node::PackageHeaderId)
+        .or_else(|| imported_name_up_to(s_file, &node))
+}
+
+/// Resolves the unqualified `name` within `s_file`. Explicit imports take precedence over
+/// declarations of the same package, which take precedence over wildcard imports and lastly the
+/// default imports. Declarations imported with an alias are only accessible by the alias.
+/// Declarations not satisfying `is_accessible` are skipped
+pub fn resolve_name(
+    index: &SymbolIndex,
+    s_file: &GSFile,
+    imports: &[&SImport],
+    name: &str,
+    is_accessible: &dyn Fn(&Symbol) -> bool,
+) -> Vec<Symbol> {
+    let aliased = imports
+        .iter()
+        .filter(|import| import.alias.is_some())
+        .map(|import| import.fq_name.as_str())
+        .collect_vec();
+    let lookup = |fq_name: &str| {
+        if aliased.contains(&fq_name) {
+            return vec![];
+        }
+        index
+            .lookup(fq_name)
+            .into_iter()
+            .filter(|symbol| is_accessible(symbol))
+            .cloned()
+            .collect_vec()
+    };
+
+    let explicitly_imported = imports
+        .iter()
+        .filter(|import| import.visible_name() == Some(name))
+        .flat_map(|import| index.lookup(&import.fq_name).into_iter().cloned())
+        .filter(|symbol| is_accessible(symbol))
+        .collect_vec();
+    if !explicitly_imported.is_empty() {
+        return explicitly_imported;
+    }
+
+    // Declarations of a script are members of its class
+    if s_file.is_script() {
+        let script_members = lookup(&qualified(&s_file.top_level_container(), name));
+        if !script_members.is_empty() {
+            return script_members;
+        }
+    }
+
+    let same_package = lookup(&qualified(s_file.package().unwrap_or_default(), name));
+    if !same_package.is_empty() {
+        return same_package;
+    }
+
+    let wildcard_imported = imports
+        .iter()
+        .filter(|import| import.is_wildcard)
+        .flat_map(|import| lookup(&qualified(&import.fq_name, name)))
+        .collect_vec();
+    if !wildcard_imported.is_empty() {
+        return wildcard_imported;
+    }
+
+    stdlib::DEFAULT_IMPORTS
+        .iter()
+        .flat_map(|package| lookup(&qualified(package, name)))
+        .collect()
+}
+
+fn qualified(container: &str, name: &str) -> String {
+    if container.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", container, name)
+    }
+}
+
+/// Returns the location of the declaration of `symbol`. Declarations of libraries are located
+/// within their (read only) source file
+pub fn location_of(scopes: &GScopes, symbol: &Symbol) -> anyhow::Result<Location> {
+    match &symbol.origin {
+        SymbolOrigin::Source { id, range } => {
+            let s_file = match scopes.project_file(&id.path) {
+                Ok(s_file) => s_file,
+                // Locals of library sources are declared in the read only library file
+                Err(e) => scopes.opened_library_file(&id.path).ok_or(e)?,
+            };
+            // The file might have changed since the symbol got indexed
+            let range = id
+                .scope_in(&s_file)
+                .map_or(*range, |scope_id| s_file.scopes[scope_id].get().range);
+            let range = if symbol.is_synthetic {
+                primary_constructor_range(&s_file, range).unwrap_or(range)
+            } else {
+                range
+            };
+            Ok(Location::new(
+                s_file.uri()?,
+                text_range_to_lsp_range(&s_file.text, range),
+            ))
+        }
+        SymbolOrigin::Library(library_symbol) => {
+            let uri = source_uri_of(library_symbol)?;
+            let s_file = scopes.library_file(&uri)?;
+            let range = declaration_range(&s_file, symbol, library_symbol)
+                .map(|range| text_range_to_lsp_range(&s_file.text, range))
+                .unwrap_or_default();
+            Ok(Location::new(uri, range))
+        }
+    }
+}
+
+/// The range of the primary constructor of the class declared at `class_range`
+fn primary_constructor_range(s_file: &GSFile, class_range: TextRange) -> Option<TextRange> {
+    let class = s_file
+        .ast
+        .root_node()
+        .descendant_for_byte_range(class_range.start as usize, class_range.end as usize)?;
+    let mut cursor = class.walk();
+    let constructor = class
+        .named_children(&mut cursor)
+        .find(|child| child.kind_id() == *parser::node::PrimaryConstructorId)?;
+    TextRange::try_from(constructor.byte_range()).ok()
+}
+
+/// Returns the locations of the package headers of the project files declaring `package`
+pub fn package_locations(scopes: &GScopes, package: &str) -> Vec<Location> {
+    let files = scopes
+        .0
+        .read()
+        .packages
+        .files_of(package)
+        .cloned()
+        .collect_vec();
+    files
+        .iter()
+        .filter_map(|path| {
+            let s_file = scopes.project_file(path).ok()?;
+            let range = s_file
+                .package_header_range()
+                .map(|range| text_range_to_lsp_range(&s_file.text, range))
+                .unwrap_or_default();
+            Some(Location::new(s_file.uri().ok()?, range))
+        })
+        .collect()
+}
+
+/// Locates the declaration of the library symbol `symbol` within its source file `s_file` by the
+/// classes containing it, its name and, among overloads, its parameters
+fn declaration_range(
+    s_file: &GSFile,
+    symbol: &Symbol,
+    library_symbol: &LibrarySymbol,
+) -> Option<TextRange> {
+    let package = s_file.package().unwrap_or_default();
+    let class_path = symbol
+        .container
+        .strip_prefix(package)
+        .unwrap_or(&symbol.container)
+        .trim_start_matches('.');
+    let is_segment = |id: &DeclId, kinds: &[DeclKind], name: &str| {
+        id.segments
+            .last()
+            .is_some_and(|segment| kinds.contains(&segment.kind) && segment.name == name)
+    };
+
+    let mut id = DeclId::file(&s_file.path);
+    let mut parent = None;
+    for class_name in class_path.split('.').filter(|name| !name.is_empty()) {
+        let (class, class_id) = id
+            .children_in(s_file, parent)
+            .into_iter()
+            .find(|(_, child_id)| is_segment(child_id, &[DeclKind::Class], class_name))?;
+        id = class_id;
+        parent = Some(class);
+    }
+
+    let decl_kinds: &[DeclKind] = match symbol.kind {
+        SymbolKind::Class => &[DeclKind::Class],
+        SymbolKind::Function => &[DeclKind::Function],
+        SymbolKind::Property => &[DeclKind::Property, DeclKind::EnumEntry],
+        SymbolKind::TypeAlias => &[DeclKind::TypeAlias],
+    };
+    let candidates = id
+        .children_in(s_file, parent)
+        .into_iter()
+        .filter(|(_, child_id)| is_segment(child_id, decl_kinds, &symbol.name))
+        .map(|(child, _)| child)
+        .collect_vec();
+    let declaration = candidates
+        .iter()
+        .find(|child| has_signature_of(&s_file.scopes[**child].get().kind, &library_symbol.decl))
+        .or(candidates.first())?;
+    Some(s_file.scopes[*declaration].get().range)
+}
+
+/// Whether the receiver and parameters of the function `kind` match the ones of the library
+/// function `decl`. Other declarations are not overloaded
+fn has_signature_of(kind: &SKind, decl: &LibraryDecl) -> bool {
+    let LibraryDecl::Function(km_function) = decl else {
+        return true;
+    };
+    let (has_receiver, parameters) = match kind {
+        SKind::FunDecl(s_fun_decl) => (s_fun_decl.receiver.is_some(), &s_fun_decl.parameters),
+        SKind::JavaMethod(s_java_method) => (false, &s_java_method.parameters),
+        _ => return false,
+    };
+    has_receiver == km_function.receiver_type.is_some()
+        && parameters.len() == km_function.value_parameters.len()
+        && parameters
+            .iter()
+            .zip(&km_function.value_parameters)
+            .all(|(parameter, km_parameter)| {
+                parameter.ident.as_deref() == Some(km_parameter.name.as_str())
+                    && has_type_of(parameter.type_.as_ref(), km_parameter)
+            })
+}
+
+/// Whether `type_` names the class of the library parameter `km_parameter`. Classes are compared
+/// by their simple name, as `type_` is written as imported
+fn has_type_of(type_: Option<&Type_>, km_parameter: &KmValueParameter) -> bool {
+    let km_type = km_parameter
+        .vararg_element_type
+        .as_ref()
+        .unwrap_or(&km_parameter.type_);
+    let KmClassifier::Class(class_name) = &km_type.classifier else {
+        return true;
+    };
+    let simple_name = |name: &str| name.rsplit(['/', '.']).next().unwrap_or(name).to_string();
+    type_
+        .and_then(Type_::classifier_name)
+        .is_none_or(|name| simple_name(&name) == simple_name(class_name))
+}
@@ -1,59 +1,207 @@
+pub mod class_scope;
+pub mod decl_id;
 mod file_scope;
 mod file_scope_creation;
 pub mod fun_decl_scope;
+pub mod import_scope;
+pub mod java_scope;
+mod library_file_creation;
+pub mod local_scope;
 mod project_scope;
+pub mod property_scope;
+pub mod script_scope;
 mod source_set_scope;
+pub mod type_alias_scope;
 
-pub use file_scope::GSFile;
+pub use class_scope::SClass;
+pub use decl_id::DeclId;
+pub use file_scope::{GSFile, SourceLanguage};
 pub use fun_decl_scope::SFunDecl;
+pub use import_scope::SImport;
+pub use java_scope::{SJavaClass, SJavaField, SJavaMethod};
+pub use local_scope::SLocalBinding;
 pub use project_scope::GSProject;
+pub use property_scope::SProperty;
 pub use source_set_scope::GSSourceSet;
+pub use type_alias_scope::STypeAlias;
 
-use crate::project::{PSourceSet, ProjectI};
+use crate::{
+    index_cache::IndexCache,
+    library::index_jar,
+    package_index::PackageIndex,
+    project::{PDependencyKind, PSourceSet, ProjectI},
+    query::{declarations_fingerprint, Input, QueryCache, Revisions},
+    stdlib,
+    symbol_index::{file_symbols, Symbol, SymbolIndex},
+};
+use anyhow::anyhow;
 use enum_as_inner::EnumAsInner;
+use futures::future::join_all;
 use indextree::{Arena, NodeId};
-use std::{collections::HashMap, fmt, path::PathBuf, sync::Arc};
-use stdx::{new_arc_rw_lock, ARwLock, TextRange};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use stdx::{new_arc_lock, new_arc_rw_lock, AMtx, ARwLock, TextRange};
 use tokio::task::JoinHandle;
-use tracing::{debug, error};
+use tower_lsp::lsp_types::{Diagnostic, Url};
+use tracing::{debug, error, warn};
 use tree_sitter::{Node, Range};
 
-use self::file_scope_creation::create_file_scopes;
+use self::file_scope_creation::{create_file_scopes, create_pending_file_scopes};
 
 /// Global scopes are protected by a AMtx and operation on them can be concurrent. In comparison
 /// normal [scope::Scope]'s, which are used on a file level and below, are not protected by a AMtx.
 /// Once we are on a file level, we use non AMtx scopes to make life easier ...
+///
+/// Files are immutable [GSFile]s, which are replaced by a changed copy. The second field
+/// serializes the changes of each file
 #[derive(Clone)]
-pub struct GScopes(pub ARwLock<GScopesData>);
+pub struct GScopes(pub ARwLock<GScopesData>, AMtx<HashMap<PathBuf, AMtx<()>>>);
 
 impl GScopes {
     pub fn new() -> Self {
-        GScopes(new_arc_rw_lock(GScopesData::new()))
+        GScopes(
+            new_arc_rw_lock(GScopesData::new()),
+            new_arc_lock(HashMap::new()),
+        )
     }
 
-    /// Adds scopes
+    /// Returns the current scopes and indexes. The snapshot is immutable and cheap to take, as
+    /// files and indexes are shared until they change. Long running requests therefore never
+    /// block changes, nor see them
+    pub fn snapshot(&self) -> GScopesData {
+        self.0.read().clone()
+    }
+
+    /// Replaces the project file at `path` by a copy changed by `update`, even if `update` fails,
+    /// as the change might have been applied partially. Snapshots keep the previous version.
+    /// Changes of the same file wait for each other, but never for readers
+    pub fn update_file<T>(
+        &self,
+        path: &Path,
+        update: impl FnOnce(&mut GSFile) -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let writer = self.file_writer(path);
+        let _writer = writer.lock();
+
+        let s_file = self.project_file_locked(path)?;
+        let mut s_file = GSFile::clone(&s_file);
+        let result = update(&mut s_file);
+        let symbols = file_symbols(&s_file);
+        let fingerprint = declarations_fingerprint(&s_file);
+
+        let mut w_scopes = self.0.write();
+        let source_set_id = w_scopes
+            .files
+            .get(path)
+            .ok_or_else(|| anyhow!("File {} got removed", path.display()))?
+            .source_set_id;
+        w_scopes.set_file(source_set_id, Arc::new(s_file), symbols, fingerprint);
+        result
+    }
+
+    /// Returns the lock, which serializes the writers of the file at `path`
+    fn file_writer(&self, path: &Path) -> AMtx<()> {
+        self.1.lock().entry(path.to_owned()).or_default().clone()
+    }
+
+    /// Adds scopes. Files are indexed from the cache at `cache_path` if they did not change, and
+    /// the cache is updated afterwards
     pub async fn add_scopes_from_project_recursive(
         &self,
         project: Box<dyn ProjectI>,
+        cache_path: Option<PathBuf>,
     ) -> anyhow::Result<()> {
-        let (project_node_id, s_project) = GSProject::create_project_scope(self, &project)?;
+        if let Some(cache_path) = cache_path.clone() {
+            let cache = tokio::task::spawn_blocking(move || IndexCache::load(&cache_path)).await?;
+            self.0.write().index_cache = Arc::new(cache);
+        }
+
+        let (project_node_id, s_project) = GSProject::create_project_scope(self, project.as_ref())?;
         let source_sets = GSSourceSet::create_source_set_scopes(self, project_node_id, &s_project)?;
 
-        for (source_set_node_id, source_set) in source_sets {
+        let mut tasks = Vec::with_capacity(source_sets.len());
+        {
             let scopes = self.clone();
-            tokio::spawn(async move {
-                if let Err(e) = create_file_scopes(scopes, source_set_node_id, &source_set).await {
-                    error!(
-                        "Error while creating files of source_set {:?} - {}",
-                        source_set.read().kind.as_source_set().unwrap(),
-                        e
-                    );
+            tasks.push(tokio::spawn(async move {
+                match tokio::task::spawn_blocking(stdlib::symbols).await {
+                    Ok(symbols) => scopes.0.write().add_library_symbols(symbols),
+                    Err(e) => error!("Loading the bundled stdlib panicked - {}", e),
                 }
-            });
+            }));
+        }
+        for jar in s_project.read().kind.as_project().unwrap().library_jars() {
+            let scopes = self.clone();
+            tasks.push(tokio::spawn(async move {
+                let jar_clone = jar.clone();
+                match tokio::task::spawn_blocking(move || index_jar(&jar_clone)).await {
+                    Ok(Ok(symbols)) => scopes.0.write().add_library_symbols(symbols),
+                    Ok(Err(e)) => error!("Error while indexing jar {} - {}", jar.display(), e),
+                    Err(e) => error!("Indexing jar {} panicked - {}", jar.display(), e),
+                }
+            }));
+        }
+        let mut source_set_tasks = Vec::with_capacity(source_sets.len());
+        for (source_set_node_id, source_set) in source_sets {
+            let scopes = self.clone();
+            source_set_tasks.push(tokio::spawn(async move {
+                create_file_scopes(scopes, source_set_node_id, &source_set)
+                    .await
+                    .unwrap_or_else(|e| {
+                        error!(
+                            "Error while creating files of source_set {:?} - {}",
+                            source_set.read().kind.as_source_set().unwrap(),
+                            e
+                        );
+                        vec![]
+                    })
+            }));
+        }
+        // Changed files are parsed first. Unchanged ones are indexed already and parsed afterwards
+        let pending_files = join_all(source_set_tasks)
+            .await
+            .into_iter()
+            .flat_map(|files| files.unwrap_or_default())
+            .collect::<Vec<_>>();
+        debug!(
+            "Parsing {} files indexed from the cache",
+            pending_files.len()
+        );
+        create_pending_file_scopes(self.clone(), pending_files).await;
+        // Wait for all files to be imported, so that cross file information (e.G. diagnostics)
+        // can be computed afterwards
+        join_all(tasks).await;
+
+        if let Some(cache_path) = cache_path {
+            self.save_index_cache(cache_path).await;
         }
 
         Ok(())
     }
+
+    /// Writes the cache of the files of all projects to `cache_path`
+    async fn save_index_cache(&self, cache_path: PathBuf) {
+        let mut cache = {
+            let mut w_scopes = self.0.write();
+            Arc::unwrap_or_clone(std::mem::take(&mut w_scopes.index_cache))
+        };
+        let file_paths = self.0.read().files.keys().cloned().collect::<HashSet<_>>();
+        let cache = tokio::task::spawn_blocking(move || {
+            cache.retain_files(|path| file_paths.contains(path));
+            if let Err(e) = cache.save(&cache_path) {
+                warn!("Could not save index cache {}: {}", cache_path.display(), e);
+            }
+            cache
+        })
+        .await;
+        match cache {
+            Ok(cache) => self.0.write().index_cache = Arc::new(cache),
+            Err(e) => error!("Saving the index cache panicked - {}", e),
+        }
+    }
 }
 
 impl Default for GScopes {
@@ -62,11 +210,35 @@ impl Default for GScopes {
     }
 }
 
+/// The scopes of all projects and the indexes derived from them. Data is shared with
+/// [GScopes::snapshot]s and copied on write, so that writers never wait for long running readers
+#[derive(Clone)]
 pub struct GScopesData {
-    pub(crate) scopes: indextree::Arena<ARwLock<GScope>>,
+    /// Projects and their source sets
+    pub(crate) scopes: Arc<indextree::Arena<ARwLock<GScope>>>,
     /// root nodes in scopes
     pub project_nodes: Vec<NodeId>,
-    pub file_nodes: HashMap<PathBuf, NodeId>,
+    pub files: im::HashMap<PathBuf, ProjectFile>,
+    /// Read only files opened from libraries. Keyed by their `kls-jar:` uri
+    pub library_files: im::HashMap<Url, Arc<GSFile>>,
+    /// Declarations of all files and library jars
+    pub symbols: Arc<SymbolIndex>,
+    /// Packages declared by the project files
+    pub packages: Arc<PackageIndex>,
+    /// Files, which are indexed from the [IndexCache] but not yet parsed, with their source set
+    pub pending_files: im::HashMap<PathBuf, NodeId>,
+    pub index_cache: Arc<IndexCache>,
+    pub revisions: Arc<Revisions>,
+    /// Diagnostics of references within each file, which do not resolve. Shared by all
+    /// snapshots, as memoized results are only reused for unchanged inputs
+    pub resolution_diagnostics: Arc<QueryCache<PathBuf, Vec<Diagnostic>>>,
+}
+
+/// A parsed file of a project
+#[derive(Debug, Clone)]
+pub struct ProjectFile {
+    pub source_set_id: NodeId,
+    pub s_file: Arc<GSFile>,
 }
 
 impl GScopesData {
@@ -89,10 +261,70 @@ impl GScopesData {
 
     pub fn new() -> Self {
         GScopesData {
-            scopes: Arena::new(),
+            scopes: Arc::new(Arena::new()),
             project_nodes: vec![],
-            file_nodes: HashMap::new(),
+            files: im::HashMap::new(),
+            library_files: im::HashMap::new(),
+            symbols: Arc::default(),
+            packages: Arc::default(),
+            pending_files: im::HashMap::new(),
+            index_cache: Arc::default(),
+            revisions: Arc::default(),
+            resolution_diagnostics: Arc::default(),
+        }
+    }
+}
+
+impl GScopesData {
+    /// Replaces the project file at the path of `s_file` and its declarations
+    pub fn set_file(
+        &mut self,
+        source_set_id: NodeId,
+        s_file: Arc<GSFile>,
+        symbols: Vec<Symbol>,
+        fingerprint: u64,
+    ) {
+        let path = s_file.path.clone();
+        let package = s_file.package().unwrap_or_default().to_string();
+        self.set_file_declarations(&path, &package, symbols, Some(fingerprint));
+        self.pending_files.remove(&path);
+        self.files.insert(
+            path,
+            ProjectFile {
+                source_set_id,
+                s_file,
+            },
+        );
+    }
+
+    /// Replaces the package and symbols of the file at `path`. `fingerprint` is the
+    /// [crate::query::declarations_fingerprint] of the file, if it is parsed
+    pub fn set_file_declarations(
+        &mut self,
+        path: &Path,
+        package: &str,
+        symbols: Vec<Symbol>,
+        fingerprint: Option<u64>,
+    ) {
+        Arc::make_mut(&mut self.packages).set_file_package(path, package);
+        // The declarations the file contributed before and after the change
+        let mut declarations = self.symbols.declarations_of_file(path);
+        if self.symbols.package_of_file(path) != Some(package) {
+            declarations.push(Input::Packages);
         }
+        Arc::make_mut(&mut self.symbols).set_file_symbols(path, package, symbols);
+        declarations.extend(self.symbols.declarations_of_file(path));
+        Arc::make_mut(&mut self.revisions).file_changed(path, fingerprint, declarations);
+    }
+
+    pub fn add_library_symbols(&mut self, symbols: Vec<Symbol>) {
+        let containers = symbols
+            .iter()
+            .map(|symbol| Input::Declarations(symbol.container.clone()))
+            .collect::<HashSet<_>>();
+        Arc::make_mut(&mut self.symbols).add_library_symbols(symbols);
+        Arc::make_mut(&mut self.revisions)
+            .bump_all(containers.into_iter().chain([Input::Packages]));
     }
 }
 
@@ -121,7 +353,6 @@ impl GScope {
 pub enum GSKind {
     Project(GSProject),
     SourceSet(GSSourceSet),
-    File(GSFile),
     // Module { path: PathBuf, range: Range },
     // Class { name: String, range: Range },
     // Function(String /*name*/),
@@ -132,13 +363,32 @@ pub enum GSKind {
 pub struct Scope {
     pub kind: SKind,
     pub range: TextRange,
+    /// Recovered from a declaration, which tree-sitter could not parse, e.G. `fun name(` without
+    /// body. Recovered scopes are replaced with the next change of their declaration
+    #[new(default)]
+    pub is_recovered: bool,
 }
 
-#[derive(Debug, EnumAsInner, Clone)]
+#[derive(Debug, EnumAsInner, Clone, PartialEq, Eq, Hash)]
 pub enum SKind {
-    PackageHeader { ident: String },
+    PackageHeader {
+        ident: String,
+    },
+    Import(SImport),
     FunDecl(SFunDecl),
-    // Class { name: String, range: Range },
+    Property(SProperty),
+    /// Classes, interfaces and objects. Member functions and nested classes are children
+    Class(SClass),
+    EnumEntry(class_scope::SEnumEntry),
+    TypeAlias(STypeAlias),
+    /// Blocks of function bodies. Local declarations are children of the function or block
+    /// declaring them
+    Block(local_scope::BlockKind),
+    LocalBinding(SLocalBinding),
+    /// Declarations of `.java` files. Methods and fields are children of their class
+    JavaClass(SJavaClass),
+    JavaMethod(SJavaMethod),
+    JavaField(SJavaField),
     // Function(String /*name*/),
     // MemberFunction(String /*name*/),
 }
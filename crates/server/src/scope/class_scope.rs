@@ -0,0 +1,331 @@
+use crate::symbol_index::SymbolKind;
+
+use super::{
+    fun_decl_scope::{MultiplatformModifier, Parameter, Type_},
+    property_scope::PropertyBinding,
+};
+
+/// A class, interface or object declaration. Member functions and nested classes are child
+/// scopes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SClass {
+    pub ident: Option<String>,
+    pub kind: ClassKind,
+    /// The modifiers as written, e.G. `private`, `data`, `abstract`. Annotations are not included
+    pub modifiers: Vec<String>,
+    pub supertypes: Vec<Type_>,
+    pub constructor_parameters: Vec<SClassParameter>,
+    pub multiplatform: Option<MultiplatformModifier>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum ClassKind {
+    Class,
+    DataClass,
+    SealedClass,
+    EnumClass,
+    Interface,
+    Object,
+    /// A `companion object`. Its members are accessible by the name of the enclosing class
+    CompanionObject,
+}
+
+impl ClassKind {
+    /// The kind of a `class` declaration with the class modifiers `modifiers`
+    pub fn of_class(modifiers: &[String]) -> Self {
+        let has = |modifier: &str| modifiers.iter().any(|m| m == modifier);
+        if has("data") {
+            ClassKind::DataClass
+        } else if has("sealed") {
+            ClassKind::SealedClass
+        } else if has("enum") {
+            ClassKind::EnumClass
+        } else {
+            ClassKind::Class
+        }
+    }
+
+    fn keyword(&self) -> &'static str {
+        match self {
+            ClassKind::Interface => "interface",
+            ClassKind::Object => "object",
+            ClassKind::CompanionObject => "companion object",
+            _ => "class",
+        }
+    }
+}
+
+/// An entry of an enum class. Members declared in its body are children
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SEnumEntry {
+    pub ident: String,
+}
+
+/// A member generated by the compiler, e.G. `values()` of enum classes or `copy()` of data
+/// classes. Synthetic members are located at the primary constructor of the class declaring them,
+/// or the class itself if it has none
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntheticMember {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// The declaration as it would be written in kotlin
+    pub signature: String,
+}
+
+impl SClass {
+    pub fn synthetic_members(&self) -> Vec<SyntheticMember> {
+        let Some(ident) = &self.ident else {
+            return vec![];
+        };
+        let member = |name: &str, kind, signature: String| SyntheticMember {
+            name: name.to_string(),
+            kind,
+            signature,
+        };
+        match self.kind {
+            ClassKind::EnumClass => vec![
+                member(
+                    "values",
+                    SymbolKind::Function,
+                    format!("fun values(): Array<{}>", ident),
+                ),
+                member(
+                    "valueOf",
+                    SymbolKind::Function,
+                    format!("fun valueOf(value: String): {}", ident),
+                ),
+                member(
+                    "entries",
+                    SymbolKind::Property,
+                    format!("val entries: EnumEntries<{}>", ident),
+                ),
+            ],
+            ClassKind::DataClass => {
+                // Components are numbered by position, even if a property is missing its name
+                let properties = self
+                    .constructor_parameters
+                    .iter()
+                    .filter(|p| p.binding.is_some())
+                    .enumerate()
+                    .filter_map(|(i, p)| Some((i, p.parameter.ident.as_ref()?, &p.parameter.type_)))
+                    .collect::<Vec<_>>();
+                let copy_parameters = properties
+                    .iter()
+                    .map(|(_, name, type_)| match type_ {
+                        Some(type_) => format!("{}: {} = this.{}", name, type_, name),
+                        None => format!("{} = this.{}", name, name),
+                    })
+                    .collect::<Vec<_>>();
+                let mut members = vec![member(
+                    "copy",
+                    SymbolKind::Function,
+                    format!("fun copy({}): {}", copy_parameters.join(", "), ident),
+                )];
+                members.extend(properties.iter().map(|(i, _, type_)| {
+                    let name = component_name(*i);
+                    let signature = match type_ {
+                        Some(type_) => format!("operator fun {}(): {}", name, type_),
+                        None => format!("operator fun {}()", name),
+                    };
+                    member(&name, SymbolKind::Function, signature)
+                }));
+                members.extend([
+                    member(
+                        "equals",
+                        SymbolKind::Function,
+                        "override fun equals(other: Any?): Boolean".to_string(),
+                    ),
+                    member(
+                        "hashCode",
+                        SymbolKind::Function,
+                        "override fun hashCode(): Int".to_string(),
+                    ),
+                    member(
+                        "toString",
+                        SymbolKind::Function,
+                        "override fun toString(): String".to_string(),
+                    ),
+                ]);
+                members
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// The name of the function destructuring the component at `index`, e.G. `component1` for 0
+pub fn component_name(index: usize) -> String {
+    format!("component{}", index + 1)
+}
+
+/// A parameter of the primary constructor. `val` and `var` parameters declare a property
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SClassParameter {
+    pub parameter: Parameter,
+    pub binding: Option<PropertyBinding>,
+    /// The modifiers as written, e.G. `private` or `vararg`. Annotations are not included
+    pub modifiers: Vec<String>,
+}
+
+impl SClassParameter {
+    pub fn eq_no_ty(&self, other: &SClassParameter) -> bool {
+        self.binding == other.binding
+            && self.modifiers == other.modifiers
+            && self.parameter.eq_no_ty(&other.parameter)
+    }
+}
+
+impl std::fmt::Display for SClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{} ", modifier)?;
+        }
+        write!(f, "{}", self.kind.keyword())?;
+        if let Some(ident) = &self.ident {
+            write!(f, " {}", ident)?;
+        }
+        if !self.constructor_parameters.is_empty() {
+            let parameters = self
+                .constructor_parameters
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<_>>();
+            write!(f, "({})", parameters.join(", "))?;
+        }
+        if !self.supertypes.is_empty() {
+            let supertypes = self
+                .supertypes
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>();
+            write!(f, " : {}", supertypes.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for SEnumEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "enum entry {}", self.ident)
+    }
+}
+
+impl std::fmt::Display for SClassParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // `vararg` is shown by the parameter
+        for modifier in self.modifiers.iter().filter(|m| *m != "vararg") {
+            write!(f, "{} ", modifier)?;
+        }
+        if let Some(binding) = self.binding {
+            write!(f, "{} ", binding)?;
+        }
+        write!(f, "{}", self.parameter)
+    }
+}
+
+#[test]
+fn class_kind_follows_the_class_modifiers() {
+    let modifiers = |m: &[&str]| m.iter().map(|m| m.to_string()).collect::<Vec<_>>();
+    assert_eq!(ClassKind::of_class(&modifiers(&[])), ClassKind::Class);
+    assert_eq!(
+        ClassKind::of_class(&modifiers(&["private", "data"])),
+        ClassKind::DataClass
+    );
+    assert_eq!(
+        ClassKind::of_class(&modifiers(&["sealed"])),
+        ClassKind::SealedClass
+    );
+    assert_eq!(
+        ClassKind::of_class(&modifiers(&["enum"])),
+        ClassKind::EnumClass
+    );
+}
+
+#[test]
+fn enum_classes_have_synthetic_members() {
+    let class = |kind| SClass {
+        ident: Some("Color".to_string()),
+        kind,
+        modifiers: vec![],
+        supertypes: vec![],
+        constructor_parameters: vec![],
+        multiplatform: None,
+    };
+    let signatures = class(ClassKind::EnumClass)
+        .synthetic_members()
+        .into_iter()
+        .map(|m| m.signature)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        signatures,
+        vec![
+            "fun values(): Array<Color>",
+            "fun valueOf(value: String): Color",
+            "val entries: EnumEntries<Color>",
+        ]
+    );
+    assert!(class(ClassKind::Class).synthetic_members().is_empty());
+}
+
+#[test]
+fn data_classes_have_synthetic_members() {
+    let parameter = |ident: &str, type_: &str, binding| SClassParameter {
+        parameter: Parameter {
+            ident: Some(ident.to_string()),
+            type_: Some(Type_::Simple(type_.to_string())),
+            ..Default::default()
+        },
+        binding,
+        modifiers: vec![],
+    };
+    let class = SClass {
+        ident: Some("User".to_string()),
+        kind: ClassKind::DataClass,
+        modifiers: vec!["data".to_string()],
+        supertypes: vec![],
+        constructor_parameters: vec![
+            parameter("name", "String", Some(PropertyBinding::Val)),
+            parameter("age", "Int", Some(PropertyBinding::Var)),
+            parameter("tag", "String", None),
+        ],
+        multiplatform: None,
+    };
+    let signatures = class
+        .synthetic_members()
+        .into_iter()
+        .map(|m| m.signature)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        signatures,
+        vec![
+            "fun copy(name: String = this.name, age: Int = this.age): User",
+            "operator fun component1(): String",
+            "operator fun component2(): Int",
+            "override fun equals(other: Any?): Boolean",
+            "override fun hashCode(): Int",
+            "override fun toString(): String",
+        ]
+    );
+
+    let mut unnamed = parameter("", "Int", Some(PropertyBinding::Val));
+    unnamed.parameter.ident = None;
+    let class = SClass {
+        constructor_parameters: vec![
+            unnamed,
+            parameter("name", "String", Some(PropertyBinding::Val)),
+        ],
+        ..class
+    };
+    let signatures = class
+        .synthetic_members()
+        .into_iter()
+        .map(|m| m.signature)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        &signatures[..2],
+        [
+            "fun copy(name: String = this.name): User",
+            "operator fun component2(): String",
+        ]
+    );
+}
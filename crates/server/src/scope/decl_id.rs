@@ -0,0 +1,253 @@
+//! Identities of declarations, which survive edits and re-indexing. Scopes are stored by indextree
+//! [NodeId]s, which get reallocated whenever a scope is deleted and re-inserted. A [DeclId] is
+//! derived from the declarations enclosing a scope instead, e.G. `Class Outer/Function foo#1` for
+//! the second overload of `foo` within the class `Outer`. It maps back to the current [NodeId]
+//! with [DeclId::scope_in], as long as the declaration was not renamed or moved.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use indextree::NodeId;
+use serde::{Deserialize, Serialize};
+
+use super::{GSFile, SKind};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeclId {
+    pub path: PathBuf,
+    /// The enclosing declarations, outermost first, followed by the declaration itself. Empty for
+    /// the file
+    pub segments: Vec<DeclSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DeclSegment {
+    pub kind: DeclKind,
+    /// Empty for anonymous declarations, e.G. blocks
+    pub name: String,
+    /// Distinguishes declarations of the same kind and name within the same parent, e.G.
+    /// overloads. The number of such declarations before this one
+    pub disambiguator: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeclKind {
+    Class,
+    Function,
+    Property,
+    EnumEntry,
+    TypeAlias,
+    Block,
+    LocalBinding,
+}
+
+impl DeclKind {
+    /// The kind of declaration `kind` is. None for package headers and imports
+    pub(crate) fn of(kind: &SKind) -> Option<Self> {
+        Some(match kind {
+            SKind::PackageHeader { .. } | SKind::Import(_) => return None,
+            SKind::Class(_) | SKind::JavaClass(_) => DeclKind::Class,
+            SKind::FunDecl(_) | SKind::JavaMethod(_) => DeclKind::Function,
+            SKind::Property(_) | SKind::JavaField(_) => DeclKind::Property,
+            SKind::EnumEntry(_) => DeclKind::EnumEntry,
+            SKind::TypeAlias(_) => DeclKind::TypeAlias,
+            SKind::Block(_) => DeclKind::Block,
+            SKind::LocalBinding(_) => DeclKind::LocalBinding,
+        })
+    }
+}
+
+/// The name of the declaration. Empty for anonymous ones
+pub(crate) fn name_of(kind: &SKind) -> &str {
+    let name = match kind {
+        SKind::Class(s_class) => s_class.ident.as_ref(),
+        SKind::FunDecl(s_fun_decl) => s_fun_decl.ident.as_ref(),
+        SKind::Property(s_property) => s_property.ident.as_ref(),
+        SKind::EnumEntry(s_enum_entry) => Some(&s_enum_entry.ident),
+        SKind::TypeAlias(s_type_alias) => Some(&s_type_alias.ident),
+        SKind::LocalBinding(s_local_binding) => Some(&s_local_binding.ident),
+        SKind::JavaClass(s_java_class) => Some(&s_java_class.ident),
+        SKind::JavaMethod(s_java_method) => Some(&s_java_method.ident),
+        SKind::JavaField(s_java_field) => Some(&s_java_field.ident),
+        SKind::PackageHeader { .. } | SKind::Import(_) | SKind::Block(_) => None,
+    };
+    name.map_or("", |name| name.as_str())
+}
+
+impl DeclId {
+    /// The identity of the file at `path`. Its top level declarations are its children
+    pub fn file(path: &Path) -> Self {
+        DeclId {
+            path: path.to_owned(),
+            segments: vec![],
+        }
+    }
+
+    /// The identity of the declaration `node_id` of `s_file`. None for package headers and
+    /// imports
+    pub fn of(s_file: &GSFile, node_id: NodeId) -> Option<Self> {
+        let mut id = DeclId::file(&s_file.path);
+        let mut parent = None;
+        for ancestor in node_id
+            .ancestors(&s_file.scopes)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+        {
+            id = id
+                .children_in(s_file, parent)
+                .into_iter()
+                .find_map(|(child, child_id)| (child == ancestor).then_some(child_id))?;
+            parent = Some(ancestor);
+        }
+        Some(id)
+    }
+
+    /// The identities of the children of the scope `parent`, which is the declaration identified
+    /// by `self`. The top level declarations if `parent` is None
+    pub fn children_in(&self, s_file: &GSFile, parent: Option<NodeId>) -> Vec<(NodeId, DeclId)> {
+        let children = match parent {
+            Some(parent) => parent.children(&s_file.scopes).collect(),
+            None => s_file.root_nodes.clone(),
+        };
+        let mut counts = HashMap::<(DeclKind, &str), u32>::new();
+        children
+            .into_iter()
+            .filter_map(|child| {
+                let kind = &s_file.scopes[child].get().kind;
+                let decl_kind = DeclKind::of(kind)?;
+                let name = name_of(kind);
+                let count = counts.entry((decl_kind, name)).or_default();
+                let segment = DeclSegment {
+                    kind: decl_kind,
+                    name: name.to_string(),
+                    disambiguator: *count,
+                };
+                *count += 1;
+                Some((child, self.child(segment)))
+            })
+            .collect()
+    }
+
+    pub fn child(&self, segment: DeclSegment) -> Self {
+        let mut segments = self.segments.clone();
+        segments.push(segment);
+        DeclId {
+            path: self.path.clone(),
+            segments,
+        }
+    }
+
+    /// The scope of the declaration within `s_file`. None if it no longer exists
+    pub fn scope_in(&self, s_file: &GSFile) -> Option<NodeId> {
+        if self.path != s_file.path {
+            return None;
+        }
+        let mut id = DeclId::file(&self.path);
+        let mut scope = None;
+        for segment in &self.segments {
+            let (child, child_id) = id
+                .children_in(s_file, scope)
+                .into_iter()
+                .find(|(_, child_id)| child_id.segments.last() == Some(segment))?;
+            id = child_id;
+            scope = Some(child);
+        }
+        scope
+    }
+}
+
+impl fmt::Display for DeclId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path.display())?;
+        for segment in &self.segments {
+            write!(f, "/{:?} {}", segment.kind, segment.name)?;
+            if segment.disambiguator > 0 {
+                write!(f, "#{}", segment.disambiguator)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn test_file(text: &str) -> GSFile {
+    GSFile::build(PathBuf::from("/a.kt"), crop::Rope::from(text)).unwrap()
+}
+
+#[cfg(test)]
+fn scope_named(s_file: &GSFile, name: &str) -> NodeId {
+    s_file
+        .root_nodes
+        .iter()
+        .flat_map(|root_node| root_node.descendants(&s_file.scopes))
+        .find(|scope| name_of(&s_file.scopes[*scope].get().kind) == name)
+        .unwrap()
+}
+
+#[test]
+fn ids_map_back_to_their_scopes() {
+    let s_file = test_file(
+        "package a
+import b.C
+class Outer {
+    fun f(x: Int) = x
+    fun f(x: String) = x
+    class Inner { val p = 1 }
+}
+fun f() {}
+",
+    );
+    let mut ids = vec![];
+    for root_node in &s_file.root_nodes {
+        for scope_id in root_node.descendants(&s_file.scopes) {
+            let Some(id) = DeclId::of(&s_file, scope_id) else {
+                continue;
+            };
+            assert_eq!(id.scope_in(&s_file), Some(scope_id), "{}", id);
+            ids.push(id.to_string());
+        }
+    }
+    assert!(ids.contains(&"/a.kt/Class Outer/Function f#1".to_string()));
+    assert!(ids.contains(&"/a.kt/Class Outer/Class Inner/Property p".to_string()));
+}
+
+#[test]
+fn ids_survive_deleting_and_reinserting_a_sibling() {
+    let mut s_file = test_file("fun a() {}\nfun b() {}\n");
+    let a = scope_named(&s_file, "a");
+    let b = scope_named(&s_file, "b");
+    let a_id = DeclId::of(&s_file, a).unwrap();
+    let b_id = DeclId::of(&s_file, b).unwrap();
+
+    let a_scope = s_file.scopes[a].get().clone();
+    s_file.delete_scope(a);
+    assert_eq!(b_id.scope_in(&s_file), Some(b));
+    assert_eq!(a_id.scope_in(&s_file), None);
+
+    let reinserted = s_file.new_root_scope(a_scope);
+    assert_eq!(b_id.scope_in(&s_file), Some(b));
+    assert_eq!(a_id.scope_in(&s_file), Some(reinserted));
+    assert_eq!(DeclId::of(&s_file, reinserted), Some(a_id));
+}
+
+#[test]
+fn ids_of_overloads_survive_editing_an_earlier_overload() {
+    let mut s_file = test_file("fun f(x: Int) = x\nfun f(x: String) = x\n");
+    let [first, second] = s_file.root_nodes[..] else {
+        panic!("Expected two functions");
+    };
+    let second_id = DeclId::of(&s_file, second).unwrap();
+
+    let scope = s_file.scopes[first].get_mut();
+    let s_fun_decl = scope.kind.as_fun_decl_mut().unwrap();
+    s_fun_decl.parameters.clear();
+    s_fun_decl.return_type = None;
+    scope.range = stdx::TextRange::new(scope.range.start, scope.range.end - 4);
+
+    assert_eq!(second_id.scope_in(&s_file), Some(second));
+    assert_eq!(DeclId::of(&s_file, second), Some(second_id));
+}
@@ -1,18 +1,20 @@
 use crate::project::{PProject, ProjectI};
-use anyhow::bail;
+use anyhow::{anyhow, bail};
 use crop::Rope;
 use indextree::*;
 use itertools::Itertools;
 use std::cell::RefCell;
 use std::ops::Range;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tap::Tap;
 use tokio::fs;
+use tower_lsp::lsp_types::Url;
 use tracing::trace;
 
-use super::Scope;
+use super::{local_scope::Local, SKind, Scope};
+use crate::symbol_index::SymbolKind;
 
-#[derive(Debug, new)]
+#[derive(Debug, Clone, new)]
 pub struct GSFile {
     pub path: PathBuf,
     pub text: Rope,
@@ -21,13 +23,111 @@ pub struct GSFile {
     pub scopes: indextree::Arena<Scope>,
     #[new(default)]
     pub root_nodes: Vec<NodeId>,
+    /// The `kls-jar:` or `kls-stdlib:` uri of a file read from a library. Library files are read
+    /// only
+    #[new(default)]
+    pub library_uri: Option<Url>,
+}
+
+/// The language of a source file
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SourceLanguage {
+    Kotlin,
+    Java,
+}
+
+impl SourceLanguage {
+    pub fn of_path(path: &Path) -> Self {
+        if path.extension().is_some_and(|ext| ext == "java") {
+            SourceLanguage::Java
+        } else {
+            SourceLanguage::Kotlin
+        }
+    }
+
+    pub fn parse(
+        &self,
+        rope: &Rope,
+        old_tree: Option<&tree_sitter::Tree>,
+    ) -> Option<tree_sitter::Tree> {
+        match self {
+            SourceLanguage::Kotlin => parser::parse(rope, old_tree),
+            SourceLanguage::Java => parser::parse_java(rope, old_tree),
+        }
+    }
 }
 
 impl GSFile {
-    pub fn scope_at_byte(&mut self, byte: u32) -> Option<NodeId> {
+    pub fn language(&self) -> SourceLanguage {
+        SourceLanguage::of_path(&self.path)
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.library_uri.is_some()
+    }
+
+    pub fn uri(&self) -> anyhow::Result<Url> {
+        match &self.library_uri {
+            Some(uri) => Ok(uri.clone()),
+            None => Url::from_file_path(&self.path)
+                .map_err(|_| anyhow!("Invalid file path {}", self.path.display())),
+        }
+    }
+
+    pub fn scope_at_byte(&self, byte: u32) -> Option<NodeId> {
         self.scope_having_best_match(&|scope| scope.range.contains(byte))
     }
 
+    /// The names declared within functions, which are visible at `byte`: parameters, local
+    /// declarations before `byte` and the bindings of the enclosing blocks. Inner declarations
+    /// come first, as they shadow the outer ones
+    pub fn locals_at(&self, byte: u32) -> Vec<Local<'_>> {
+        let Some(innermost) = self.scope_at_byte(byte) else {
+            return vec![];
+        };
+        let mut locals = vec![];
+        for ancestor in innermost.ancestors(&self.scopes) {
+            match &self.scopes[ancestor].get().kind {
+                SKind::FunDecl(_) | SKind::Block(_) => {}
+                _ => continue,
+            }
+            let children = ancestor.children(&self.scopes).collect_vec();
+            for child in children.into_iter().rev() {
+                let scope = self.scopes[child].get();
+                let (name, kind) = match &scope.kind {
+                    SKind::LocalBinding(binding) => (Some(&binding.ident), SymbolKind::Property),
+                    SKind::Property(property) if scope.range.end <= byte => {
+                        (property.ident.as_ref(), SymbolKind::Property)
+                    }
+                    SKind::FunDecl(fun_decl) if scope.range.start <= byte => {
+                        (fun_decl.ident.as_ref(), SymbolKind::Function)
+                    }
+                    SKind::Class(class) if scope.range.start <= byte => {
+                        (class.ident.as_ref(), SymbolKind::Class)
+                    }
+                    _ => continue,
+                };
+                if let Some(name) = name {
+                    locals.push(Local {
+                        name,
+                        kind,
+                        scope_id: child,
+                    });
+                }
+            }
+            if let SKind::FunDecl(fun_decl) = &self.scopes[ancestor].get().kind {
+                locals.extend(fun_decl.parameters.iter().filter_map(|parameter| {
+                    Some(Local {
+                        name: parameter.ident.as_ref()?,
+                        kind: SymbolKind::Property,
+                        scope_id: ancestor,
+                    })
+                }));
+            }
+        }
+        locals
+    }
+
     /// iterates down the scope-tree, until condition is not satisfied
     /// If no scope satisfies `condition`, returns None
     pub fn scope_having_best_match(&self, condition: &dyn Fn(&Scope) -> bool) -> Option<NodeId> {
@@ -37,7 +137,7 @@ impl GSFile {
             .iter()
             .find(|n| {
                 let root_scope = self.root_scope(n);
-                return condition(root_scope.get());
+                condition(root_scope.get())
             })?;
 
         'outer: loop {
@@ -51,16 +151,92 @@ impl GSFile {
         }
     }
 
+    /// The package of the file as declared by the package header
+    pub fn package(&self) -> Option<&str> {
+        self.root_nodes.iter().find_map(|n| {
+            self.root_scope(n)
+                .get()
+                .kind
+                .as_package_header()
+                .map(|ident| ident.as_str())
+        })
+    }
+
+    /// The range of the package header. None if the file declares no package
+    pub fn package_header_range(&self) -> Option<stdx::TextRange> {
+        self.root_nodes.iter().find_map(|n| {
+            let scope = self.root_scope(n).get();
+            scope.kind.is_package_header().then_some(scope.range)
+        })
+    }
+
     fn root_scope(&self, node_id: &NodeId) -> &Node<Scope> {
-        return self
-            .scopes
+        self.scopes
             .get(*node_id)
-            .unwrap_or_else(|| panic!("No scope for node-id {}", node_id));
+            .unwrap_or_else(|| panic!("No scope for node-id {}", node_id))
+    }
+
+    /// Inserts `scope` as root scope. Root scopes are ordered by their position in the file
+    pub fn new_root_scope(&mut self, scope: Scope) -> NodeId {
+        let id = self.scopes.new_node(scope);
+        self.insert_root_node(id);
+        id
+    }
+
+    fn insert_root_node(&mut self, id: NodeId) {
+        let start = self.scopes[id].get().range.start;
+        let position = self
+            .root_nodes
+            .iter()
+            .position(|n| self.scopes[*n].get().range.start > start)
+            .unwrap_or(self.root_nodes.len());
+        self.root_nodes.insert(position, id);
     }
 
-    pub fn new_root_scope(&mut self, scope: Scope) {
+    /// Inserts `scope` as child of `parent`. Children are ordered by their position in the file
+    pub fn new_child_scope(&mut self, parent: NodeId, scope: Scope) -> NodeId {
         let id = self.scopes.new_node(scope);
-        self.root_nodes.push(id);
+        self.insert_child_node(parent, id);
+        id
+    }
+
+    fn insert_child_node(&mut self, parent: NodeId, id: NodeId) {
+        let start = self.scopes[id].get().range.start;
+        match parent
+            .children(&self.scopes)
+            .find(|sibling| self.scopes[*sibling].get().range.start > start)
+        {
+            Some(next_sibling) => next_sibling.insert_before(id, &mut self.scopes),
+            None => parent.append(id, &mut self.scopes),
+        }
+    }
+
+    /// Replaces the scope `scope_id` by `scope`, keeping its id. Its children are deleted
+    pub fn replace_scope(&mut self, scope_id: NodeId, scope: Scope) {
+        for child in scope_id.children(&self.scopes).collect::<Vec<_>>() {
+            child.remove_subtree(&mut self.scopes);
+        }
+        *self.scopes[scope_id].get_mut() = scope;
+    }
+
+    /// Orders the children of `parent` (the root scopes if None) by their position in the file,
+    /// e.G. after their ranges got replaced
+    pub fn sort_children(&mut self, parent: Option<NodeId>) {
+        let children: Vec<NodeId> = match parent {
+            Some(parent) => parent.children(&self.scopes).collect(),
+            None => std::mem::take(&mut self.root_nodes),
+        };
+        if parent.is_some() {
+            for child in &children {
+                child.detach(&mut self.scopes);
+            }
+        }
+        for child in children {
+            match parent {
+                Some(parent) => self.insert_child_node(parent, child),
+                None => self.insert_root_node(child),
+            }
+        }
     }
 
     pub fn delete_scope(&mut self, scope_id: NodeId) {
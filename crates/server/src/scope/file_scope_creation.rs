@@ -8,79 +8,137 @@ use tap::Tap;
 use tokio::fs;
 use tracing::trace;
 
+use crate::index_cache::FileStamp;
 use crate::project::{PProject, ProjectI};
+use crate::query::declarations_fingerprint;
 use crate::scope_builder::{ChangedRange, ScopeBuilder, UpsertOrDelete};
+use crate::symbol_index::file_symbols;
+use walkdir::WalkDir;
 
 use super::*;
 
+/// Creates the scopes of the files of a source set. Files unchanged since the [IndexCache] got
+/// written are only indexed from the cache and returned, to be parsed after all other files
 pub async fn create_file_scopes(
     scopes: GScopes,
     source_set_node_id: NodeId,
     s_source_set: &GARwScope,
-) -> anyhow::Result<()> {
-    let source_set_dir = {
+) -> anyhow::Result<Vec<PathBuf>> {
+    let (source_set_dir, is_build_scripts) = {
         let r_source_set = s_source_set.read();
         let source_set_data = r_source_set.kind.as_source_set().unwrap();
-        source_set_data.source_set_root_dir(&source_set_data.data)
+        (
+            source_set_data.source_set_root_dir(&source_set_data.data),
+            source_set_data.is_build_scripts,
+        )
     };
 
     trace!("Reading files of dir {}", source_set_dir.display());
-    let mut files = tokio::fs::read_dir(&source_set_dir).await?;
-    while let Some(file) = files.next_entry().await? {
-        let file_path = file.path();
+    // Build scripts are directly within the project directory. Its subdirectories are sources
+    let max_depth = if is_build_scripts { 1 } else { usize::MAX };
+    let files = tokio::task::spawn_blocking(move || {
+        WalkDir::new(&source_set_dir)
+            .max_depth(max_depth)
+            .into_iter()
+            .map(|file| file.map(|file| file.into_path()))
+            .collect::<Result<Vec<_>, _>>()
+    })
+    .await??;
+    let mut tasks = vec![];
+    let mut deferred = vec![];
+    for file_path in files {
         trace!(
             "Checking whether to create scope for file {}",
             file_path.display()
         );
-        if file_path.extension().is_some_and(|ext| ext == "kt") {
-            let scopes = scopes.clone();
-            tokio::spawn(async move {
-                match create_file_scope(&scopes, source_set_node_id, file_path.clone()).await {
-                    Err(e) => error!("Error while creating file scope {}", e),
-                    Ok(s_file_node_id) => {
-                        scopes
-                            .0
-                            .write()
-                            .file_nodes
-                            .insert(file_path, s_file_node_id);
-                    }
-                }
-            });
+        let is_source = file_path.extension().is_some_and(|ext| {
+            ext == script_scope::SCRIPT_EXTENSION
+                || (!is_build_scripts && (ext == "kt" || ext == "java"))
+        });
+        if !is_source {
+            continue;
         }
+
+        let cached = fs::metadata(&file_path)
+            .await
+            .ok()
+            .and_then(|metadata| FileStamp::of(&metadata))
+            .and_then(|stamp| scopes.0.read().index_cache.fresh_symbols(&file_path, stamp));
+        if let Some((package, symbols)) = cached {
+            let mut w_scopes = scopes.0.write();
+            w_scopes.set_file_declarations(&file_path, &package, symbols, None);
+            w_scopes
+                .pending_files
+                .insert(file_path.clone(), source_set_node_id);
+            deferred.push(file_path);
+            continue;
+        }
+
+        let scopes = scopes.clone();
+        tasks.push(tokio::spawn(async move {
+            if let Err(e) = create_file_scope(&scopes, source_set_node_id, file_path).await {
+                error!("Error while creating file scope {}", e)
+            }
+        }));
     }
+    join_all(tasks).await;
 
-    Ok(())
+    Ok(deferred)
 }
 
-/// Returns the created file node id on success
+/// Creates the scopes of the files, which got indexed from the cache and are still pending
+pub async fn create_pending_file_scopes(scopes: GScopes, files: Vec<PathBuf>) {
+    let mut tasks = vec![];
+    for file_path in files {
+        // Files might have been created on demand already, project_file creates each file once
+        let scopes = scopes.clone();
+        tasks.push(tokio::task::spawn_blocking(move || {
+            if let Err(e) = scopes.project_file(&file_path) {
+                error!("Error while creating file scope {}", e)
+            }
+        }));
+    }
+    join_all(tasks).await;
+}
+
+/// Creates the scopes of the file at `file_path`
 pub async fn create_file_scope(
     scopes: &GScopes,
     source_set_node_id: NodeId,
     file_path: PathBuf,
-) -> anyhow::Result<NodeId> {
+) -> anyhow::Result<Arc<GSFile>> {
     debug!("Creating scope for file {}", file_path.display());
+    let stamp = fs::metadata(&file_path)
+        .await
+        .ok()
+        .and_then(|metadata| FileStamp::of(&metadata));
     let file_content = fs::read_to_string(&file_path).await?;
+    create_file_scope_of(scopes, source_set_node_id, file_path, file_content, stamp)
+}
+
+/// Creates the scopes of the file at `file_path` with the content `file_content`, which is
+/// identified by `stamp` on disk
+fn create_file_scope_of(
+    scopes: &GScopes,
+    source_set_node_id: NodeId,
+    file_path: PathBuf,
+    file_content: String,
+    stamp: Option<FileStamp>,
+) -> anyhow::Result<Arc<GSFile>> {
     let file_content_len = file_content.len();
     let rope = Rope::from(file_content);
-    let ast = parser::parse(&rope, None).unwrap_or_else(|| panic!("No tree for {}", rope));
-
-    let s_file = GScope::new_arw(GSKind::File(GSFile::new(
-        file_path.clone(),
-        rope,
-        ast.clone(),
-    )));
+    let ast = SourceLanguage::of_path(&file_path)
+        .parse(&rope, None)
+        .unwrap_or_else(|| panic!("No tree for {}", rope));
 
-    let s_file_node_id = {
-        let mut w_scopes = scopes.0.write();
-        source_set_node_id.append_value(s_file.clone(), &mut w_scopes.scopes)
-    };
+    let mut s_file = GSFile::new(file_path.clone(), rope, ast.clone());
     debug!(
         "Created scope for file {}. Now building scopes within the file",
         file_path.display()
     );
 
     ScopeBuilder::new(
-        s_file.write().kind.as_file_mut().unwrap(),
+        &mut s_file,
         ChangedRange(
             TextRange::new(0, file_content_len as u32),
             UpsertOrDelete::Upsert,
@@ -88,5 +146,73 @@ pub async fn create_file_scope(
     )
     .update_scopes(&ast)?;
 
-    Ok(s_file_node_id)
+    let package = s_file.package().unwrap_or_default().to_string();
+    let symbols = file_symbols(&s_file);
+    let fingerprint = declarations_fingerprint(&s_file);
+    let s_file = Arc::new(s_file);
+    let mut w_scopes = scopes.0.write();
+    if let Some(stamp) = stamp {
+        Arc::make_mut(&mut w_scopes.index_cache).set_file(&file_path, stamp, &package, &symbols);
+    }
+    w_scopes.set_file(source_set_node_id, s_file.clone(), symbols, fingerprint);
+    drop(w_scopes);
+
+    Ok(s_file)
+}
+
+impl GSFile {
+    /// Parses `text` and builds all scopes of the file at `path`
+    pub fn build(path: PathBuf, text: Rope) -> anyhow::Result<Self> {
+        let ast = SourceLanguage::of_path(&path)
+            .parse(&text, None)
+            .ok_or_else(|| anyhow!("No tree for {}", path.display()))?;
+        let mut s_file = GSFile::new(path, text, ast);
+        s_file.rebuild_scopes()?;
+        Ok(s_file)
+    }
+
+    /// Replaces all scopes by the ones built from the tree, e.G. when updating them incrementally
+    /// failed
+    pub fn rebuild_scopes(&mut self) -> anyhow::Result<()> {
+        self.scopes = indextree::Arena::new();
+        self.root_nodes.clear();
+        let ast = self.ast.clone();
+        let text_len = self.text.byte_len() as u32;
+        ScopeBuilder::new(
+            self,
+            ChangedRange(TextRange::new(0, text_len), UpsertOrDelete::Upsert),
+        )
+        .update_scopes(&ast)
+    }
+}
+
+impl GScopes {
+    /// Returns the project file at `path`. Files, which are indexed from the cache but not yet
+    /// parsed, are read and parsed on first access, so callers must not run on the async executor
+    pub fn project_file(&self, path: &Path) -> anyhow::Result<Arc<GSFile>> {
+        if !self.0.read().pending_files.contains_key(path) {
+            return self.0.read().project_file(path);
+        }
+        let writer = self.file_writer(path);
+        let _writer = writer.lock();
+        self.project_file_locked(path)
+    }
+
+    /// Like [GScopes::project_file], but expects the caller to hold the writer lock of `path`
+    pub(crate) fn project_file_locked(&self, path: &Path) -> anyhow::Result<Arc<GSFile>> {
+        let pending = self.0.read().pending_files.get(path).copied();
+        let Some(source_set_node_id) = pending else {
+            return self.0.read().project_file(path);
+        };
+        debug!("Creating pending file {} on demand", path.display());
+        let metadata = std::fs::metadata(path)?;
+        let content = std::fs::read_to_string(path)?;
+        create_file_scope_of(
+            self,
+            source_set_node_id,
+            path.to_owned(),
+            content,
+            FileStamp::of(&metadata),
+        )
+    }
 }
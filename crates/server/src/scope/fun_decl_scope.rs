@@ -1,16 +1,123 @@
+use serde::{Deserialize, Serialize};
 use stdx::WithTR;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SFunDecl {
     pub ident: Option<String>,
+    /// The modifiers as written, e.G. `private`, `suspend`, `override`. Annotations are not
+    /// included
+    pub modifiers: Vec<String>,
+    pub type_parameters: Vec<TypeParameter>,
+    /// The receiver type of an extension function
+    pub receiver: Option<Type_>,
     pub parameters: Vec<Parameter>,
     pub return_type: Option<Type_>,
+    /// The bounds of the `where` clause
+    pub type_constraints: Vec<TypeConstraint>,
+    pub multiplatform: Option<MultiplatformModifier>,
 }
 
-#[derive(Debug, Clone)]
+/// A type parameter, e.G. `reified T : Any`
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct TypeParameter {
+    pub ident: String,
+    /// `reified`, `in` or `out`
+    pub modifiers: Vec<String>,
+    pub bound: Option<Type_>,
+}
+
+/// A bound of the `where` clause, e.G. `T : Comparable<T>`
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct TypeConstraint {
+    pub ident: String,
+    pub bound: Type_,
+}
+
+/// The visibility of a declaration. Declarations without visibility modifier are public
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum Visibility {
+    Public,
+    Internal,
+    Protected,
+    Private,
+}
+
+impl Visibility {
+    pub fn of_modifiers(modifiers: &[String]) -> Self {
+        modifiers
+            .iter()
+            .find_map(|modifier| match modifier.as_str() {
+                "public" => Some(Visibility::Public),
+                "internal" => Some(Visibility::Internal),
+                "protected" => Some(Visibility::Protected),
+                "private" => Some(Visibility::Private),
+                _ => None,
+            })
+            .unwrap_or(Visibility::Public)
+    }
+}
+
+/// The kotlin multiplatform `expect` / `actual` modifier of a declaration
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum MultiplatformModifier {
+    Expect,
+    Actual,
+}
+
+impl MultiplatformModifier {
+    pub fn from_text(text: &str) -> Option<Self> {
+        match text {
+            "expect" => Some(MultiplatformModifier::Expect),
+            "actual" => Some(MultiplatformModifier::Actual),
+            _ => None,
+        }
+    }
+}
+
+/// Identifies a parameter among the ones of its declaration. Parameters are identified by their
+/// position when the declaration is created. Unchanged, moved and renamed parameters keep their id
+/// across edits
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
+pub struct ParameterId(pub u32);
+
+impl ParameterId {
+    /// Identifies `parameters` by their position
+    pub fn assign_positions<'p>(parameters: impl IntoIterator<Item = &'p mut Parameter>) {
+        for (i, parameter) in parameters.into_iter().enumerate() {
+            parameter.id = ParameterId(i as u32);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct Parameter {
+    /// Not compared or hashed, as it depends on the edits since the file got parsed
+    pub id: ParameterId,
     pub ident: Option<String>,
     pub type_: Option<Type_>,
+    pub is_vararg: bool,
+    /// The default value as written
+    pub default_value: Option<String>,
+}
+
+impl PartialEq for Parameter {
+    fn eq(&self, other: &Self) -> bool {
+        self.ident == other.ident
+            && self.type_ == other.type_
+            && self.is_vararg == other.is_vararg
+            && self.default_value == other.default_value
+    }
+}
+
+impl Eq for Parameter {}
+
+impl std::hash::Hash for Parameter {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.ident.hash(state);
+        self.type_.hash(state);
+        self.is_vararg.hash(state);
+        self.default_value.hash(state);
+    }
 }
 
 impl Parameter {
@@ -19,17 +126,192 @@ impl Parameter {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone)]
+/// A type as written in kotlin. [std::fmt::Display] writes it back
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
 pub enum Type_ {
     Unit,
+    /// A class or type parameter without type arguments. Qualified names are kept as written
+    /// (e.G. `kotlin.String`)
     Simple(String),
+    /// A class with type arguments, e.G. `Map<String, out T>`. Every segment of a qualified name
+    /// has its own arguments (`Outer<A>.Inner<B>`)
+    Generic(Vec<TypeSegment>),
+    Nullable(Box<Type_>),
+    Function(FunctionType),
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct TypeSegment {
+    pub name: String,
+    pub arguments: Vec<TypeProjection>,
+}
+
+/// A type argument
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum TypeProjection {
+    /// `*`
+    Star,
+    Type {
+        variance: Option<Variance>,
+        type_: Type_,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum Variance {
+    In,
+    Out,
+}
+
+/// A function type, e.G. `suspend String.(index: Int) -> Unit`. Parameters are not required to
+/// be named
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct FunctionType {
+    pub is_suspend: bool,
+    pub receiver: Option<Box<Type_>>,
+    pub parameters: Vec<Parameter>,
+    pub return_type: Box<Type_>,
+}
+
+impl Type_ {
+    /// The type `name` without type arguments
+    pub fn simple(name: &str) -> Self {
+        if name == "Unit" {
+            Type_::Unit
+        } else {
+            Type_::Simple(name.to_string())
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        matches!(self, Type_::Nullable(_))
+    }
+
+    /// The name of the class or type parameter without type arguments, e.G. `a.B` of `a.B<T>?`
+    pub fn classifier_name(&self) -> Option<String> {
+        match self {
+            Type_::Simple(name) => Some(name.clone()),
+            Type_::Generic(segments) => Some(
+                segments
+                    .iter()
+                    .map(|s| s.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join("."),
+            ),
+            Type_::Nullable(type_) => type_.classifier_name(),
+            Type_::Unit | Type_::Function(_) => None,
+        }
+    }
+}
+
+impl SFunDecl {
+    pub fn has_modifier(&self, modifier: &str) -> bool {
+        self.modifiers.iter().any(|m| m == modifier)
+    }
+
+    pub fn is_suspend(&self) -> bool {
+        self.has_modifier("suspend")
+    }
+
+    pub fn is_inline(&self) -> bool {
+        self.has_modifier("inline")
+    }
+
+    pub fn is_infix(&self) -> bool {
+        self.has_modifier("infix")
+    }
+
+    pub fn is_operator(&self) -> bool {
+        self.has_modifier("operator")
+    }
+
+    pub fn is_tailrec(&self) -> bool {
+        self.has_modifier("tailrec")
+    }
+
+    pub fn is_override(&self) -> bool {
+        self.has_modifier("override")
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        Visibility::of_modifiers(&self.modifiers)
+    }
+
+    /// The bounds of the type parameter `ident`, declared inline or in the `where` clause
+    pub fn bounds_of(&self, ident: &str) -> Vec<&Type_> {
+        self.type_parameters
+            .iter()
+            .filter(|p| p.ident == ident)
+            .filter_map(|p| p.bound.as_ref())
+            .chain(
+                self.type_constraints
+                    .iter()
+                    .filter(|c| c.ident == ident)
+                    .map(|c| &c.bound),
+            )
+            .collect()
+    }
+
+    /// The declaration as written in kotlin (e.G. `fun <T> List<T>.foo(a: Int = 1): T`)
+    pub fn signature(&self) -> String {
+        let mut signature = String::new();
+        for modifier in &self.modifiers {
+            signature += &format!("{} ", modifier);
+        }
+        signature += "fun ";
+        if !self.type_parameters.is_empty() {
+            signature += &format!("{} ", self.type_parameters_text());
+        }
+        if let Some(receiver) = &self.receiver {
+            signature += &format!("{}.", receiver);
+        }
+        signature += &format!(
+            "{}({})",
+            self.ident.as_deref().unwrap_or_default(),
+            self.parameters
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        if let Some(return_type) = &self.return_type {
+            signature += &format!(": {}", return_type);
+        }
+        if !self.type_constraints.is_empty() {
+            let constraints = self
+                .type_constraints
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>();
+            signature += &format!(" where {}", constraints.join(", "));
+        }
+        signature
+    }
+
+    fn type_parameters_text(&self) -> String {
+        let type_parameters = self
+            .type_parameters
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>();
+        format!("<{}>", type_parameters.join(", "))
+    }
 }
 
 impl std::fmt::Display for SFunDecl {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{} ", modifier)?;
+        }
         write!(f, "fn")?;
-        if let Some(ident) = &self.ident {
-            write!(f, " {}", ident)?;
+        if !self.type_parameters.is_empty() {
+            write!(f, " {}", self.type_parameters_text())?;
+        }
+        match (&self.receiver, &self.ident) {
+            (Some(receiver), Some(ident)) => write!(f, " {}.{}", receiver, ident)?,
+            (None, Some(ident)) => write!(f, " {}", ident)?,
+            (Some(receiver), None) => write!(f, " {}.", receiver)?,
+            (None, None) => {}
         }
         write!(f, "(")?;
         for parameter in &self.parameters {
@@ -46,14 +328,50 @@ impl std::fmt::Display for SFunDecl {
     }
 }
 
+impl std::fmt::Display for TypeParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{} ", modifier)?;
+        }
+        write!(f, "{}", self.ident)?;
+        if let Some(bound) = &self.bound {
+            write!(f, " : {}", bound)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for TypeConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} : {}", self.ident, self.bound)
+    }
+}
+
+impl std::fmt::Display for Visibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Visibility::Public => write!(f, "public"),
+            Visibility::Internal => write!(f, "internal"),
+            Visibility::Protected => write!(f, "protected"),
+            Visibility::Private => write!(f, "private"),
+        }
+    }
+}
+
 impl std::fmt::Display for Parameter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_vararg {
+            write!(f, "vararg ")?;
+        }
         if let Some(ident) = &self.ident {
             write!(f, "{}", ident)?;
         }
         if let Some(type_) = &self.type_ {
             write!(f, ": {}", type_)?;
         }
+        if let Some(default_value) = &self.default_value {
+            write!(f, " = {}", default_value)?;
+        }
         Ok(())
     }
 }
@@ -63,7 +381,183 @@ impl std::fmt::Display for Type_ {
         match self {
             Type_::Unit => write!(f, "Unit")?,
             Type_::Simple(name) => write!(f, "{}", name)?,
+            Type_::Generic(segments) => {
+                let segments = segments.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+                write!(f, "{}", segments.join("."))?
+            }
+            // `(() -> Unit)?` is nullable, `() -> Unit?` returns a nullable
+            Type_::Nullable(type_) if matches!(**type_, Type_::Function(_)) => {
+                write!(f, "({})?", type_)?
+            }
+            Type_::Nullable(type_) => write!(f, "{}?", type_)?,
+            Type_::Function(function_type) => write!(f, "{}", function_type)?,
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for TypeSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.arguments.is_empty() {
+            let arguments = self
+                .arguments
+                .iter()
+                .map(|a| a.to_string())
+                .collect::<Vec<_>>();
+            write!(f, "<{}>", arguments.join(", "))?;
         }
         Ok(())
     }
 }
+
+impl std::fmt::Display for TypeProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeProjection::Star => write!(f, "*"),
+            TypeProjection::Type {
+                variance: Some(variance),
+                type_,
+            } => write!(f, "{} {}", variance, type_),
+            TypeProjection::Type {
+                variance: None,
+                type_,
+            } => write!(f, "{}", type_),
+        }
+    }
+}
+
+impl std::fmt::Display for Variance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Variance::In => write!(f, "in"),
+            Variance::Out => write!(f, "out"),
+        }
+    }
+}
+
+impl std::fmt::Display for FunctionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_suspend {
+            write!(f, "suspend ")?;
+        }
+        match self.receiver.as_deref() {
+            Some(receiver @ Type_::Function(_)) => write!(f, "({}).", receiver)?,
+            Some(receiver) => write!(f, "{}.", receiver)?,
+            None => {}
+        }
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|p| match (&p.ident, &p.type_) {
+                (Some(ident), Some(type_)) => format!("{}: {}", ident, type_),
+                (None, Some(type_)) => type_.to_string(),
+                (ident, None) => ident.clone().unwrap_or_default(),
+            })
+            .collect::<Vec<_>>();
+        write!(f, "({}) -> {}", parameters.join(", "), self.return_type)
+    }
+}
+
+impl std::fmt::Display for MultiplatformModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MultiplatformModifier::Expect => write!(f, "expect"),
+            MultiplatformModifier::Actual => write!(f, "actual"),
+        }
+    }
+}
+
+#[test]
+fn types_are_displayed_as_written() {
+    let simple = |name: &str| Type_::simple(name);
+    let argument = |variance, type_| TypeProjection::Type { variance, type_ };
+    let list = Type_::Generic(vec![TypeSegment {
+        name: "List".to_string(),
+        arguments: vec![argument(Some(Variance::Out), simple("T"))],
+    }]);
+    assert_eq!(list.to_string(), "List<out T>");
+
+    let entry = Type_::Generic(vec![
+        TypeSegment {
+            name: "Map".to_string(),
+            arguments: vec![],
+        },
+        TypeSegment {
+            name: "Entry".to_string(),
+            arguments: vec![argument(None, simple("String")), TypeProjection::Star],
+        },
+    ]);
+    assert_eq!(
+        Type_::Nullable(Box::new(entry)).to_string(),
+        "Map.Entry<String, *>?"
+    );
+
+    let function = FunctionType {
+        is_suspend: true,
+        receiver: Some(Box::new(simple("String"))),
+        parameters: vec![
+            Parameter {
+                ident: Some("index".to_string()),
+                type_: Some(simple("Int")),
+                ..Default::default()
+            },
+            Parameter {
+                ident: None,
+                type_: Some(Type_::Nullable(Box::new(simple("kotlin.Any")))),
+                ..Default::default()
+            },
+        ],
+        return_type: Box::new(simple("Unit")),
+    };
+    assert_eq!(
+        Type_::Function(function.clone()).to_string(),
+        "suspend String.(index: Int, kotlin.Any?) -> Unit"
+    );
+    assert_eq!(
+        Type_::Nullable(Box::new(Type_::Function(function))).to_string(),
+        "(suspend String.(index: Int, kotlin.Any?) -> Unit)?"
+    );
+}
+
+#[test]
+fn signature_contains_generics_receiver_and_defaults() {
+    let fun_decl = SFunDecl {
+        ident: Some("maxOf".to_string()),
+        modifiers: vec!["private".to_string(), "inline".to_string()],
+        type_parameters: vec![TypeParameter {
+            ident: "T".to_string(),
+            modifiers: vec!["reified".to_string()],
+            bound: Some(Type_::simple("Any")),
+        }],
+        receiver: Some(Type_::Generic(vec![TypeSegment {
+            name: "List".to_string(),
+            arguments: vec![TypeProjection::Type {
+                variance: None,
+                type_: Type_::simple("T"),
+            }],
+        }])),
+        parameters: vec![Parameter {
+            ident: Some("defaults".to_string()),
+            type_: Some(Type_::simple("T")),
+            is_vararg: true,
+            default_value: Some("listOf()".to_string()),
+            ..Default::default()
+        }],
+        return_type: Some(Type_::simple("T")),
+        type_constraints: vec![TypeConstraint {
+            ident: "T".to_string(),
+            bound: Type_::simple("Comparable"),
+        }],
+        multiplatform: None,
+    };
+
+    assert_eq!(
+        fun_decl.signature(),
+        "private inline fun <reified T : Any> List<T>.maxOf(vararg defaults: T = listOf()): T \
+         where T : Comparable"
+    );
+    assert!(fun_decl.is_inline());
+    assert_eq!(fun_decl.visibility(), Visibility::Private);
+    assert_eq!(fun_decl.bounds_of("T").len(), 2);
+}
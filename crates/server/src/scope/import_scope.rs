@@ -0,0 +1,33 @@
+/// An import directive, e.G. `import a.b.C`, `import a.b.*` or `import a.b.C as D`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SImport {
+    /// The imported name. For wildcard imports the package or class whose members are imported
+    pub fq_name: String,
+    pub alias: Option<String>,
+    pub is_wildcard: bool,
+}
+
+impl SImport {
+    /// The name under which the import is accessible within the file
+    pub fn visible_name(&self) -> Option<&str> {
+        if self.is_wildcard {
+            return None;
+        }
+        self.alias
+            .as_deref()
+            .or_else(|| self.fq_name.rsplit('.').next())
+    }
+}
+
+impl std::fmt::Display for SImport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "import {}", self.fq_name)?;
+        if self.is_wildcard {
+            write!(f, ".*")?;
+        }
+        if let Some(alias) = &self.alias {
+            write!(f, " as {}", alias)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,340 @@
+use indextree::NodeId;
+
+use super::{
+    fun_decl_scope::{Parameter, TypeProjection, TypeSegment, Type_, Variance},
+    GSFile,
+};
+
+/// A class, interface, enum, record or annotation declared in a `.java` file. Members are child
+/// scopes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SJavaClass {
+    pub ident: String,
+    pub kind: JavaClassKind,
+    pub supertypes: Vec<Type_>,
+    /// Constants of an enum
+    pub enum_constants: Vec<String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum JavaClassKind {
+    Class,
+    Interface,
+    Enum,
+    Record,
+    Annotation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SJavaMethod {
+    pub ident: String,
+    pub parameters: Vec<Parameter>,
+    /// The kotlin view on the return type. Constructors have none
+    pub return_type: Option<Type_>,
+    pub is_static: bool,
+    pub is_constructor: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SJavaField {
+    pub ident: String,
+    pub type_: Type_,
+    pub is_static: bool,
+    pub is_final: bool,
+}
+
+/// The accessor of a kotlin synthetic property. Kotlin exposes `getFoo()`/`isFoo()` and
+/// `setFoo(value)` of java classes as property `foo` (or `isFoo`)
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SyntheticAccessor {
+    Getter,
+    Setter,
+}
+
+impl SJavaMethod {
+    /// Returns the name of the synthetic property, if this method is a getter or setter
+    pub fn synthetic_property(&self) -> Option<(String, SyntheticAccessor)> {
+        if self.is_static || self.is_constructor {
+            return None;
+        }
+        let is_bean_name = |rest: &str| rest.starts_with(|c: char| c.is_uppercase());
+
+        match (self.parameters.len(), &self.return_type) {
+            (0, Some(Type_::Simple(t)))
+                if t == "Boolean" && self.ident.strip_prefix("is").is_some_and(is_bean_name) =>
+            {
+                Some((self.ident.clone(), SyntheticAccessor::Getter))
+            }
+            (0, Some(return_type)) if *return_type != Type_::Unit => {
+                let rest = self.ident.strip_prefix("get").filter(|r| is_bean_name(r))?;
+                Some((decapitalize(rest), SyntheticAccessor::Getter))
+            }
+            (1, _) => {
+                let rest = self.ident.strip_prefix("set").filter(|r| is_bean_name(r))?;
+                Some((decapitalize(rest), SyntheticAccessor::Setter))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Decapitalizes like kotlin does for synthetic properties: `Name` -> `name`, `URL` -> `url`,
+/// `URLString` -> `urlString`
+fn decapitalize(name: &str) -> String {
+    let upper_len = name.chars().take_while(|c| c.is_uppercase()).count();
+    let chars = name.chars().collect::<Vec<_>>();
+    let lower_len = if upper_len > 1 && upper_len < chars.len() {
+        // The last upper case char starts the next word
+        upper_len - 1
+    } else {
+        upper_len
+    };
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            if i < lower_len {
+                c.to_lowercase().to_string()
+            } else {
+                c.to_string()
+            }
+        })
+        .collect()
+}
+
+/// Returns the kotlin declaration of the synthetic property `name`, which is accessed by the
+/// method at `method_id`. The property is a `var`, if the class declares a matching setter
+pub fn synthetic_property_signature(s_file: &GSFile, method_id: NodeId, name: &str) -> String {
+    let accessors = method_id
+        .parent(&s_file.scopes)
+        .into_iter()
+        .flat_map(|class_id| class_id.children(&s_file.scopes))
+        .filter_map(|id| s_file.scopes[id].get().kind.as_java_method())
+        .filter_map(|method| Some((method.synthetic_property()?, method)))
+        .filter(|((property, _), _)| property == name)
+        .collect::<Vec<_>>();
+
+    let type_ = accessors
+        .iter()
+        .find(|((_, accessor), _)| *accessor == SyntheticAccessor::Getter)
+        .and_then(|(_, getter)| getter.return_type.clone());
+    let is_var = accessors
+        .iter()
+        .any(|((_, accessor), _)| *accessor == SyntheticAccessor::Setter);
+
+    let mut signature = format!("{} {}", if is_var { "var" } else { "val" }, name);
+    if let Some(type_) = type_ {
+        signature += &format!(": {}", type_);
+    }
+    signature
+}
+
+/// Maps a java type to the kotlin type it is seen as (e.G. `int` -> `Int`, `Object` -> `Any`)
+pub fn kotlin_type_of_java(java_type: &str) -> Type_ {
+    let java_type = java_type.trim();
+    if let Some(element) = java_type.strip_suffix("[]") {
+        let kotlin_type = match element.trim() {
+            "int" => "IntArray".to_string(),
+            "long" => "LongArray".to_string(),
+            "short" => "ShortArray".to_string(),
+            "byte" => "ByteArray".to_string(),
+            "char" => "CharArray".to_string(),
+            "float" => "FloatArray".to_string(),
+            "double" => "DoubleArray".to_string(),
+            "boolean" => "BooleanArray".to_string(),
+            element => {
+                return Type_::Generic(vec![TypeSegment {
+                    name: "Array".to_string(),
+                    arguments: vec![TypeProjection::Type {
+                        variance: None,
+                        type_: kotlin_type_of_java(element),
+                    }],
+                }])
+            }
+        };
+        return Type_::Simple(kotlin_type);
+    }
+    if let Some((base, arguments)) = java_type.strip_suffix('>').and_then(|t| t.split_once('<')) {
+        let arguments = split_type_arguments(arguments)
+            .into_iter()
+            .map(|argument| match argument.trim() {
+                "?" => TypeProjection::Star,
+                argument => match argument.strip_prefix("? extends ") {
+                    Some(bound) => TypeProjection::Type {
+                        variance: Some(Variance::Out),
+                        type_: kotlin_type_of_java(bound),
+                    },
+                    None => match argument.strip_prefix("? super ") {
+                        Some(bound) => TypeProjection::Type {
+                            variance: Some(Variance::In),
+                            type_: kotlin_type_of_java(bound),
+                        },
+                        None => TypeProjection::Type {
+                            variance: None,
+                            type_: kotlin_type_of_java(argument),
+                        },
+                    },
+                },
+            })
+            .collect::<Vec<_>>();
+        return Type_::Generic(vec![TypeSegment {
+            name: kotlin_type_of_java(base).to_string(),
+            arguments,
+        }]);
+    }
+
+    let kotlin_type = match java_type {
+        "void" => return Type_::Unit,
+        "int" | "Integer" | "java.lang.Integer" => "Int",
+        "long" | "Long" | "java.lang.Long" => "Long",
+        "short" | "Short" | "java.lang.Short" => "Short",
+        "byte" | "Byte" | "java.lang.Byte" => "Byte",
+        "char" | "Character" | "java.lang.Character" => "Char",
+        "float" | "Float" | "java.lang.Float" => "Float",
+        "double" | "Double" | "java.lang.Double" => "Double",
+        "boolean" | "Boolean" | "java.lang.Boolean" => "Boolean",
+        "Object" | "java.lang.Object" => "Any",
+        "String" | "java.lang.String" => "String",
+        other => other,
+    };
+    Type_::Simple(kotlin_type.to_string())
+}
+
+/// Splits `A, Map<B, C>` into `A` and `Map<B, C>`
+fn split_type_arguments(arguments: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in arguments.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(&arguments[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    result.push(&arguments[start..]);
+    result
+}
+
+impl std::fmt::Display for SJavaClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            JavaClassKind::Class => "class",
+            JavaClassKind::Interface => "interface",
+            JavaClassKind::Enum => "enum",
+            JavaClassKind::Record => "record",
+            JavaClassKind::Annotation => "@interface",
+        };
+        write!(f, "java {} {}", kind, self.ident)?;
+        if !self.supertypes.is_empty() {
+            let supertypes = self
+                .supertypes
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>();
+            write!(f, " : {}", supertypes.join(", "))?;
+        }
+        if !self.enum_constants.is_empty() {
+            write!(f, " {{{}}}", self.enum_constants.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for SJavaMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_static {
+            write!(f, "static ")?;
+        }
+        if self.is_constructor {
+            write!(f, "constructor")?;
+        } else {
+            write!(f, "fun {}", self.ident)?;
+        }
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "({})", parameters.join(", "))?;
+        if let Some(return_type) = &self.return_type {
+            write!(f, ": {}", return_type)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for SJavaField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_static {
+            write!(f, "static ")?;
+        }
+        write!(
+            f,
+            "{} {}: {}",
+            if self.is_final { "val" } else { "var" },
+            self.ident,
+            self.type_
+        )
+    }
+}
+
+#[test]
+fn getters_and_setters_are_synthetic_properties() {
+    let method = |ident: &str, parameters: usize, return_type: Type_| SJavaMethod {
+        ident: ident.to_string(),
+        parameters: (0..parameters)
+            .map(|_| Parameter {
+                ident: Some("value".to_string()),
+                type_: Some(Type_::Simple("String".to_string())),
+                ..Default::default()
+            })
+            .collect(),
+        return_type: Some(return_type),
+        is_static: false,
+        is_constructor: false,
+    };
+    let string = || Type_::Simple("String".to_string());
+    let boolean = || Type_::Simple("Boolean".to_string());
+
+    assert_eq!(
+        method("getName", 0, string()).synthetic_property(),
+        Some(("name".to_string(), SyntheticAccessor::Getter))
+    );
+    assert_eq!(
+        method("getURL", 0, string()).synthetic_property(),
+        Some(("url".to_string(), SyntheticAccessor::Getter))
+    );
+    assert_eq!(
+        method("getURLString", 0, string()).synthetic_property(),
+        Some(("urlString".to_string(), SyntheticAccessor::Getter))
+    );
+    assert_eq!(
+        method("isEnabled", 0, boolean()).synthetic_property(),
+        Some(("isEnabled".to_string(), SyntheticAccessor::Getter))
+    );
+    assert_eq!(
+        method("setName", 1, Type_::Unit).synthetic_property(),
+        Some(("name".to_string(), SyntheticAccessor::Setter))
+    );
+    assert_eq!(method("getName", 1, string()).synthetic_property(), None);
+    assert_eq!(method("getaway", 0, string()).synthetic_property(), None);
+    assert_eq!(method("getUnit", 0, Type_::Unit).synthetic_property(), None);
+}
+
+#[test]
+fn java_types_are_mapped_to_kotlin() {
+    let kotlin = |java: &str| kotlin_type_of_java(java).to_string();
+    assert_eq!(kotlin("int"), "Int");
+    assert_eq!(kotlin("void"), "Unit");
+    assert_eq!(kotlin("int[]"), "IntArray");
+    assert_eq!(kotlin("String[]"), "Array<String>");
+    assert_eq!(
+        kotlin("Map<String, List<? extends Integer>>"),
+        "Map<String, List<out Int>>"
+    );
+}
@@ -0,0 +1,83 @@
+use crop::Rope;
+use stdx::TextRange;
+use tower_lsp::lsp_types::Url;
+
+use crate::library::sources::{is_library_uri, JarEntryUri};
+use crate::scope_builder::{ChangedRange, ScopeBuilder, UpsertOrDelete};
+use crate::stdlib;
+
+use super::*;
+
+impl GScopes {
+    /// Returns the read only file of the `kls-jar:` or `kls-stdlib:` `uri`. The file is created
+    /// on first access
+    pub fn library_file(&self, uri: &Url) -> anyhow::Result<Arc<GSFile>> {
+        if let Some(s_file) = self.0.read().library_files.get(uri) {
+            return Ok(s_file.clone());
+        }
+
+        let (path, content) = if uri.scheme() == stdlib::KLS_STDLIB_SCHEME {
+            (
+                PathBuf::from(uri.path()),
+                stdlib::file_content(uri)?.to_string(),
+            )
+        } else {
+            let jar_entry = JarEntryUri::parse(uri)?;
+            debug!("Creating library file {:?}", jar_entry);
+            let content = jar_entry.read_content(&self.0.read().symbols)?;
+            (PathBuf::from(&jar_entry.entry), content)
+        };
+        let content_len = content.len();
+        let rope = Rope::from(content);
+        let ast = SourceLanguage::of_path(&path)
+            .parse(&rope, None)
+            .unwrap_or_else(|| panic!("No tree for {}", uri));
+
+        let mut s_file = GSFile::new(path, rope, ast.clone());
+        s_file.library_uri = Some(uri.clone());
+        ScopeBuilder::new(
+            &mut s_file,
+            ChangedRange(
+                TextRange::new(0, content_len as u32),
+                UpsertOrDelete::Upsert,
+            ),
+        )
+        .update_scopes(&ast)?;
+
+        let mut w_scopes = self.0.write();
+        Ok(w_scopes
+            .library_files
+            .entry(uri.clone())
+            .or_insert(Arc::new(s_file))
+            .clone())
+    }
+
+    /// Returns the opened library file at `path`, e.G. the file of a local declared in a library
+    /// source. Library files are only found by path once they were opened by their uri
+    pub fn opened_library_file(&self, path: &Path) -> Option<Arc<GSFile>> {
+        self.0
+            .read()
+            .library_files
+            .values()
+            .find(|s_file| s_file.path == path)
+            .cloned()
+    }
+
+    /// Returns the file of `uri`. Library files are created if not yet opened
+    pub fn file_of_uri(&self, uri: &Url) -> anyhow::Result<Arc<GSFile>> {
+        if is_library_uri(uri) {
+            return self.library_file(uri);
+        }
+        self.project_file(&crate::to_file_path(uri)?)
+    }
+}
+
+impl GScopesData {
+    /// Returns the parsed project file at `path`
+    pub fn project_file(&self, path: &Path) -> anyhow::Result<Arc<GSFile>> {
+        self.files
+            .get(path)
+            .map(|project_file| project_file.s_file.clone())
+            .ok_or_else(|| anyhow!("File {} is not registered in files", path.display()))
+    }
+}
@@ -0,0 +1,73 @@
+use indextree::NodeId;
+
+use crate::symbol_index::SymbolKind;
+
+use super::fun_decl_scope::Type_;
+
+/// A block within a function body or script, whose declarations are only visible inside of it. Local
+/// declarations, bindings and nested blocks are children
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum BlockKind {
+    /// A block of a control structure or `try`, e.G. the body of `if` or `while`
+    Block,
+    For,
+    Catch,
+    When,
+    /// Lambdas and anonymous functions
+    Lambda,
+    /// A top level statement of a script, e.G. `dependencies { ... }` of a build script
+    Script,
+}
+
+/// A name bound by a block: loop variables, caught exceptions, `when` subjects and lambda
+/// parameters
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SLocalBinding {
+    pub ident: String,
+    pub kind: BindingKind,
+    /// The declared type. Bindings with an inferred type have none
+    pub type_: Option<Type_>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum BindingKind {
+    ForVariable,
+    CatchParameter,
+    WhenSubject,
+    LambdaParameter,
+    /// `it` of a lambda without declared parameters
+    ImplicitIt,
+}
+
+/// A name declared within a function, which is visible at some position. See
+/// [super::GSFile::locals_at]
+#[derive(Debug)]
+pub struct Local<'a> {
+    pub name: &'a str,
+    pub kind: SymbolKind,
+    /// The scope declaring the name. Parameters are declared by their function
+    pub scope_id: NodeId,
+}
+
+impl std::fmt::Display for BlockKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlockKind::Block => write!(f, "{{...}}"),
+            BlockKind::For => write!(f, "for {{...}}"),
+            BlockKind::Catch => write!(f, "catch {{...}}"),
+            BlockKind::When => write!(f, "when {{...}}"),
+            BlockKind::Lambda => write!(f, "lambda {{...}}"),
+            BlockKind::Script => write!(f, "script {{...}}"),
+        }
+    }
+}
+
+impl std::fmt::Display for SLocalBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "val {}", self.ident)?;
+        if let Some(type_) = &self.type_ {
+            write!(f, ": {}", type_)?;
+        }
+        Ok(())
+    }
+}
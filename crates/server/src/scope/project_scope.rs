@@ -1,4 +1,5 @@
 use anyhow::bail;
+use itertools::Itertools;
 
 use crate::project::{PProject, ProjectI};
 
@@ -12,7 +13,7 @@ pub struct GSProject {
 impl GSProject {
     pub fn create_project_scope(
         scopes: &GScopes,
-        project: &Box<dyn ProjectI>,
+        project: &dyn ProjectI,
     ) -> anyhow::Result<(NodeId, GARwScope)> {
         let project_info = project.project_info()?;
 
@@ -23,7 +24,7 @@ impl GSProject {
         let project_node_id = {
             let mut w_scopes = scopes.0.write();
 
-            let project_node_id = w_scopes.scopes.new_node(s_project.clone());
+            let project_node_id = Arc::make_mut(&mut w_scopes.scopes).new_node(s_project.clone());
             w_scopes.project_nodes.push(project_node_id);
             project_node_id
         };
@@ -31,3 +32,37 @@ impl GSProject {
         Ok((project_node_id, s_project))
     }
 }
+
+impl GSProject {
+    /// Paths of all jar dependencies of all source sets. Each jar is only returned once
+    pub fn library_jars(&self) -> Vec<PathBuf> {
+        self.data
+            .source_sets
+            .iter()
+            .flat_map(|source_set| source_set.dependencies.iter())
+            .filter(|dependency| matches!(dependency.kind, PDependencyKind::Jar))
+            .filter_map(|dependency| dependency.path.as_ref())
+            .map(|path| self.data.root_dir.join(path))
+            .unique()
+            .collect()
+    }
+}
+
+impl GScopesData {
+    /// The project (module) the project file at `path` belongs to
+    pub fn project_of_file(&self, path: &Path) -> Option<NodeId> {
+        let source_set_id = self
+            .files
+            .get(path)
+            .map(|project_file| project_file.source_set_id)
+            .or_else(|| self.pending_files.get(path).copied())?;
+        source_set_id
+            .ancestors(&self.scopes)
+            .find(|id| self.project_nodes.contains(id))
+    }
+
+    pub fn project_name(&self, project_id: NodeId) -> Option<String> {
+        let r_scope = self.scopes.get(project_id)?.get().read();
+        r_scope.kind.as_project().map(|p| p.data.name.clone())
+    }
+}
@@ -0,0 +1,79 @@
+use super::fun_decl_scope::{MultiplatformModifier, Type_};
+
+/// A `val` or `var` declared at top level or in a class body
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SProperty {
+    pub ident: Option<String>,
+    pub binding: PropertyBinding,
+    /// The declared type. Properties with an inferred type have none
+    pub type_: Option<Type_>,
+    /// The modifiers as written, e.G. `private`, `const`, `lateinit`. Annotations are not included
+    pub modifiers: Vec<String>,
+    pub has_getter: bool,
+    pub has_setter: bool,
+    /// The delegate of `by` as written, without its arguments (e.G. `lazy` or
+    /// `Delegates.observable`)
+    pub delegate: Option<String>,
+    pub multiplatform: Option<MultiplatformModifier>,
+}
+
+/// Whether a property is read only (`val`) or mutable (`var`)
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum PropertyBinding {
+    Val,
+    Var,
+}
+
+impl PropertyBinding {
+    pub fn from_text(text: &str) -> Option<Self> {
+        match text {
+            "val" => Some(PropertyBinding::Val),
+            "var" => Some(PropertyBinding::Var),
+            _ => None,
+        }
+    }
+}
+
+impl SProperty {
+    pub fn is_const(&self) -> bool {
+        self.modifiers.iter().any(|m| m == "const")
+    }
+
+    pub fn is_lateinit(&self) -> bool {
+        self.modifiers.iter().any(|m| m == "lateinit")
+    }
+}
+
+impl std::fmt::Display for SProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{} ", modifier)?;
+        }
+        write!(f, "{}", self.binding)?;
+        if let Some(ident) = &self.ident {
+            write!(f, " {}", ident)?;
+        }
+        if let Some(type_) = &self.type_ {
+            write!(f, ": {}", type_)?;
+        }
+        if let Some(delegate) = &self.delegate {
+            write!(f, " by {}", delegate)?;
+        }
+        match (self.has_getter, self.has_setter) {
+            (true, true) => write!(f, " {{ get set }}")?,
+            (true, false) => write!(f, " {{ get }}")?,
+            (false, true) => write!(f, " {{ set }}")?,
+            (false, false) => {}
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for PropertyBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyBinding::Val => write!(f, "val"),
+            PropertyBinding::Var => write!(f, "var"),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Kotlin scripts (`.kts`), e.G. gradle build scripts. A script compiles to a class named after
+//! its file, e.G. `Build_gradle` of `build.gradle.kts`. Its top level declarations are members of
+//! that class and its top level statements are run by its constructor. Each top level statement
+//! is a [super::local_scope::BlockKind::Script] scope, whose children are the lambdas and
+//! bindings within it.
+//!
+//! Members of the implicit receivers of a script are accessible without qualifier, e.G. the
+//! gradle `Project` of build scripts. They are declared by the [PScriptDefinition]s of the project
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use stdx::TextRange;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
+
+use crate::{project::PScriptDefinition, range_util::text_range_to_lsp_range};
+
+use super::{GSFile, GScopesData};
+
+pub const SCRIPT_EXTENSION: &str = "kts";
+
+/// Gradle scripts and their implicit receivers, if the project does not define them. Settings
+/// and init scripts are matched before other gradle scripts
+const GRADLE_SCRIPT_RECEIVERS: &[(&str, &str)] = &[
+    (
+        "settings.gradle.kts",
+        "org.gradle.api.initialization.Settings",
+    ),
+    ("init.gradle.kts", "org.gradle.api.invocation.Gradle"),
+    (".gradle.kts", "org.gradle.api.Project"),
+];
+
+pub fn is_script(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == SCRIPT_EXTENSION)
+}
+
+/// The name of the class the script at `path` compiles to. Characters, which are not allowed in
+/// names, are replaced by `_`, and a leading digit is prefixed with `_`
+pub fn script_class_name(path: &Path) -> String {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let name = file_name
+        .strip_suffix(&format!(".{}", SCRIPT_EXTENSION))
+        .unwrap_or(&file_name);
+    let mut class_name = name
+        .chars()
+        .enumerate()
+        .map(|(i, c)| match c {
+            c if c.is_alphanumeric() || c == '_' => match i {
+                0 => c.to_ascii_uppercase(),
+                _ => c,
+            },
+            _ => '_',
+        })
+        .collect::<String>();
+    if class_name.starts_with(|c: char| c.is_ascii_digit()) {
+        class_name.insert(0, '_');
+    }
+    class_name
+}
+
+/// The implicit receivers of the script at `path`, innermost first. The first of `definitions`
+/// matching the file name takes precedence over the gradle defaults
+pub fn implicit_receivers(definitions: &[PScriptDefinition], path: &Path) -> Vec<String> {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    if let Some(definition) = definitions
+        .iter()
+        .find(|definition| file_name.ends_with(&definition.file_suffix))
+    {
+        return definition.implicit_receivers.clone();
+    }
+    GRADLE_SCRIPT_RECEIVERS
+        .iter()
+        .find(|(suffix, _)| file_name.ends_with(suffix))
+        .map_or(vec![], |(_, receiver)| vec![receiver.to_string()])
+}
+
+impl GSFile {
+    pub fn is_script(&self) -> bool {
+        is_script(&self.path)
+    }
+
+    /// The fully qualified name of the container of the top level declarations: the package, or
+    /// the class of a script
+    pub fn top_level_container(&self) -> String {
+        let package = self.package().unwrap_or_default();
+        if !self.is_script() {
+            return package.to_string();
+        }
+        let class_name = script_class_name(&self.path);
+        if package.is_empty() {
+            class_name
+        } else {
+            format!("{}.{}", package, class_name)
+        }
+    }
+}
+
+impl GScopesData {
+    /// The implicit receivers of the script at `path`, as defined by its project
+    pub fn script_receivers(&self, path: &Path) -> Vec<String> {
+        let definitions = self
+            .project_of_file(path)
+            .and_then(|project_id| {
+                let r_scope = self.scopes.get(project_id)?.get().read();
+                r_scope
+                    .kind
+                    .as_project()
+                    .map(|project| project.data.script_definitions.clone())
+            })
+            .unwrap_or_default();
+        implicit_receivers(&definitions, path)
+    }
+}
+
+/// Reports the syntax errors of scripts. Scripts are edited without compiler feedback more often
+/// than other sources, e.G. build scripts
+pub fn add_script_syntax_diagnostics(
+    scopes: &GScopesData,
+    diagnostics: &mut HashMap<PathBuf, Vec<Diagnostic>>,
+) {
+    for (path, project_file) in scopes.files.iter() {
+        if !is_script(path) {
+            continue;
+        }
+        let file_diagnostics = syntax_diagnostics(&project_file.s_file);
+        if !file_diagnostics.is_empty() {
+            diagnostics
+                .entry(path.clone())
+                .or_default()
+                .extend(file_diagnostics);
+        }
+    }
+}
+
+/// The error and missing nodes of `s_file`. The nodes within an error node are not reported
+fn syntax_diagnostics(s_file: &GSFile) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let mut cursor = s_file.ast.walk();
+    loop {
+        let node = cursor.node();
+        let message = if node.is_error() {
+            Some("Syntax error".to_string())
+        } else if node.is_missing() {
+            Some(format!("Missing {}", node.kind()))
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            if let Ok(range) = TextRange::try_from(node.byte_range()) {
+                diagnostics.push(Diagnostic {
+                    range: text_range_to_lsp_range(&s_file.text, range),
+                    severity: Some(DiagnosticSeverity::ERROR),
+                    source: Some("kls".to_string()),
+                    message,
+                    ..Default::default()
+                });
+            }
+        } else if node.has_error() && cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return diagnostics;
+            }
+        }
+    }
+}
+
+#[test]
+fn script_class_names_and_receivers() {
+    assert_eq!(
+        script_class_name(Path::new("/p/build.gradle.kts")),
+        "Build_gradle"
+    );
+    assert_eq!(
+        script_class_name(Path::new("/p/my-tool.main.kts")),
+        "My_tool_main"
+    );
+    assert_eq!(script_class_name(Path::new("/p/-x.main.kts")), "_x_main");
+    assert_eq!(script_class_name(Path::new("/p/1tool.kts")), "_1tool");
+    assert_eq!(
+        implicit_receivers(&[], Path::new("/p/settings.gradle.kts")),
+        vec!["org.gradle.api.initialization.Settings"]
+    );
+    assert_eq!(
+        implicit_receivers(&[], Path::new("/p/app/build.gradle.kts")),
+        vec!["org.gradle.api.Project"]
+    );
+    assert!(implicit_receivers(&[], Path::new("/p/tool.main.kts")).is_empty());
+    let definitions = [PScriptDefinition {
+        file_suffix: ".main.kts".to_string(),
+        implicit_receivers: vec!["org.example.Host".to_string()],
+    }];
+    assert_eq!(
+        implicit_receivers(&definitions, Path::new("/p/tool.main.kts")),
+        vec!["org.example.Host"]
+    );
+}
@@ -9,8 +9,14 @@ use super::*;
 pub struct GSSourceSet {
     pub data: PSourceSet,
     pub project_root_dir: PathBuf,
+    /// The build scripts directly within the project directory, e.G. `build.gradle.kts`. See
+    /// [GSSourceSet::build_scripts_of]
+    pub is_build_scripts: bool,
 }
 
+/// The name of the source set of the build scripts of a project
+pub const BUILD_SCRIPTS_SOURCE_SET: &str = "buildScripts";
+
 impl GSSourceSet {
     pub fn source_set_root_dir(&self, source_set: &PSourceSet) -> PathBuf {
         self.project_root_dir.join(source_set.src_dir.as_path())
@@ -28,10 +34,13 @@ impl GSSourceSet {
             .expect("Logic error. Expected project to be passed")
             .data;
 
+        let build_scripts = Self::build_scripts_of(project_data);
         let result = project_data
             .source_sets
             .iter()
-            .map(|source_set| {
+            .map(|source_set| (source_set, false))
+            .chain(build_scripts.as_ref().map(|source_set| (source_set, true)))
+            .map(|(source_set, is_build_scripts)| {
                 debug!(
                     "Creating scope for source set {} - {}",
                     source_set.name,
@@ -40,10 +49,12 @@ impl GSSourceSet {
                 let s_source_set = GScope::new_arw(GSKind::SourceSet(GSSourceSet {
                     data: source_set.clone(),
                     project_root_dir: project_data.root_dir.clone(),
+                    is_build_scripts,
                 }));
                 let source_set_id = {
                     let mut w_scopes = scopes.0.write();
-                    project_node_id.append_value(s_source_set.clone(), &mut w_scopes.scopes)
+                    project_node_id
+                        .append_value(s_source_set.clone(), Arc::make_mut(&mut w_scopes.scopes))
                 };
                 (source_set_id, s_source_set)
             })
@@ -51,4 +62,83 @@ impl GSSourceSet {
 
         Ok(result)
     }
+
+    /// The source set of the scripts directly within the project directory. None if there are
+    /// none. Scripts within source directories belong to their source set
+    fn build_scripts_of(project: &PProject) -> Option<PSourceSet> {
+        let has_scripts = std::fs::read_dir(&project.root_dir)
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .any(|entry| script_scope::is_script(&entry.path()));
+        has_scripts.then(|| PSourceSet {
+            name: BUILD_SCRIPTS_SOURCE_SET.to_string(),
+            src_dir: PathBuf::new(),
+            dependencies: vec![],
+            depends_on: vec![],
+            target: None,
+        })
+    }
+}
+
+impl GSSourceSet {
+    pub fn name(&self) -> &str {
+        &self.data.name
+    }
+
+    /// The kotlin multiplatform target, if this source set is a leaf of the `dependsOn` hierarchy
+    pub fn target(&self) -> Option<&str> {
+        self.data.target.as_deref()
+    }
+}
+
+impl GScopesData {
+    /// Returns the source sets refined by `source_set_id` via `dependsOn` (transitively).
+    /// The first element is always `source_set_id` itself
+    pub fn depends_on_closure(&self, source_set_id: NodeId) -> Vec<NodeId> {
+        let source_sets = self.sibling_source_sets(source_set_id);
+
+        let mut result = vec![source_set_id];
+        let mut i = 0;
+        while i < result.len() {
+            let depends_on = source_sets
+                .iter()
+                .find(|(id, _, _)| *id == result[i])
+                .map_or(vec![], |(_, _, depends_on)| depends_on.clone()); // CLONE
+            for name in depends_on {
+                match source_sets.iter().find(|(_, n, _)| *n == name) {
+                    Some((id, _, _)) if !result.contains(id) => result.push(*id),
+                    Some(_) => {}
+                    None => warn!("dependsOn references unknown source set {}", name),
+                }
+            }
+            i += 1;
+        }
+        result
+    }
+
+    /// Returns all source sets which refine `source_set_id` (transitively), including `source_set_id`
+    pub fn refining_source_sets(&self, source_set_id: NodeId) -> Vec<NodeId> {
+        self.sibling_source_sets(source_set_id)
+            .into_iter()
+            .map(|(id, _, _)| id)
+            .filter(|id| self.depends_on_closure(*id).contains(&source_set_id))
+            .collect()
+    }
+
+    /// Returns (node_id, name, depends_on) of all source sets of the project `source_set_id` belongs to
+    fn sibling_source_sets(&self, source_set_id: NodeId) -> Vec<(NodeId, String, Vec<String>)> {
+        let Some(project_id) = self.scopes[source_set_id].parent() else {
+            return vec![];
+        };
+        project_id
+            .children(&self.scopes)
+            .filter_map(|id| {
+                let r_scope = self.scopes[id].get().read();
+                r_scope
+                    .kind
+                    .as_source_set()
+                    .map(|s| (id, s.data.name.clone(), s.data.depends_on.clone()))
+            })
+            .collect()
+    }
 }
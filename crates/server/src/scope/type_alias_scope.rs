@@ -0,0 +1,39 @@
+use super::fun_decl_scope::{TypeParameter, Type_, Visibility};
+
+/// A `typealias` declaration. References to it resolve through to `target`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct STypeAlias {
+    pub ident: String,
+    /// The modifiers as written, e.G. `private`. Annotations are not included
+    pub modifiers: Vec<String>,
+    pub type_parameters: Vec<TypeParameter>,
+    /// The aliased type. None while it is not yet written
+    pub target: Option<Type_>,
+}
+
+impl STypeAlias {
+    pub fn visibility(&self) -> Visibility {
+        Visibility::of_modifiers(&self.modifiers)
+    }
+}
+
+impl std::fmt::Display for STypeAlias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{} ", modifier)?;
+        }
+        write!(f, "typealias {}", self.ident)?;
+        if !self.type_parameters.is_empty() {
+            let type_parameters = self
+                .type_parameters
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<_>>();
+            write!(f, "<{}>", type_parameters.join(", "))?;
+        }
+        if let Some(target) = &self.target {
+            write!(f, " = {}", target)?;
+        }
+        Ok(())
+    }
+}
@@ -1,17 +1,32 @@
-use crate::scope::{SKind, Scope};
+use crate::scope::{SKind, Scope, SourceLanguage};
+use anyhow::{anyhow, bail};
 use indextree::NodeId;
 use std::{
     cell::{Ref, RefCell},
     ops::Range,
 };
 use stdx::TextRange;
-use tracing::{debug, warn};
+use tracing::{debug, trace, warn};
 use tree_sitter::{Node, Tree};
 
-use crate::scope::GSFile;
+use crate::scope::{
+    decl_id::{name_of, DeclKind},
+    local_scope::BlockKind,
+    GSFile,
+};
+use sequence_diff::Change;
 
+mod class_declaration;
+mod error_recovery;
 mod function_declaration;
+mod import_header;
+mod java_declaration;
+mod local_declaration;
 mod package_header;
+mod property_declaration;
+mod sequence_diff;
+mod type_alias_declaration;
+mod type_reference;
 
 #[derive(Debug)]
 pub enum UpsertOrDelete {
@@ -30,124 +45,326 @@ pub struct ScopeBuilder<'a> {
 
 impl<'a> ScopeBuilder<'a> {
     pub fn update_scopes(&'a mut self, tree: &Tree) -> anyhow::Result<()> {
+        // Scopes are deleted once their range shrank to nothing, so the range to delete is empty
+        if let ChangedRange(r, UpsertOrDelete::Delete) = self.changed_range {
+            self.delete_scope(r);
+            return Ok(());
+        }
         if self.changed_range.0.is_empty() {
             debug!("Not updating scopes, because the changed range is empty");
             return Ok(());
         }
+        if self.s_file.language() == SourceLanguage::Java {
+            return java_declaration::rebuild_java_scopes(self, tree);
+        }
 
         debug!(
             "updating scopes for changed ranges {:?}",
             self.changed_range
         );
-        let upsert_range = match self.changed_range {
-            ChangedRange(r, UpsertOrDelete::Delete) => {
-                self.delete_scope(r);
+        let upsert_range = self.changed_range.0;
+
+        if import_header::overlaps_imports(self.s_file, tree, upsert_range) {
+            import_header::rebuild_import_scopes(self, tree)?;
+            if import_header::is_within_imports(tree, upsert_range) {
                 return Ok(());
             }
-            ChangedRange(r, UpsertOrDelete::Upsert) => r,
+        }
+
+        self.update_declarations(None, tree, upsert_range)
+    }
+
+    /// Updates the declarations among the children of `parent` (the top level declarations if
+    /// None), which overlap `range`. A single declaration, which kept its kind, is updated in
+    /// place. Otherwise the overlapping declarations are diffed with the ones declared in `tree`,
+    /// e.G. when a change spans multiple declarations or turned a function into a property.
+    /// Declarations, which kept their kind, keep their scope. The others are deleted or inserted
+    pub(super) fn update_declarations(
+        &mut self,
+        parent: Option<NodeId>,
+        tree: &Tree,
+        range: TextRange,
+    ) -> anyhow::Result<()> {
+        let siblings = match parent {
+            Some(parent) => parent.children(&self.s_file.scopes).collect(),
+            None => self.s_file.root_nodes.clone(),
+        };
+        let container = match parent {
+            Some(parent) => class_declaration::body_node_of(self.s_file, parent, tree)?,
+            None => Some(tree.root_node()),
         };
 
-        let Some(existing_scope_id) = self
-            .s_file
-            .scope_having_best_match(&|scope| scope.range.overlaps_with(upsert_range))
-        else {
-            return self.insert_top_level_scopes(tree, upsert_range);
+        let (changed, declarations) = self.overlapping_declarations(&siblings, container, range);
+        if let ([scope_id], [node]) = (changed.as_slice(), declarations.as_slice()) {
+            let scope = self.s_file.scopes[*scope_id].get();
+            if !range.contains_range(scope.range)
+                && !scope.is_recovered
+                && is_declaration_of(node, &scope.kind)
+            {
+                match self.update_declaration(*scope_id, tree, range) {
+                    Ok(()) => return Ok(()),
+                    Err(e) => debug!("Replacing the declaration, as updating it failed: {}", e),
+                }
+            }
+        }
+
+        // Replaced and replacing declarations might extend beyond `range`, overlapping further
+        // declarations
+        let mut replaced_range = range;
+        let (changed, declarations) = loop {
+            let (changed, declarations) =
+                self.overlapping_declarations(&siblings, container, replaced_range);
+            let spanned = changed
+                .iter()
+                .map(|scope_id| self.s_file.scopes[*scope_id].get().range)
+                .chain(
+                    declarations
+                        .iter()
+                        .map(|node| node.byte_range().try_into().unwrap()),
+                )
+                .fold(replaced_range, |spanned, r| {
+                    TextRange::new(spanned.start.min(r.start), spanned.end.max(r.end))
+                });
+            if spanned == replaced_range {
+                break (changed, declarations);
+            }
+            replaced_range = spanned;
         };
+        debug!(
+            "Replacing {} declarations at {} by {} declared in the tree",
+            changed.len(),
+            replaced_range,
+            declarations.len()
+        );
+        self.replace_declarations(parent, changed, declarations)
+    }
 
-        // CLONE
-        let existing_scope = self
-            .s_file
-            .scopes
-            .get(existing_scope_id)
-            .unwrap()
-            .get()
-            .clone();
-
-        // Check whether update replaces existing scope with a different scope kind
-        if upsert_range.contains_range(existing_scope.range) {
-            // just delete and reinsert
-            debug!("removing existing scope as it got completely replaced during update");
-            self.s_file.delete_scope(existing_scope_id);
-            return self.insert_top_level_scopes(tree, upsert_range);
-        }
-
-        // Update existing scope
-
-        let mut cursor = tree.walk();
-        parser::first_descendant_for_byte(&mut cursor, upsert_range.start)?;
-
-        loop {
-            debug!("updating existing scope {:?}", existing_scope.kind);
-            match &existing_scope.kind {
-                SKind::PackageHeader { .. } => package_header::update_package_header(
-                    self,
-                    &existing_scope_id,
-                    tree,
-                    &cursor.node(),
-                )?,
-                SKind::FunDecl(_s_fun_decl) => function_declaration::update_function_declaration(
-                    self,
-                    existing_scope_id,
-                    tree,
-                    &mut cursor.clone(),
-                    upsert_range,
-                )?,
-            };
+    /// The non-import scopes among `siblings` and the declarations within `container`, which
+    /// overlap `range`
+    fn overlapping_declarations<'t>(
+        &self,
+        siblings: &[NodeId],
+        container: Option<Node<'t>>,
+        range: TextRange,
+    ) -> (Vec<NodeId>, Vec<Node<'t>>) {
+        let changed = siblings
+            .iter()
+            .copied()
+            .filter(|sibling| {
+                let scope = self.s_file.scopes[*sibling].get();
+                !scope.kind.is_import() && intersects(scope.range, range)
+            })
+            .collect();
+        let is_script = self.s_file.is_script();
+        let declarations = container.map_or(vec![], |container| {
+            declaration_nodes_in(container, range, is_script)
+        });
+        (changed, declarations)
+    }
 
-            if parser::move_right(&mut cursor, parser::MoveMode::SkipUnnamed).is_ok()
-                && cursor.node().start_byte() < upsert_range.end as usize
-            {
-                // TODO maybe update the existing_scope?
-                // existing_scope = ;
-                // another iteration => another update
-            } else {
-                break;
+    /// Replaces the scopes `old_scopes` of the children of `parent` by the ones of `declarations`.
+    /// An old scope, whose declaration is unchanged, moved or renamed but kept its kind, keeps
+    /// its id. Its members and local scopes are recreated
+    fn replace_declarations(
+        &mut self,
+        parent: Option<NodeId>,
+        old_scopes: Vec<NodeId>,
+        declarations: Vec<Node>,
+    ) -> anyhow::Result<()> {
+        // Recovered scopes are recreated from their `ERROR` node
+        let (recovered, old_scopes): (Vec<_>, Vec<_>) = old_scopes
+            .into_iter()
+            .partition(|scope_id| self.s_file.scopes[*scope_id].get().is_recovered);
+        for scope_id in recovered {
+            self.s_file.delete_scope(scope_id);
+        }
+
+        let mut error_nodes = vec![];
+        let mut new_scopes = vec![];
+        for node in declarations {
+            if node.is_error() {
+                error_nodes.push(node);
+            } else if let Some(scope) = self.create_declaration(node)? {
+                new_scopes.push((node, scope));
+            }
+        }
+
+        fn key(kind: &SKind) -> Option<(DeclKind, &str)> {
+            DeclKind::of(kind).map(|decl_kind| (decl_kind, name_of(kind)))
+        }
+        let diff = sequence_diff::diff(
+            &old_scopes
+                .iter()
+                .map(|scope_id| key(&self.s_file.scopes[*scope_id].get().kind))
+                .collect::<Vec<_>>(),
+            &new_scopes
+                .iter()
+                .map(|(_, scope)| key(&scope.kind))
+                .collect::<Vec<_>>(),
+        );
+        trace!("Diff of the declarations: {:?}", diff);
+
+        for i in diff.deleted {
+            self.s_file.delete_scope(old_scopes[i]);
+        }
+        for ((node, scope), change) in new_scopes.into_iter().zip(diff.changes) {
+            let old_scope = match change {
+                Change::Unchanged(i) | Change::Moved(i) | Change::Replaced(i) => {
+                    Some(old_scopes[i])
+                }
+                Change::Inserted => None,
             };
+            let scope_id = match old_scope {
+                Some(scope_id)
+                    if is_declaration_of(&node, &self.s_file.scopes[scope_id].get().kind) =>
+                {
+                    let mut scope = scope;
+                    keep_parameter_ids_of(
+                        &self.s_file.scopes[scope_id].get().kind,
+                        &mut scope.kind,
+                    );
+                    self.s_file.replace_scope(scope_id, scope);
+                    scope_id
+                }
+                old_scope => {
+                    if let Some(old_scope) = old_scope {
+                        self.s_file.delete_scope(old_scope);
+                    }
+                    match parent {
+                        Some(parent) => self.s_file.new_child_scope(parent, scope),
+                        None => self.s_file.new_root_scope(scope),
+                    }
+                }
+            };
+            self.insert_declaration_children(scope_id, node)?;
         }
+        self.s_file.sort_children(parent);
 
+        for node in error_nodes {
+            error_recovery::insert_recovered_declarations(self, parent, node)?;
+        }
         Ok(())
     }
 
-    pub fn insert_top_level_scopes(&mut self, tree: &Tree, r: TextRange) -> anyhow::Result<()> {
-        debug!("Inserting top level scope for text at {}", r);
-        let mut cursor = tree.walk();
-        let node = parser::first_child_for_byte(&mut cursor, r.start)?;
-
-        loop {
-            debug!("inserting top level scope for range {r}");
-            let node_kind_id = cursor.node().kind_id();
-
-            let scope = if node_kind_id == *parser::node::PackageHeaderId {
-                package_header::create_package_header(self, node)
-            } else if node_kind_id == *parser::node::FunctionDeclarationId {
-                function_declaration::create_fun_decl(self, node)
-            } else {
-                warn!("Unhandled to insert node of kind {}", node.kind());
-                Ok(None)
-            };
-
-            if let Some(scope) = scope? {
-                self.s_file.new_root_scope(scope);
+    /// Updates the declaration `scope_id` in place from its declaration in `tree`. Fails, if it
+    /// can not be updated, e.G. as it is no longer valid
+    fn update_declaration(
+        &mut self,
+        scope_id: NodeId,
+        tree: &Tree,
+        range: TextRange,
+    ) -> anyhow::Result<()> {
+        // CLONE
+        let scope = self.s_file.scopes[scope_id].get().clone();
+        debug!("updating existing scope {:?}", scope.kind);
+        match &scope.kind {
+            SKind::PackageHeader { .. } => {
+                let node = declaration_node_of(tree, scope.range, |node| {
+                    node.kind_id() == *parser::node::PackageHeaderId
+                })
+                .ok_or_else(|| anyhow!("Found no package header at {}", scope.range))?;
+                package_header::update_package_header(self, &scope_id, tree, &node)
             }
-
-            if !cursor.goto_next_sibling() {
-                debug!("No more ast nodes present. stopping to insert");
-                return Ok(());
+            SKind::FunDecl(_) => {
+                // Local scopes are recreated together with the body of their function
+                if !local_declaration::is_within_body(self.s_file, scope_id, tree, range) {
+                    let mut cursor = tree.walk();
+                    parser::first_descendant_for_byte(
+                        &mut cursor,
+                        range.start.max(scope.range.start),
+                    )?;
+                    function_declaration::update_function_declaration(
+                        self,
+                        scope_id,
+                        tree,
+                        &mut cursor,
+                        range,
+                    )?;
+                }
+                local_declaration::rebuild_body_scopes(self, scope_id, tree)
             }
-            if cursor.node().byte_range().start >= r.end as usize {
-                debug!(
-                    "Not inserting {} as the node is after the passed range to insert",
-                    cursor.node().kind_id()
-                );
-                return Ok(());
+            SKind::Property(_) => {
+                property_declaration::update_property_declaration(self, scope_id, tree)
+            }
+            SKind::Class(_) => {
+                class_declaration::update_class_declaration(self, scope_id, tree, range)
             }
+            SKind::TypeAlias(_) => type_alias_declaration::update_type_alias(self, scope_id, tree),
+            // Entries are recreated by their class
+            SKind::EnumEntry(_) => bail!("Enum entries are replaced, not updated"),
+            SKind::Import(_) => bail!("Imports are rebuilt, not updated"),
+            SKind::JavaClass(_) | SKind::JavaMethod(_) | SKind::JavaField(_) => {
+                bail!("Java scopes are rebuilt, not updated")
+            }
+            SKind::Block(_) | SKind::LocalBinding(_) => {
+                bail!("Local scopes are rebuilt with the body of their function")
+            }
+        }
+    }
+
+    /// Inserts the declaration `node` and its members as root scope
+    pub(super) fn insert_top_level_declaration(&mut self, node: Node) -> anyhow::Result<()> {
+        if let Some(scope) = self.create_declaration(node)? {
+            let scope_id = self.s_file.new_root_scope(scope);
+            self.insert_declaration_children(scope_id, node)?;
+        }
+        Ok(())
+    }
+
+    /// The scope declared by `node`, without its members and local scopes
+    pub(super) fn create_declaration(&mut self, node: Node) -> anyhow::Result<Option<Scope>> {
+        let node_kind_id = node.kind_id();
+        if node_kind_id == *parser::node::PackageHeaderId {
+            package_header::create_package_header(self, node)
+        } else if node_kind_id == *parser::node::FunctionDeclarationId {
+            function_declaration::create_fun_decl(self, node)
+        } else if node_kind_id == *parser::node::PropertyDeclarationId {
+            property_declaration::create_property_decl(self, node)
+        } else if class_declaration::is_class_node(&node) {
+            class_declaration::create_class_decl(self, node)
+        } else if node_kind_id == *parser::node::TypeAliasId {
+            type_alias_declaration::create_type_alias(self, node)
+        } else if node_kind_id == *parser::node::EnumEntryId {
+            Ok(class_declaration::create_enum_entry(self, node))
+        } else if self.s_file.is_script() && is_script_statement(&node) {
+            Ok(Some(Scope::new(
+                SKind::Block(BlockKind::Script),
+                node.byte_range().try_into().unwrap(),
+            )))
+        } else {
+            warn!("Unhandled to insert node of kind {}", node.kind());
+            Ok(None)
+        }
+    }
+
+    /// Inserts the members of the class or enum entry `node`, or the local scopes of the function
+    /// or script statement `node`, as children of `scope_id`
+    pub(super) fn insert_declaration_children(
+        &mut self,
+        scope_id: NodeId,
+        node: Node,
+    ) -> anyhow::Result<()> {
+        if class_declaration::is_class_node(&node) || node.kind_id() == *parser::node::EnumEntryId {
+            class_declaration::insert_members(self, scope_id, node)
+        } else if node.kind_id() == *parser::node::FunctionDeclarationId {
+            local_declaration::insert_body_scopes(self, scope_id, node)
+        } else if self.s_file.is_script() && is_script_statement(&node) {
+            local_declaration::insert_local_scope(self, scope_id, node)
+        } else {
+            Ok(())
         }
     }
 
     pub fn delete_scope(&mut self, r: TextRange) {
+        // Members of classes and local scopes are deleted too. The innermost scope matching is
+        // deleted
         if let Some(scope) = self
             .s_file
-            .scope_having_best_match(&|scope| scope.range == r)
+            .root_nodes
+            .iter()
+            .flat_map(|n| n.descendants(&self.s_file.scopes))
+            .filter(|n| self.s_file.scopes[*n].get().range == r)
+            .last()
         {
             debug!(
                 "deleting scope {:?} at {}",
@@ -160,3 +377,104 @@ impl<'a> ScopeBuilder<'a> {
         }
     }
 }
+
+/// Whether the ranges share at least one byte. Declarations merely touching a changed range are
+/// not affected by it
+fn intersects(a: TextRange, b: TextRange) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Whether `node` declares a scope, which is updated incrementally
+fn is_declaration_node(node: &Node) -> bool {
+    let kind_id = node.kind_id();
+    kind_id == *parser::node::PackageHeaderId
+        || kind_id == *parser::node::FunctionDeclarationId
+        || kind_id == *parser::node::PropertyDeclarationId
+        || kind_id == *parser::node::TypeAliasId
+        || kind_id == *parser::node::EnumEntryId
+        || class_declaration::is_class_node(node)
+}
+
+/// Gives the parameters of the declaration `new` the ids of the corresponding ones of `old`, the
+/// declaration it replaces
+pub(super) fn keep_parameter_ids_of(old: &SKind, new: &mut SKind) {
+    match (old, new) {
+        (SKind::FunDecl(old), SKind::FunDecl(new)) => {
+            function_declaration::keep_parameter_ids(&old.parameters, &mut new.parameters)
+        }
+        (SKind::Class(old), SKind::Class(new)) => function_declaration::keep_parameter_ids(
+            old.constructor_parameters.iter().map(|p| &p.parameter),
+            new.constructor_parameters
+                .iter_mut()
+                .map(|p| &mut p.parameter),
+        ),
+        _ => {}
+    }
+}
+
+/// Whether `node` declares a scope of the same kind as `kind`
+fn is_declaration_of(node: &Node, kind: &SKind) -> bool {
+    let kind_id = node.kind_id();
+    match kind {
+        SKind::PackageHeader { .. } => kind_id == *parser::node::PackageHeaderId,
+        SKind::FunDecl(_) => kind_id == *parser::node::FunctionDeclarationId,
+        SKind::Property(_) => kind_id == *parser::node::PropertyDeclarationId,
+        SKind::TypeAlias(_) => kind_id == *parser::node::TypeAliasId,
+        SKind::EnumEntry(_) => kind_id == *parser::node::EnumEntryId,
+        SKind::Class(_) => class_declaration::is_class_node(node),
+        SKind::Block(BlockKind::Script) => is_script_statement(node),
+        _ => false,
+    }
+}
+
+/// Whether `node` is a top level statement of a script, e.G. `plugins { ... }`
+fn is_script_statement(node: &Node) -> bool {
+    let kind_id = node.kind_id();
+    node.is_named()
+        && !node.is_error()
+        && node
+            .parent()
+            .is_some_and(|parent| parent.kind_id() == *parser::node::SourceFileId)
+        && !is_declaration_node(node)
+        && !import_header::is_import_node(node)
+        && kind_id != *parser::node::PackageHeaderId
+        && kind_id != *parser::node::ShebangLineId
+        && kind_id != *parser::node::FileAnnotationId
+        && kind_id != *parser::node::LineCommentId
+        && kind_id != *parser::node::MultilineCommentId
+}
+
+/// The declarations among the children of `container` overlapping `range`. `ERROR` nodes are
+/// included, as they might contain declarations. So are the statements of scripts
+fn declaration_nodes_in<'t>(
+    container: Node<'t>,
+    range: TextRange,
+    is_script: bool,
+) -> Vec<Node<'t>> {
+    let mut cursor = container.walk();
+    let declarations = container
+        .named_children(&mut cursor)
+        .filter(|child| {
+            (is_declaration_node(child)
+                || child.is_error()
+                || (is_script && is_script_statement(child)))
+                && intersects(child.byte_range().try_into().unwrap(), range)
+        })
+        .collect();
+    declarations
+}
+
+/// The innermost declaration of kind `is_kind` spanning `range`
+fn declaration_node_of<'t>(
+    tree: &'t Tree,
+    range: TextRange,
+    is_kind: fn(&Node) -> bool,
+) -> Option<Node<'t>> {
+    let mut node = tree
+        .root_node()
+        .descendant_for_byte_range(range.start as usize, range.end as usize)?;
+    while !is_kind(&node) {
+        node = node.parent()?;
+    }
+    Some(node)
+}
@@ -0,0 +1,249 @@
+use anyhow::anyhow;
+use indextree::NodeId;
+use parser::node::{
+    ClassDeclaration, ClassParameter, CompanionObject, DelegationSpecifier, EnumEntry, Modifiers,
+    ObjectDeclaration, PrimaryConstructor,
+};
+use stdx::TextRange;
+use tracing::debug;
+use tree_sitter::{Node, Tree};
+
+use crate::scope::{
+    class_scope::{ClassKind, SClassParameter, SEnumEntry},
+    fun_decl_scope::{Parameter, ParameterId, Type_},
+    property_scope::PropertyBinding,
+    GSFile, SClass, SKind, Scope,
+};
+
+use super::{
+    declaration_node_of, error_recovery, function_declaration, is_declaration_node,
+    keep_parameter_ids_of, type_reference, ScopeBuilder,
+};
+
+pub(super) fn is_class_node(node: &Node) -> bool {
+    node.kind_id() == *parser::node::ClassDeclarationId
+        || node.kind_id() == *parser::node::ObjectDeclarationId
+        || node.kind_id() == *parser::node::CompanionObjectId
+}
+
+pub(super) fn create_class_decl(
+    self_: &mut ScopeBuilder<'_>,
+    node: Node,
+) -> anyhow::Result<Option<Scope>> {
+    debug!("creating class declaration");
+    Ok(Some(Scope::new(
+        SKind::Class(get_class_of(node, self_)),
+        node.byte_range().try_into().unwrap(),
+    )))
+}
+
+fn get_class_of(node: Node, self_: &ScopeBuilder<'_>) -> SClass {
+    let text = &self_.s_file.text;
+    if node.kind_id() == *parser::node::ObjectDeclarationId {
+        let object = ObjectDeclaration::new(node, text);
+        return SClass {
+            ident: object.find_type_identifier().map(|ident| ident.text()),
+            kind: ClassKind::Object,
+            modifiers: get_modifiers_of(object.find_modifiers()),
+            supertypes: get_supertypes_of(object.find_all_delegation_specifier()),
+            constructor_parameters: vec![],
+            multiplatform: function_declaration::get_multiplatform_modifier_of(
+                object.find_modifiers(),
+            ),
+        };
+    }
+    if node.kind_id() == *parser::node::CompanionObjectId {
+        let companion = CompanionObject::new(node, text);
+        return SClass {
+            // Companions without name are named `Companion`
+            ident: Some(
+                companion
+                    .find_type_identifier()
+                    .map_or("Companion".to_string(), |ident| ident.text()),
+            ),
+            kind: ClassKind::CompanionObject,
+            modifiers: get_modifiers_of(companion.find_modifiers()),
+            supertypes: get_supertypes_of(companion.find_all_delegation_specifier()),
+            constructor_parameters: vec![],
+            multiplatform: function_declaration::get_multiplatform_modifier_of(
+                companion.find_modifiers(),
+            ),
+        };
+    }
+
+    let class = ClassDeclaration::new(node, text);
+    let modifiers = get_modifiers_of(class.find_modifiers());
+    let mut cursor = node.walk();
+    let is_interface = node
+        .children(&mut cursor)
+        .any(|child| child.kind() == "interface");
+    let constructor_parameters = class
+        .find_primary_constructor()
+        .map_or(vec![], get_constructor_parameters_of);
+
+    SClass {
+        ident: class.find_type_identifier().map(|ident| ident.text()),
+        kind: if is_interface {
+            ClassKind::Interface
+        } else {
+            ClassKind::of_class(&modifiers)
+        },
+        supertypes: get_supertypes_of(class.find_all_delegation_specifier()),
+        modifiers,
+        constructor_parameters,
+        multiplatform: function_declaration::get_multiplatform_modifier_of(class.find_modifiers()),
+    }
+}
+
+pub(super) fn get_constructor_parameters_of(
+    constructor: PrimaryConstructor,
+) -> Vec<SClassParameter> {
+    let mut parameters = constructor
+        .find_all_class_parameter()
+        .into_iter()
+        .map(get_class_parameter_of)
+        .collect::<Vec<_>>();
+    ParameterId::assign_positions(parameters.iter_mut().map(|p| &mut p.parameter));
+    parameters
+}
+
+pub(super) fn get_class_parameter_of(parameter: ClassParameter) -> SClassParameter {
+    let text = parameter.source;
+    let modifiers = get_modifiers_of(parameter.find_modifiers());
+    SClassParameter {
+        parameter: Parameter {
+            ident: parameter.find_simple_identifier().map(|ident| ident.text()),
+            type_: type_reference::type_of_children(parameter.node, text),
+            is_vararg: modifiers.iter().any(|m| m == "vararg"),
+            default_value: function_declaration::default_value_of(&parameter.node, text),
+            ..Default::default()
+        },
+        binding: parameter
+            .find_binding_pattern_kind()
+            .and_then(|binding| PropertyBinding::from_text(&binding.text())),
+        modifiers,
+    }
+}
+
+pub(super) fn get_modifiers_of(modifiers: Option<Modifiers>) -> Vec<String> {
+    let Some(modifiers) = modifiers else {
+        return vec![];
+    };
+    let mut cursor = modifiers.node.walk();
+    let modifiers = modifiers
+        .node
+        .named_children(&mut cursor)
+        .filter(|modifier| modifier.kind_id() != *parser::node::AnnotationId)
+        .map(|modifier| parser::text_of(&modifier, modifiers.source))
+        .collect();
+    modifiers
+}
+
+pub(super) fn get_supertypes_of(delegation_specifiers: Vec<DelegationSpecifier>) -> Vec<Type_> {
+    delegation_specifiers
+        .iter()
+        .filter_map(|specifier| {
+            match type_reference::type_of_children(specifier.node, specifier.source) {
+                Some(type_) => Some(type_),
+                // Superclasses are written as constructor invocation, e.G. `Base()`
+                None => specifier
+                    .find_constructor_invocation()
+                    .and_then(|invocation| {
+                        type_reference::type_of_children(invocation.node, invocation.source)
+                    }),
+            }
+        })
+        .collect()
+}
+
+/// Inserts the members of the class or enum entry `node` as children of `class_id`
+pub(super) fn insert_members(
+    self_: &mut ScopeBuilder<'_>,
+    class_id: NodeId,
+    node: Node,
+) -> anyhow::Result<()> {
+    let Some(body) = class_body_of(node) else {
+        return Ok(());
+    };
+
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        if member.is_error() {
+            error_recovery::insert_recovered_declarations(self_, Some(class_id), member)?;
+        } else {
+            insert_member(self_, class_id, member)?;
+        }
+    }
+    Ok(())
+}
+
+/// Inserts the member `member` of a class or enum entry body as child of `class_id`
+pub(super) fn insert_member(
+    self_: &mut ScopeBuilder<'_>,
+    class_id: NodeId,
+    member: Node,
+) -> anyhow::Result<()> {
+    if !is_declaration_node(&member) {
+        debug!("Not inserting class member of kind {}", member.kind());
+        return Ok(());
+    }
+    if let Some(scope) = self_.create_declaration(member)? {
+        let member_id = self_.s_file.new_child_scope(class_id, scope);
+        self_.insert_declaration_children(member_id, member)?;
+    }
+    Ok(())
+}
+
+pub(super) fn create_enum_entry(self_: &ScopeBuilder<'_>, node: Node) -> Option<Scope> {
+    let entry = EnumEntry::new(node, &self_.s_file.text);
+    let ident = entry.find_simple_identifier()?;
+    Some(Scope::new(
+        SKind::EnumEntry(SEnumEntry {
+            ident: ident.text(),
+        }),
+        node.byte_range().try_into().unwrap(),
+    ))
+}
+
+/// Updates the class `scope_node_id` from its declaration in `tree`. Members overlapping
+/// `upsert_range` are updated, the others are kept
+pub(crate) fn update_class_declaration(
+    self_: &mut ScopeBuilder<'_>,
+    scope_node_id: NodeId,
+    tree: &Tree,
+    upsert_range: TextRange,
+) -> anyhow::Result<()> {
+    let range = self_.s_file.scopes[scope_node_id].get().range;
+    let node = declaration_node_of(tree, range, is_class_node)
+        .ok_or_else(|| anyhow!("Found no class declaration at {} to update", range))?;
+    debug!("updating class declaration at {}", range);
+
+    let mut kind = SKind::Class(get_class_of(node, self_));
+    let scope = self_.s_file.scopes[scope_node_id].get_mut();
+    keep_parameter_ids_of(&scope.kind, &mut kind);
+    scope.kind = kind;
+    scope.range = node.byte_range().try_into().unwrap();
+
+    self_.update_declarations(Some(scope_node_id), tree, upsert_range)
+}
+
+/// The body of the class `class_id` in `tree`. None if the class has no body
+pub(super) fn body_node_of<'t>(
+    s_file: &GSFile,
+    class_id: NodeId,
+    tree: &'t Tree,
+) -> anyhow::Result<Option<Node<'t>>> {
+    let class_range = s_file.scopes[class_id].get().range;
+    let node = declaration_node_of(tree, class_range, is_class_node)
+        .ok_or_else(|| anyhow!("Found no class declaration at {}", class_range))?;
+    Ok(class_body_of(node))
+}
+
+fn class_body_of(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let body = node.children(&mut cursor).find(|child| {
+        child.kind_id() == *parser::node::ClassBodyId
+            || child.kind_id() == *parser::node::EnumClassBodyId
+    });
+    body
+}
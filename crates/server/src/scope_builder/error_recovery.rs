@@ -0,0 +1,455 @@
+//! Declarations within `ERROR` nodes. While typing, tree-sitter can not parse unfinished
+//! declarations, e.G. `fun name(` without body or `class Name {` without closing brace, and wraps
+//! them together with their neighbours in an `ERROR` node. Complete declarations within it are
+//! inserted as usual. Unfinished ones are recovered from the tokens of the node, so that outline,
+//! completion and goto definition know about them mid-edit. Recovered scopes are marked as
+//! [Scope::is_recovered] and are always replaced, never updated in place.
+
+use crop::Rope;
+use indextree::NodeId;
+use parser::node::{
+    ClassParameter, DelegationSpecifier, FunctionValueParameters, Modifiers,
+    Parameter as ParameterNode, PrimaryConstructor, TypeParameters, VariableDeclaration,
+};
+use stdx::TextRange;
+use tracing::debug;
+use tree_sitter::Node;
+
+use crate::scope::{
+    class_scope::ClassKind,
+    fun_decl_scope::{Parameter, ParameterId},
+    property_scope::{PropertyBinding, SProperty},
+    SClass, SFunDecl, SKind, Scope,
+};
+
+use super::{
+    class_declaration, function_declaration, is_declaration_node, type_reference, ScopeBuilder,
+};
+
+/// A declaration within an `ERROR` node
+enum Recovered<'t> {
+    /// A declaration, which parsed completely
+    Complete(Node<'t>),
+    /// An unfinished declaration. `members` are declared within its unclosed body
+    Unfinished {
+        scope: Box<Scope>,
+        members: Vec<Recovered<'t>>,
+    },
+}
+
+/// Inserts the declarations within the `ERROR` node `error_node` as children of `parent`, or as
+/// root scopes if None
+pub(super) fn insert_recovered_declarations(
+    self_: &mut ScopeBuilder<'_>,
+    parent: Option<NodeId>,
+    error_node: Node,
+) -> anyhow::Result<()> {
+    let tokens = tokens_of(error_node);
+    for declaration in recover(&self_.s_file.text, &tokens) {
+        insert(self_, parent, declaration)?;
+    }
+    Ok(())
+}
+
+fn insert(
+    self_: &mut ScopeBuilder<'_>,
+    parent: Option<NodeId>,
+    declaration: Recovered,
+) -> anyhow::Result<()> {
+    match declaration {
+        Recovered::Complete(node) => match parent {
+            Some(class_id) => class_declaration::insert_member(self_, class_id, node),
+            None => self_.insert_top_level_declaration(node),
+        },
+        Recovered::Unfinished { mut scope, members } => {
+            debug!("recovered {:?} at {}", scope.kind, scope.range);
+            scope.is_recovered = true;
+            let scope_id = match parent {
+                Some(class_id) => self_.s_file.new_child_scope(class_id, *scope),
+                None => self_.s_file.new_root_scope(*scope),
+            };
+            for member in members {
+                insert(self_, Some(scope_id), member)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// The children of `node`. The children of nested `ERROR` nodes are inlined
+fn tokens_of(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    let mut tokens = vec![];
+    for child in node.children(&mut cursor) {
+        if child.is_error() {
+            tokens.extend(tokens_of(child));
+        } else {
+            tokens.push(child);
+        }
+    }
+    tokens
+}
+
+fn recover<'t>(text: &Rope, tokens: &[Node<'t>]) -> Vec<Recovered<'t>> {
+    let mut recovered = vec![];
+    let mut modifiers = None;
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+        // Modifiers precede the keyword of their declaration
+        let start = modifiers.unwrap_or(token).start_byte();
+        let unfinished = match token.kind() {
+            "modifiers" => {
+                modifiers = Some(token);
+                i += 1;
+                continue;
+            }
+            // `fun interface`
+            "fun"
+                if tokens
+                    .get(i + 1)
+                    .is_some_and(|next| next.kind() == "interface") =>
+            {
+                recover_class(text, tokens, i + 1, start, modifiers)
+            }
+            "fun" => recover_function(text, tokens, i, start, modifiers),
+            "class" | "interface" | "object" => recover_class(text, tokens, i, start, modifiers),
+            "binding_pattern_kind" | "val" | "var" => {
+                recover_property(text, tokens, i, start, modifiers)
+            }
+            _ => {
+                if is_declaration_node(&token) {
+                    recovered.push(Recovered::Complete(token));
+                }
+                None
+            }
+        };
+        match unfinished {
+            Some((declaration, next)) => {
+                recovered.push(declaration);
+                i = next;
+            }
+            None => i += 1,
+        }
+        modifiers = None;
+    }
+    recovered
+}
+
+/// Recovers the function, whose `fun` keyword is `tokens[i]`. Returns the index of the token after
+/// it. None, if it has no name yet
+fn recover_function<'t>(
+    text: &Rope,
+    tokens: &[Node<'t>],
+    i: usize,
+    start: usize,
+    modifiers: Option<Node>,
+) -> Option<(Recovered<'t>, usize)> {
+    let mut j = i + 1;
+    let mut end = tokens[i].end_byte();
+
+    // Type parameters and receiver precede the name, e.G. `fun <T> List<T>.name(`
+    let mut type_parameters = vec![];
+    let mut receiver = None;
+    let mut ident = None;
+    while let Some(token) = tokens.get(j) {
+        match token.kind() {
+            "type_parameters" => {
+                type_parameters = function_declaration::get_type_parameters_of(Some(
+                    TypeParameters::new(*token, text),
+                ))
+            }
+            "simple_identifier" => ident = Some(parser::text_of(token, text)),
+            "." => {}
+            _ if type_reference::is_type_node(token) => {
+                receiver = type_reference::type_of(*token, text)
+            }
+            _ => break,
+        }
+        end = token.end_byte();
+        j += 1;
+        if ident.is_some() {
+            break;
+        }
+    }
+    let ident = ident?;
+
+    let mut parameters = vec![];
+    match tokens.get(j) {
+        Some(token) if token.kind() == "function_value_parameters" => {
+            parameters =
+                function_declaration::get_parameters_of(FunctionValueParameters::new(*token, text));
+            end = token.end_byte();
+            j += 1;
+        }
+        // Parameters of an unclosed parameter list, e.G. `fun name(a: Int`
+        Some(token) if token.kind() == "(" => {
+            end = token.end_byte();
+            j += 1;
+            while let Some(token) = tokens.get(j) {
+                match token.kind() {
+                    "parameter" => {
+                        let parameter = ParameterNode::new(*token, text);
+                        parameters.push(Parameter {
+                            id: ParameterId(parameters.len() as u32),
+                            ident: parameter.find_simple_identifier().map(|ident| ident.text()),
+                            type_: type_reference::type_of_children(*token, text),
+                            is_vararg: false,
+                            default_value: None,
+                        })
+                    }
+                    "simple_identifier" => parameters.push(Parameter {
+                        id: ParameterId(parameters.len() as u32),
+                        ident: Some(parser::text_of(token, text)),
+                        type_: None,
+                        is_vararg: false,
+                        default_value: None,
+                    }),
+                    "," | ":" => {}
+                    _ if type_reference::is_type_node(token) => {
+                        if let Some(parameter) = parameters.last_mut() {
+                            parameter.type_ = parameter
+                                .type_
+                                .take()
+                                .or_else(|| type_reference::type_of(*token, text));
+                        }
+                    }
+                    ")" => {
+                        end = token.end_byte();
+                        j += 1;
+                        break;
+                    }
+                    _ => break,
+                }
+                end = token.end_byte();
+                j += 1;
+            }
+        }
+        _ => {}
+    }
+
+    let mut return_type = None;
+    if tokens.get(j).is_some_and(|token| token.kind() == ":") {
+        if let Some(token) = tokens
+            .get(j + 1)
+            .filter(|token| type_reference::is_type_node(token))
+        {
+            return_type = type_reference::type_of(*token, text);
+            end = token.end_byte();
+            j += 2;
+        }
+    }
+
+    // The statements of an unclosed body are not recovered
+    if tokens.get(j).is_some_and(|token| token.kind() == "{") {
+        (j, end) = skip_block(tokens, j);
+    }
+
+    let modifiers_of = || modifiers.map(|modifiers| Modifiers::new(modifiers, text));
+    let s_fun_decl = SFunDecl {
+        ident: Some(ident),
+        modifiers: class_declaration::get_modifiers_of(modifiers_of()),
+        type_parameters,
+        receiver,
+        parameters,
+        return_type,
+        type_constraints: vec![],
+        multiplatform: function_declaration::get_multiplatform_modifier_of(modifiers_of()),
+    };
+    let scope = Scope::new(
+        SKind::FunDecl(s_fun_decl),
+        TextRange::new(start as u32, end as u32),
+    );
+    Some((
+        Recovered::Unfinished {
+            scope: Box::new(scope),
+            members: vec![],
+        },
+        j,
+    ))
+}
+
+/// Recovers the class, interface or object, whose keyword is `tokens[i]`. Returns the index of the
+/// token after it. None, if it has no name yet
+fn recover_class<'t>(
+    text: &Rope,
+    tokens: &[Node<'t>],
+    i: usize,
+    start: usize,
+    modifiers: Option<Node>,
+) -> Option<(Recovered<'t>, usize)> {
+    let keyword = tokens[i].kind();
+    let modifiers_of = || modifiers.map(|modifiers| Modifiers::new(modifiers, text));
+    let multiplatform = function_declaration::get_multiplatform_modifier_of(modifiers_of());
+    let modifiers = class_declaration::get_modifiers_of(modifiers_of());
+    let is_companion = modifiers.iter().any(|m| m == "companion");
+    let mut j = i + 1;
+    let mut end = tokens[i].end_byte();
+
+    let mut ident = None;
+    if let Some(token) = tokens
+        .get(j)
+        .filter(|token| matches!(token.kind(), "type_identifier" | "simple_identifier"))
+    {
+        ident = Some(parser::text_of(token, text));
+        end = token.end_byte();
+        j += 1;
+    }
+    if ident.is_none() && !is_companion {
+        return None;
+    }
+
+    let mut constructor_parameters = vec![];
+    let mut delegation_specifiers = vec![];
+    let mut members = vec![];
+    while let Some(token) = tokens.get(j) {
+        match token.kind() {
+            "type_parameters" | "," | ":" | "(" | ")" => {}
+            "primary_constructor" => {
+                constructor_parameters = class_declaration::get_constructor_parameters_of(
+                    PrimaryConstructor::new(*token, text),
+                )
+            }
+            // Parameters of an unclosed constructor, e.G. `class Name(val a: Int`
+            "class_parameter" => constructor_parameters.push(
+                class_declaration::get_class_parameter_of(ClassParameter::new(*token, text)),
+            ),
+            "delegation_specifier" => {
+                delegation_specifiers.push(DelegationSpecifier::new(*token, text))
+            }
+            "class_body" | "enum_class_body" => {
+                let mut cursor = token.walk();
+                members = token
+                    .named_children(&mut cursor)
+                    .filter(|member| is_declaration_node(member))
+                    .map(Recovered::Complete)
+                    .collect();
+                end = token.end_byte();
+                j += 1;
+                break;
+            }
+            // The members of an unclosed body
+            "{" => {
+                let (next, body_end) = skip_block(tokens, j);
+                let body_tokens = &tokens[j + 1..next];
+                let body_tokens = match body_tokens.last() {
+                    Some(last) if last.kind() == "}" => &body_tokens[..body_tokens.len() - 1],
+                    _ => body_tokens,
+                };
+                members = recover(text, body_tokens);
+                (j, end) = (next, body_end);
+                break;
+            }
+            _ => break,
+        }
+        end = token.end_byte();
+        j += 1;
+    }
+
+    let kind = match keyword {
+        "interface" => ClassKind::Interface,
+        "object" if is_companion => ClassKind::CompanionObject,
+        "object" => ClassKind::Object,
+        _ => ClassKind::of_class(&modifiers),
+    };
+    ParameterId::assign_positions(constructor_parameters.iter_mut().map(|p| &mut p.parameter));
+    let s_class = SClass {
+        ident,
+        kind,
+        modifiers,
+        supertypes: class_declaration::get_supertypes_of(delegation_specifiers),
+        constructor_parameters,
+        multiplatform,
+    };
+    let scope = Scope::new(
+        SKind::Class(s_class),
+        TextRange::new(start as u32, end as u32),
+    );
+    Some((
+        Recovered::Unfinished {
+            scope: Box::new(scope),
+            members,
+        },
+        j,
+    ))
+}
+
+/// Recovers the property, whose `val` or `var` is `tokens[i]`. Returns the index of the token
+/// after it. None, if it has no name yet
+fn recover_property<'t>(
+    text: &Rope,
+    tokens: &[Node<'t>],
+    i: usize,
+    start: usize,
+    modifiers: Option<Node>,
+) -> Option<(Recovered<'t>, usize)> {
+    let binding = PropertyBinding::from_text(&parser::text_of(&tokens[i], text))?;
+    let mut j = i + 1;
+    let (ident, mut type_, mut end) = match tokens.get(j) {
+        Some(token) if token.kind() == "variable_declaration" => {
+            let variable = VariableDeclaration::new(*token, text);
+            (
+                variable.find_simple_identifier().map(|ident| ident.text()),
+                type_reference::type_of_children(*token, text),
+                token.end_byte(),
+            )
+        }
+        Some(token) if token.kind() == "simple_identifier" => {
+            (Some(parser::text_of(token, text)), None, token.end_byte())
+        }
+        _ => return None,
+    };
+    j += 1;
+    if type_.is_none() && tokens.get(j).is_some_and(|token| token.kind() == ":") {
+        if let Some(token) = tokens
+            .get(j + 1)
+            .filter(|token| type_reference::is_type_node(token))
+        {
+            type_ = type_reference::type_of(*token, text);
+            end = token.end_byte();
+            j += 2;
+        }
+    }
+
+    let modifiers_of = || modifiers.map(|modifiers| Modifiers::new(modifiers, text));
+    let s_property = SProperty {
+        ident,
+        binding,
+        type_,
+        modifiers: class_declaration::get_modifiers_of(modifiers_of()),
+        has_getter: false,
+        has_setter: false,
+        delegate: None,
+        multiplatform: function_declaration::get_multiplatform_modifier_of(modifiers_of()),
+    };
+    let scope = Scope::new(
+        SKind::Property(s_property),
+        TextRange::new(start as u32, end as u32),
+    );
+    Some((
+        Recovered::Unfinished {
+            scope: Box::new(scope),
+            members: vec![],
+        },
+        j,
+    ))
+}
+
+/// Skips the block opened by `tokens[open]`, up to its closing brace or the end of the `ERROR`
+/// node. Returns the index of the token after the block and the end of the block
+fn skip_block(tokens: &[Node], open: usize) -> (usize, usize) {
+    let mut depth = 0;
+    let mut end = tokens[open].end_byte();
+    for (j, token) in tokens.iter().enumerate().skip(open) {
+        end = token.end_byte();
+        match token.kind() {
+            "{" => depth += 1,
+            "}" => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            return (j + 1, end);
+        }
+    }
+    (tokens.len(), end)
+}
@@ -1,17 +1,26 @@
 use crate::scope::{
-    fun_decl_scope::{Parameter, Type_},
+    fun_decl_scope::{
+        MultiplatformModifier, Parameter, ParameterId, TypeConstraint, TypeParameter, Type_,
+    },
     SFunDecl,
 };
 use anyhow::{bail, ensure};
+use crop::Rope;
 use indextree::NodeId;
-use parser::node::{FunctionDeclaration, FunctionValueParameters, UserType};
+use parser::node::{
+    FunctionDeclaration, FunctionValueParameters, Modifiers, ParameterModifiers, TypeParameters,
+};
 use std::{cell::RefCell, thread::panicking};
 use tracing::{debug, trace};
 use tree_sitter::{Node, Tree, TreeCursor};
 
 use crate::scope::{SKind, Scope};
 
-use super::ScopeBuilder;
+use super::{
+    class_declaration,
+    sequence_diff::{self, Change},
+    type_reference, ScopeBuilder,
+};
 
 pub(super) fn create_fun_decl(
     self_: &mut ScopeBuilder<'_>,
@@ -19,47 +28,168 @@ pub(super) fn create_fun_decl(
 ) -> anyhow::Result<Option<Scope>> {
     debug!("creating function declaration");
     let fun_decl = FunctionDeclaration::new(node, &self_.s_file.text);
-    let ident = fun_decl
-        .find_simple_identifier()
-        .map(|ident_node| ident_node.text());
+
+    Ok(Some(Scope::new(
+        SKind::FunDecl(get_fun_decl_of(fun_decl)),
+        node.byte_range().try_into().unwrap(),
+    )))
+}
+
+fn get_fun_decl_of(fun_decl: FunctionDeclaration) -> SFunDecl {
+    let ident_node = fun_decl.find_simple_identifier();
+    let ident = ident_node.as_ref().map(|ident_node| ident_node.text());
 
     let parameters = fun_decl
         .find_function_value_parameters()
         .map_or(vec![], |params| get_parameters_of(params));
 
-    let return_type = fun_decl.find_user_type().and_then(|n| get_type_of(n));
+    let return_type = get_return_type_of(&fun_decl);
 
-    Ok(Some(Scope::new(
-        SKind::FunDecl(SFunDecl {
-            ident,
-            parameters,
-            return_type,
-        }),
-        node.byte_range().try_into().unwrap(),
-    )))
+    // The receiver is the type before the name, e.G. `String` of `fun String.foo()`
+    let receiver = ident_node.and_then(|ident_node| {
+        type_reference::types_of_children(fun_decl.node, fun_decl.source)
+            .into_iter()
+            .find(|(node, _)| node.end_byte() <= ident_node.node.start_byte())
+            .map(|(_, type_)| type_)
+    });
+
+    let type_parameters = get_type_parameters_of(fun_decl.find_type_parameters());
+
+    let type_constraints = fun_decl
+        .find_type_constraints()
+        .map_or(vec![], |constraints| {
+            constraints
+                .find_all_type_constraint()
+                .into_iter()
+                .filter_map(|constraint| {
+                    Some(TypeConstraint {
+                        ident: constraint.find_type_identifier()?.text(),
+                        bound: type_reference::type_of_children(
+                            constraint.node,
+                            constraint.source,
+                        )?,
+                    })
+                })
+                .collect()
+        });
+
+    let multiplatform = get_multiplatform_modifier_of(fun_decl.find_modifiers());
+
+    SFunDecl {
+        ident,
+        modifiers: class_declaration::get_modifiers_of(fun_decl.find_modifiers()),
+        type_parameters,
+        receiver,
+        parameters,
+        return_type,
+        type_constraints,
+        multiplatform,
+    }
 }
 
-fn get_parameters_of(fun_value_parameters: FunctionValueParameters) -> Vec<Parameter> {
-    return fun_value_parameters
-        .find_all_parameter()
+/// The type parameters as declared inline, e.G. `reified T : Any` of `<reified T : Any>`
+pub(super) fn get_type_parameters_of(
+    type_parameters: Option<TypeParameters>,
+) -> Vec<TypeParameter> {
+    type_parameters.map_or(vec![], |type_parameters| {
+        type_parameters
+            .find_all_type_parameter()
+            .into_iter()
+            .filter_map(|type_parameter| {
+                Some(TypeParameter {
+                    ident: type_parameter.find_type_identifier()?.text(),
+                    modifiers: type_parameter.find_type_parameter_modifiers().map_or(
+                        vec![],
+                        |modifiers| {
+                            modifiers
+                                .find_all_reification_modifier()
+                                .into_iter()
+                                .map(|m| m.text())
+                                .chain(
+                                    modifiers
+                                        .find_all_variance_modifier()
+                                        .into_iter()
+                                        .map(|m| m.text()),
+                                )
+                                .collect()
+                        },
+                    ),
+                    bound: type_reference::type_of_children(
+                        type_parameter.node,
+                        type_parameter.source,
+                    ),
+                })
+            })
+            .collect()
+    })
+}
+
+pub(super) fn get_multiplatform_modifier_of(
+    modifiers: Option<Modifiers>,
+) -> Option<MultiplatformModifier> {
+    modifiers?
+        .find_all_platform_modifier()
         .into_iter()
-        .map(|parameter| {
-            let ident = parameter.find_simple_identifier().map(|ident| ident.text());
-            let type_ = parameter.find_user_type().and_then(get_type_of);
-            Parameter { ident, type_ }
-        })
-        .collect::<Vec<_>>();
+        .find_map(|modifier| MultiplatformModifier::from_text(&modifier.text()))
 }
 
-fn get_type_of(user_type: UserType) -> Option<Type_> {
-    user_type.find_type_identifier().map(|t| {
-        let t = t.text();
-        if t == "Unit" {
-            Type_::Unit
-        } else {
-            Type_::Simple(t)
+/// The parameters of a function. Modifiers and default values are siblings of a parameter, e.G.
+/// `vararg values: Int = 1`
+pub(super) fn get_parameters_of(fun_value_parameters: FunctionValueParameters) -> Vec<Parameter> {
+    let text = fun_value_parameters.source;
+    let mut parameters: Vec<Parameter> = vec![];
+    let mut modifiers = vec![];
+    let mut is_default_value = false;
+
+    let mut cursor = fun_value_parameters.node.walk();
+    for child in fun_value_parameters.node.children(&mut cursor) {
+        if child.kind_id() == *parser::node::ParameterModifiersId {
+            modifiers = ParameterModifiers::new(child, text)
+                .find_all_parameter_modifier()
+                .into_iter()
+                .map(|modifier| modifier.text())
+                .collect();
+        } else if child.kind_id() == *parser::node::ParameterId {
+            let parameter = parser::node::Parameter::new(child, text);
+            parameters.push(Parameter {
+                id: ParameterId(parameters.len() as u32),
+                ident: parameter.find_simple_identifier().map(|ident| ident.text()),
+                type_: type_reference::type_of_children(child, text),
+                is_vararg: modifiers.iter().any(|m| m == "vararg"),
+                default_value: None,
+            });
+            modifiers.clear();
+        } else if child.kind() == "=" {
+            is_default_value = true;
+        } else if is_default_value && child.is_named() {
+            if let Some(parameter) = parameters.last_mut() {
+                parameter.default_value = Some(parser::text_of(&child, text));
+            }
+            is_default_value = false;
         }
-    })
+    }
+    parameters
+}
+
+/// The text of the expression following `=` within `node`, e.G. the default value of a class
+/// parameter
+pub(super) fn default_value_of(node: &Node, text: &Rope) -> Option<String> {
+    let mut cursor = node.walk();
+    let default_value = node
+        .children(&mut cursor)
+        .skip_while(|child| child.kind() != "=")
+        .find(|child| child.is_named())
+        .map(|child| parser::text_of(&child, text));
+    default_value
+}
+
+/// The type written after the parameters. The type before the name is the receiver
+fn get_return_type_of(fun_decl: &FunctionDeclaration) -> Option<Type_> {
+    let parameters_end = fun_decl.find_function_value_parameters()?.node.end_byte();
+    type_reference::types_of_children(fun_decl.node, fun_decl.source)
+        .into_iter()
+        .find(|(node, _)| node.start_byte() >= parameters_end)
+        .map(|(_, type_)| type_)
 }
 
 pub(crate) fn update_function_declaration<'a>(
@@ -67,13 +197,22 @@ pub(crate) fn update_function_declaration<'a>(
     scope_node_id: NodeId,
     _tree: &Tree,
     cursor: &mut TreeCursor,
-    _upsert_range: stdx::TextRange,
+    upsert_range: stdx::TextRange,
 ) -> anyhow::Result<()> {
     debug!(
         "updating function declaration. Cursor is at {}",
         cursor.node().kind()
     );
     move_cursor_to_mapable_node(cursor)?;
+    if !contains_change(&cursor.node(), upsert_range) {
+        // The change spans further parts, e.G. the name and the parameters
+        while cursor.node().kind_id() != *parser::node::FunctionDeclarationId {
+            ensure!(
+                cursor.goto_parent(),
+                "Found no function declaration to update"
+            );
+        }
+    }
 
     let scope = self_
         .s_file
@@ -88,46 +227,30 @@ pub(crate) fn update_function_declaration<'a>(
         debug!("Updated function name to {}", new_name);
         scope_func_decl.ident = Some(new_name);
     } else if cursor.node().kind_id() == *parser::node::FunctionValueParametersId {
-        let new_params = get_parameters_of(FunctionValueParameters::new(
+        let mut new_params = get_parameters_of(FunctionValueParameters::new(
             cursor.node(),
             &self_.s_file.text,
         ));
-        diff_and_assign_new_params(&mut scope_func_decl.parameters, new_params)?;
-    } else if cursor.node().kind_id() == *parser::node::UserTypeId {
-        let new_return_type = UserType::new(cursor.node(), &self_.s_file.text);
-        scope_func_decl.return_type = get_type_of(new_return_type);
-    }
-
-    Ok(())
-}
-
-fn diff_and_assign_new_params(
-    old_params: &mut Vec<Parameter>,
-    new_params: Vec<Parameter>,
-) -> anyhow::Result<()> {
-    trace!("Updating params {:?} with {:?}", old_params, new_params);
-
-    let mut i = 0;
-    while i < old_params.len() {
-        if i >= new_params.len() {
-            // params got deleted after including i
-            old_params.truncate(i);
-            break;
-        }
-        let new_param = &new_params[i];
-        let param = &old_params[i];
-
-        if param.eq_no_ty(new_param) {
-            // nothing changed. compare next parameters
-        } else {
-            // insert new_param. Diff `param` with next new_param
-            old_params.insert(i, new_param.clone());
-        }
-
-        i += 1;
+        keep_parameter_ids(scope_func_decl.parameters.iter(), new_params.iter_mut());
+        scope_func_decl.parameters = new_params;
+    } else if is_return_type_node(&cursor.node()) {
+        scope_func_decl.return_type = type_reference::type_of(cursor.node(), &self_.s_file.text);
+    } else if is_function_modifiers_node(&cursor.node()) {
+        let modifiers = || Some(Modifiers::new(cursor.node(), &self_.s_file.text));
+        scope_func_decl.multiplatform = get_multiplatform_modifier_of(modifiers());
+        scope_func_decl.modifiers = class_declaration::get_modifiers_of(modifiers());
+    } else if cursor.node().kind_id() == *parser::node::FunctionDeclarationId {
+        // The change is not within a specific part of the function (e.G. a removed modifier).
+        // Recreate the whole declaration
+        let mut new_fun_decl =
+            get_fun_decl_of(FunctionDeclaration::new(cursor.node(), &self_.s_file.text));
+        keep_parameter_ids(
+            scope_func_decl.parameters.iter(),
+            new_fun_decl.parameters.iter_mut(),
+        );
+        *scope_func_decl = new_fun_decl;
     }
 
-    trace!("Updated params: {:?}", old_params);
     Ok(())
 }
 
@@ -139,9 +262,15 @@ fn move_cursor_to_mapable_node(cursor: &mut TreeCursor) -> anyhow::Result<()> {
         } else if cursor.node().kind_id() == *parser::node::FunctionValueParametersId {
             // update the parameter
             return Ok(());
-        } else if cursor.node().kind_id() == *parser::node::UserTypeId {
+        } else if is_return_type_node(&cursor.node()) {
             // update the return type
             return Ok(());
+        } else if is_function_modifiers_node(&cursor.node()) {
+            // update the modifiers
+            return Ok(());
+        } else if cursor.node().kind_id() == *parser::node::FunctionDeclarationId {
+            // update the whole function
+            return Ok(());
         }
 
         ensure!(
@@ -153,9 +282,97 @@ fn move_cursor_to_mapable_node(cursor: &mut TreeCursor) -> anyhow::Result<()> {
     }
 }
 
+/// Gives the parameters of `new`, which replace `old`, the ids of the old parameters they
+/// correspond to: unchanged, moved and renamed ones. Inserted parameters get new ids
+pub(super) fn keep_parameter_ids<'o, 'n>(
+    old: impl IntoIterator<Item = &'o Parameter>,
+    new: impl IntoIterator<Item = &'n mut Parameter>,
+) {
+    let old = old.into_iter().collect::<Vec<_>>();
+    let mut new = new.into_iter().collect::<Vec<_>>();
+    let diff = sequence_diff::diff(
+        &old.iter().map(|p| p.ident.as_ref()).collect::<Vec<_>>(),
+        &new.iter().map(|p| p.ident.as_ref()).collect::<Vec<_>>(),
+    );
+    trace!("Diff of the parameters: {:?}", diff);
+    let mut next_id = old.iter().map(|p| p.id.0 + 1).max().unwrap_or(0);
+    for (parameter, change) in new.iter_mut().zip(diff.changes) {
+        parameter.id = match change {
+            Change::Unchanged(i) | Change::Moved(i) | Change::Replaced(i) => old[i].id,
+            Change::Inserted => {
+                next_id += 1;
+                ParameterId(next_id - 1)
+            }
+        };
+    }
+}
+
+/// Whether the part `node` of a function contains the part of `range` within the function
+fn contains_change(node: &Node, range: stdx::TextRange) -> bool {
+    let Some(function) = std::iter::successors(Some(*node), |node| node.parent())
+        .find(|node| node.kind_id() == *parser::node::FunctionDeclarationId)
+    else {
+        return false;
+    };
+    let start = range.start.max(function.start_byte() as u32);
+    let end = range.end.min(function.end_byte() as u32);
+    node.start_byte() as u32 <= start && end <= node.end_byte() as u32
+}
+
 fn is_function_name_node(node: &Node) -> bool {
     node.kind_id() == *parser::node::SimpleIdentifierId
         && node
             .parent()
             .is_some_and(|parent| parent.kind_id() == *parser::node::FunctionDeclarationId)
 }
+
+/// Whether `node` is the return type of a function. Types of parameters, the receiver and the
+/// body are not
+fn is_return_type_node(node: &Node) -> bool {
+    type_reference::is_type_node(node)
+        && node.parent().is_some_and(|parent| {
+            parent.kind_id() == *parser::node::FunctionDeclarationId
+                && node.prev_named_sibling().is_some_and(|sibling| {
+                    sibling.kind_id() == *parser::node::FunctionValueParametersId
+                })
+        })
+}
+
+fn is_function_modifiers_node(node: &Node) -> bool {
+    node.kind_id() == *parser::node::ModifiersId
+        && node
+            .parent()
+            .is_some_and(|parent| parent.kind_id() == *parser::node::FunctionDeclarationId)
+}
+
+#[test]
+fn unchanged_and_renamed_parameters_keep_their_ids() {
+    let parameter = |id, ident: &str| Parameter {
+        id: ParameterId(id),
+        ident: Some(ident.to_string()),
+        ..Default::default()
+    };
+    let ids = |parameters: &[Parameter]| parameters.iter().map(|p| p.id.0).collect::<Vec<_>>();
+
+    let old = vec![parameter(0, "a"), parameter(1, "b")];
+    let mut new = vec![
+        parameter(0, "a"),
+        parameter(1, "inserted"),
+        parameter(2, "b"),
+    ];
+    keep_parameter_ids(&old, &mut new);
+    assert_eq!(ids(&new), vec![0, 2, 1]);
+
+    let old = new;
+    let mut new = vec![
+        parameter(0, "a"),
+        parameter(1, "renamed"),
+        parameter(2, "b"),
+    ];
+    keep_parameter_ids(&old, &mut new);
+    assert_eq!(ids(&new), vec![0, 2, 1]);
+
+    let mut new = vec![parameter(0, "b"), parameter(1, "a")];
+    keep_parameter_ids(&old, &mut new);
+    assert_eq!(ids(&new), vec![1, 0]);
+}
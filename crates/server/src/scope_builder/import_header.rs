@@ -0,0 +1,92 @@
+use itertools::Itertools;
+use parser::node::ImportHeader;
+use stdx::TextRange;
+use tracing::debug;
+use tree_sitter::{Node, Tree};
+
+use crate::scope::{import_scope::SImport, GSFile, SKind, Scope};
+
+use super::ScopeBuilder;
+
+/// Imports are few and edits often add or remove whole import lines. So all import scopes are
+/// rebuilt, instead of updating single imports
+pub(super) fn rebuild_import_scopes(
+    self_: &mut ScopeBuilder<'_>,
+    tree: &Tree,
+) -> anyhow::Result<()> {
+    debug!("Rebuilding import scopes");
+    let existing_imports = self_
+        .s_file
+        .root_nodes
+        .iter()
+        .filter(|n| self_.s_file.scopes[**n].get().kind.is_import())
+        .copied()
+        .collect_vec();
+    for import in existing_imports {
+        self_.s_file.delete_scope(import);
+    }
+
+    for node in import_header_nodes(tree) {
+        let import_header = ImportHeader::new(node, &self_.s_file.text);
+        let Some(identifier) = import_header.find_identifier() else {
+            debug!("not inserting import without identifier");
+            continue;
+        };
+        let s_import = SImport {
+            fq_name: identifier.text().split_whitespace().join(""),
+            alias: import_header
+                .find_import_alias()
+                .and_then(|a| a.find_type_identifier().map(|ident| ident.text())),
+            is_wildcard: import_header.find_wildcard_import().is_some(),
+        };
+        self_.s_file.new_root_scope(Scope::new(
+            SKind::Import(s_import),
+            import_header.text_range(),
+        ));
+    }
+    Ok(())
+}
+
+/// Whether `range` touches the imports of the file, either the ones in `tree` or the existing
+/// import scopes
+pub(super) fn overlaps_imports(s_file: &GSFile, tree: &Tree, range: TextRange) -> bool {
+    imports_range(tree).is_some_and(|imports| imports.overlaps_with(range))
+        || s_file.root_nodes.iter().any(|n| {
+            let scope = s_file.scopes[*n].get();
+            scope.kind.is_import() && scope.range.overlaps_with(range)
+        })
+}
+
+/// Whether `range` is completely within the import list of `tree`
+pub(super) fn is_within_imports(tree: &Tree, range: TextRange) -> bool {
+    imports_range(tree).is_some_and(|imports| imports.contains_range(range))
+}
+
+pub(super) fn is_import_node(node: &Node) -> bool {
+    node.kind_id() == *parser::node::ImportListId || node.kind_id() == *parser::node::ImportHeaderId
+}
+
+fn imports_range(tree: &Tree) -> Option<TextRange> {
+    let nodes = import_header_nodes(tree);
+    let start = nodes.first()?.start_byte();
+    let end = nodes.last()?.end_byte();
+    TextRange::try_from(start..end).ok()
+}
+
+/// The import headers of the file. They are either within an `import_list` or direct children of
+/// the `source_file`
+fn import_header_nodes(tree: &Tree) -> Vec<Node<'_>> {
+    let root = tree.root_node();
+    let mut cursor = root.walk();
+    root.children(&mut cursor)
+        .flat_map(|n| {
+            if n.kind_id() == *parser::node::ImportListId {
+                let mut cursor = n.walk();
+                n.children(&mut cursor).collect_vec()
+            } else {
+                vec![n]
+            }
+        })
+        .filter(|n| n.kind_id() == *parser::node::ImportHeaderId)
+        .collect()
+}
@@ -0,0 +1,285 @@
+use indextree::NodeId;
+use parser::java_node;
+use stdx::TextRange;
+use tracing::debug;
+use tree_sitter::{Node, Tree};
+
+use crate::scope::{
+    fun_decl_scope::{Parameter, ParameterId},
+    java_scope::{kotlin_type_of_java, JavaClassKind},
+    SJavaClass, SJavaField, SJavaMethod, SKind, Scope,
+};
+
+use super::ScopeBuilder;
+
+/// Java files are small compared to the cost of tracking edits, so their scopes are rebuilt on
+/// every change
+pub(super) fn rebuild_java_scopes(self_: &mut ScopeBuilder<'_>, tree: &Tree) -> anyhow::Result<()> {
+    debug!(
+        "Rebuilding scopes of java file {}",
+        self_.s_file.path.display()
+    );
+    for root_node in self_.s_file.root_nodes.clone() {
+        self_.s_file.delete_scope(root_node);
+    }
+
+    let root = tree.root_node();
+    let mut cursor = root.walk();
+    for node in root.named_children(&mut cursor) {
+        if node.kind_id() == *java_node::PackageDeclarationId {
+            let mut cursor = node.walk();
+            let ident = node
+                .named_children(&mut cursor)
+                .find(|n| {
+                    n.kind_id() == *java_node::ScopedIdentifierId
+                        || n.kind_id() == *java_node::IdentifierId
+                })
+                .map(|n| self_.text_of(&n));
+            if let Some(ident) = ident {
+                self_
+                    .s_file
+                    .new_root_scope(Scope::new(SKind::PackageHeader { ident }, range_of(&node)));
+            }
+        } else if let Some(s_java_class) = java_class_of(self_, &node) {
+            let class_id = self_
+                .s_file
+                .scopes
+                .new_node(Scope::new(SKind::JavaClass(s_java_class), range_of(&node)));
+            self_.s_file.root_nodes.push(class_id);
+            insert_members(self_, class_id, &node);
+        }
+    }
+
+    Ok(())
+}
+
+fn range_of(node: &Node) -> TextRange {
+    node.byte_range().try_into().unwrap()
+}
+
+impl<'a> ScopeBuilder<'a> {
+    fn text_of(&self, node: &Node) -> String {
+        parser::text_of(node, &self.s_file.text)
+    }
+}
+
+fn java_class_of(self_: &ScopeBuilder<'_>, node: &Node) -> Option<SJavaClass> {
+    let kind_id = node.kind_id();
+    let kind = if kind_id == *java_node::ClassDeclarationId {
+        JavaClassKind::Class
+    } else if kind_id == *java_node::InterfaceDeclarationId {
+        JavaClassKind::Interface
+    } else if kind_id == *java_node::EnumDeclarationId {
+        JavaClassKind::Enum
+    } else if kind_id == *java_node::RecordDeclarationId {
+        JavaClassKind::Record
+    } else if kind_id == *java_node::AnnotationTypeDeclarationId {
+        JavaClassKind::Annotation
+    } else {
+        return None;
+    };
+    let ident = self_.text_of(&node.child_by_field_name("name")?);
+
+    // `extends A implements B, C` or, for interfaces, `extends B, C`
+    let mut supertypes = vec![];
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let types = if child.kind_id() == *java_node::SuperclassId {
+            child.named_child(0).into_iter().collect::<Vec<_>>()
+        } else if child.kind_id() == *java_node::SuperInterfacesId
+            || child.kind_id() == *java_node::ExtendsInterfacesId
+        {
+            let mut cursor = child.walk();
+            child
+                .named_children(&mut cursor)
+                .filter(|n| n.kind_id() == *java_node::TypeListId)
+                .flat_map(|type_list| {
+                    let mut cursor = type_list.walk();
+                    type_list.named_children(&mut cursor).collect::<Vec<_>>()
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+        supertypes.extend(types.iter().map(|t| kotlin_type_of_java(&self_.text_of(t))));
+    }
+
+    let enum_constants = match node.child_by_field_name("body") {
+        Some(body) if kind == JavaClassKind::Enum => {
+            let mut cursor = body.walk();
+            body.named_children(&mut cursor)
+                .filter(|n| n.kind_id() == *java_node::EnumConstantId)
+                .filter_map(|n| n.child_by_field_name("name"))
+                .map(|n| self_.text_of(&n))
+                .collect()
+        }
+        _ => vec![],
+    };
+
+    Some(SJavaClass {
+        ident,
+        kind,
+        supertypes,
+        enum_constants,
+    })
+}
+
+/// Inserts the methods, fields and nested classes of the class `node` as children of `class_id`
+fn insert_members(self_: &mut ScopeBuilder<'_>, class_id: NodeId, node: &Node) {
+    let Some(body) = node.child_by_field_name("body") else {
+        return;
+    };
+    let mut cursor = body.walk();
+    let mut members = body.named_children(&mut cursor).collect::<Vec<_>>();
+    // Members of enums follow the constants
+    if let Some(declarations) = members
+        .iter()
+        .find(|n| n.kind_id() == *java_node::EnumBodyDeclarationsId)
+    {
+        let mut cursor = declarations.walk();
+        members = declarations.named_children(&mut cursor).collect();
+    }
+    // The components of records are accessed like methods (`point.x()`)
+    let record_components = node
+        .child_by_field_name("parameters")
+        .map(|parameters| parameters_of(self_, &parameters))
+        .unwrap_or_default();
+    for component in record_components {
+        let (Some(ident), Some(type_)) = (component.ident, component.type_) else {
+            continue;
+        };
+        let method = SJavaMethod {
+            ident,
+            parameters: vec![],
+            return_type: Some(type_),
+            is_static: false,
+            is_constructor: false,
+        };
+        class_id.append_value(
+            Scope::new(SKind::JavaMethod(method), range_of(node)),
+            &mut self_.s_file.scopes,
+        );
+    }
+
+    for member in members {
+        let kind_id = member.kind_id();
+        let is_static = has_modifier(self_, &member, "static");
+        if kind_id == *java_node::MethodDeclarationId
+            || kind_id == *java_node::ConstructorDeclarationId
+        {
+            let is_constructor = kind_id == *java_node::ConstructorDeclarationId;
+            let Some(ident) = member
+                .child_by_field_name("name")
+                .map(|n| self_.text_of(&n))
+            else {
+                continue;
+            };
+            let method = SJavaMethod {
+                ident,
+                parameters: member
+                    .child_by_field_name("parameters")
+                    .map(|parameters| parameters_of(self_, &parameters))
+                    .unwrap_or_default(),
+                return_type: member
+                    .child_by_field_name("type")
+                    .map(|t| kotlin_type_of_java(&self_.text_of(&t))),
+                is_static,
+                is_constructor,
+            };
+            class_id.append_value(
+                Scope::new(SKind::JavaMethod(method), range_of(&member)),
+                &mut self_.s_file.scopes,
+            );
+        } else if kind_id == *java_node::FieldDeclarationId
+            || kind_id == *java_node::ConstantDeclarationId
+        {
+            // Interface constants are implicitly static final
+            let is_constant = kind_id == *java_node::ConstantDeclarationId;
+            let Some(type_) = member.child_by_field_name("type") else {
+                continue;
+            };
+            let type_ = kotlin_type_of_java(&self_.text_of(&type_));
+            let mut cursor = member.walk();
+            let declarators = member
+                .children_by_field_name("declarator", &mut cursor)
+                .collect::<Vec<_>>();
+            for declarator in declarators {
+                let Some(ident) = declarator.child_by_field_name("name") else {
+                    continue;
+                };
+                let field = SJavaField {
+                    ident: self_.text_of(&ident),
+                    type_: type_.clone(),
+                    is_static: is_static || is_constant,
+                    is_final: has_modifier(self_, &member, "final") || is_constant,
+                };
+                class_id.append_value(
+                    Scope::new(SKind::JavaField(field), range_of(&member)),
+                    &mut self_.s_file.scopes,
+                );
+            }
+        } else if let Some(s_java_class) = java_class_of(self_, &member) {
+            let nested_id = class_id.append_value(
+                Scope::new(SKind::JavaClass(s_java_class), range_of(&member)),
+                &mut self_.s_file.scopes,
+            );
+            insert_members(self_, nested_id, &member);
+        }
+    }
+}
+
+fn parameters_of(self_: &ScopeBuilder<'_>, formal_parameters: &Node) -> Vec<Parameter> {
+    let mut cursor = formal_parameters.walk();
+    let mut parameters = formal_parameters
+        .named_children(&mut cursor)
+        .filter_map(|parameter| {
+            if parameter.kind_id() == *java_node::FormalParameterId {
+                Some(Parameter {
+                    ident: parameter
+                        .child_by_field_name("name")
+                        .map(|n| self_.text_of(&n)),
+                    type_: parameter
+                        .child_by_field_name("type")
+                        .map(|t| kotlin_type_of_java(&self_.text_of(&t))),
+                    ..Default::default()
+                })
+            } else if parameter.kind_id() == *java_node::SpreadParameterId {
+                // `String... values`. The parameter is typed by its element type
+                let mut cursor = parameter.walk();
+                let children = parameter.named_children(&mut cursor).collect::<Vec<_>>();
+                let type_ = children
+                    .iter()
+                    .find(|n| n.kind_id() != *java_node::ModifiersId)
+                    .map(|t| kotlin_type_of_java(&self_.text_of(t)));
+                let ident = children
+                    .iter()
+                    .find(|n| n.kind_id() == *java_node::VariableDeclaratorId)
+                    .and_then(|d| d.child_by_field_name("name"))
+                    .map(|n| self_.text_of(&n));
+                Some(Parameter {
+                    ident,
+                    type_,
+                    is_vararg: true,
+                    ..Default::default()
+                })
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    ParameterId::assign_positions(&mut parameters);
+    parameters
+}
+
+fn has_modifier(self_: &ScopeBuilder<'_>, declaration: &Node, modifier: &str) -> bool {
+    let mut cursor = declaration.walk();
+    let modifiers = declaration
+        .named_children(&mut cursor)
+        .find(|n| n.kind_id() == *java_node::ModifiersId);
+    modifiers.is_some_and(|modifiers| {
+        self_
+            .text_of(&modifiers)
+            .split_whitespace()
+            .any(|word| word == modifier)
+    })
+}
@@ -0,0 +1,279 @@
+use anyhow::anyhow;
+use indextree::NodeId;
+use parser::node::{CatchBlock, VariableDeclaration};
+use stdx::TextRange;
+use tracing::debug;
+use tree_sitter::{Node, Tree};
+
+use crate::scope::{
+    local_scope::{BindingKind, BlockKind},
+    GSFile, SKind, SLocalBinding, Scope,
+};
+
+use super::{
+    class_declaration, declaration_node_of, function_declaration, property_declaration,
+    type_reference, ScopeBuilder,
+};
+
+fn is_function_node(node: &Node) -> bool {
+    node.kind_id() == *parser::node::FunctionDeclarationId
+}
+
+/// Inserts the local declarations and blocks of the body of the function `node` as children of
+/// `fun_id`
+pub(super) fn insert_body_scopes(
+    self_: &mut ScopeBuilder<'_>,
+    fun_id: NodeId,
+    node: Node,
+) -> anyhow::Result<()> {
+    let mut cursor = node.walk();
+    let body = node
+        .children(&mut cursor)
+        .find(|child| child.kind_id() == *parser::node::FunctionBodyId);
+    match body {
+        Some(body) => insert_local_scopes(self_, fun_id, body),
+        None => Ok(()),
+    }
+}
+
+/// Recreates the scopes of the body of the function `fun_id` from its declaration in `tree`
+pub(super) fn rebuild_body_scopes(
+    self_: &mut ScopeBuilder<'_>,
+    fun_id: NodeId,
+    tree: &Tree,
+) -> anyhow::Result<()> {
+    let range = self_.s_file.scopes[fun_id].get().range;
+    let node = declaration_node_of(tree, range, is_function_node)
+        .ok_or_else(|| anyhow!("Found no function declaration at {} to update", range))?;
+    debug!("rebuilding the body scopes of the function at {}", range);
+
+    let children = fun_id.children(&self_.s_file.scopes).collect::<Vec<_>>();
+    for child in children {
+        self_.s_file.delete_scope(child);
+    }
+    insert_body_scopes(self_, fun_id, node)
+}
+
+/// Whether `range` is within the body of the function `fun_id`
+pub(super) fn is_within_body(
+    s_file: &GSFile,
+    fun_id: NodeId,
+    tree: &Tree,
+    range: TextRange,
+) -> bool {
+    let fun_range = s_file.scopes[fun_id].get().range;
+    let Some(node) = declaration_node_of(tree, fun_range, is_function_node) else {
+        return false;
+    };
+    let mut cursor = node.walk();
+    let body = node
+        .children(&mut cursor)
+        .find(|child| child.kind_id() == *parser::node::FunctionBodyId);
+    body.is_some_and(|body| {
+        TextRange::try_from(body.byte_range()).is_ok_and(|body| body.contains_range(range))
+    })
+}
+
+/// Inserts the local declarations and blocks below `node` as children of `parent`
+fn insert_local_scopes(
+    self_: &mut ScopeBuilder<'_>,
+    parent: NodeId,
+    node: Node,
+) -> anyhow::Result<()> {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        insert_local_scope(self_, parent, child)?;
+    }
+    Ok(())
+}
+
+/// Inserts the local declarations and blocks of `node` and below as children of `parent`
+pub(super) fn insert_local_scope(
+    self_: &mut ScopeBuilder<'_>,
+    parent: NodeId,
+    node: Node,
+) -> anyhow::Result<()> {
+    if node.kind_id() == *parser::node::PropertyDeclarationId {
+        let Some(scope) = property_declaration::create_property_decl(self_, node)? else {
+            return insert_local_scopes(self_, parent, node);
+        };
+        // Lambdas of the initializer are children of the property
+        let property_id = self_.s_file.new_child_scope(parent, scope);
+        return insert_local_scopes(self_, property_id, node);
+    }
+    if is_function_node(&node) {
+        if let Some(scope) = function_declaration::create_fun_decl(self_, node)? {
+            let fun_id = self_.s_file.new_child_scope(parent, scope);
+            insert_body_scopes(self_, fun_id, node)?;
+        }
+        return Ok(());
+    }
+    if class_declaration::is_class_node(&node) {
+        if let Some(scope) = class_declaration::create_class_decl(self_, node)? {
+            let class_id = self_.s_file.new_child_scope(parent, scope);
+            class_declaration::insert_members(self_, class_id, node)?;
+        }
+        return Ok(());
+    }
+
+    let Some(block_kind) = block_kind_of(&node) else {
+        return insert_local_scopes(self_, parent, node);
+    };
+    let block_id = self_.s_file.new_child_scope(
+        parent,
+        Scope::new(
+            SKind::Block(block_kind),
+            node.byte_range().try_into().unwrap(),
+        ),
+    );
+    insert_bindings(self_, block_id, block_kind, node);
+    insert_local_scopes(self_, block_id, node)?;
+    if block_id.children(&self_.s_file.scopes).next().is_none() {
+        // Blocks are only kept when they declare something
+        self_.s_file.delete_scope(block_id);
+    }
+    Ok(())
+}
+
+fn block_kind_of(node: &Node) -> Option<BlockKind> {
+    let kind_id = node.kind_id();
+    if kind_id == *parser::node::ControlStructureBodyId || kind_id == *parser::node::FinallyBlockId
+    {
+        Some(BlockKind::Block)
+    } else if kind_id == *parser::node::StatementsId {
+        // The statements of `try { ... }` are not wrapped by a block node
+        node.parent()
+            .filter(|parent| parent.kind_id() == *parser::node::TryExpressionId)
+            .map(|_| BlockKind::Block)
+    } else if kind_id == *parser::node::ForStatementId {
+        Some(BlockKind::For)
+    } else if kind_id == *parser::node::CatchBlockId {
+        Some(BlockKind::Catch)
+    } else if kind_id == *parser::node::WhenExpressionId {
+        Some(BlockKind::When)
+    } else if kind_id == *parser::node::LambdaLiteralId
+        || kind_id == *parser::node::AnonymousFunctionId
+    {
+        Some(BlockKind::Lambda)
+    } else {
+        None
+    }
+}
+
+/// Inserts the names bound by the block `node` as children of `block_id`
+fn insert_bindings(self_: &mut ScopeBuilder<'_>, block_id: NodeId, kind: BlockKind, node: Node) {
+    let text = &self_.s_file.text;
+    let bindings = match kind {
+        BlockKind::Block | BlockKind::Script => vec![],
+        BlockKind::For => variable_bindings_of(node, text, BindingKind::ForVariable),
+        BlockKind::Catch => {
+            let catch_block = CatchBlock::new(node, text);
+            catch_block
+                .find_simple_identifier()
+                .map(|ident| {
+                    let binding = SLocalBinding {
+                        ident: ident.text(),
+                        kind: BindingKind::CatchParameter,
+                        type_: type_reference::type_of_children(node, text),
+                    };
+                    (binding, ident.node.byte_range())
+                })
+                .into_iter()
+                .collect()
+        }
+        BlockKind::When => {
+            let mut cursor = node.walk();
+            let subject = node
+                .named_children(&mut cursor)
+                .find(|child| child.kind_id() == *parser::node::WhenSubjectId);
+            subject.map_or(vec![], |subject| {
+                variable_bindings_of(subject, text, BindingKind::WhenSubject)
+            })
+        }
+        BlockKind::Lambda if node.kind_id() == *parser::node::AnonymousFunctionId => {
+            let mut cursor = node.walk();
+            let parameters = node
+                .named_children(&mut cursor)
+                .find(|child| child.kind_id() == *parser::node::FunctionValueParametersId);
+            parameters.map_or(vec![], |parameters| {
+                let mut cursor = parameters.walk();
+                let parameters = parameters
+                    .named_children(&mut cursor)
+                    .filter(|p| p.kind_id() == *parser::node::ParameterId)
+                    .filter_map(|p| {
+                        let ident = p.named_child(0)?;
+                        let binding = SLocalBinding {
+                            ident: parser::text_of(&ident, text),
+                            kind: BindingKind::LambdaParameter,
+                            type_: type_reference::type_of_children(p, text),
+                        };
+                        Some((binding, ident.byte_range()))
+                    })
+                    .collect();
+                parameters
+            })
+        }
+        BlockKind::Lambda => {
+            let mut cursor = node.walk();
+            let parameters = node
+                .named_children(&mut cursor)
+                .find(|child| child.kind_id() == *parser::node::LambdaParametersId);
+            match parameters {
+                Some(parameters) => {
+                    variable_bindings_of(parameters, text, BindingKind::LambdaParameter)
+                }
+                None => {
+                    // The range of `{`, as `it` is not written
+                    let binding = SLocalBinding {
+                        ident: "it".to_string(),
+                        kind: BindingKind::ImplicitIt,
+                        type_: None,
+                    };
+                    vec![(binding, node.start_byte()..node.start_byte() + 1)]
+                }
+            }
+        }
+    };
+
+    for (binding, range) in bindings {
+        self_.s_file.new_child_scope(
+            block_id,
+            Scope::new(SKind::LocalBinding(binding), range.try_into().unwrap()),
+        );
+    }
+}
+
+/// The variables declared by the direct children of `node`. Destructuring declarations bind
+/// each of their variables
+fn variable_bindings_of(
+    node: Node,
+    text: &crop::Rope,
+    kind: BindingKind,
+) -> Vec<(SLocalBinding, std::ops::Range<usize>)> {
+    let mut cursor = node.walk();
+    let variables = node
+        .named_children(&mut cursor)
+        .flat_map(|child| {
+            if child.kind_id() == *parser::node::MultiVariableDeclarationId {
+                let mut cursor = child.walk();
+                child.named_children(&mut cursor).collect::<Vec<_>>()
+            } else {
+                vec![child]
+            }
+        })
+        .filter(|child| child.kind_id() == *parser::node::VariableDeclarationId)
+        .collect::<Vec<_>>();
+
+    variables
+        .into_iter()
+        .filter_map(|variable| {
+            let variable = VariableDeclaration::new(variable, text);
+            let binding = SLocalBinding {
+                ident: variable.find_simple_identifier()?.text(),
+                kind,
+                type_: type_reference::type_of_children(variable.node, text),
+            };
+            Some((binding, variable.node.byte_range()))
+        })
+        .collect()
+}
@@ -0,0 +1,87 @@
+use anyhow::anyhow;
+use indextree::NodeId;
+use parser::node::PropertyDeclaration;
+use tracing::debug;
+use tree_sitter::{Node, Tree};
+
+use crate::scope::{
+    property_scope::{PropertyBinding, SProperty},
+    SKind, Scope,
+};
+
+use super::{
+    class_declaration, declaration_node_of, function_declaration, type_reference, ScopeBuilder,
+};
+
+pub(super) fn create_property_decl(
+    self_: &mut ScopeBuilder<'_>,
+    node: Node,
+) -> anyhow::Result<Option<Scope>> {
+    debug!("creating property declaration");
+    let Some(s_property) = get_property_of(PropertyDeclaration::new(node, &self_.s_file.text))
+    else {
+        debug!("not inserting property, as it is neither val nor var");
+        return Ok(None);
+    };
+
+    Ok(Some(Scope::new(
+        SKind::Property(s_property),
+        node.byte_range().try_into().unwrap(),
+    )))
+}
+
+fn get_property_of(property: PropertyDeclaration) -> Option<SProperty> {
+    let binding = PropertyBinding::from_text(&property.find_binding_pattern_kind()?.text())?;
+    let variable = property.find_variable_declaration();
+
+    Some(SProperty {
+        ident: variable
+            .as_ref()
+            .and_then(|v| v.find_simple_identifier())
+            .map(|ident| ident.text()),
+        binding,
+        type_: variable
+            .as_ref()
+            .and_then(|v| type_reference::type_of_children(v.node, v.source)),
+        modifiers: class_declaration::get_modifiers_of(property.find_modifiers()),
+        has_getter: property.find_getter().is_some(),
+        has_setter: property.find_setter().is_some(),
+        delegate: property.find_property_delegate().map(|delegate| {
+            let mut cursor = delegate.node.walk();
+            let expression = delegate.node.named_children(&mut cursor).next();
+            // `lazy { 1 }` is delegated to `lazy`
+            let callee = expression.map(|e| {
+                if e.kind_id() == *parser::node::CallExpressionId {
+                    e.named_child(0).unwrap_or(e)
+                } else {
+                    e
+                }
+            });
+            callee.map_or(delegate.text(), |c| parser::text_of(&c, delegate.source))
+        }),
+        multiplatform: function_declaration::get_multiplatform_modifier_of(
+            property.find_modifiers(),
+        ),
+    })
+}
+
+/// Recreates the property `scope_node_id` from its declaration in `tree`
+pub(crate) fn update_property_declaration(
+    self_: &mut ScopeBuilder<'_>,
+    scope_node_id: NodeId,
+    tree: &Tree,
+) -> anyhow::Result<()> {
+    let range = self_.s_file.scopes[scope_node_id].get().range;
+    let node = declaration_node_of(tree, range, |node| {
+        node.kind_id() == *parser::node::PropertyDeclarationId
+    })
+    .ok_or_else(|| anyhow!("Found no property declaration at {} to update", range))?;
+    debug!("updating property declaration at {}", range);
+
+    let s_property = get_property_of(PropertyDeclaration::new(node, &self_.s_file.text))
+        .ok_or_else(|| anyhow!("Property at {} is neither val nor var", range))?;
+    let scope = self_.s_file.scopes[scope_node_id].get_mut();
+    scope.kind = SKind::Property(s_property);
+    scope.range = node.byte_range().try_into().unwrap();
+    Ok(())
+}
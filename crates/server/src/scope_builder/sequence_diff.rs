@@ -0,0 +1,147 @@
+//! Diff of two sequences of declarations, e.G. the members of a class body or the parameters of a
+//! function before and after an edit. Elements are identified by a key, e.G. their kind and name.
+//! Elements with the same key are matched by their longest common subsequence first and as moved
+//! afterwards. The remaining elements between the same unchanged neighbours replace each other,
+//! e.G. a renamed function. Matched elements keep their identity: the scope of a declaration or
+//! the id of a parameter
+
+/// Where an element of the new sequence comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Change {
+    /// The old element at the index, which kept its key and relative position
+    Unchanged(usize),
+    /// The old element at the index, which kept its key, but moved before or after other elements
+    Moved(usize),
+    /// The old element at the index got a new key, e.G. it was renamed
+    Replaced(usize),
+    Inserted,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct SequenceDiff {
+    /// One change for each element of the new sequence
+    pub changes: Vec<Change>,
+    /// The indices of the old elements, which are not part of the new sequence
+    pub deleted: Vec<usize>,
+}
+
+/// The diff from the elements with the keys `old` to the ones with the keys `new`. Elements
+/// without key are never unchanged or moved, only replaced
+pub(super) fn diff<K: PartialEq>(old: &[Option<K>], new: &[Option<K>]) -> SequenceDiff {
+    let is_same = |i: usize, j: usize| old[i].is_some() && old[i] == new[j];
+
+    // The length of the longest common subsequence of `old[i..]` and `new[j..]`
+    let mut lengths = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if is_same(i, j) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = vec![Change::Inserted; new.len()];
+    let mut is_matched = vec![false; old.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if is_same(i, j) {
+            changes[j] = Change::Unchanged(i);
+            is_matched[i] = true;
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    for j in 0..new.len() {
+        if changes[j] != Change::Inserted || new[j].is_none() {
+            continue;
+        }
+        if let Some(i) = (0..old.len()).find(|&i| !is_matched[i] && old[i] == new[j]) {
+            changes[j] = Change::Moved(i);
+            is_matched[i] = true;
+        }
+    }
+
+    // The old elements after the previous unchanged one are candidates to be replaced
+    let mut candidates_start = 0;
+    for j in 0..new.len() {
+        match changes[j] {
+            Change::Unchanged(i) => candidates_start = i + 1,
+            Change::Inserted => {
+                let candidates_end = changes[j..]
+                    .iter()
+                    .find_map(|change| match change {
+                        Change::Unchanged(i) => Some(*i),
+                        _ => None,
+                    })
+                    .unwrap_or(old.len());
+                if let Some(i) = (candidates_start..candidates_end).find(|&i| !is_matched[i]) {
+                    changes[j] = Change::Replaced(i);
+                    is_matched[i] = true;
+                    candidates_start = i + 1;
+                }
+            }
+            Change::Moved(_) | Change::Replaced(_) => {}
+        }
+    }
+
+    SequenceDiff {
+        changes,
+        deleted: (0..old.len()).filter(|&i| !is_matched[i]).collect(),
+    }
+}
+
+#[test]
+fn diff_deleted_inserted_and_moved_elements() {
+    use Change::*;
+
+    let keys = |keys: &[&'static str]| keys.iter().map(|key| Some(*key)).collect::<Vec<_>>();
+    assert_eq!(
+        diff(&keys(&["a", "b", "c"]), &keys(&["a", "c"])),
+        SequenceDiff {
+            changes: vec![Unchanged(0), Unchanged(2)],
+            deleted: vec![1]
+        }
+    );
+    assert_eq!(
+        diff(&keys(&["a", "c"]), &keys(&["a", "b", "c"])),
+        SequenceDiff {
+            changes: vec![Unchanged(0), Inserted, Unchanged(1)],
+            deleted: vec![]
+        }
+    );
+    assert_eq!(
+        diff(&keys(&["a", "b", "c"]), &keys(&["c", "a", "b"])),
+        SequenceDiff {
+            changes: vec![Moved(2), Unchanged(0), Unchanged(1)],
+            deleted: vec![]
+        }
+    );
+    assert_eq!(
+        diff(&keys(&["a", "b", "c"]), &keys(&["a", "renamed", "c", "d"])),
+        SequenceDiff {
+            changes: vec![Unchanged(0), Replaced(1), Unchanged(2), Inserted],
+            deleted: vec![]
+        }
+    );
+    assert_eq!(
+        diff(&keys(&["a", "b", "c", "d"]), &keys(&["a", "c", "b", "d"])),
+        SequenceDiff {
+            changes: vec![Unchanged(0), Unchanged(2), Moved(1), Unchanged(3)],
+            deleted: vec![]
+        }
+    );
+    assert_eq!(
+        diff(&[None, Some("a")], &[Some("a"), None]),
+        SequenceDiff {
+            changes: vec![Unchanged(1), Inserted],
+            deleted: vec![0]
+        }
+    );
+}
@@ -0,0 +1,61 @@
+use anyhow::anyhow;
+use indextree::NodeId;
+use parser::node::TypeAlias;
+use tracing::debug;
+use tree_sitter::{Node, Tree};
+
+use crate::scope::{type_alias_scope::STypeAlias, SKind, Scope};
+
+use super::{
+    class_declaration, declaration_node_of, function_declaration, type_reference, ScopeBuilder,
+};
+
+fn is_type_alias_node(node: &Node) -> bool {
+    node.kind_id() == *parser::node::TypeAliasId
+}
+
+pub(super) fn create_type_alias(
+    self_: &mut ScopeBuilder<'_>,
+    node: Node,
+) -> anyhow::Result<Option<Scope>> {
+    debug!("creating type alias");
+    let Some(s_type_alias) = get_type_alias_of(TypeAlias::new(node, &self_.s_file.text)) else {
+        debug!("not inserting type alias, as it has no name");
+        return Ok(None);
+    };
+
+    Ok(Some(Scope::new(
+        SKind::TypeAlias(s_type_alias),
+        node.byte_range().try_into().unwrap(),
+    )))
+}
+
+fn get_type_alias_of(type_alias: TypeAlias) -> Option<STypeAlias> {
+    Some(STypeAlias {
+        ident: type_alias.find_type_identifier()?.text(),
+        modifiers: class_declaration::get_modifiers_of(type_alias.find_modifiers()),
+        type_parameters: function_declaration::get_type_parameters_of(
+            type_alias.find_type_parameters(),
+        ),
+        target: type_reference::type_of_children(type_alias.node, type_alias.source),
+    })
+}
+
+/// Recreates the type alias `scope_node_id` from its declaration in `tree`
+pub(crate) fn update_type_alias(
+    self_: &mut ScopeBuilder<'_>,
+    scope_node_id: NodeId,
+    tree: &Tree,
+) -> anyhow::Result<()> {
+    let range = self_.s_file.scopes[scope_node_id].get().range;
+    let node = declaration_node_of(tree, range, is_type_alias_node)
+        .ok_or_else(|| anyhow!("Found no type alias at {} to update", range))?;
+    debug!("updating type alias at {}", range);
+
+    let s_type_alias = get_type_alias_of(TypeAlias::new(node, &self_.s_file.text))
+        .ok_or_else(|| anyhow!("Type alias at {} has no name", range))?;
+    let scope = self_.s_file.scopes[scope_node_id].get_mut();
+    scope.kind = SKind::TypeAlias(s_type_alias);
+    scope.range = node.byte_range().try_into().unwrap();
+    Ok(())
+}
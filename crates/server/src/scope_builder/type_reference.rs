@@ -0,0 +1,207 @@
+use crop::Rope;
+use itertools::Itertools;
+use tree_sitter::Node;
+
+use crate::scope::fun_decl_scope::{
+    FunctionType, Parameter, TypeProjection, TypeSegment, Type_, Variance,
+};
+
+/// Whether `node` is a type, e.G. a `user_type` or a `function_type`
+pub(super) fn is_type_node(node: &Node) -> bool {
+    let kind_id = node.kind_id();
+    kind_id == *parser::node::UserTypeId
+        || kind_id == *parser::node::NullableTypeId
+        || kind_id == *parser::node::FunctionTypeId
+        || kind_id == *parser::node::ParenthesizedTypeId
+        || kind_id == *parser::node::NotNullableTypeId
+}
+
+/// The types, which are direct children of `node`, in the order they are written. A `suspend`
+/// modifier written before a function type is part of it
+pub(super) fn types_of_children<'t>(node: Node<'t>, text: &Rope) -> Vec<(Node<'t>, Type_)> {
+    let mut cursor = node.walk();
+    let mut is_suspend = false;
+    let mut types = vec![];
+    for child in node.named_children(&mut cursor) {
+        if child.kind_id() == *parser::node::TypeModifiersId {
+            is_suspend = has_suspend_modifier(&child, text);
+            continue;
+        }
+        if is_type_node(&child) {
+            if let Some(type_) = type_of(child, text) {
+                types.push((child, with_suspend(type_, is_suspend)));
+            }
+        }
+        is_suspend = false;
+    }
+    types
+}
+
+/// The first type, which is a direct child of `node`
+pub(super) fn type_of_children(node: Node, text: &Rope) -> Option<Type_> {
+    types_of_children(node, text)
+        .into_iter()
+        .next()
+        .map(|(_, type_)| type_)
+}
+
+/// The type of the type node `node`
+pub(super) fn type_of(node: Node, text: &Rope) -> Option<Type_> {
+    let kind_id = node.kind_id();
+    if kind_id == *parser::node::UserTypeId {
+        user_type_of(node, text)
+    } else if kind_id == *parser::node::NullableTypeId {
+        type_of_children(node, text).map(|type_| Type_::Nullable(Box::new(type_)))
+    } else if kind_id == *parser::node::FunctionTypeId {
+        function_type_of(node, text)
+    } else if kind_id == *parser::node::ParenthesizedTypeId {
+        type_of_children(node, text)
+    } else if kind_id == *parser::node::NotNullableTypeId {
+        // `T & Any` is kept as written
+        Some(Type_::Simple(
+            parser::text_of(&node, text).split_whitespace().join(" "),
+        ))
+    } else {
+        None
+    }
+}
+
+/// A `user_type` (e.G. `a.b.C<T>`) or the receiver of a function type. Type arguments belong to
+/// the preceding segment
+fn user_type_of(node: Node, text: &Rope) -> Option<Type_> {
+    let mut segments: Vec<TypeSegment> = vec![];
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if child.kind_id() == *parser::node::TypeIdentifierId {
+            segments.push(TypeSegment {
+                name: parser::text_of(&child, text),
+                arguments: vec![],
+            });
+        } else if child.kind_id() == *parser::node::TypeArgumentsId {
+            if let Some(segment) = segments.last_mut() {
+                segment.arguments = type_arguments_of(child, text);
+            }
+        }
+    }
+
+    if segments.is_empty() {
+        None
+    } else if segments.iter().all(|segment| segment.arguments.is_empty()) {
+        Some(Type_::simple(
+            &segments.iter().map(|segment| &segment.name).join("."),
+        ))
+    } else {
+        Some(Type_::Generic(segments))
+    }
+}
+
+fn type_arguments_of(node: Node, text: &Rope) -> Vec<TypeProjection> {
+    let mut cursor = node.walk();
+    let projections = node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind_id() == *parser::node::TypeProjectionId)
+        .filter_map(|projection| {
+            if parser::text_of(&projection, text).trim() == "*" {
+                return Some(TypeProjection::Star);
+            }
+            let mut cursor = projection.walk();
+            let variance = projection
+                .named_children(&mut cursor)
+                .find(|child| child.kind_id() == *parser::node::TypeProjectionModifiersId)
+                .and_then(|modifiers| match parser::text_of(&modifiers, text).trim() {
+                    "in" => Some(Variance::In),
+                    "out" => Some(Variance::Out),
+                    _ => None,
+                });
+            Some(TypeProjection::Type {
+                variance,
+                type_: type_of_children(projection, text)?,
+            })
+        })
+        .collect();
+    projections
+}
+
+fn function_type_of(node: Node, text: &Rope) -> Option<Type_> {
+    let mut cursor = node.walk();
+    let children = node.named_children(&mut cursor).collect::<Vec<_>>();
+    let parameters_position = children
+        .iter()
+        .position(|child| child.kind_id() == *parser::node::FunctionTypeParametersId)?;
+
+    // The receiver is written as the segment of a user type, e.G. `String.() -> Unit`
+    let receiver = if parameters_position > 0 {
+        user_type_of(node, text).map(Box::new)
+    } else {
+        None
+    };
+    let return_type = types_of_children(node, text)
+        .into_iter()
+        .find(|(child, _)| child.start_byte() > children[parameters_position].start_byte())
+        .map(|(_, type_)| type_)?;
+
+    Some(Type_::Function(FunctionType {
+        is_suspend: false,
+        receiver,
+        parameters: function_type_parameters_of(children[parameters_position], text),
+        return_type: Box::new(return_type),
+    }))
+}
+
+fn function_type_parameters_of(node: Node, text: &Rope) -> Vec<Parameter> {
+    let mut parameters = types_of_children(node, text)
+        .into_iter()
+        .map(|(child, type_)| {
+            (
+                child.start_byte(),
+                Parameter {
+                    type_: Some(type_),
+                    ..Default::default()
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut cursor = node.walk();
+    let named_parameters = node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind_id() == *parser::node::ParameterId)
+        .map(|parameter| {
+            let mut cursor = parameter.walk();
+            let ident = parameter
+                .named_children(&mut cursor)
+                .find(|child| child.kind_id() == *parser::node::SimpleIdentifierId)
+                .map(|ident| parser::text_of(&ident, text));
+            (
+                parameter.start_byte(),
+                Parameter {
+                    ident,
+                    type_: type_of_children(parameter, text),
+                    ..Default::default()
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    parameters.extend(named_parameters);
+    parameters.sort_by_key(|(start, _)| *start);
+    parameters
+        .into_iter()
+        .map(|(_, parameter)| parameter)
+        .collect()
+}
+
+fn has_suspend_modifier(type_modifiers: &Node, text: &Rope) -> bool {
+    parser::text_of(type_modifiers, text)
+        .split_whitespace()
+        .any(|modifier| modifier == "suspend")
+}
+
+fn with_suspend(type_: Type_, is_suspend: bool) -> Type_ {
+    match type_ {
+        Type_::Function(function_type) if is_suspend => Type_::Function(FunctionType {
+            is_suspend,
+            ..function_type
+        }),
+        type_ => type_,
+    }
+}
@@ -0,0 +1,343 @@
+//! The index of the kotlin stdlib shipped with kls. Declarations of the default imported
+//! packages are available, even if the project does not configure a stdlib jar.
+//!
+//! The index consists of one stub per package in `crates/server/stdlib`. They are generated from
+//! a kotlin stdlib jar with `just generate-stdlib-index <jar>`.
+
+use std::{collections::HashMap, fmt::Write, path::Path};
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use tower_lsp::lsp_types::Url;
+use tracing::{debug, error};
+use tycheck::Ty;
+
+use crate::{
+    library::{
+        self,
+        kotlin_metadata::{flags, KmClass, KmClassifier, KmType, KmTypeProjection, KmVisibility},
+        stub_parser::{self, KmStub},
+        LibraryDecl, LibraryOrigin, SymbolFactory,
+    },
+    symbol_index::{Symbol, SymbolKind, SymbolOrigin},
+};
+
+pub const KLS_STDLIB_SCHEME: &str = "kls-stdlib";
+
+/// Packages imported into every kotlin file. The last two only on the jvm. See
+/// https://kotlinlang.org/docs/packages.html#default-imports
+pub const DEFAULT_IMPORTS: &[&str] = &[
+    "kotlin",
+    "kotlin.annotation",
+    "kotlin.collections",
+    "kotlin.comparisons",
+    "kotlin.io",
+    "kotlin.ranges",
+    "kotlin.sequences",
+    "kotlin.text",
+    "java.lang",
+    "kotlin.jvm",
+];
+
+/// The stub files by package
+const STUB_FILES: &[(&str, &str)] = &[
+    ("kotlin", include_str!("../stdlib/kotlin.kt")),
+    (
+        "kotlin.annotation",
+        include_str!("../stdlib/kotlin.annotation.kt"),
+    ),
+    (
+        "kotlin.collections",
+        include_str!("../stdlib/kotlin.collections.kt"),
+    ),
+    (
+        "kotlin.comparisons",
+        include_str!("../stdlib/kotlin.comparisons.kt"),
+    ),
+    ("kotlin.io", include_str!("../stdlib/kotlin.io.kt")),
+    ("kotlin.ranges", include_str!("../stdlib/kotlin.ranges.kt")),
+    (
+        "kotlin.sequences",
+        include_str!("../stdlib/kotlin.sequences.kt"),
+    ),
+    ("kotlin.text", include_str!("../stdlib/kotlin.text.kt")),
+    ("kotlin.jvm", include_str!("../stdlib/kotlin.jvm.kt")),
+];
+
+static SYMBOLS: Lazy<Vec<Symbol>> = Lazy::new(|| match load_symbols() {
+    Ok(symbols) => symbols,
+    Err(e) => {
+        error!("Could not load the bundled stdlib: {}", e);
+        vec![]
+    }
+});
+
+/// Returns the symbols of the bundled stdlib. They are parsed once, on first use
+pub fn symbols() -> Vec<Symbol> {
+    SYMBOLS.clone()
+}
+
+/// Returns the uri of the stub declaring the library symbol with the class name `class_name`
+pub fn file_uri(class_name: &str) -> anyhow::Result<Url> {
+    let package = class_name
+        .rsplit_once('/')
+        .map_or(String::new(), |(dir, _)| dir.replace('/', "."));
+    Ok(Url::parse(&format!(
+        "{}:/{}.kt",
+        KLS_STDLIB_SCHEME, package
+    ))?)
+}
+
+/// Returns the content of a `kls-stdlib:` uri
+pub fn file_content(uri: &Url) -> anyhow::Result<&'static str> {
+    let package = uri
+        .path()
+        .strip_prefix('/')
+        .and_then(|path| path.strip_suffix(".kt"))
+        .filter(|_| uri.scheme() == KLS_STDLIB_SCHEME)
+        .ok_or_else(|| anyhow!("Uri {} does not name a stdlib file", uri))?;
+    STUB_FILES
+        .iter()
+        .find(|(p, _)| *p == package)
+        .map(|(_, content)| *content)
+        .ok_or_else(|| anyhow!("No stdlib file for package {}", package))
+}
+
+/// Returns the type checker type of the builtin class `symbol` (e.G. `kotlin.Int`)
+pub fn builtin_ty(symbol: &Symbol) -> Option<Ty> {
+    if symbol.kind != SymbolKind::Class {
+        return None;
+    }
+    Ty::builtin(&symbol.fq_name())
+}
+
+fn load_symbols() -> anyhow::Result<Vec<Symbol>> {
+    let mut stubs = STUB_FILES
+        .iter()
+        .map(|(package, content)| {
+            stub_parser::parse_stub(content)
+                .map_err(|e| anyhow!("Invalid stdlib stub of {}: {}", package, e))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    qualify_class_names(&mut stubs);
+
+    let origin = LibraryOrigin::BundledStdlib;
+    let mut symbols = vec![];
+    for stub in stubs {
+        let package_dir = stub.package.replace('.', "/");
+        for km_class in stub.classes {
+            let factory = SymbolFactory {
+                origin: &origin,
+                class_name: km_class.name.clone(),
+                source_file: None,
+            };
+            symbols.extend(factory.class_symbols(km_class));
+        }
+        let factory = SymbolFactory {
+            origin: &origin,
+            class_name: format!("{}/{}", package_dir, facade_name(&stub.package)),
+            source_file: None,
+        };
+        symbols.extend(factory.package_symbols(&stub.package, stub.package_decls));
+    }
+    debug!("Loaded {} symbols of the bundled stdlib", symbols.len());
+    Ok(symbols)
+}
+
+/// The name of the class declaring top level declarations, e.G. `CollectionsKt`
+fn facade_name(package: &str) -> String {
+    let last = package.rsplit('.').next().unwrap_or(package);
+    let mut chars = last.chars();
+    chars
+        .next()
+        .map(|first| format!("{}{}Kt", first.to_uppercase(), chars.as_str()))
+        .unwrap_or_default()
+}
+
+/// Replaces the class names as written in the stubs by their internal names (e.G. `List` by
+/// `kotlin/collections/List`). Names are looked up in the own package, then in the default
+/// imported packages
+fn qualify_class_names(stubs: &mut [KmStub]) {
+    let mut classes_by_package: HashMap<String, Vec<String>> = HashMap::new();
+    for stub in stubs.iter_mut() {
+        let package_dir = stub.package.replace('.', "/");
+        for class in &mut stub.classes {
+            classes_by_package
+                .entry(stub.package.clone())
+                .or_default()
+                .push(class.name.clone());
+            class.name = format!("{}/{}", package_dir, class.name);
+        }
+    }
+    let qualify = |package: &str, name: &str| -> Option<String> {
+        std::iter::once(package)
+            .chain(DEFAULT_IMPORTS.iter().copied())
+            .find(|p| {
+                classes_by_package
+                    .get(*p)
+                    .is_some_and(|names| names.iter().any(|n| n == name))
+            })
+            .map(|p| format!("{}/{}", p.replace('.', "/"), name))
+    };
+
+    for stub in stubs.iter_mut() {
+        let package = stub.package.clone();
+        let mut qualify_type = |type_: &mut KmType| {
+            visit_types(type_, &mut |t| {
+                if let KmClassifier::Class(name) = &mut t.classifier {
+                    if let Some(qualified) = qualify(&package, name) {
+                        *name = qualified;
+                    }
+                }
+            })
+        };
+        for class in &mut stub.classes {
+            class_types(class).into_iter().for_each(&mut qualify_type);
+        }
+        for function in &mut stub.package_decls.functions {
+            library::function_types(function)
+                .into_iter()
+                .for_each(&mut qualify_type);
+        }
+        for property in &mut stub.package_decls.properties {
+            library::property_types(property)
+                .into_iter()
+                .for_each(&mut qualify_type);
+        }
+    }
+}
+
+fn class_types(class: &mut KmClass) -> Vec<&mut KmType> {
+    let mut types = class
+        .type_parameters
+        .iter_mut()
+        .flat_map(|tp| tp.upper_bounds.iter_mut())
+        .chain(class.supertypes.iter_mut())
+        .collect_vec();
+    for function in &mut class.functions {
+        types.extend(library::function_types(function));
+    }
+    for property in &mut class.properties {
+        types.extend(library::property_types(property));
+    }
+    types
+}
+
+fn visit_types(type_: &mut KmType, f: &mut impl FnMut(&mut KmType)) {
+    f(type_);
+    for argument in &mut type_.arguments {
+        if let KmTypeProjection::Projection { type_, .. } = argument {
+            visit_types(type_, f);
+        }
+    }
+}
+
+/// Generates the stub files of the default imported packages from the stdlib `jar`. Returns the
+/// file names and their contents
+pub fn generate_stubs(jar: &Path) -> anyhow::Result<Vec<(String, String)>> {
+    let symbols = library::index_jar(jar)?;
+    let mut by_container: HashMap<&str, Vec<&Symbol>> = HashMap::new();
+    for symbol in &symbols {
+        by_container
+            .entry(symbol.container.as_str())
+            .or_default()
+            .push(symbol);
+    }
+
+    Ok(STUB_FILES
+        .iter()
+        .map(|(package, _)| {
+            let mut stub = format!(
+                "package {}\n\n// Generated from {} by `just generate-stdlib-index`\n\n",
+                package,
+                jar.file_name().unwrap_or_default().to_string_lossy()
+            );
+            write_container(&mut stub, &by_container, package, 0);
+            (format!("{}.kt", package), stub)
+        })
+        .collect())
+}
+
+/// Writes the declarations of `container`. Classes are written with their members
+fn write_container(
+    stub: &mut String,
+    by_container: &HashMap<&str, Vec<&Symbol>>,
+    container: &str,
+    depth: usize,
+) {
+    let indent = "    ".repeat(depth);
+    let decls = by_container
+        .get(container)
+        .into_iter()
+        .flatten()
+        .filter_map(|symbol| match &symbol.origin {
+            SymbolOrigin::Library(l) => Some((*symbol, &l.decl)),
+            SymbolOrigin::Source { .. } => None,
+        })
+        // Internal declarations (e.G. `@PublishedApi` helpers) are not accessible to users
+        .filter(|(_, decl)| flags::visibility(decl.flags()) != KmVisibility::Internal)
+        .collect_vec();
+
+    // Functions and properties. Class files and builtins might both declare them
+    let members = decls
+        .iter()
+        .filter(|(_, decl)| !matches!(decl, LibraryDecl::Class(_)))
+        .map(|(_, decl)| decl.to_string())
+        .sorted()
+        .dedup();
+    for member in members {
+        let _ = writeln!(stub, "{}{}", indent, member);
+    }
+
+    let classes = decls
+        .iter()
+        .filter_map(|(symbol, decl)| match decl {
+            LibraryDecl::Class(km_class) => Some((*symbol, km_class)),
+            _ => None,
+        })
+        .sorted_by_key(|(symbol, _)| &symbol.name)
+        .dedup_by(|(a, _), (b, _)| a.name == b.name);
+    for (symbol, km_class) in classes {
+        // Nested classes are written by their simple name within the outer class
+        let header = KmClass {
+            name: symbol.name.clone(),
+            functions: vec![],
+            properties: vec![],
+            ..km_class.clone()
+        };
+        let fq_name = symbol.fq_name();
+        if by_container.contains_key(fq_name.as_str()) {
+            let _ = writeln!(stub, "{}{} {{", indent, header);
+            write_container(stub, by_container, &fq_name, depth + 1);
+            let _ = writeln!(stub, "{}}}", indent);
+        } else {
+            let _ = writeln!(stub, "{}{}", indent, header);
+        }
+    }
+}
+
+#[test]
+fn bundled_stdlib_declares_builtins() {
+    let symbols = symbols();
+    assert!(!symbols.is_empty());
+    for builtin in Ty::builtin_names() {
+        assert!(
+            symbols.iter().any(|s| s.fq_name() == builtin),
+            "{} is not declared",
+            builtin
+        );
+    }
+    let list_of = symbols
+        .iter()
+        .find(|s| s.fq_name() == "kotlin.collections.listOf")
+        .unwrap();
+    let SymbolOrigin::Library(l) = &list_of.origin else {
+        panic!()
+    };
+    assert_eq!(l.origin, LibraryOrigin::BundledStdlib);
+    assert_eq!(
+        file_uri(&l.class_name).unwrap().as_str(),
+        "kls-stdlib:/kotlin.collections.kt"
+    );
+}
@@ -0,0 +1,512 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use indextree::NodeId;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use stdx::TextRange;
+use tracing::trace;
+
+use crate::{
+    library::{LibraryOrigin, LibrarySymbol},
+    query::{record_read, Input},
+    scope::{
+        class_scope::ClassKind, fun_decl_scope::Visibility, java_scope::SyntheticAccessor, DeclId,
+        GSFile, SKind,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SymbolKind {
+    Class,
+    Function,
+    Property,
+    /// A `typealias`. References to it resolve through to the aliased type
+    TypeAlias,
+}
+
+/// A declaration, which can be referenced by its fully qualified name
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub name: String,
+    /// Fully qualified name of the package or class declaring the symbol. Empty for the root package
+    pub container: String,
+    pub kind: SymbolKind,
+    /// Declarations of java files are treated as public
+    pub visibility: Visibility,
+    pub origin: SymbolOrigin,
+    /// Generated by the compiler, e.G. `copy()` of data classes. See
+    /// [crate::scope::class_scope::SyntheticMember]
+    pub is_synthetic: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum SymbolOrigin {
+    /// Declared in a file of the project. `range` is the range of `id` when the symbol got
+    /// indexed
+    Source { id: DeclId, range: TextRange },
+    /// Read from a class file of a library. Library symbols are read only
+    Library(Arc<LibrarySymbol>),
+}
+
+impl Symbol {
+    pub fn fq_name(&self) -> String {
+        if self.container.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", self.container, self.name)
+        }
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        matches!(self.origin, SymbolOrigin::Library(_))
+    }
+
+    /// Whether the symbol is part of the stdlib index shipped with kls
+    pub fn is_bundled(&self) -> bool {
+        matches!(&self.origin, SymbolOrigin::Library(l) if l.origin == LibraryOrigin::BundledStdlib)
+    }
+
+    fn is_from_jar(&self) -> bool {
+        matches!(&self.origin, SymbolOrigin::Library(l) if matches!(l.origin, LibraryOrigin::Jar(_)))
+    }
+}
+
+/// Index of all declarations of the project files and libraries. Symbols are grouped by their
+/// container, so that all declarations of a package or members of a class can be looked up.
+/// Clones share their maps, so that a change of a file only copies the parts it touches
+#[derive(Debug, Default, Clone)]
+pub struct SymbolIndex {
+    /// Shared with clones of the index until a container changes
+    by_container: im::HashMap<String, Arc<Vec<Symbol>>>,
+    /// The containers each file contributed symbols to. Used to remove the symbols on re-index
+    containers_of_file: im::HashMap<PathBuf, HashSet<String>>,
+    /// Declared packages with the number of files and libraries declaring them
+    packages: im::OrdMap<String, usize>,
+    package_of_file: im::HashMap<PathBuf, String>,
+    declarations_of_library: im::HashMap<LibraryOrigin, LibraryDeclarations>,
+}
+
+/// The packages and containers a library contributed symbols to
+#[derive(Debug, Clone, Default)]
+struct LibraryDeclarations {
+    packages: HashSet<String>,
+    containers: HashSet<String>,
+}
+
+impl SymbolIndex {
+    /// Adds the symbols of libraries. Symbols of a library, which got indexed before, are
+    /// replaced
+    pub fn add_library_symbols(&mut self, symbols: Vec<Symbol>) {
+        let by_library = symbols
+            .into_iter()
+            .filter_map(|symbol| match &symbol.origin {
+                SymbolOrigin::Library(l) => Some((l.origin.clone(), symbol)),
+                SymbolOrigin::Source { .. } => None,
+            })
+            .into_group_map();
+        for (library, symbols) in by_library {
+            self.remove_library(&library);
+            trace!("Indexing {} symbols of {:?}", symbols.len(), library);
+
+            let mut declarations = LibraryDeclarations::default();
+            for symbol in symbols {
+                if let SymbolOrigin::Library(l) = &symbol.origin {
+                    declarations.packages.insert(
+                        l.class_name
+                            .rsplit_once('/')
+                            .map_or(String::new(), |(dir, _)| dir.replace('/', ".")),
+                    );
+                }
+                declarations.containers.insert(symbol.container.clone());
+                Arc::make_mut(
+                    self.by_container
+                        .entry(symbol.container.clone())
+                        .or_default(),
+                )
+                .push(symbol);
+            }
+            for package in &declarations.packages {
+                *self.packages.entry(package.clone()).or_default() += 1;
+            }
+            self.declarations_of_library.insert(library, declarations);
+        }
+    }
+
+    fn remove_library(&mut self, library: &LibraryOrigin) {
+        let Some(declarations) = self.declarations_of_library.remove(library) else {
+            return;
+        };
+        for package in declarations.packages {
+            if let Some(count) = self.packages.get_mut(&package) {
+                *count -= 1;
+                if *count == 0 {
+                    self.packages.remove(&package);
+                }
+            }
+        }
+        for container in declarations.containers {
+            if let Some(symbols) = self.by_container.get_mut(&container) {
+                Arc::make_mut(symbols).retain(
+                    |s| !matches!(&s.origin, SymbolOrigin::Library(l) if l.origin == *library),
+                );
+            }
+        }
+    }
+
+    /// Replaces all symbols of the file at `path` with `symbols`. `package` is the package
+    /// declared by the file
+    pub fn set_file_symbols(&mut self, path: &Path, package: &str, symbols: Vec<Symbol>) {
+        self.remove_file(path);
+        trace!("Indexing {} symbols of {}", symbols.len(), path.display());
+
+        *self.packages.entry(package.to_string()).or_default() += 1;
+        self.package_of_file
+            .insert(path.to_owned(), package.to_string());
+
+        let containers = self.containers_of_file.entry(path.to_owned()).or_default();
+        for symbol in symbols {
+            containers.insert(symbol.container.clone());
+            Arc::make_mut(
+                self.by_container
+                    .entry(symbol.container.clone())
+                    .or_default(),
+            )
+            .push(symbol);
+        }
+    }
+
+    pub fn remove_file(&mut self, path: &Path) {
+        if let Some(package) = self.package_of_file.remove(path) {
+            if let Some(count) = self.packages.get_mut(&package) {
+                *count -= 1;
+                if *count == 0 {
+                    self.packages.remove(&package);
+                }
+            }
+        }
+        let Some(containers) = self.containers_of_file.remove(path) else {
+            return;
+        };
+        for container in containers {
+            if let Some(symbols) = self.by_container.get_mut(&container) {
+                Arc::make_mut(symbols).retain(
+                    |s| !matches!(&s.origin, SymbolOrigin::Source { id, .. } if id.path == path),
+                );
+            }
+        }
+    }
+
+    /// The package declared by the file at `path`, if it is indexed
+    pub fn package_of_file(&self, path: &Path) -> Option<&str> {
+        self.package_of_file
+            .get(path)
+            .map(|package| package.as_str())
+    }
+
+    /// The declarations the file at `path` contributes to the index, by their package or class
+    pub fn declarations_of_file(&self, path: &Path) -> Vec<Input> {
+        self.package_of_file
+            .get(path)
+            .into_iter()
+            .chain(self.containers_of_file.get(path).into_iter().flatten())
+            .map(|container| Input::Declarations(container.clone()))
+            .collect()
+    }
+
+    /// Whether `package` is declared by a file or library, or is the parent of a declared package
+    pub fn has_package(&self, package: &str) -> bool {
+        record_read(|| Input::Packages);
+        if package.is_empty() {
+            return true;
+        }
+        let sub_package_prefix = format!("{}.", package);
+        self.packages
+            .range(package.to_string()..)
+            .next()
+            .is_some_and(|(p, _)| p == package || p.starts_with(&sub_package_prefix))
+    }
+
+    /// All packages declared by files and libraries. Parent packages are not included
+    pub fn packages(&self) -> impl Iterator<Item = &str> {
+        record_read(|| Input::Packages);
+        self.packages.keys().map(|p| p.as_str())
+    }
+
+    /// All symbols declared directly within the package or class `container`
+    pub fn symbols_in(&self, container: &str) -> &[Symbol] {
+        record_read(|| Input::Declarations(container.to_string()));
+        self.by_container
+            .get(container)
+            .map_or(&[], |symbols| symbols.as_slice())
+    }
+
+    /// All symbols with the fully qualified name `fq_name` (multiple in case of overloads).
+    /// Symbols of the bundled stdlib are shadowed by the ones of a stdlib jar
+    pub fn lookup(&self, fq_name: &str) -> Vec<&Symbol> {
+        let (container, name) = fq_name.rsplit_once('.').unwrap_or(("", fq_name));
+        let mut symbols = self
+            .symbols_in(container)
+            .iter()
+            .filter(|s| s.name == name)
+            .collect::<Vec<_>>();
+        if symbols.iter().any(|s| s.is_from_jar()) {
+            symbols.retain(|s| !s.is_bundled());
+        }
+        symbols
+    }
+}
+
+/// Returns the symbols declared by `s_file`, which are visible to other files
+pub fn file_symbols(s_file: &GSFile) -> Vec<Symbol> {
+    let container = s_file.top_level_container();
+    let mut symbols = vec![];
+    for (root_node, id) in DeclId::file(&s_file.path).children_in(s_file, None) {
+        scope_symbols(s_file, root_node, &id, &container, &mut symbols);
+    }
+    symbols
+}
+
+/// Adds the symbols of the scope `node_id`, identified by `id`, and its members
+fn scope_symbols(
+    s_file: &GSFile,
+    node_id: NodeId,
+    id: &DeclId,
+    container: &str,
+    symbols: &mut Vec<Symbol>,
+) {
+    let scope = s_file.scopes[node_id].get();
+    let symbol = |name: String, kind, visibility| Symbol {
+        name,
+        container: container.to_string(),
+        kind,
+        visibility,
+        origin: SymbolOrigin::Source {
+            id: id.clone(),
+            range: scope.range,
+        },
+        is_synthetic: false,
+    };
+
+    match &scope.kind {
+        SKind::FunDecl(s_fun_decl) => {
+            if let Some(ident) = &s_fun_decl.ident {
+                symbols.push(symbol(
+                    ident.clone(),
+                    SymbolKind::Function,
+                    s_fun_decl.visibility(),
+                ));
+            }
+        }
+        SKind::Property(s_property) => {
+            if let Some(ident) = &s_property.ident {
+                symbols.push(symbol(
+                    ident.clone(),
+                    SymbolKind::Property,
+                    Visibility::of_modifiers(&s_property.modifiers),
+                ));
+            }
+        }
+        SKind::Class(s_class) => {
+            let Some(ident) = &s_class.ident else {
+                return;
+            };
+            symbols.push(symbol(
+                ident.clone(),
+                SymbolKind::Class,
+                Visibility::of_modifiers(&s_class.modifiers),
+            ));
+            let class_fq_name = qualified_name(container, ident);
+            // `val` and `var` parameters of the primary constructor are properties
+            for parameter in &s_class.constructor_parameters {
+                if let (Some(_), Some(ident)) = (parameter.binding, &parameter.parameter.ident) {
+                    symbols.push(Symbol {
+                        container: class_fq_name.clone(),
+                        ..symbol(
+                            ident.clone(),
+                            SymbolKind::Property,
+                            Visibility::of_modifiers(&parameter.modifiers),
+                        )
+                    });
+                }
+            }
+            // Members declared explicitly, e.G. `toString()`, are not generated
+            let declared = node_id
+                .children(&s_file.scopes)
+                .filter_map(|member| match &s_file.scopes[member].get().kind {
+                    SKind::FunDecl(s_fun_decl) => s_fun_decl.ident.clone(),
+                    _ => None,
+                })
+                .collect::<HashSet<_>>();
+            for synthetic in s_class.synthetic_members() {
+                if declared.contains(&synthetic.name) {
+                    continue;
+                }
+                symbols.push(Symbol {
+                    container: class_fq_name.clone(),
+                    is_synthetic: true,
+                    ..symbol(synthetic.name, synthetic.kind, Visibility::Public)
+                });
+            }
+            let members = id.children_in(s_file, Some(node_id));
+            for (member, member_id) in &members {
+                scope_symbols(s_file, *member, member_id, &class_fq_name, symbols);
+            }
+            if s_class.kind == ClassKind::CompanionObject {
+                // Members of companion objects are accessible by the name of the class too
+                for (member, member_id) in &members {
+                    scope_symbols(s_file, *member, member_id, container, symbols);
+                }
+            }
+        }
+        // Members declared in the body of an entry are not accessible from outside
+        SKind::EnumEntry(s_enum_entry) => {
+            symbols.push(symbol(
+                s_enum_entry.ident.clone(),
+                SymbolKind::Property,
+                Visibility::Public,
+            ));
+        }
+        SKind::TypeAlias(s_type_alias) => {
+            symbols.push(symbol(
+                s_type_alias.ident.clone(),
+                SymbolKind::TypeAlias,
+                s_type_alias.visibility(),
+            ));
+        }
+        SKind::JavaClass(s_java_class) => {
+            symbols.push(symbol(
+                s_java_class.ident.clone(),
+                SymbolKind::Class,
+                Visibility::Public,
+            ));
+            let class_fq_name = qualified_name(container, &s_java_class.ident);
+            for (member, member_id) in id.children_in(s_file, Some(node_id)) {
+                scope_symbols(s_file, member, &member_id, &class_fq_name, symbols);
+            }
+        }
+        SKind::JavaMethod(s_java_method) => {
+            if s_java_method.is_constructor {
+                return;
+            }
+            symbols.push(symbol(
+                s_java_method.ident.clone(),
+                SymbolKind::Function,
+                Visibility::Public,
+            ));
+            // Getters are accessible as synthetic property. Setters only make it mutable
+            if let Some((property, SyntheticAccessor::Getter)) = s_java_method.synthetic_property()
+            {
+                symbols.push(symbol(property, SymbolKind::Property, Visibility::Public));
+            }
+        }
+        SKind::JavaField(s_java_field) => {
+            symbols.push(symbol(
+                s_java_field.ident.clone(),
+                SymbolKind::Property,
+                Visibility::Public,
+            ));
+        }
+        // Locals are not accessible outside of their function
+        SKind::PackageHeader { .. }
+        | SKind::Import(_)
+        | SKind::Block(_)
+        | SKind::LocalBinding(_) => {}
+    }
+}
+
+fn qualified_name(container: &str, name: &str) -> String {
+    if container.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", container, name)
+    }
+}
+
+#[test]
+fn parent_packages_are_declared() {
+    let mut index = SymbolIndex::default();
+    index.set_file_symbols(Path::new("/a.kt"), "com.example.app", vec![]);
+
+    assert!(index.has_package("com.example.app"));
+    assert!(index.has_package("com.example"));
+    assert!(index.has_package(""));
+    assert!(!index.has_package("com.exam"));
+    assert!(!index.has_package("com.example.app.ui"));
+
+    index.remove_file(Path::new("/a.kt"));
+    assert!(!index.has_package("com"));
+}
+
+#[test]
+fn snapshots_are_not_changed_by_later_updates() {
+    let path = Path::new("/a.kt");
+    let symbol = |name: &str| Symbol {
+        name: name.to_string(),
+        container: "example".to_string(),
+        kind: SymbolKind::Function,
+        visibility: Visibility::Public,
+        origin: SymbolOrigin::Source {
+            id: DeclId::file(path),
+            range: TextRange::new(0, 1),
+        },
+        is_synthetic: false,
+    };
+    let mut index = SymbolIndex::default();
+    index.set_file_symbols(path, "example", vec![symbol("a")]);
+
+    let snapshot = Arc::new(index.clone());
+    index.set_file_symbols(path, "example", vec![symbol("b")]);
+
+    assert_eq!(snapshot.symbols_in("example")[0].name, "a");
+    assert_eq!(index.symbols_in("example")[0].name, "b");
+}
+
+#[test]
+fn libraries_indexed_twice_replace_their_symbols() {
+    use crate::library::{
+        kotlin_metadata::{KmClassifier, KmFunction, KmType},
+        LibraryDecl,
+    };
+
+    let jar = LibraryOrigin::Jar(PathBuf::from("/a.jar"));
+    let symbols = |names: &[&str]| {
+        names
+            .iter()
+            .map(|name| Symbol {
+                name: name.to_string(),
+                container: "example".to_string(),
+                kind: SymbolKind::Function,
+                visibility: Visibility::Public,
+                origin: SymbolOrigin::Library(Arc::new(LibrarySymbol {
+                    origin: jar.clone(),
+                    class_name: "example/AKt".to_string(),
+                    source_file: None,
+                    decl: LibraryDecl::Function(KmFunction {
+                        name: name.to_string(),
+                        flags: 0,
+                        type_parameters: vec![],
+                        receiver_type: None,
+                        value_parameters: vec![],
+                        return_type: KmType {
+                            classifier: KmClassifier::Class("kotlin/Unit".to_string()),
+                            arguments: vec![],
+                            nullable: false,
+                            flags: 0,
+                        },
+                    }),
+                })),
+                is_synthetic: false,
+            })
+            .collect()
+    };
+    let mut index = SymbolIndex::default();
+    index.add_library_symbols(symbols(&["a", "b"]));
+    index.add_library_symbols(symbols(&["a"]));
+
+    assert_eq!(index.symbols_in("example").len(), 1);
+    assert!(index.has_package("example"));
+}
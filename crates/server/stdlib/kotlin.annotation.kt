@@ -0,0 +1,14 @@
+package kotlin.annotation
+
+// Generated from cloud-pubsub-emulator-0.8.20-all.jar by `just generate-stdlib-index`
+
+enum class AnnotationRetention : Enum<AnnotationRetention>
+enum class AnnotationTarget : Enum<AnnotationTarget>
+annotation class MustBeDocumented : Annotation
+annotation class Repeatable : Annotation
+annotation class Retention : Annotation {
+    val value: AnnotationRetention
+}
+annotation class Target : Annotation {
+    val allowedTargets: Array<out AnnotationTarget>
+}
@@ -0,0 +1,3406 @@
+package kotlin.collections
+
+// Generated from cloud-pubsub-emulator-0.8.20-all.jar by `just generate-stdlib-index`
+
+fun <A : Appendable> BooleanArray.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Boolean, CharSequence>? = ...): A
+fun <A : Appendable> ByteArray.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Byte, CharSequence>? = ...): A
+fun <A : Appendable> CharArray.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Char, CharSequence>? = ...): A
+fun <A : Appendable> DoubleArray.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Double, CharSequence>? = ...): A
+fun <A : Appendable> FloatArray.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Float, CharSequence>? = ...): A
+fun <A : Appendable> IntArray.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Int, CharSequence>? = ...): A
+fun <A : Appendable> LongArray.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Long, CharSequence>? = ...): A
+fun <A : Appendable> ShortArray.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Short, CharSequence>? = ...): A
+fun <C : MutableCollection<in Boolean>> BooleanArray.toCollection(destination: C): C
+fun <C : MutableCollection<in Byte>> ByteArray.toCollection(destination: C): C
+fun <C : MutableCollection<in Char>> CharArray.toCollection(destination: C): C
+fun <C : MutableCollection<in Double>> DoubleArray.toCollection(destination: C): C
+fun <C : MutableCollection<in Float>> FloatArray.toCollection(destination: C): C
+fun <C : MutableCollection<in Int>> IntArray.toCollection(destination: C): C
+fun <C : MutableCollection<in Long>> LongArray.toCollection(destination: C): C
+fun <C : MutableCollection<in R>, R> Array<*>.filterIsInstanceTo(destination: C, klass: Class<R>): C
+fun <C : MutableCollection<in R>, R> Iterable<*>.filterIsInstanceTo(destination: C, klass: Class<R>): C
+fun <C : MutableCollection<in Short>> ShortArray.toCollection(destination: C): C
+fun <C : MutableCollection<in T>, T : Any> Array<out T?>.filterNotNullTo(destination: C): C
+fun <C : MutableCollection<in T>, T : Any> Iterable<T?>.filterNotNullTo(destination: C): C
+fun <K : Comparable<K>, V> Map<out K, V>.toSortedMap(): SortedMap<K, V>
+fun <K : Comparable<K>, V> sortedMapOf(vararg pairs: Pair<K, V>): SortedMap<K, V>
+fun <K, V, M : MutableMap<in K, in V>> Array<out Pair<K, V>>.toMap(destination: M): M
+fun <K, V, M : MutableMap<in K, in V>> Iterable<Pair<K, V>>.toMap(destination: M): M
+fun <K, V, M : MutableMap<in K, in V>> Map<out K, V>.toMap(destination: M): M
+fun <K, V, M : MutableMap<in K, in V>> Sequence<Pair<K, V>>.toMap(destination: M): M
+fun <K, V> Array<out Pair<K, V>>.toMap(): Map<K, V>
+fun <K, V> Iterable<Pair<K, V>>.toMap(): Map<K, V>
+fun <K, V> Map<K, V>.getValue(key: K): V
+fun <K, V> Map<K, V>.withDefault(defaultValue: Function1<K, V>): Map<K, V>
+fun <K, V> Map<out K, V>.any(): Boolean
+fun <K, V> Map<out K, V>.asSequence(): Sequence<Map.Entry<K, V>>
+fun <K, V> Map<out K, V>.minWith(comparator: Comparator<in Map.Entry<K, V>>): Map.Entry<K, V>?
+fun <K, V> Map<out K, V>.none(): Boolean
+fun <K, V> Map<out K, V>.toList(): List<Pair<K, V>>
+fun <K, V> Map<out K, V>.toMap(): Map<K, V>
+fun <K, V> Map<out K, V>.toMutableMap(): MutableMap<K, V>
+fun <K, V> Map<out K, V>.toSortedMap(comparator: Comparator<in K>): SortedMap<K, V>
+fun <K, V> MutableMap<K, V>.withDefault(defaultValue: Function1<K, V>): MutableMap<K, V>
+fun <K, V> MutableMap<in K, in V>.putAll(pairs: Array<out Pair<K, V>>): Unit
+fun <K, V> MutableMap<in K, in V>.putAll(pairs: Iterable<Pair<K, V>>): Unit
+fun <K, V> MutableMap<in K, in V>.putAll(pairs: Sequence<Pair<K, V>>): Unit
+fun <K, V> Sequence<Pair<K, V>>.toMap(): Map<K, V>
+fun <K, V> emptyMap(): Map<K, V>
+fun <K, V> hashMapOf(vararg pairs: Pair<K, V>): HashMap<K, V>
+fun <K, V> linkedMapOf(vararg pairs: Pair<K, V>): LinkedHashMap<K, V>
+fun <K, V> mapOf(pair: Pair<K, V>): Map<K, V>
+fun <K, V> mapOf(vararg pairs: Pair<K, V>): Map<K, V>
+fun <K, V> mutableMapOf(vararg pairs: Pair<K, V>): MutableMap<K, V>
+fun <K, V> sortedMapOf(comparator: Comparator<in K>, vararg pairs: Pair<K, V>): SortedMap<K, V>
+fun <R> Array<*>.filterIsInstance(klass: Class<R>): List<R>
+fun <R> Iterable<*>.filterIsInstance(klass: Class<R>): List<R>
+fun <T : Any> Array<T?>.requireNoNulls(): Array<T>
+fun <T : Any> Array<out T?>.filterNotNull(): List<T>
+fun <T : Any> Iterable<T?>.filterNotNull(): List<T>
+fun <T : Any> Iterable<T?>.requireNoNulls(): Iterable<T>
+fun <T : Any> List<T?>.requireNoNulls(): List<T>
+fun <T : Any> listOfNotNull(element: T?): List<T>
+fun <T : Any> listOfNotNull(vararg elements: T?): List<T>
+fun <T : Any> setOfNotNull(element: T?): Set<T>
+fun <T : Any> setOfNotNull(vararg elements: T?): Set<T>
+fun <T : Comparable<T>> Array<T>.sortedArray(): Array<T>
+fun <T : Comparable<T>> Array<T>.sortedArrayDescending(): Array<T>
+fun <T : Comparable<T>> Array<out T>.max(): T
+fun <T : Comparable<T>> Array<out T>.max(): T?
+fun <T : Comparable<T>> Array<out T>.maxOrNull(): T?
+fun <T : Comparable<T>> Array<out T>.min(): T
+fun <T : Comparable<T>> Array<out T>.min(): T?
+fun <T : Comparable<T>> Array<out T>.minOrNull(): T?
+fun <T : Comparable<T>> Array<out T>.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun <T : Comparable<T>> Array<out T>.sortDescending(): Unit
+fun <T : Comparable<T>> Array<out T>.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun <T : Comparable<T>> Array<out T>.sorted(): List<T>
+fun <T : Comparable<T>> Array<out T>.sortedDescending(): List<T>
+fun <T : Comparable<T>> Array<out T>.toSortedSet(): SortedSet<T>
+fun <T : Comparable<T>> Iterable<T>.max(): T
+fun <T : Comparable<T>> Iterable<T>.max(): T?
+fun <T : Comparable<T>> Iterable<T>.maxOrNull(): T?
+fun <T : Comparable<T>> Iterable<T>.min(): T
+fun <T : Comparable<T>> Iterable<T>.min(): T?
+fun <T : Comparable<T>> Iterable<T>.minOrNull(): T?
+fun <T : Comparable<T>> Iterable<T>.sorted(): List<T>
+fun <T : Comparable<T>> Iterable<T>.sortedDescending(): List<T>
+fun <T : Comparable<T>> Iterable<T>.toSortedSet(): SortedSet<T>
+fun <T : Comparable<T>> List<T?>.binarySearch(element: T?, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun <T : Comparable<T>> MutableList<T>.sort(): Unit
+fun <T : Comparable<T>> MutableList<T>.sortDescending(): Unit
+fun <T, A : Appendable> Array<out T>.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<T, CharSequence>? = ...): A
+fun <T, A : Appendable> Iterable<T>.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<T, CharSequence>? = ...): A
+fun <T, C : MutableCollection<in T>> Array<out T>.toCollection(destination: C): C
+fun <T, C : MutableCollection<in T>> Iterable<T>.toCollection(destination: C): C
+fun <T, K, M : MutableMap<in K, Int>> Grouping<T, K>.eachCountTo(destination: M): M
+fun <T, K> Grouping<T, K>.eachCount(): Map<K, Int>
+fun <T, R> Array<out Pair<T, R>>.unzip(): Pair<List<T>, List<R>>
+fun <T, R> Iterable<Pair<T, R>>.unzip(): Pair<List<T>, List<R>>
+fun <T, R> Iterable<T>.chunked(size: Int, transform: Function1<List<T>, R>): List<R>
+fun <T, R> Iterable<T>.windowed(size: Int, step: Int = ..., partialWindows: Boolean = ..., transform: Function1<List<T>, R>): List<R>
+fun <T> Array<T>.fill(element: T, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun <T> Array<T>.reverse(): Unit
+fun <T> Array<T>.reverse(fromIndex: Int, toIndex: Int): Unit
+fun <T> Array<T>.reversedArray(): Array<T>
+fun <T> Array<T>.shuffle(): Unit
+fun <T> Array<T>.shuffle(random: Random): Unit
+fun <T> Array<T>.sliceArray(indices: Collection<Int>): Array<T>
+fun <T> Array<T>.sliceArray(indices: IntRange): Array<T>
+fun <T> Array<out Array<out T>>.flatten(): List<T>
+fun <T> Array<out T>.any(): Boolean
+fun <T> Array<out T>.asIterable(): Iterable<T>
+fun <T> Array<out T>.asList(): List<T>
+fun <T> Array<out T>.asSequence(): Sequence<T>
+fun <T> Array<out T>.binarySearch(element: T, comparator: Comparator<in T>, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun <T> Array<out T>.binarySearch(element: T, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun <T> Array<out T>.copyInto(destination: Array<T>, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): Array<T>
+fun <T> Array<out T>.distinct(): List<T>
+fun <T> Array<out T>.drop(n: Int): List<T>
+fun <T> Array<out T>.dropLast(n: Int): List<T>
+fun <T> Array<out T>.first(): T
+fun <T> Array<out T>.firstOrNull(): T?
+fun <T> Array<out T>.getOrNull(index: Int): T?
+fun <T> Array<out T>.indexOf(element: T): Int
+fun <T> Array<out T>.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<T, CharSequence>? = ...): String
+fun <T> Array<out T>.last(): T
+fun <T> Array<out T>.lastIndexOf(element: T): Int
+fun <T> Array<out T>.lastOrNull(): T?
+fun <T> Array<out T>.maxWith(comparator: Comparator<in T>): T
+fun <T> Array<out T>.maxWith(comparator: Comparator<in T>): T?
+fun <T> Array<out T>.maxWithOrNull(comparator: Comparator<in T>): T?
+fun <T> Array<out T>.minWith(comparator: Comparator<in T>): T
+fun <T> Array<out T>.minWith(comparator: Comparator<in T>): T?
+fun <T> Array<out T>.minWithOrNull(comparator: Comparator<in T>): T?
+fun <T> Array<out T>.none(): Boolean
+fun <T> Array<out T>.random(random: Random): T
+fun <T> Array<out T>.randomOrNull(random: Random): T?
+fun <T> Array<out T>.reversed(): List<T>
+fun <T> Array<out T>.single(): T
+fun <T> Array<out T>.singleOrNull(): T?
+fun <T> Array<out T>.slice(indices: IntRange): List<T>
+fun <T> Array<out T>.slice(indices: Iterable<Int>): List<T>
+fun <T> Array<out T>.sort(): Unit
+fun <T> Array<out T>.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun <T> Array<out T>.sortWith(comparator: Comparator<in T>): Unit
+fun <T> Array<out T>.sortWith(comparator: Comparator<in T>, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun <T> Array<out T>.sortedArrayWith(comparator: Comparator<in T>): Array<out T>
+fun <T> Array<out T>.sortedWith(comparator: Comparator<in T>): List<T>
+fun <T> Array<out T>.take(n: Int): List<T>
+fun <T> Array<out T>.takeLast(n: Int): List<T>
+fun <T> Array<out T>.toHashSet(): HashSet<T>
+fun <T> Array<out T>.toList(): List<T>
+fun <T> Array<out T>.toMutableList(): MutableList<T>
+fun <T> Array<out T>.toMutableSet(): MutableSet<T>
+fun <T> Array<out T>.toSet(): Set<T>
+fun <T> Array<out T>.toSortedSet(comparator: Comparator<in T>): SortedSet<T>
+fun <T> Array<out T>.withIndex(): Iterable<IndexedValue<T>>
+fun <T> Collection<T>.random(random: Random): T
+fun <T> Collection<T>.randomOrNull(random: Random): T?
+fun <T> Collection<T>.toMutableList(): MutableList<T>
+fun <T> Iterable<Iterable<T>>.flatten(): List<T>
+fun <T> Iterable<T>.any(): Boolean
+fun <T> Iterable<T>.asSequence(): Sequence<T>
+fun <T> Iterable<T>.chunked(size: Int): List<List<T>>
+fun <T> Iterable<T>.count(): Int
+fun <T> Iterable<T>.distinct(): List<T>
+fun <T> Iterable<T>.drop(n: Int): List<T>
+fun <T> Iterable<T>.elementAt(index: Int): T
+fun <T> Iterable<T>.elementAtOrElse(index: Int, defaultValue: Function1<Int, T>): T
+fun <T> Iterable<T>.elementAtOrNull(index: Int): T?
+fun <T> Iterable<T>.first(): T
+fun <T> Iterable<T>.firstOrNull(): T?
+fun <T> Iterable<T>.indexOf(element: T): Int
+fun <T> Iterable<T>.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<T, CharSequence>? = ...): String
+fun <T> Iterable<T>.last(): T
+fun <T> Iterable<T>.lastIndexOf(element: T): Int
+fun <T> Iterable<T>.lastOrNull(): T?
+fun <T> Iterable<T>.maxWith(comparator: Comparator<in T>): T
+fun <T> Iterable<T>.maxWith(comparator: Comparator<in T>): T?
+fun <T> Iterable<T>.maxWithOrNull(comparator: Comparator<in T>): T?
+fun <T> Iterable<T>.minWith(comparator: Comparator<in T>): T
+fun <T> Iterable<T>.minWith(comparator: Comparator<in T>): T?
+fun <T> Iterable<T>.minWithOrNull(comparator: Comparator<in T>): T?
+fun <T> Iterable<T>.none(): Boolean
+fun <T> Iterable<T>.reversed(): List<T>
+fun <T> Iterable<T>.shuffled(): List<T>
+fun <T> Iterable<T>.shuffled(random: Random): List<T>
+fun <T> Iterable<T>.single(): T
+fun <T> Iterable<T>.singleOrNull(): T?
+fun <T> Iterable<T>.sortedWith(comparator: Comparator<in T>): List<T>
+fun <T> Iterable<T>.take(n: Int): List<T>
+fun <T> Iterable<T>.toHashSet(): HashSet<T>
+fun <T> Iterable<T>.toList(): List<T>
+fun <T> Iterable<T>.toMutableList(): MutableList<T>
+fun <T> Iterable<T>.toMutableSet(): MutableSet<T>
+fun <T> Iterable<T>.toSet(): Set<T>
+fun <T> Iterable<T>.toSortedSet(comparator: Comparator<in T>): SortedSet<T>
+fun <T> Iterable<T>.windowed(size: Int, step: Int = ..., partialWindows: Boolean = ...): List<List<T>>
+fun <T> Iterable<T>.withIndex(): Iterable<IndexedValue<T>>
+fun <T> Iterable<T>.zipWithNext(): List<Pair<T, T>>
+fun <T> Iterator<T>.withIndex(): Iterator<IndexedValue<T>>
+fun <T> List<T>.asReversed(): List<T>
+fun <T> List<T>.binarySearch(element: T, comparator: Comparator<in T>, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun <T> List<T>.binarySearch(fromIndex: Int = ..., toIndex: Int = ..., comparison: Function1<T, Int>): Int
+fun <T> List<T>.dropLast(n: Int): List<T>
+fun <T> List<T>.first(): T
+fun <T> List<T>.firstOrNull(): T?
+fun <T> List<T>.getOrNull(index: Int): T?
+fun <T> List<T>.indexOf(element: T): Int
+fun <T> List<T>.last(): T
+fun <T> List<T>.lastIndexOf(element: T): Int
+fun <T> List<T>.lastOrNull(): T?
+fun <T> List<T>.single(): T
+fun <T> List<T>.singleOrNull(): T?
+fun <T> List<T>.slice(indices: IntRange): List<T>
+fun <T> List<T>.slice(indices: Iterable<Int>): List<T>
+fun <T> List<T>.takeLast(n: Int): List<T>
+fun <T> MutableCollection<in T>.addAll(elements: Array<out T>): Boolean
+fun <T> MutableCollection<in T>.addAll(elements: Iterable<T>): Boolean
+fun <T> MutableCollection<in T>.addAll(elements: Sequence<T>): Boolean
+fun <T> MutableCollection<in T>.removeAll(elements: Array<out T>): Boolean
+fun <T> MutableCollection<in T>.removeAll(elements: Iterable<T>): Boolean
+fun <T> MutableCollection<in T>.removeAll(elements: Sequence<T>): Boolean
+fun <T> MutableCollection<in T>.retainAll(elements: Array<out T>): Boolean
+fun <T> MutableCollection<in T>.retainAll(elements: Iterable<T>): Boolean
+fun <T> MutableCollection<in T>.retainAll(elements: Sequence<T>): Boolean
+fun <T> MutableIterable<T>.removeAll(predicate: Function1<T, Boolean>): Boolean
+fun <T> MutableIterable<T>.retainAll(predicate: Function1<T, Boolean>): Boolean
+fun <T> MutableList<T>.asReversed(): MutableList<T>
+fun <T> MutableList<T>.removeAll(predicate: Function1<T, Boolean>): Boolean
+fun <T> MutableList<T>.removeFirst(): T
+fun <T> MutableList<T>.removeFirstOrNull(): T?
+fun <T> MutableList<T>.removeLast(): T
+fun <T> MutableList<T>.removeLastOrNull(): T?
+fun <T> MutableList<T>.retainAll(predicate: Function1<T, Boolean>): Boolean
+fun <T> MutableList<T>.reverse(): Unit
+fun <T> MutableList<T>.shuffle(random: Random): Unit
+fun <T> MutableList<T>.sortWith(comparator: Comparator<in T>): Unit
+fun <T> arrayListOf(vararg elements: T): ArrayList<T>
+fun <T> emptyList(): List<T>
+fun <T> emptySet(): Set<T>
+fun <T> hashSetOf(vararg elements: T): HashSet<T>
+fun <T> linkedSetOf(vararg elements: T): LinkedHashSet<T>
+fun <T> listOf(element: T): List<T>
+fun <T> listOf(vararg elements: T): List<T>
+fun <T> mutableListOf(vararg elements: T): MutableList<T>
+fun <T> mutableSetOf(vararg elements: T): MutableSet<T>
+fun <T> setOf(element: T): Set<T>
+fun <T> setOf(vararg elements: T): Set<T>
+fun <T> sortedSetOf(comparator: Comparator<in T>, vararg elements: T): TreeSet<T>
+fun <T> sortedSetOf(vararg elements: T): TreeSet<T>
+fun Array<out Boolean>.toBooleanArray(): BooleanArray
+fun Array<out Byte>.average(): Double
+fun Array<out Byte>.sum(): Int
+fun Array<out Byte>.toByteArray(): ByteArray
+fun Array<out Char>.toCharArray(): CharArray
+fun Array<out Double>.average(): Double
+fun Array<out Double>.max(): Double
+fun Array<out Double>.max(): Double?
+fun Array<out Double>.maxOrNull(): Double?
+fun Array<out Double>.min(): Double
+fun Array<out Double>.min(): Double?
+fun Array<out Double>.minOrNull(): Double?
+fun Array<out Double>.sum(): Double
+fun Array<out Double>.toDoubleArray(): DoubleArray
+fun Array<out Float>.average(): Double
+fun Array<out Float>.max(): Float
+fun Array<out Float>.max(): Float?
+fun Array<out Float>.maxOrNull(): Float?
+fun Array<out Float>.min(): Float
+fun Array<out Float>.min(): Float?
+fun Array<out Float>.minOrNull(): Float?
+fun Array<out Float>.sum(): Float
+fun Array<out Float>.toFloatArray(): FloatArray
+fun Array<out Int>.average(): Double
+fun Array<out Int>.sum(): Int
+fun Array<out Int>.toIntArray(): IntArray
+fun Array<out Long>.average(): Double
+fun Array<out Long>.sum(): Long
+fun Array<out Long>.toLongArray(): LongArray
+fun Array<out Short>.average(): Double
+fun Array<out Short>.sum(): Int
+fun Array<out Short>.toShortArray(): ShortArray
+fun Array<out UByte>.sum(): UInt
+fun Array<out UByte>.toUByteArray(): UByteArray
+fun Array<out UInt>.sum(): UInt
+fun Array<out UInt>.toUIntArray(): UIntArray
+fun Array<out ULong>.sum(): ULong
+fun Array<out ULong>.toULongArray(): ULongArray
+fun Array<out UShort>.sum(): UInt
+fun Array<out UShort>.toUShortArray(): UShortArray
+fun BooleanArray.any(): Boolean
+fun BooleanArray.asIterable(): Iterable<Boolean>
+fun BooleanArray.asList(): List<Boolean>
+fun BooleanArray.asSequence(): Sequence<Boolean>
+fun BooleanArray.copyInto(destination: BooleanArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): BooleanArray
+fun BooleanArray.distinct(): List<Boolean>
+fun BooleanArray.drop(n: Int): List<Boolean>
+fun BooleanArray.dropLast(n: Int): List<Boolean>
+fun BooleanArray.fill(element: Boolean, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun BooleanArray.first(): Boolean
+fun BooleanArray.firstOrNull(): Boolean?
+fun BooleanArray.getOrNull(index: Int): Boolean?
+fun BooleanArray.indexOf(element: Boolean): Int
+fun BooleanArray.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Boolean, CharSequence>? = ...): String
+fun BooleanArray.last(): Boolean
+fun BooleanArray.lastIndexOf(element: Boolean): Int
+fun BooleanArray.lastOrNull(): Boolean?
+fun BooleanArray.maxWith(comparator: Comparator<in Boolean>): Boolean
+fun BooleanArray.maxWith(comparator: Comparator<in Boolean>): Boolean?
+fun BooleanArray.maxWithOrNull(comparator: Comparator<in Boolean>): Boolean?
+fun BooleanArray.minWith(comparator: Comparator<in Boolean>): Boolean
+fun BooleanArray.minWith(comparator: Comparator<in Boolean>): Boolean?
+fun BooleanArray.minWithOrNull(comparator: Comparator<in Boolean>): Boolean?
+fun BooleanArray.none(): Boolean
+fun BooleanArray.random(random: Random): Boolean
+fun BooleanArray.randomOrNull(random: Random): Boolean?
+fun BooleanArray.reverse(): Unit
+fun BooleanArray.reverse(fromIndex: Int, toIndex: Int): Unit
+fun BooleanArray.reversed(): List<Boolean>
+fun BooleanArray.reversedArray(): BooleanArray
+fun BooleanArray.shuffle(): Unit
+fun BooleanArray.shuffle(random: Random): Unit
+fun BooleanArray.single(): Boolean
+fun BooleanArray.singleOrNull(): Boolean?
+fun BooleanArray.slice(indices: IntRange): List<Boolean>
+fun BooleanArray.slice(indices: Iterable<Int>): List<Boolean>
+fun BooleanArray.sliceArray(indices: Collection<Int>): BooleanArray
+fun BooleanArray.sliceArray(indices: IntRange): BooleanArray
+fun BooleanArray.sortedWith(comparator: Comparator<in Boolean>): List<Boolean>
+fun BooleanArray.take(n: Int): List<Boolean>
+fun BooleanArray.takeLast(n: Int): List<Boolean>
+fun BooleanArray.toHashSet(): HashSet<Boolean>
+fun BooleanArray.toList(): List<Boolean>
+fun BooleanArray.toMutableList(): MutableList<Boolean>
+fun BooleanArray.toMutableSet(): MutableSet<Boolean>
+fun BooleanArray.toSet(): Set<Boolean>
+fun BooleanArray.toSortedSet(): SortedSet<Boolean>
+fun BooleanArray.toTypedArray(): Array<Boolean>
+fun BooleanArray.withIndex(): Iterable<IndexedValue<Boolean>>
+fun ByteArray.any(): Boolean
+fun ByteArray.asIterable(): Iterable<Byte>
+fun ByteArray.asList(): List<Byte>
+fun ByteArray.asSequence(): Sequence<Byte>
+fun ByteArray.average(): Double
+fun ByteArray.binarySearch(element: Byte, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun ByteArray.copyInto(destination: ByteArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): ByteArray
+fun ByteArray.distinct(): List<Byte>
+fun ByteArray.drop(n: Int): List<Byte>
+fun ByteArray.dropLast(n: Int): List<Byte>
+fun ByteArray.fill(element: Byte, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun ByteArray.first(): Byte
+fun ByteArray.firstOrNull(): Byte?
+fun ByteArray.getOrNull(index: Int): Byte?
+fun ByteArray.indexOf(element: Byte): Int
+fun ByteArray.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Byte, CharSequence>? = ...): String
+fun ByteArray.last(): Byte
+fun ByteArray.lastIndexOf(element: Byte): Int
+fun ByteArray.lastOrNull(): Byte?
+fun ByteArray.max(): Byte
+fun ByteArray.max(): Byte?
+fun ByteArray.maxOrNull(): Byte?
+fun ByteArray.maxWith(comparator: Comparator<in Byte>): Byte
+fun ByteArray.maxWith(comparator: Comparator<in Byte>): Byte?
+fun ByteArray.maxWithOrNull(comparator: Comparator<in Byte>): Byte?
+fun ByteArray.min(): Byte
+fun ByteArray.min(): Byte?
+fun ByteArray.minOrNull(): Byte?
+fun ByteArray.minWith(comparator: Comparator<in Byte>): Byte
+fun ByteArray.minWith(comparator: Comparator<in Byte>): Byte?
+fun ByteArray.minWithOrNull(comparator: Comparator<in Byte>): Byte?
+fun ByteArray.none(): Boolean
+fun ByteArray.random(random: Random): Byte
+fun ByteArray.randomOrNull(random: Random): Byte?
+fun ByteArray.reverse(): Unit
+fun ByteArray.reverse(fromIndex: Int, toIndex: Int): Unit
+fun ByteArray.reversed(): List<Byte>
+fun ByteArray.reversedArray(): ByteArray
+fun ByteArray.shuffle(): Unit
+fun ByteArray.shuffle(random: Random): Unit
+fun ByteArray.single(): Byte
+fun ByteArray.singleOrNull(): Byte?
+fun ByteArray.slice(indices: IntRange): List<Byte>
+fun ByteArray.slice(indices: Iterable<Int>): List<Byte>
+fun ByteArray.sliceArray(indices: Collection<Int>): ByteArray
+fun ByteArray.sliceArray(indices: IntRange): ByteArray
+fun ByteArray.sort(): Unit
+fun ByteArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun ByteArray.sortDescending(): Unit
+fun ByteArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun ByteArray.sorted(): List<Byte>
+fun ByteArray.sortedArray(): ByteArray
+fun ByteArray.sortedArrayDescending(): ByteArray
+fun ByteArray.sortedDescending(): List<Byte>
+fun ByteArray.sortedWith(comparator: Comparator<in Byte>): List<Byte>
+fun ByteArray.sum(): Int
+fun ByteArray.take(n: Int): List<Byte>
+fun ByteArray.takeLast(n: Int): List<Byte>
+fun ByteArray.toHashSet(): HashSet<Byte>
+fun ByteArray.toList(): List<Byte>
+fun ByteArray.toMutableList(): MutableList<Byte>
+fun ByteArray.toMutableSet(): MutableSet<Byte>
+fun ByteArray.toSet(): Set<Byte>
+fun ByteArray.toSortedSet(): SortedSet<Byte>
+fun ByteArray.toTypedArray(): Array<Byte>
+fun ByteArray.withIndex(): Iterable<IndexedValue<Byte>>
+fun CharArray.any(): Boolean
+fun CharArray.asIterable(): Iterable<Char>
+fun CharArray.asList(): List<Char>
+fun CharArray.asSequence(): Sequence<Char>
+fun CharArray.binarySearch(element: Char, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun CharArray.copyInto(destination: CharArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): CharArray
+fun CharArray.distinct(): List<Char>
+fun CharArray.drop(n: Int): List<Char>
+fun CharArray.dropLast(n: Int): List<Char>
+fun CharArray.fill(element: Char, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun CharArray.first(): Char
+fun CharArray.firstOrNull(): Char?
+fun CharArray.getOrNull(index: Int): Char?
+fun CharArray.indexOf(element: Char): Int
+fun CharArray.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Char, CharSequence>? = ...): String
+fun CharArray.last(): Char
+fun CharArray.lastIndexOf(element: Char): Int
+fun CharArray.lastOrNull(): Char?
+fun CharArray.max(): Char
+fun CharArray.max(): Char?
+fun CharArray.maxOrNull(): Char?
+fun CharArray.maxWith(comparator: Comparator<in Char>): Char
+fun CharArray.maxWith(comparator: Comparator<in Char>): Char?
+fun CharArray.maxWithOrNull(comparator: Comparator<in Char>): Char?
+fun CharArray.min(): Char
+fun CharArray.min(): Char?
+fun CharArray.minOrNull(): Char?
+fun CharArray.minWith(comparator: Comparator<in Char>): Char
+fun CharArray.minWith(comparator: Comparator<in Char>): Char?
+fun CharArray.minWithOrNull(comparator: Comparator<in Char>): Char?
+fun CharArray.none(): Boolean
+fun CharArray.random(random: Random): Char
+fun CharArray.randomOrNull(random: Random): Char?
+fun CharArray.reverse(): Unit
+fun CharArray.reverse(fromIndex: Int, toIndex: Int): Unit
+fun CharArray.reversed(): List<Char>
+fun CharArray.reversedArray(): CharArray
+fun CharArray.shuffle(): Unit
+fun CharArray.shuffle(random: Random): Unit
+fun CharArray.single(): Char
+fun CharArray.singleOrNull(): Char?
+fun CharArray.slice(indices: IntRange): List<Char>
+fun CharArray.slice(indices: Iterable<Int>): List<Char>
+fun CharArray.sliceArray(indices: Collection<Int>): CharArray
+fun CharArray.sliceArray(indices: IntRange): CharArray
+fun CharArray.sort(): Unit
+fun CharArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun CharArray.sortDescending(): Unit
+fun CharArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun CharArray.sorted(): List<Char>
+fun CharArray.sortedArray(): CharArray
+fun CharArray.sortedArrayDescending(): CharArray
+fun CharArray.sortedDescending(): List<Char>
+fun CharArray.sortedWith(comparator: Comparator<in Char>): List<Char>
+fun CharArray.take(n: Int): List<Char>
+fun CharArray.takeLast(n: Int): List<Char>
+fun CharArray.toHashSet(): HashSet<Char>
+fun CharArray.toList(): List<Char>
+fun CharArray.toMutableList(): MutableList<Char>
+fun CharArray.toMutableSet(): MutableSet<Char>
+fun CharArray.toSet(): Set<Char>
+fun CharArray.toSortedSet(): SortedSet<Char>
+fun CharArray.toTypedArray(): Array<Char>
+fun CharArray.withIndex(): Iterable<IndexedValue<Char>>
+fun Collection<Boolean>.toBooleanArray(): BooleanArray
+fun Collection<Byte>.toByteArray(): ByteArray
+fun Collection<Char>.toCharArray(): CharArray
+fun Collection<Double>.toDoubleArray(): DoubleArray
+fun Collection<Float>.toFloatArray(): FloatArray
+fun Collection<Int>.toIntArray(): IntArray
+fun Collection<Long>.toLongArray(): LongArray
+fun Collection<Short>.toShortArray(): ShortArray
+fun Collection<UByte>.toUByteArray(): UByteArray
+fun Collection<UInt>.toUIntArray(): UIntArray
+fun Collection<ULong>.toULongArray(): ULongArray
+fun Collection<UShort>.toUShortArray(): UShortArray
+fun DoubleArray.any(): Boolean
+fun DoubleArray.asIterable(): Iterable<Double>
+fun DoubleArray.asList(): List<Double>
+fun DoubleArray.asSequence(): Sequence<Double>
+fun DoubleArray.average(): Double
+fun DoubleArray.binarySearch(element: Double, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun DoubleArray.copyInto(destination: DoubleArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): DoubleArray
+fun DoubleArray.distinct(): List<Double>
+fun DoubleArray.drop(n: Int): List<Double>
+fun DoubleArray.dropLast(n: Int): List<Double>
+fun DoubleArray.fill(element: Double, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun DoubleArray.first(): Double
+fun DoubleArray.firstOrNull(): Double?
+fun DoubleArray.getOrNull(index: Int): Double?
+fun DoubleArray.indexOf(element: Double): Int
+fun DoubleArray.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Double, CharSequence>? = ...): String
+fun DoubleArray.last(): Double
+fun DoubleArray.lastIndexOf(element: Double): Int
+fun DoubleArray.lastOrNull(): Double?
+fun DoubleArray.max(): Double
+fun DoubleArray.max(): Double?
+fun DoubleArray.maxOrNull(): Double?
+fun DoubleArray.maxWith(comparator: Comparator<in Double>): Double
+fun DoubleArray.maxWith(comparator: Comparator<in Double>): Double?
+fun DoubleArray.maxWithOrNull(comparator: Comparator<in Double>): Double?
+fun DoubleArray.min(): Double
+fun DoubleArray.min(): Double?
+fun DoubleArray.minOrNull(): Double?
+fun DoubleArray.minWith(comparator: Comparator<in Double>): Double
+fun DoubleArray.minWith(comparator: Comparator<in Double>): Double?
+fun DoubleArray.minWithOrNull(comparator: Comparator<in Double>): Double?
+fun DoubleArray.none(): Boolean
+fun DoubleArray.random(random: Random): Double
+fun DoubleArray.randomOrNull(random: Random): Double?
+fun DoubleArray.reverse(): Unit
+fun DoubleArray.reverse(fromIndex: Int, toIndex: Int): Unit
+fun DoubleArray.reversed(): List<Double>
+fun DoubleArray.reversedArray(): DoubleArray
+fun DoubleArray.shuffle(): Unit
+fun DoubleArray.shuffle(random: Random): Unit
+fun DoubleArray.single(): Double
+fun DoubleArray.singleOrNull(): Double?
+fun DoubleArray.slice(indices: IntRange): List<Double>
+fun DoubleArray.slice(indices: Iterable<Int>): List<Double>
+fun DoubleArray.sliceArray(indices: Collection<Int>): DoubleArray
+fun DoubleArray.sliceArray(indices: IntRange): DoubleArray
+fun DoubleArray.sort(): Unit
+fun DoubleArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun DoubleArray.sortDescending(): Unit
+fun DoubleArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun DoubleArray.sorted(): List<Double>
+fun DoubleArray.sortedArray(): DoubleArray
+fun DoubleArray.sortedArrayDescending(): DoubleArray
+fun DoubleArray.sortedDescending(): List<Double>
+fun DoubleArray.sortedWith(comparator: Comparator<in Double>): List<Double>
+fun DoubleArray.sum(): Double
+fun DoubleArray.take(n: Int): List<Double>
+fun DoubleArray.takeLast(n: Int): List<Double>
+fun DoubleArray.toHashSet(): HashSet<Double>
+fun DoubleArray.toList(): List<Double>
+fun DoubleArray.toMutableList(): MutableList<Double>
+fun DoubleArray.toMutableSet(): MutableSet<Double>
+fun DoubleArray.toSet(): Set<Double>
+fun DoubleArray.toSortedSet(): SortedSet<Double>
+fun DoubleArray.toTypedArray(): Array<Double>
+fun DoubleArray.withIndex(): Iterable<IndexedValue<Double>>
+fun FloatArray.any(): Boolean
+fun FloatArray.asIterable(): Iterable<Float>
+fun FloatArray.asList(): List<Float>
+fun FloatArray.asSequence(): Sequence<Float>
+fun FloatArray.average(): Double
+fun FloatArray.binarySearch(element: Float, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun FloatArray.copyInto(destination: FloatArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): FloatArray
+fun FloatArray.distinct(): List<Float>
+fun FloatArray.drop(n: Int): List<Float>
+fun FloatArray.dropLast(n: Int): List<Float>
+fun FloatArray.fill(element: Float, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun FloatArray.first(): Float
+fun FloatArray.firstOrNull(): Float?
+fun FloatArray.getOrNull(index: Int): Float?
+fun FloatArray.indexOf(element: Float): Int
+fun FloatArray.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Float, CharSequence>? = ...): String
+fun FloatArray.last(): Float
+fun FloatArray.lastIndexOf(element: Float): Int
+fun FloatArray.lastOrNull(): Float?
+fun FloatArray.max(): Float
+fun FloatArray.max(): Float?
+fun FloatArray.maxOrNull(): Float?
+fun FloatArray.maxWith(comparator: Comparator<in Float>): Float
+fun FloatArray.maxWith(comparator: Comparator<in Float>): Float?
+fun FloatArray.maxWithOrNull(comparator: Comparator<in Float>): Float?
+fun FloatArray.min(): Float
+fun FloatArray.min(): Float?
+fun FloatArray.minOrNull(): Float?
+fun FloatArray.minWith(comparator: Comparator<in Float>): Float
+fun FloatArray.minWith(comparator: Comparator<in Float>): Float?
+fun FloatArray.minWithOrNull(comparator: Comparator<in Float>): Float?
+fun FloatArray.none(): Boolean
+fun FloatArray.random(random: Random): Float
+fun FloatArray.randomOrNull(random: Random): Float?
+fun FloatArray.reverse(): Unit
+fun FloatArray.reverse(fromIndex: Int, toIndex: Int): Unit
+fun FloatArray.reversed(): List<Float>
+fun FloatArray.reversedArray(): FloatArray
+fun FloatArray.shuffle(): Unit
+fun FloatArray.shuffle(random: Random): Unit
+fun FloatArray.single(): Float
+fun FloatArray.singleOrNull(): Float?
+fun FloatArray.slice(indices: IntRange): List<Float>
+fun FloatArray.slice(indices: Iterable<Int>): List<Float>
+fun FloatArray.sliceArray(indices: Collection<Int>): FloatArray
+fun FloatArray.sliceArray(indices: IntRange): FloatArray
+fun FloatArray.sort(): Unit
+fun FloatArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun FloatArray.sortDescending(): Unit
+fun FloatArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun FloatArray.sorted(): List<Float>
+fun FloatArray.sortedArray(): FloatArray
+fun FloatArray.sortedArrayDescending(): FloatArray
+fun FloatArray.sortedDescending(): List<Float>
+fun FloatArray.sortedWith(comparator: Comparator<in Float>): List<Float>
+fun FloatArray.sum(): Float
+fun FloatArray.take(n: Int): List<Float>
+fun FloatArray.takeLast(n: Int): List<Float>
+fun FloatArray.toHashSet(): HashSet<Float>
+fun FloatArray.toList(): List<Float>
+fun FloatArray.toMutableList(): MutableList<Float>
+fun FloatArray.toMutableSet(): MutableSet<Float>
+fun FloatArray.toSet(): Set<Float>
+fun FloatArray.toSortedSet(): SortedSet<Float>
+fun FloatArray.toTypedArray(): Array<Float>
+fun FloatArray.withIndex(): Iterable<IndexedValue<Float>>
+fun IntArray.any(): Boolean
+fun IntArray.asIterable(): Iterable<Int>
+fun IntArray.asList(): List<Int>
+fun IntArray.asSequence(): Sequence<Int>
+fun IntArray.average(): Double
+fun IntArray.binarySearch(element: Int, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun IntArray.copyInto(destination: IntArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): IntArray
+fun IntArray.distinct(): List<Int>
+fun IntArray.drop(n: Int): List<Int>
+fun IntArray.dropLast(n: Int): List<Int>
+fun IntArray.fill(element: Int, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun IntArray.first(): Int
+fun IntArray.firstOrNull(): Int?
+fun IntArray.getOrNull(index: Int): Int?
+fun IntArray.indexOf(element: Int): Int
+fun IntArray.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Int, CharSequence>? = ...): String
+fun IntArray.last(): Int
+fun IntArray.lastIndexOf(element: Int): Int
+fun IntArray.lastOrNull(): Int?
+fun IntArray.max(): Int
+fun IntArray.max(): Int?
+fun IntArray.maxOrNull(): Int?
+fun IntArray.maxWith(comparator: Comparator<in Int>): Int
+fun IntArray.maxWith(comparator: Comparator<in Int>): Int?
+fun IntArray.maxWithOrNull(comparator: Comparator<in Int>): Int?
+fun IntArray.min(): Int
+fun IntArray.min(): Int?
+fun IntArray.minOrNull(): Int?
+fun IntArray.minWith(comparator: Comparator<in Int>): Int
+fun IntArray.minWith(comparator: Comparator<in Int>): Int?
+fun IntArray.minWithOrNull(comparator: Comparator<in Int>): Int?
+fun IntArray.none(): Boolean
+fun IntArray.random(random: Random): Int
+fun IntArray.randomOrNull(random: Random): Int?
+fun IntArray.reverse(): Unit
+fun IntArray.reverse(fromIndex: Int, toIndex: Int): Unit
+fun IntArray.reversed(): List<Int>
+fun IntArray.reversedArray(): IntArray
+fun IntArray.shuffle(): Unit
+fun IntArray.shuffle(random: Random): Unit
+fun IntArray.single(): Int
+fun IntArray.singleOrNull(): Int?
+fun IntArray.slice(indices: IntRange): List<Int>
+fun IntArray.slice(indices: Iterable<Int>): List<Int>
+fun IntArray.sliceArray(indices: Collection<Int>): IntArray
+fun IntArray.sliceArray(indices: IntRange): IntArray
+fun IntArray.sort(): Unit
+fun IntArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun IntArray.sortDescending(): Unit
+fun IntArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun IntArray.sorted(): List<Int>
+fun IntArray.sortedArray(): IntArray
+fun IntArray.sortedArrayDescending(): IntArray
+fun IntArray.sortedDescending(): List<Int>
+fun IntArray.sortedWith(comparator: Comparator<in Int>): List<Int>
+fun IntArray.sum(): Int
+fun IntArray.take(n: Int): List<Int>
+fun IntArray.takeLast(n: Int): List<Int>
+fun IntArray.toHashSet(): HashSet<Int>
+fun IntArray.toList(): List<Int>
+fun IntArray.toMutableList(): MutableList<Int>
+fun IntArray.toMutableSet(): MutableSet<Int>
+fun IntArray.toSet(): Set<Int>
+fun IntArray.toSortedSet(): SortedSet<Int>
+fun IntArray.toTypedArray(): Array<Int>
+fun IntArray.withIndex(): Iterable<IndexedValue<Int>>
+fun Iterable<Byte>.average(): Double
+fun Iterable<Byte>.sum(): Int
+fun Iterable<Double>.average(): Double
+fun Iterable<Double>.max(): Double
+fun Iterable<Double>.max(): Double?
+fun Iterable<Double>.maxOrNull(): Double?
+fun Iterable<Double>.min(): Double
+fun Iterable<Double>.min(): Double?
+fun Iterable<Double>.minOrNull(): Double?
+fun Iterable<Double>.sum(): Double
+fun Iterable<Float>.average(): Double
+fun Iterable<Float>.max(): Float
+fun Iterable<Float>.max(): Float?
+fun Iterable<Float>.maxOrNull(): Float?
+fun Iterable<Float>.min(): Float
+fun Iterable<Float>.min(): Float?
+fun Iterable<Float>.minOrNull(): Float?
+fun Iterable<Float>.sum(): Float
+fun Iterable<Int>.average(): Double
+fun Iterable<Int>.sum(): Int
+fun Iterable<Long>.average(): Double
+fun Iterable<Long>.sum(): Long
+fun Iterable<Short>.average(): Double
+fun Iterable<Short>.sum(): Int
+fun Iterable<UByte>.sum(): UInt
+fun Iterable<UInt>.sum(): UInt
+fun Iterable<ULong>.sum(): ULong
+fun Iterable<UShort>.sum(): UInt
+fun LongArray.any(): Boolean
+fun LongArray.asIterable(): Iterable<Long>
+fun LongArray.asList(): List<Long>
+fun LongArray.asSequence(): Sequence<Long>
+fun LongArray.average(): Double
+fun LongArray.binarySearch(element: Long, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun LongArray.copyInto(destination: LongArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): LongArray
+fun LongArray.distinct(): List<Long>
+fun LongArray.drop(n: Int): List<Long>
+fun LongArray.dropLast(n: Int): List<Long>
+fun LongArray.fill(element: Long, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun LongArray.first(): Long
+fun LongArray.firstOrNull(): Long?
+fun LongArray.getOrNull(index: Int): Long?
+fun LongArray.indexOf(element: Long): Int
+fun LongArray.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Long, CharSequence>? = ...): String
+fun LongArray.last(): Long
+fun LongArray.lastIndexOf(element: Long): Int
+fun LongArray.lastOrNull(): Long?
+fun LongArray.max(): Long
+fun LongArray.max(): Long?
+fun LongArray.maxOrNull(): Long?
+fun LongArray.maxWith(comparator: Comparator<in Long>): Long
+fun LongArray.maxWith(comparator: Comparator<in Long>): Long?
+fun LongArray.maxWithOrNull(comparator: Comparator<in Long>): Long?
+fun LongArray.min(): Long
+fun LongArray.min(): Long?
+fun LongArray.minOrNull(): Long?
+fun LongArray.minWith(comparator: Comparator<in Long>): Long
+fun LongArray.minWith(comparator: Comparator<in Long>): Long?
+fun LongArray.minWithOrNull(comparator: Comparator<in Long>): Long?
+fun LongArray.none(): Boolean
+fun LongArray.random(random: Random): Long
+fun LongArray.randomOrNull(random: Random): Long?
+fun LongArray.reverse(): Unit
+fun LongArray.reverse(fromIndex: Int, toIndex: Int): Unit
+fun LongArray.reversed(): List<Long>
+fun LongArray.reversedArray(): LongArray
+fun LongArray.shuffle(): Unit
+fun LongArray.shuffle(random: Random): Unit
+fun LongArray.single(): Long
+fun LongArray.singleOrNull(): Long?
+fun LongArray.slice(indices: IntRange): List<Long>
+fun LongArray.slice(indices: Iterable<Int>): List<Long>
+fun LongArray.sliceArray(indices: Collection<Int>): LongArray
+fun LongArray.sliceArray(indices: IntRange): LongArray
+fun LongArray.sort(): Unit
+fun LongArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun LongArray.sortDescending(): Unit
+fun LongArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun LongArray.sorted(): List<Long>
+fun LongArray.sortedArray(): LongArray
+fun LongArray.sortedArrayDescending(): LongArray
+fun LongArray.sortedDescending(): List<Long>
+fun LongArray.sortedWith(comparator: Comparator<in Long>): List<Long>
+fun LongArray.sum(): Long
+fun LongArray.take(n: Int): List<Long>
+fun LongArray.takeLast(n: Int): List<Long>
+fun LongArray.toHashSet(): HashSet<Long>
+fun LongArray.toList(): List<Long>
+fun LongArray.toMutableList(): MutableList<Long>
+fun LongArray.toMutableSet(): MutableSet<Long>
+fun LongArray.toSet(): Set<Long>
+fun LongArray.toSortedSet(): SortedSet<Long>
+fun LongArray.toTypedArray(): Array<Long>
+fun LongArray.withIndex(): Iterable<IndexedValue<Long>>
+fun ShortArray.any(): Boolean
+fun ShortArray.asIterable(): Iterable<Short>
+fun ShortArray.asList(): List<Short>
+fun ShortArray.asSequence(): Sequence<Short>
+fun ShortArray.average(): Double
+fun ShortArray.binarySearch(element: Short, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun ShortArray.copyInto(destination: ShortArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): ShortArray
+fun ShortArray.distinct(): List<Short>
+fun ShortArray.drop(n: Int): List<Short>
+fun ShortArray.dropLast(n: Int): List<Short>
+fun ShortArray.fill(element: Short, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun ShortArray.first(): Short
+fun ShortArray.firstOrNull(): Short?
+fun ShortArray.getOrNull(index: Int): Short?
+fun ShortArray.indexOf(element: Short): Int
+fun ShortArray.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<Short, CharSequence>? = ...): String
+fun ShortArray.last(): Short
+fun ShortArray.lastIndexOf(element: Short): Int
+fun ShortArray.lastOrNull(): Short?
+fun ShortArray.max(): Short
+fun ShortArray.max(): Short?
+fun ShortArray.maxOrNull(): Short?
+fun ShortArray.maxWith(comparator: Comparator<in Short>): Short
+fun ShortArray.maxWith(comparator: Comparator<in Short>): Short?
+fun ShortArray.maxWithOrNull(comparator: Comparator<in Short>): Short?
+fun ShortArray.min(): Short
+fun ShortArray.min(): Short?
+fun ShortArray.minOrNull(): Short?
+fun ShortArray.minWith(comparator: Comparator<in Short>): Short
+fun ShortArray.minWith(comparator: Comparator<in Short>): Short?
+fun ShortArray.minWithOrNull(comparator: Comparator<in Short>): Short?
+fun ShortArray.none(): Boolean
+fun ShortArray.random(random: Random): Short
+fun ShortArray.randomOrNull(random: Random): Short?
+fun ShortArray.reverse(): Unit
+fun ShortArray.reverse(fromIndex: Int, toIndex: Int): Unit
+fun ShortArray.reversed(): List<Short>
+fun ShortArray.reversedArray(): ShortArray
+fun ShortArray.shuffle(): Unit
+fun ShortArray.shuffle(random: Random): Unit
+fun ShortArray.single(): Short
+fun ShortArray.singleOrNull(): Short?
+fun ShortArray.slice(indices: IntRange): List<Short>
+fun ShortArray.slice(indices: Iterable<Int>): List<Short>
+fun ShortArray.sliceArray(indices: Collection<Int>): ShortArray
+fun ShortArray.sliceArray(indices: IntRange): ShortArray
+fun ShortArray.sort(): Unit
+fun ShortArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun ShortArray.sortDescending(): Unit
+fun ShortArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun ShortArray.sorted(): List<Short>
+fun ShortArray.sortedArray(): ShortArray
+fun ShortArray.sortedArrayDescending(): ShortArray
+fun ShortArray.sortedDescending(): List<Short>
+fun ShortArray.sortedWith(comparator: Comparator<in Short>): List<Short>
+fun ShortArray.sum(): Int
+fun ShortArray.take(n: Int): List<Short>
+fun ShortArray.takeLast(n: Int): List<Short>
+fun ShortArray.toHashSet(): HashSet<Short>
+fun ShortArray.toList(): List<Short>
+fun ShortArray.toMutableList(): MutableList<Short>
+fun ShortArray.toMutableSet(): MutableSet<Short>
+fun ShortArray.toSet(): Set<Short>
+fun ShortArray.toSortedSet(): SortedSet<Short>
+fun ShortArray.toTypedArray(): Array<Short>
+fun ShortArray.withIndex(): Iterable<IndexedValue<Short>>
+fun UByteArray.asList(): List<UByte>
+fun UByteArray.binarySearch(element: UByte, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun UByteArray.contentHashCode(): Int
+fun UByteArray.contentToString(): String
+fun UByteArray.drop(n: Int): List<UByte>
+fun UByteArray.dropLast(n: Int): List<UByte>
+fun UByteArray.fill(element: UByte, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun UByteArray.firstOrNull(): UByte?
+fun UByteArray.getOrNull(index: Int): UByte?
+fun UByteArray.lastOrNull(): UByte?
+fun UByteArray.max(): UByte
+fun UByteArray.max(): UByte?
+fun UByteArray.maxOrNull(): UByte?
+fun UByteArray.maxWith(comparator: Comparator<in UByte>): UByte
+fun UByteArray.maxWith(comparator: Comparator<in UByte>): UByte?
+fun UByteArray.maxWithOrNull(comparator: Comparator<in UByte>): UByte?
+fun UByteArray.min(): UByte
+fun UByteArray.min(): UByte?
+fun UByteArray.minOrNull(): UByte?
+fun UByteArray.minWith(comparator: Comparator<in UByte>): UByte
+fun UByteArray.minWith(comparator: Comparator<in UByte>): UByte?
+fun UByteArray.minWithOrNull(comparator: Comparator<in UByte>): UByte?
+fun UByteArray.random(random: Random): UByte
+fun UByteArray.randomOrNull(random: Random): UByte?
+fun UByteArray.reversed(): List<UByte>
+fun UByteArray.shuffle(): Unit
+fun UByteArray.shuffle(random: Random): Unit
+fun UByteArray.singleOrNull(): UByte?
+fun UByteArray.slice(indices: IntRange): List<UByte>
+fun UByteArray.slice(indices: Iterable<Int>): List<UByte>
+fun UByteArray.sliceArray(indices: Collection<Int>): UByteArray
+fun UByteArray.sliceArray(indices: IntRange): UByteArray
+fun UByteArray.sort(): Unit
+fun UByteArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun UByteArray.sortDescending(): Unit
+fun UByteArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun UByteArray.sorted(): List<UByte>
+fun UByteArray.sortedArray(): UByteArray
+fun UByteArray.sortedArrayDescending(): UByteArray
+fun UByteArray.sortedDescending(): List<UByte>
+fun UByteArray.take(n: Int): List<UByte>
+fun UByteArray.takeLast(n: Int): List<UByte>
+fun UByteArray.toTypedArray(): Array<UByte>
+fun UByteArray.withIndex(): Iterable<IndexedValue<UByte>>
+fun UByteArray?.contentHashCode(): Int
+fun UByteArray?.contentToString(): String
+fun UIntArray.asList(): List<UInt>
+fun UIntArray.binarySearch(element: UInt, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun UIntArray.contentHashCode(): Int
+fun UIntArray.contentToString(): String
+fun UIntArray.drop(n: Int): List<UInt>
+fun UIntArray.dropLast(n: Int): List<UInt>
+fun UIntArray.fill(element: UInt, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun UIntArray.firstOrNull(): UInt?
+fun UIntArray.getOrNull(index: Int): UInt?
+fun UIntArray.lastOrNull(): UInt?
+fun UIntArray.max(): UInt
+fun UIntArray.max(): UInt?
+fun UIntArray.maxOrNull(): UInt?
+fun UIntArray.maxWith(comparator: Comparator<in UInt>): UInt
+fun UIntArray.maxWith(comparator: Comparator<in UInt>): UInt?
+fun UIntArray.maxWithOrNull(comparator: Comparator<in UInt>): UInt?
+fun UIntArray.min(): UInt
+fun UIntArray.min(): UInt?
+fun UIntArray.minOrNull(): UInt?
+fun UIntArray.minWith(comparator: Comparator<in UInt>): UInt
+fun UIntArray.minWith(comparator: Comparator<in UInt>): UInt?
+fun UIntArray.minWithOrNull(comparator: Comparator<in UInt>): UInt?
+fun UIntArray.random(random: Random): UInt
+fun UIntArray.randomOrNull(random: Random): UInt?
+fun UIntArray.reversed(): List<UInt>
+fun UIntArray.shuffle(): Unit
+fun UIntArray.shuffle(random: Random): Unit
+fun UIntArray.singleOrNull(): UInt?
+fun UIntArray.slice(indices: IntRange): List<UInt>
+fun UIntArray.slice(indices: Iterable<Int>): List<UInt>
+fun UIntArray.sliceArray(indices: Collection<Int>): UIntArray
+fun UIntArray.sliceArray(indices: IntRange): UIntArray
+fun UIntArray.sort(): Unit
+fun UIntArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun UIntArray.sortDescending(): Unit
+fun UIntArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun UIntArray.sorted(): List<UInt>
+fun UIntArray.sortedArray(): UIntArray
+fun UIntArray.sortedArrayDescending(): UIntArray
+fun UIntArray.sortedDescending(): List<UInt>
+fun UIntArray.take(n: Int): List<UInt>
+fun UIntArray.takeLast(n: Int): List<UInt>
+fun UIntArray.toTypedArray(): Array<UInt>
+fun UIntArray.withIndex(): Iterable<IndexedValue<UInt>>
+fun UIntArray?.contentHashCode(): Int
+fun UIntArray?.contentToString(): String
+fun ULongArray.asList(): List<ULong>
+fun ULongArray.binarySearch(element: ULong, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun ULongArray.contentHashCode(): Int
+fun ULongArray.contentToString(): String
+fun ULongArray.drop(n: Int): List<ULong>
+fun ULongArray.dropLast(n: Int): List<ULong>
+fun ULongArray.fill(element: ULong, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun ULongArray.firstOrNull(): ULong?
+fun ULongArray.getOrNull(index: Int): ULong?
+fun ULongArray.lastOrNull(): ULong?
+fun ULongArray.max(): ULong
+fun ULongArray.max(): ULong?
+fun ULongArray.maxOrNull(): ULong?
+fun ULongArray.maxWith(comparator: Comparator<in ULong>): ULong
+fun ULongArray.maxWith(comparator: Comparator<in ULong>): ULong?
+fun ULongArray.maxWithOrNull(comparator: Comparator<in ULong>): ULong?
+fun ULongArray.min(): ULong
+fun ULongArray.min(): ULong?
+fun ULongArray.minOrNull(): ULong?
+fun ULongArray.minWith(comparator: Comparator<in ULong>): ULong
+fun ULongArray.minWith(comparator: Comparator<in ULong>): ULong?
+fun ULongArray.minWithOrNull(comparator: Comparator<in ULong>): ULong?
+fun ULongArray.random(random: Random): ULong
+fun ULongArray.randomOrNull(random: Random): ULong?
+fun ULongArray.reversed(): List<ULong>
+fun ULongArray.shuffle(): Unit
+fun ULongArray.shuffle(random: Random): Unit
+fun ULongArray.singleOrNull(): ULong?
+fun ULongArray.slice(indices: IntRange): List<ULong>
+fun ULongArray.slice(indices: Iterable<Int>): List<ULong>
+fun ULongArray.sliceArray(indices: Collection<Int>): ULongArray
+fun ULongArray.sliceArray(indices: IntRange): ULongArray
+fun ULongArray.sort(): Unit
+fun ULongArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun ULongArray.sortDescending(): Unit
+fun ULongArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun ULongArray.sorted(): List<ULong>
+fun ULongArray.sortedArray(): ULongArray
+fun ULongArray.sortedArrayDescending(): ULongArray
+fun ULongArray.sortedDescending(): List<ULong>
+fun ULongArray.take(n: Int): List<ULong>
+fun ULongArray.takeLast(n: Int): List<ULong>
+fun ULongArray.toTypedArray(): Array<ULong>
+fun ULongArray.withIndex(): Iterable<IndexedValue<ULong>>
+fun ULongArray?.contentHashCode(): Int
+fun ULongArray?.contentToString(): String
+fun UShortArray.asList(): List<UShort>
+fun UShortArray.binarySearch(element: UShort, fromIndex: Int = ..., toIndex: Int = ...): Int
+fun UShortArray.contentHashCode(): Int
+fun UShortArray.contentToString(): String
+fun UShortArray.drop(n: Int): List<UShort>
+fun UShortArray.dropLast(n: Int): List<UShort>
+fun UShortArray.fill(element: UShort, fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun UShortArray.firstOrNull(): UShort?
+fun UShortArray.getOrNull(index: Int): UShort?
+fun UShortArray.lastOrNull(): UShort?
+fun UShortArray.max(): UShort
+fun UShortArray.max(): UShort?
+fun UShortArray.maxOrNull(): UShort?
+fun UShortArray.maxWith(comparator: Comparator<in UShort>): UShort
+fun UShortArray.maxWith(comparator: Comparator<in UShort>): UShort?
+fun UShortArray.maxWithOrNull(comparator: Comparator<in UShort>): UShort?
+fun UShortArray.min(): UShort
+fun UShortArray.min(): UShort?
+fun UShortArray.minOrNull(): UShort?
+fun UShortArray.minWith(comparator: Comparator<in UShort>): UShort
+fun UShortArray.minWith(comparator: Comparator<in UShort>): UShort?
+fun UShortArray.minWithOrNull(comparator: Comparator<in UShort>): UShort?
+fun UShortArray.random(random: Random): UShort
+fun UShortArray.randomOrNull(random: Random): UShort?
+fun UShortArray.reversed(): List<UShort>
+fun UShortArray.shuffle(): Unit
+fun UShortArray.shuffle(random: Random): Unit
+fun UShortArray.singleOrNull(): UShort?
+fun UShortArray.slice(indices: IntRange): List<UShort>
+fun UShortArray.slice(indices: Iterable<Int>): List<UShort>
+fun UShortArray.sliceArray(indices: Collection<Int>): UShortArray
+fun UShortArray.sliceArray(indices: IntRange): UShortArray
+fun UShortArray.sort(): Unit
+fun UShortArray.sort(fromIndex: Int = ..., toIndex: Int = ...): Unit
+fun UShortArray.sortDescending(): Unit
+fun UShortArray.sortDescending(fromIndex: Int, toIndex: Int): Unit
+fun UShortArray.sorted(): List<UShort>
+fun UShortArray.sortedArray(): UShortArray
+fun UShortArray.sortedArrayDescending(): UShortArray
+fun UShortArray.sortedDescending(): List<UShort>
+fun UShortArray.take(n: Int): List<UShort>
+fun UShortArray.takeLast(n: Int): List<UShort>
+fun UShortArray.toTypedArray(): Array<UShort>
+fun UShortArray.withIndex(): Iterable<IndexedValue<UShort>>
+fun UShortArray?.contentHashCode(): Int
+fun UShortArray?.contentToString(): String
+infix fun <R> BooleanArray.zip(other: Array<out R>): List<Pair<Boolean, R>>
+infix fun <R> BooleanArray.zip(other: Iterable<R>): List<Pair<Boolean, R>>
+infix fun <R> ByteArray.zip(other: Array<out R>): List<Pair<Byte, R>>
+infix fun <R> ByteArray.zip(other: Iterable<R>): List<Pair<Byte, R>>
+infix fun <R> CharArray.zip(other: Array<out R>): List<Pair<Char, R>>
+infix fun <R> CharArray.zip(other: Iterable<R>): List<Pair<Char, R>>
+infix fun <R> DoubleArray.zip(other: Array<out R>): List<Pair<Double, R>>
+infix fun <R> DoubleArray.zip(other: Iterable<R>): List<Pair<Double, R>>
+infix fun <R> FloatArray.zip(other: Array<out R>): List<Pair<Float, R>>
+infix fun <R> FloatArray.zip(other: Iterable<R>): List<Pair<Float, R>>
+infix fun <R> IntArray.zip(other: Array<out R>): List<Pair<Int, R>>
+infix fun <R> IntArray.zip(other: Iterable<R>): List<Pair<Int, R>>
+infix fun <R> LongArray.zip(other: Array<out R>): List<Pair<Long, R>>
+infix fun <R> LongArray.zip(other: Iterable<R>): List<Pair<Long, R>>
+infix fun <R> ShortArray.zip(other: Array<out R>): List<Pair<Short, R>>
+infix fun <R> ShortArray.zip(other: Iterable<R>): List<Pair<Short, R>>
+infix fun <R> UByteArray.zip(other: Array<out R>): List<Pair<UByte, R>>
+infix fun <R> UByteArray.zip(other: Iterable<R>): List<Pair<UByte, R>>
+infix fun <R> UIntArray.zip(other: Array<out R>): List<Pair<UInt, R>>
+infix fun <R> UIntArray.zip(other: Iterable<R>): List<Pair<UInt, R>>
+infix fun <R> ULongArray.zip(other: Array<out R>): List<Pair<ULong, R>>
+infix fun <R> ULongArray.zip(other: Iterable<R>): List<Pair<ULong, R>>
+infix fun <R> UShortArray.zip(other: Array<out R>): List<Pair<UShort, R>>
+infix fun <R> UShortArray.zip(other: Iterable<R>): List<Pair<UShort, R>>
+infix fun <T, R> Array<out T>.zip(other: Array<out R>): List<Pair<T, R>>
+infix fun <T, R> Array<out T>.zip(other: Iterable<R>): List<Pair<T, R>>
+infix fun <T, R> Iterable<T>.zip(other: Array<out R>): List<Pair<T, R>>
+infix fun <T, R> Iterable<T>.zip(other: Iterable<R>): List<Pair<T, R>>
+infix fun <T> Array<out T>.intersect(other: Iterable<T>): Set<T>
+infix fun <T> Array<out T>.subtract(other: Iterable<T>): Set<T>
+infix fun <T> Array<out T>.union(other: Iterable<T>): Set<T>
+infix fun <T> Iterable<T>.intersect(other: Iterable<T>): Set<T>
+infix fun <T> Iterable<T>.subtract(other: Iterable<T>): Set<T>
+infix fun <T> Iterable<T>.union(other: Iterable<T>): Set<T>
+infix fun BooleanArray.intersect(other: Iterable<Boolean>): Set<Boolean>
+infix fun BooleanArray.subtract(other: Iterable<Boolean>): Set<Boolean>
+infix fun BooleanArray.union(other: Iterable<Boolean>): Set<Boolean>
+infix fun BooleanArray.zip(other: BooleanArray): List<Pair<Boolean, Boolean>>
+infix fun ByteArray.intersect(other: Iterable<Byte>): Set<Byte>
+infix fun ByteArray.subtract(other: Iterable<Byte>): Set<Byte>
+infix fun ByteArray.union(other: Iterable<Byte>): Set<Byte>
+infix fun ByteArray.zip(other: ByteArray): List<Pair<Byte, Byte>>
+infix fun CharArray.intersect(other: Iterable<Char>): Set<Char>
+infix fun CharArray.subtract(other: Iterable<Char>): Set<Char>
+infix fun CharArray.union(other: Iterable<Char>): Set<Char>
+infix fun CharArray.zip(other: CharArray): List<Pair<Char, Char>>
+infix fun DoubleArray.intersect(other: Iterable<Double>): Set<Double>
+infix fun DoubleArray.subtract(other: Iterable<Double>): Set<Double>
+infix fun DoubleArray.union(other: Iterable<Double>): Set<Double>
+infix fun DoubleArray.zip(other: DoubleArray): List<Pair<Double, Double>>
+infix fun FloatArray.intersect(other: Iterable<Float>): Set<Float>
+infix fun FloatArray.subtract(other: Iterable<Float>): Set<Float>
+infix fun FloatArray.union(other: Iterable<Float>): Set<Float>
+infix fun FloatArray.zip(other: FloatArray): List<Pair<Float, Float>>
+infix fun IntArray.intersect(other: Iterable<Int>): Set<Int>
+infix fun IntArray.subtract(other: Iterable<Int>): Set<Int>
+infix fun IntArray.union(other: Iterable<Int>): Set<Int>
+infix fun IntArray.zip(other: IntArray): List<Pair<Int, Int>>
+infix fun LongArray.intersect(other: Iterable<Long>): Set<Long>
+infix fun LongArray.subtract(other: Iterable<Long>): Set<Long>
+infix fun LongArray.union(other: Iterable<Long>): Set<Long>
+infix fun LongArray.zip(other: LongArray): List<Pair<Long, Long>>
+infix fun ShortArray.intersect(other: Iterable<Short>): Set<Short>
+infix fun ShortArray.subtract(other: Iterable<Short>): Set<Short>
+infix fun ShortArray.union(other: Iterable<Short>): Set<Short>
+infix fun ShortArray.zip(other: ShortArray): List<Pair<Short, Short>>
+infix fun UByteArray.contentEquals(other: UByteArray): Boolean
+infix fun UByteArray.zip(other: UByteArray): List<Pair<UByte, UByte>>
+infix fun UByteArray?.contentEquals(other: UByteArray?): Boolean
+infix fun UIntArray.contentEquals(other: UIntArray): Boolean
+infix fun UIntArray.zip(other: UIntArray): List<Pair<UInt, UInt>>
+infix fun UIntArray?.contentEquals(other: UIntArray?): Boolean
+infix fun ULongArray.contentEquals(other: ULongArray): Boolean
+infix fun ULongArray.zip(other: ULongArray): List<Pair<ULong, ULong>>
+infix fun ULongArray?.contentEquals(other: ULongArray?): Boolean
+infix fun UShortArray.contentEquals(other: UShortArray): Boolean
+infix fun UShortArray.zip(other: UShortArray): List<Pair<UShort, UShort>>
+infix fun UShortArray?.contentEquals(other: UShortArray?): Boolean
+inline fun <C : MutableCollection<in Boolean>> BooleanArray.filterIndexedTo(destination: C, predicate: Function2<Int, Boolean, Boolean>): C
+inline fun <C : MutableCollection<in Boolean>> BooleanArray.filterNotTo(destination: C, predicate: Function1<Boolean, Boolean>): C
+inline fun <C : MutableCollection<in Boolean>> BooleanArray.filterTo(destination: C, predicate: Function1<Boolean, Boolean>): C
+inline fun <C : MutableCollection<in Byte>> ByteArray.filterIndexedTo(destination: C, predicate: Function2<Int, Byte, Boolean>): C
+inline fun <C : MutableCollection<in Byte>> ByteArray.filterNotTo(destination: C, predicate: Function1<Byte, Boolean>): C
+inline fun <C : MutableCollection<in Byte>> ByteArray.filterTo(destination: C, predicate: Function1<Byte, Boolean>): C
+inline fun <C : MutableCollection<in Char>> CharArray.filterIndexedTo(destination: C, predicate: Function2<Int, Char, Boolean>): C
+inline fun <C : MutableCollection<in Char>> CharArray.filterNotTo(destination: C, predicate: Function1<Char, Boolean>): C
+inline fun <C : MutableCollection<in Char>> CharArray.filterTo(destination: C, predicate: Function1<Char, Boolean>): C
+inline fun <C : MutableCollection<in Double>> DoubleArray.filterIndexedTo(destination: C, predicate: Function2<Int, Double, Boolean>): C
+inline fun <C : MutableCollection<in Double>> DoubleArray.filterNotTo(destination: C, predicate: Function1<Double, Boolean>): C
+inline fun <C : MutableCollection<in Double>> DoubleArray.filterTo(destination: C, predicate: Function1<Double, Boolean>): C
+inline fun <C : MutableCollection<in Float>> FloatArray.filterIndexedTo(destination: C, predicate: Function2<Int, Float, Boolean>): C
+inline fun <C : MutableCollection<in Float>> FloatArray.filterNotTo(destination: C, predicate: Function1<Float, Boolean>): C
+inline fun <C : MutableCollection<in Float>> FloatArray.filterTo(destination: C, predicate: Function1<Float, Boolean>): C
+inline fun <C : MutableCollection<in Int>> IntArray.filterIndexedTo(destination: C, predicate: Function2<Int, Int, Boolean>): C
+inline fun <C : MutableCollection<in Int>> IntArray.filterNotTo(destination: C, predicate: Function1<Int, Boolean>): C
+inline fun <C : MutableCollection<in Int>> IntArray.filterTo(destination: C, predicate: Function1<Int, Boolean>): C
+inline fun <C : MutableCollection<in Long>> LongArray.filterIndexedTo(destination: C, predicate: Function2<Int, Long, Boolean>): C
+inline fun <C : MutableCollection<in Long>> LongArray.filterNotTo(destination: C, predicate: Function1<Long, Boolean>): C
+inline fun <C : MutableCollection<in Long>> LongArray.filterTo(destination: C, predicate: Function1<Long, Boolean>): C
+inline fun <C : MutableCollection<in Short>> ShortArray.filterIndexedTo(destination: C, predicate: Function2<Int, Short, Boolean>): C
+inline fun <C : MutableCollection<in Short>> ShortArray.filterNotTo(destination: C, predicate: Function1<Short, Boolean>): C
+inline fun <C : MutableCollection<in Short>> ShortArray.filterTo(destination: C, predicate: Function1<Short, Boolean>): C
+inline fun <C : MutableCollection<in UByte>> UByteArray.filterIndexedTo(destination: C, predicate: Function2<Int, UByte, Boolean>): C
+inline fun <C : MutableCollection<in UByte>> UByteArray.filterNotTo(destination: C, predicate: Function1<UByte, Boolean>): C
+inline fun <C : MutableCollection<in UByte>> UByteArray.filterTo(destination: C, predicate: Function1<UByte, Boolean>): C
+inline fun <C : MutableCollection<in UInt>> UIntArray.filterIndexedTo(destination: C, predicate: Function2<Int, UInt, Boolean>): C
+inline fun <C : MutableCollection<in UInt>> UIntArray.filterNotTo(destination: C, predicate: Function1<UInt, Boolean>): C
+inline fun <C : MutableCollection<in UInt>> UIntArray.filterTo(destination: C, predicate: Function1<UInt, Boolean>): C
+inline fun <C : MutableCollection<in ULong>> ULongArray.filterIndexedTo(destination: C, predicate: Function2<Int, ULong, Boolean>): C
+inline fun <C : MutableCollection<in ULong>> ULongArray.filterNotTo(destination: C, predicate: Function1<ULong, Boolean>): C
+inline fun <C : MutableCollection<in ULong>> ULongArray.filterTo(destination: C, predicate: Function1<ULong, Boolean>): C
+inline fun <C : MutableCollection<in UShort>> UShortArray.filterIndexedTo(destination: C, predicate: Function2<Int, UShort, Boolean>): C
+inline fun <C : MutableCollection<in UShort>> UShortArray.filterNotTo(destination: C, predicate: Function1<UShort, Boolean>): C
+inline fun <C : MutableCollection<in UShort>> UShortArray.filterTo(destination: C, predicate: Function1<UShort, Boolean>): C
+inline fun <C, R> C.ifEmpty(defaultValue: Function0<R>): R
+inline fun <E> buildList(builderAction: Function1<MutableList<E>, Unit>): List<E>
+inline fun <E> buildList(capacity: Int, builderAction: Function1<MutableList<E>, Unit>): List<E>
+inline fun <E> buildSet(builderAction: Function1<MutableSet<E>, Unit>): Set<E>
+inline fun <E> buildSet(capacity: Int, builderAction: Function1<MutableSet<E>, Unit>): Set<E>
+inline fun <K, M : MutableMap<in K, MutableList<Boolean>>> BooleanArray.groupByTo(destination: M, keySelector: Function1<Boolean, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<Byte>>> ByteArray.groupByTo(destination: M, keySelector: Function1<Byte, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<Char>>> CharArray.groupByTo(destination: M, keySelector: Function1<Char, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<Double>>> DoubleArray.groupByTo(destination: M, keySelector: Function1<Double, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<Float>>> FloatArray.groupByTo(destination: M, keySelector: Function1<Float, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<Int>>> IntArray.groupByTo(destination: M, keySelector: Function1<Int, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<Long>>> LongArray.groupByTo(destination: M, keySelector: Function1<Long, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<Short>>> ShortArray.groupByTo(destination: M, keySelector: Function1<Short, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<UByte>>> UByteArray.groupByTo(destination: M, keySelector: Function1<UByte, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<UInt>>> UIntArray.groupByTo(destination: M, keySelector: Function1<UInt, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<ULong>>> ULongArray.groupByTo(destination: M, keySelector: Function1<ULong, K>): M
+inline fun <K, M : MutableMap<in K, MutableList<UShort>>> UShortArray.groupByTo(destination: M, keySelector: Function1<UShort, K>): M
+inline fun <K, M : MutableMap<in K, in Boolean>> BooleanArray.associateByTo(destination: M, keySelector: Function1<Boolean, K>): M
+inline fun <K, M : MutableMap<in K, in Byte>> ByteArray.associateByTo(destination: M, keySelector: Function1<Byte, K>): M
+inline fun <K, M : MutableMap<in K, in Char>> CharArray.associateByTo(destination: M, keySelector: Function1<Char, K>): M
+inline fun <K, M : MutableMap<in K, in Double>> DoubleArray.associateByTo(destination: M, keySelector: Function1<Double, K>): M
+inline fun <K, M : MutableMap<in K, in Float>> FloatArray.associateByTo(destination: M, keySelector: Function1<Float, K>): M
+inline fun <K, M : MutableMap<in K, in Int>> IntArray.associateByTo(destination: M, keySelector: Function1<Int, K>): M
+inline fun <K, M : MutableMap<in K, in Long>> LongArray.associateByTo(destination: M, keySelector: Function1<Long, K>): M
+inline fun <K, M : MutableMap<in K, in Short>> ShortArray.associateByTo(destination: M, keySelector: Function1<Short, K>): M
+inline fun <K, V, M : Map<out K, V>> M.onEach(action: Function1<Map.Entry<K, V>, Unit>): M
+inline fun <K, V, M : Map<out K, V>> M.onEachIndexed(action: Function2<Int, Map.Entry<K, V>, Unit>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> BooleanArray.groupByTo(destination: M, keySelector: Function1<Boolean, K>, valueTransform: Function1<Boolean, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> ByteArray.groupByTo(destination: M, keySelector: Function1<Byte, K>, valueTransform: Function1<Byte, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> CharArray.groupByTo(destination: M, keySelector: Function1<Char, K>, valueTransform: Function1<Char, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> DoubleArray.groupByTo(destination: M, keySelector: Function1<Double, K>, valueTransform: Function1<Double, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> FloatArray.groupByTo(destination: M, keySelector: Function1<Float, K>, valueTransform: Function1<Float, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> IntArray.groupByTo(destination: M, keySelector: Function1<Int, K>, valueTransform: Function1<Int, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> LongArray.groupByTo(destination: M, keySelector: Function1<Long, K>, valueTransform: Function1<Long, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> ShortArray.groupByTo(destination: M, keySelector: Function1<Short, K>, valueTransform: Function1<Short, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> UByteArray.groupByTo(destination: M, keySelector: Function1<UByte, K>, valueTransform: Function1<UByte, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> UIntArray.groupByTo(destination: M, keySelector: Function1<UInt, K>, valueTransform: Function1<UInt, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> ULongArray.groupByTo(destination: M, keySelector: Function1<ULong, K>, valueTransform: Function1<ULong, V>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> UShortArray.groupByTo(destination: M, keySelector: Function1<UShort, K>, valueTransform: Function1<UShort, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> Array<out K>.associateWithTo(destination: M, valueSelector: Function1<K, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> BooleanArray.associateByTo(destination: M, keySelector: Function1<Boolean, K>, valueTransform: Function1<Boolean, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> BooleanArray.associateTo(destination: M, transform: Function1<Boolean, Pair<K, V>>): M
+inline fun <K, V, M : MutableMap<in K, in V>> ByteArray.associateByTo(destination: M, keySelector: Function1<Byte, K>, valueTransform: Function1<Byte, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> ByteArray.associateTo(destination: M, transform: Function1<Byte, Pair<K, V>>): M
+inline fun <K, V, M : MutableMap<in K, in V>> CharArray.associateByTo(destination: M, keySelector: Function1<Char, K>, valueTransform: Function1<Char, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> CharArray.associateTo(destination: M, transform: Function1<Char, Pair<K, V>>): M
+inline fun <K, V, M : MutableMap<in K, in V>> DoubleArray.associateByTo(destination: M, keySelector: Function1<Double, K>, valueTransform: Function1<Double, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> DoubleArray.associateTo(destination: M, transform: Function1<Double, Pair<K, V>>): M
+inline fun <K, V, M : MutableMap<in K, in V>> FloatArray.associateByTo(destination: M, keySelector: Function1<Float, K>, valueTransform: Function1<Float, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> FloatArray.associateTo(destination: M, transform: Function1<Float, Pair<K, V>>): M
+inline fun <K, V, M : MutableMap<in K, in V>> IntArray.associateByTo(destination: M, keySelector: Function1<Int, K>, valueTransform: Function1<Int, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> IntArray.associateTo(destination: M, transform: Function1<Int, Pair<K, V>>): M
+inline fun <K, V, M : MutableMap<in K, in V>> Iterable<K>.associateWithTo(destination: M, valueSelector: Function1<K, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> LongArray.associateByTo(destination: M, keySelector: Function1<Long, K>, valueTransform: Function1<Long, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> LongArray.associateTo(destination: M, transform: Function1<Long, Pair<K, V>>): M
+inline fun <K, V, M : MutableMap<in K, in V>> Map<out K, V>.filterNotTo(destination: M, predicate: Function1<Map.Entry<K, V>, Boolean>): M
+inline fun <K, V, M : MutableMap<in K, in V>> Map<out K, V>.filterTo(destination: M, predicate: Function1<Map.Entry<K, V>, Boolean>): M
+inline fun <K, V, M : MutableMap<in K, in V>> ShortArray.associateByTo(destination: M, keySelector: Function1<Short, K>, valueTransform: Function1<Short, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> ShortArray.associateTo(destination: M, transform: Function1<Short, Pair<K, V>>): M
+inline fun <K, V, R : Any, C : MutableCollection<in R>> Map<out K, V>.mapNotNullTo(destination: C, transform: Function1<Map.Entry<K, V>, R?>): C
+inline fun <K, V, R : Any> Map<out K, V>.firstNotNullOf(transform: Function1<Map.Entry<K, V>, R?>): R
+inline fun <K, V, R : Any> Map<out K, V>.firstNotNullOfOrNull(transform: Function1<Map.Entry<K, V>, R?>): R?
+inline fun <K, V, R : Any> Map<out K, V>.mapNotNull(transform: Function1<Map.Entry<K, V>, R?>): List<R>
+inline fun <K, V, R : Comparable<R>> Map<out K, V>.maxBy(selector: Function1<Map.Entry<K, V>, R>): Map.Entry<K, V>
+inline fun <K, V, R : Comparable<R>> Map<out K, V>.maxBy(selector: Function1<Map.Entry<K, V>, R>): Map.Entry<K, V>?
+inline fun <K, V, R : Comparable<R>> Map<out K, V>.maxByOrNull(selector: Function1<Map.Entry<K, V>, R>): Map.Entry<K, V>?
+inline fun <K, V, R : Comparable<R>> Map<out K, V>.maxOf(selector: Function1<Map.Entry<K, V>, R>): R
+inline fun <K, V, R : Comparable<R>> Map<out K, V>.maxOfOrNull(selector: Function1<Map.Entry<K, V>, R>): R?
+inline fun <K, V, R : Comparable<R>> Map<out K, V>.minBy(selector: Function1<Map.Entry<K, V>, R>): Map.Entry<K, V>
+inline fun <K, V, R : Comparable<R>> Map<out K, V>.minBy(selector: Function1<Map.Entry<K, V>, R>): Map.Entry<K, V>?
+inline fun <K, V, R : Comparable<R>> Map<out K, V>.minByOrNull(selector: Function1<Map.Entry<K, V>, R>): Map.Entry<K, V>?
+inline fun <K, V, R : Comparable<R>> Map<out K, V>.minOf(selector: Function1<Map.Entry<K, V>, R>): R
+inline fun <K, V, R : Comparable<R>> Map<out K, V>.minOfOrNull(selector: Function1<Map.Entry<K, V>, R>): R?
+inline fun <K, V, R, C : MutableCollection<in R>> Map<out K, V>.flatMapTo(destination: C, transform: Function1<Map.Entry<K, V>, Iterable<R>>): C
+inline fun <K, V, R, C : MutableCollection<in R>> Map<out K, V>.flatMapTo(destination: C, transform: Function1<Map.Entry<K, V>, Sequence<R>>): C
+inline fun <K, V, R, C : MutableCollection<in R>> Map<out K, V>.mapTo(destination: C, transform: Function1<Map.Entry<K, V>, R>): C
+inline fun <K, V, R, M : MutableMap<in K, in R>> Map<out K, V>.mapValuesTo(destination: M, transform: Function1<Map.Entry<K, V>, R>): M
+inline fun <K, V, R, M : MutableMap<in R, in V>> Map<out K, V>.mapKeysTo(destination: M, transform: Function1<Map.Entry<K, V>, R>): M
+inline fun <K, V, R> Map<out K, V>.flatMap(transform: Function1<Map.Entry<K, V>, Iterable<R>>): List<R>
+inline fun <K, V, R> Map<out K, V>.flatMap(transform: Function1<Map.Entry<K, V>, Sequence<R>>): List<R>
+inline fun <K, V, R> Map<out K, V>.map(transform: Function1<Map.Entry<K, V>, R>): List<R>
+inline fun <K, V, R> Map<out K, V>.mapKeys(transform: Function1<Map.Entry<K, V>, R>): Map<R, V>
+inline fun <K, V, R> Map<out K, V>.mapValues(transform: Function1<Map.Entry<K, V>, R>): Map<K, R>
+inline fun <K, V, R> Map<out K, V>.maxOfWith(comparator: Comparator<in R>, selector: Function1<Map.Entry<K, V>, R>): R
+inline fun <K, V, R> Map<out K, V>.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Map.Entry<K, V>, R>): R?
+inline fun <K, V, R> Map<out K, V>.minOfWith(comparator: Comparator<in R>, selector: Function1<Map.Entry<K, V>, R>): R
+inline fun <K, V, R> Map<out K, V>.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Map.Entry<K, V>, R>): R?
+inline fun <K, V> Array<out K>.associateWith(valueSelector: Function1<K, V>): Map<K, V>
+inline fun <K, V> BooleanArray.associate(transform: Function1<Boolean, Pair<K, V>>): Map<K, V>
+inline fun <K, V> BooleanArray.associateBy(keySelector: Function1<Boolean, K>, valueTransform: Function1<Boolean, V>): Map<K, V>
+inline fun <K, V> BooleanArray.groupBy(keySelector: Function1<Boolean, K>, valueTransform: Function1<Boolean, V>): Map<K, List<V>>
+inline fun <K, V> ByteArray.associate(transform: Function1<Byte, Pair<K, V>>): Map<K, V>
+inline fun <K, V> ByteArray.associateBy(keySelector: Function1<Byte, K>, valueTransform: Function1<Byte, V>): Map<K, V>
+inline fun <K, V> ByteArray.groupBy(keySelector: Function1<Byte, K>, valueTransform: Function1<Byte, V>): Map<K, List<V>>
+inline fun <K, V> CharArray.associate(transform: Function1<Char, Pair<K, V>>): Map<K, V>
+inline fun <K, V> CharArray.associateBy(keySelector: Function1<Char, K>, valueTransform: Function1<Char, V>): Map<K, V>
+inline fun <K, V> CharArray.groupBy(keySelector: Function1<Char, K>, valueTransform: Function1<Char, V>): Map<K, List<V>>
+inline fun <K, V> ConcurrentMap<K, V>.getOrPut(key: K, defaultValue: Function0<V>): V
+inline fun <K, V> DoubleArray.associate(transform: Function1<Double, Pair<K, V>>): Map<K, V>
+inline fun <K, V> DoubleArray.associateBy(keySelector: Function1<Double, K>, valueTransform: Function1<Double, V>): Map<K, V>
+inline fun <K, V> DoubleArray.groupBy(keySelector: Function1<Double, K>, valueTransform: Function1<Double, V>): Map<K, List<V>>
+inline fun <K, V> FloatArray.associate(transform: Function1<Float, Pair<K, V>>): Map<K, V>
+inline fun <K, V> FloatArray.associateBy(keySelector: Function1<Float, K>, valueTransform: Function1<Float, V>): Map<K, V>
+inline fun <K, V> FloatArray.groupBy(keySelector: Function1<Float, K>, valueTransform: Function1<Float, V>): Map<K, List<V>>
+inline fun <K, V> IntArray.associate(transform: Function1<Int, Pair<K, V>>): Map<K, V>
+inline fun <K, V> IntArray.associateBy(keySelector: Function1<Int, K>, valueTransform: Function1<Int, V>): Map<K, V>
+inline fun <K, V> IntArray.groupBy(keySelector: Function1<Int, K>, valueTransform: Function1<Int, V>): Map<K, List<V>>
+inline fun <K, V> Iterable<K>.associateWith(valueSelector: Function1<K, V>): Map<K, V>
+inline fun <K, V> LongArray.associate(transform: Function1<Long, Pair<K, V>>): Map<K, V>
+inline fun <K, V> LongArray.associateBy(keySelector: Function1<Long, K>, valueTransform: Function1<Long, V>): Map<K, V>
+inline fun <K, V> LongArray.groupBy(keySelector: Function1<Long, K>, valueTransform: Function1<Long, V>): Map<K, List<V>>
+inline fun <K, V> Map.Entry<K, V>.toPair(): Pair<K, V>
+inline fun <K, V> Map<K, V>.containsValue(value: V): Boolean
+inline fun <K, V> Map<K, V>.getOrElse(key: K, defaultValue: Function0<V>): V
+inline fun <K, V> Map<K, V>?.orEmpty(): Map<K, V>
+inline fun <K, V> Map<out K, V>.all(predicate: Function1<Map.Entry<K, V>, Boolean>): Boolean
+inline fun <K, V> Map<out K, V>.any(predicate: Function1<Map.Entry<K, V>, Boolean>): Boolean
+inline fun <K, V> Map<out K, V>.asIterable(): Iterable<Map.Entry<K, V>>
+inline fun <K, V> Map<out K, V>.count(): Int
+inline fun <K, V> Map<out K, V>.count(predicate: Function1<Map.Entry<K, V>, Boolean>): Int
+inline fun <K, V> Map<out K, V>.filter(predicate: Function1<Map.Entry<K, V>, Boolean>): Map<K, V>
+inline fun <K, V> Map<out K, V>.filterKeys(predicate: Function1<K, Boolean>): Map<K, V>
+inline fun <K, V> Map<out K, V>.filterNot(predicate: Function1<Map.Entry<K, V>, Boolean>): Map<K, V>
+inline fun <K, V> Map<out K, V>.filterValues(predicate: Function1<V, Boolean>): Map<K, V>
+inline fun <K, V> Map<out K, V>.forEach(action: Function1<Map.Entry<K, V>, Unit>): Unit
+inline fun <K, V> Map<out K, V>.getOrDefault(key: K, defaultValue: V): V
+inline fun <K, V> Map<out K, V>.isNotEmpty(): Boolean
+inline fun <K, V> Map<out K, V>.maxOf(selector: Function1<Map.Entry<K, V>, Double>): Double
+inline fun <K, V> Map<out K, V>.maxOf(selector: Function1<Map.Entry<K, V>, Float>): Float
+inline fun <K, V> Map<out K, V>.maxOfOrNull(selector: Function1<Map.Entry<K, V>, Double>): Double?
+inline fun <K, V> Map<out K, V>.maxOfOrNull(selector: Function1<Map.Entry<K, V>, Float>): Float?
+inline fun <K, V> Map<out K, V>.maxWith(comparator: Comparator<in Map.Entry<K, V>>): Map.Entry<K, V>
+inline fun <K, V> Map<out K, V>.maxWith(comparator: Comparator<in Map.Entry<K, V>>): Map.Entry<K, V>?
+inline fun <K, V> Map<out K, V>.maxWithOrNull(comparator: Comparator<in Map.Entry<K, V>>): Map.Entry<K, V>?
+inline fun <K, V> Map<out K, V>.minOf(selector: Function1<Map.Entry<K, V>, Double>): Double
+inline fun <K, V> Map<out K, V>.minOf(selector: Function1<Map.Entry<K, V>, Float>): Float
+inline fun <K, V> Map<out K, V>.minOfOrNull(selector: Function1<Map.Entry<K, V>, Double>): Double?
+inline fun <K, V> Map<out K, V>.minOfOrNull(selector: Function1<Map.Entry<K, V>, Float>): Float?
+inline fun <K, V> Map<out K, V>.minWith(comparator: Comparator<in Map.Entry<K, V>>): Map.Entry<K, V>
+inline fun <K, V> Map<out K, V>.minWithOrNull(comparator: Comparator<in Map.Entry<K, V>>): Map.Entry<K, V>?
+inline fun <K, V> Map<out K, V>.none(predicate: Function1<Map.Entry<K, V>, Boolean>): Boolean
+inline fun <K, V> Map<out K, V>?.isNullOrEmpty(): Boolean
+inline fun <K, V> MutableMap<K, V>.getOrPut(key: K, defaultValue: Function0<V>): V
+inline fun <K, V> MutableMap<out K, V>.remove(key: K): V?
+inline fun <K, V> MutableMap<out K, out V>.remove(key: K, value: V): Boolean
+inline fun <K, V> ShortArray.associate(transform: Function1<Short, Pair<K, V>>): Map<K, V>
+inline fun <K, V> ShortArray.associateBy(keySelector: Function1<Short, K>, valueTransform: Function1<Short, V>): Map<K, V>
+inline fun <K, V> ShortArray.groupBy(keySelector: Function1<Short, K>, valueTransform: Function1<Short, V>): Map<K, List<V>>
+inline fun <K, V> UByteArray.groupBy(keySelector: Function1<UByte, K>, valueTransform: Function1<UByte, V>): Map<K, List<V>>
+inline fun <K, V> UIntArray.groupBy(keySelector: Function1<UInt, K>, valueTransform: Function1<UInt, V>): Map<K, List<V>>
+inline fun <K, V> ULongArray.groupBy(keySelector: Function1<ULong, K>, valueTransform: Function1<ULong, V>): Map<K, List<V>>
+inline fun <K, V> UShortArray.groupBy(keySelector: Function1<UShort, K>, valueTransform: Function1<UShort, V>): Map<K, List<V>>
+inline fun <K, V> buildMap(builderAction: Function1<MutableMap<K, V>, Unit>): Map<K, V>
+inline fun <K, V> buildMap(capacity: Int, builderAction: Function1<MutableMap<K, V>, Unit>): Map<K, V>
+inline fun <K, V> hashMapOf(): HashMap<K, V>
+inline fun <K, V> linkedMapOf(): LinkedHashMap<K, V>
+inline fun <K, V> mapOf(): Map<K, V>
+inline fun <K, V> mutableMapOf(): MutableMap<K, V>
+inline fun <K> BooleanArray.associateBy(keySelector: Function1<Boolean, K>): Map<K, Boolean>
+inline fun <K> BooleanArray.distinctBy(selector: Function1<Boolean, K>): List<Boolean>
+inline fun <K> BooleanArray.groupBy(keySelector: Function1<Boolean, K>): Map<K, List<Boolean>>
+inline fun <K> ByteArray.associateBy(keySelector: Function1<Byte, K>): Map<K, Byte>
+inline fun <K> ByteArray.distinctBy(selector: Function1<Byte, K>): List<Byte>
+inline fun <K> ByteArray.groupBy(keySelector: Function1<Byte, K>): Map<K, List<Byte>>
+inline fun <K> CharArray.associateBy(keySelector: Function1<Char, K>): Map<K, Char>
+inline fun <K> CharArray.distinctBy(selector: Function1<Char, K>): List<Char>
+inline fun <K> CharArray.groupBy(keySelector: Function1<Char, K>): Map<K, List<Char>>
+inline fun <K> DoubleArray.associateBy(keySelector: Function1<Double, K>): Map<K, Double>
+inline fun <K> DoubleArray.distinctBy(selector: Function1<Double, K>): List<Double>
+inline fun <K> DoubleArray.groupBy(keySelector: Function1<Double, K>): Map<K, List<Double>>
+inline fun <K> FloatArray.associateBy(keySelector: Function1<Float, K>): Map<K, Float>
+inline fun <K> FloatArray.distinctBy(selector: Function1<Float, K>): List<Float>
+inline fun <K> FloatArray.groupBy(keySelector: Function1<Float, K>): Map<K, List<Float>>
+inline fun <K> IntArray.associateBy(keySelector: Function1<Int, K>): Map<K, Int>
+inline fun <K> IntArray.distinctBy(selector: Function1<Int, K>): List<Int>
+inline fun <K> IntArray.groupBy(keySelector: Function1<Int, K>): Map<K, List<Int>>
+inline fun <K> LongArray.associateBy(keySelector: Function1<Long, K>): Map<K, Long>
+inline fun <K> LongArray.distinctBy(selector: Function1<Long, K>): List<Long>
+inline fun <K> LongArray.groupBy(keySelector: Function1<Long, K>): Map<K, List<Long>>
+inline fun <K> Map<out K, *>.containsKey(key: K): Boolean
+inline fun <K> ShortArray.associateBy(keySelector: Function1<Short, K>): Map<K, Short>
+inline fun <K> ShortArray.distinctBy(selector: Function1<Short, K>): List<Short>
+inline fun <K> ShortArray.groupBy(keySelector: Function1<Short, K>): Map<K, List<Short>>
+inline fun <K> UByteArray.groupBy(keySelector: Function1<UByte, K>): Map<K, List<UByte>>
+inline fun <K> UIntArray.groupBy(keySelector: Function1<UInt, K>): Map<K, List<UInt>>
+inline fun <K> ULongArray.groupBy(keySelector: Function1<ULong, K>): Map<K, List<ULong>>
+inline fun <K> UShortArray.groupBy(keySelector: Function1<UShort, K>): Map<K, List<UShort>>
+inline fun <M, R> M.ifEmpty(defaultValue: Function0<R>): R
+inline fun <R : Comparable<R>> BooleanArray.maxBy(selector: Function1<Boolean, R>): Boolean
+inline fun <R : Comparable<R>> BooleanArray.maxBy(selector: Function1<Boolean, R>): Boolean?
+inline fun <R : Comparable<R>> BooleanArray.maxByOrNull(selector: Function1<Boolean, R>): Boolean?
+inline fun <R : Comparable<R>> BooleanArray.maxOf(selector: Function1<Boolean, R>): R
+inline fun <R : Comparable<R>> BooleanArray.maxOfOrNull(selector: Function1<Boolean, R>): R?
+inline fun <R : Comparable<R>> BooleanArray.minBy(selector: Function1<Boolean, R>): Boolean
+inline fun <R : Comparable<R>> BooleanArray.minBy(selector: Function1<Boolean, R>): Boolean?
+inline fun <R : Comparable<R>> BooleanArray.minByOrNull(selector: Function1<Boolean, R>): Boolean?
+inline fun <R : Comparable<R>> BooleanArray.minOf(selector: Function1<Boolean, R>): R
+inline fun <R : Comparable<R>> BooleanArray.minOfOrNull(selector: Function1<Boolean, R>): R?
+inline fun <R : Comparable<R>> BooleanArray.sortedBy(selector: Function1<Boolean, R?>): List<Boolean>
+inline fun <R : Comparable<R>> BooleanArray.sortedByDescending(selector: Function1<Boolean, R?>): List<Boolean>
+inline fun <R : Comparable<R>> ByteArray.maxBy(selector: Function1<Byte, R>): Byte
+inline fun <R : Comparable<R>> ByteArray.maxBy(selector: Function1<Byte, R>): Byte?
+inline fun <R : Comparable<R>> ByteArray.maxByOrNull(selector: Function1<Byte, R>): Byte?
+inline fun <R : Comparable<R>> ByteArray.maxOf(selector: Function1<Byte, R>): R
+inline fun <R : Comparable<R>> ByteArray.maxOfOrNull(selector: Function1<Byte, R>): R?
+inline fun <R : Comparable<R>> ByteArray.minBy(selector: Function1<Byte, R>): Byte
+inline fun <R : Comparable<R>> ByteArray.minBy(selector: Function1<Byte, R>): Byte?
+inline fun <R : Comparable<R>> ByteArray.minByOrNull(selector: Function1<Byte, R>): Byte?
+inline fun <R : Comparable<R>> ByteArray.minOf(selector: Function1<Byte, R>): R
+inline fun <R : Comparable<R>> ByteArray.minOfOrNull(selector: Function1<Byte, R>): R?
+inline fun <R : Comparable<R>> ByteArray.sortedBy(selector: Function1<Byte, R?>): List<Byte>
+inline fun <R : Comparable<R>> ByteArray.sortedByDescending(selector: Function1<Byte, R?>): List<Byte>
+inline fun <R : Comparable<R>> CharArray.maxBy(selector: Function1<Char, R>): Char
+inline fun <R : Comparable<R>> CharArray.maxBy(selector: Function1<Char, R>): Char?
+inline fun <R : Comparable<R>> CharArray.maxByOrNull(selector: Function1<Char, R>): Char?
+inline fun <R : Comparable<R>> CharArray.maxOf(selector: Function1<Char, R>): R
+inline fun <R : Comparable<R>> CharArray.maxOfOrNull(selector: Function1<Char, R>): R?
+inline fun <R : Comparable<R>> CharArray.minBy(selector: Function1<Char, R>): Char
+inline fun <R : Comparable<R>> CharArray.minBy(selector: Function1<Char, R>): Char?
+inline fun <R : Comparable<R>> CharArray.minByOrNull(selector: Function1<Char, R>): Char?
+inline fun <R : Comparable<R>> CharArray.minOf(selector: Function1<Char, R>): R
+inline fun <R : Comparable<R>> CharArray.minOfOrNull(selector: Function1<Char, R>): R?
+inline fun <R : Comparable<R>> CharArray.sortedBy(selector: Function1<Char, R?>): List<Char>
+inline fun <R : Comparable<R>> CharArray.sortedByDescending(selector: Function1<Char, R?>): List<Char>
+inline fun <R : Comparable<R>> DoubleArray.maxBy(selector: Function1<Double, R>): Double
+inline fun <R : Comparable<R>> DoubleArray.maxBy(selector: Function1<Double, R>): Double?
+inline fun <R : Comparable<R>> DoubleArray.maxByOrNull(selector: Function1<Double, R>): Double?
+inline fun <R : Comparable<R>> DoubleArray.maxOf(selector: Function1<Double, R>): R
+inline fun <R : Comparable<R>> DoubleArray.maxOfOrNull(selector: Function1<Double, R>): R?
+inline fun <R : Comparable<R>> DoubleArray.minBy(selector: Function1<Double, R>): Double
+inline fun <R : Comparable<R>> DoubleArray.minBy(selector: Function1<Double, R>): Double?
+inline fun <R : Comparable<R>> DoubleArray.minByOrNull(selector: Function1<Double, R>): Double?
+inline fun <R : Comparable<R>> DoubleArray.minOf(selector: Function1<Double, R>): R
+inline fun <R : Comparable<R>> DoubleArray.minOfOrNull(selector: Function1<Double, R>): R?
+inline fun <R : Comparable<R>> DoubleArray.sortedBy(selector: Function1<Double, R?>): List<Double>
+inline fun <R : Comparable<R>> DoubleArray.sortedByDescending(selector: Function1<Double, R?>): List<Double>
+inline fun <R : Comparable<R>> FloatArray.maxBy(selector: Function1<Float, R>): Float
+inline fun <R : Comparable<R>> FloatArray.maxBy(selector: Function1<Float, R>): Float?
+inline fun <R : Comparable<R>> FloatArray.maxByOrNull(selector: Function1<Float, R>): Float?
+inline fun <R : Comparable<R>> FloatArray.maxOf(selector: Function1<Float, R>): R
+inline fun <R : Comparable<R>> FloatArray.maxOfOrNull(selector: Function1<Float, R>): R?
+inline fun <R : Comparable<R>> FloatArray.minBy(selector: Function1<Float, R>): Float
+inline fun <R : Comparable<R>> FloatArray.minBy(selector: Function1<Float, R>): Float?
+inline fun <R : Comparable<R>> FloatArray.minByOrNull(selector: Function1<Float, R>): Float?
+inline fun <R : Comparable<R>> FloatArray.minOf(selector: Function1<Float, R>): R
+inline fun <R : Comparable<R>> FloatArray.minOfOrNull(selector: Function1<Float, R>): R?
+inline fun <R : Comparable<R>> FloatArray.sortedBy(selector: Function1<Float, R?>): List<Float>
+inline fun <R : Comparable<R>> FloatArray.sortedByDescending(selector: Function1<Float, R?>): List<Float>
+inline fun <R : Comparable<R>> IntArray.maxBy(selector: Function1<Int, R>): Int
+inline fun <R : Comparable<R>> IntArray.maxBy(selector: Function1<Int, R>): Int?
+inline fun <R : Comparable<R>> IntArray.maxByOrNull(selector: Function1<Int, R>): Int?
+inline fun <R : Comparable<R>> IntArray.maxOf(selector: Function1<Int, R>): R
+inline fun <R : Comparable<R>> IntArray.maxOfOrNull(selector: Function1<Int, R>): R?
+inline fun <R : Comparable<R>> IntArray.minBy(selector: Function1<Int, R>): Int
+inline fun <R : Comparable<R>> IntArray.minBy(selector: Function1<Int, R>): Int?
+inline fun <R : Comparable<R>> IntArray.minByOrNull(selector: Function1<Int, R>): Int?
+inline fun <R : Comparable<R>> IntArray.minOf(selector: Function1<Int, R>): R
+inline fun <R : Comparable<R>> IntArray.minOfOrNull(selector: Function1<Int, R>): R?
+inline fun <R : Comparable<R>> IntArray.sortedBy(selector: Function1<Int, R?>): List<Int>
+inline fun <R : Comparable<R>> IntArray.sortedByDescending(selector: Function1<Int, R?>): List<Int>
+inline fun <R : Comparable<R>> LongArray.maxBy(selector: Function1<Long, R>): Long
+inline fun <R : Comparable<R>> LongArray.maxBy(selector: Function1<Long, R>): Long?
+inline fun <R : Comparable<R>> LongArray.maxByOrNull(selector: Function1<Long, R>): Long?
+inline fun <R : Comparable<R>> LongArray.maxOf(selector: Function1<Long, R>): R
+inline fun <R : Comparable<R>> LongArray.maxOfOrNull(selector: Function1<Long, R>): R?
+inline fun <R : Comparable<R>> LongArray.minBy(selector: Function1<Long, R>): Long
+inline fun <R : Comparable<R>> LongArray.minBy(selector: Function1<Long, R>): Long?
+inline fun <R : Comparable<R>> LongArray.minByOrNull(selector: Function1<Long, R>): Long?
+inline fun <R : Comparable<R>> LongArray.minOf(selector: Function1<Long, R>): R
+inline fun <R : Comparable<R>> LongArray.minOfOrNull(selector: Function1<Long, R>): R?
+inline fun <R : Comparable<R>> LongArray.sortedBy(selector: Function1<Long, R?>): List<Long>
+inline fun <R : Comparable<R>> LongArray.sortedByDescending(selector: Function1<Long, R?>): List<Long>
+inline fun <R : Comparable<R>> ShortArray.maxBy(selector: Function1<Short, R>): Short
+inline fun <R : Comparable<R>> ShortArray.maxBy(selector: Function1<Short, R>): Short?
+inline fun <R : Comparable<R>> ShortArray.maxByOrNull(selector: Function1<Short, R>): Short?
+inline fun <R : Comparable<R>> ShortArray.maxOf(selector: Function1<Short, R>): R
+inline fun <R : Comparable<R>> ShortArray.maxOfOrNull(selector: Function1<Short, R>): R?
+inline fun <R : Comparable<R>> ShortArray.minBy(selector: Function1<Short, R>): Short
+inline fun <R : Comparable<R>> ShortArray.minBy(selector: Function1<Short, R>): Short?
+inline fun <R : Comparable<R>> ShortArray.minByOrNull(selector: Function1<Short, R>): Short?
+inline fun <R : Comparable<R>> ShortArray.minOf(selector: Function1<Short, R>): R
+inline fun <R : Comparable<R>> ShortArray.minOfOrNull(selector: Function1<Short, R>): R?
+inline fun <R : Comparable<R>> ShortArray.sortedBy(selector: Function1<Short, R?>): List<Short>
+inline fun <R : Comparable<R>> ShortArray.sortedByDescending(selector: Function1<Short, R?>): List<Short>
+inline fun <R : Comparable<R>> UByteArray.maxBy(selector: Function1<UByte, R>): UByte
+inline fun <R : Comparable<R>> UByteArray.maxBy(selector: Function1<UByte, R>): UByte?
+inline fun <R : Comparable<R>> UByteArray.maxByOrNull(selector: Function1<UByte, R>): UByte?
+inline fun <R : Comparable<R>> UByteArray.maxOf(selector: Function1<UByte, R>): R
+inline fun <R : Comparable<R>> UByteArray.maxOfOrNull(selector: Function1<UByte, R>): R?
+inline fun <R : Comparable<R>> UByteArray.minBy(selector: Function1<UByte, R>): UByte
+inline fun <R : Comparable<R>> UByteArray.minBy(selector: Function1<UByte, R>): UByte?
+inline fun <R : Comparable<R>> UByteArray.minByOrNull(selector: Function1<UByte, R>): UByte?
+inline fun <R : Comparable<R>> UByteArray.minOf(selector: Function1<UByte, R>): R
+inline fun <R : Comparable<R>> UByteArray.minOfOrNull(selector: Function1<UByte, R>): R?
+inline fun <R : Comparable<R>> UIntArray.maxBy(selector: Function1<UInt, R>): UInt
+inline fun <R : Comparable<R>> UIntArray.maxBy(selector: Function1<UInt, R>): UInt?
+inline fun <R : Comparable<R>> UIntArray.maxByOrNull(selector: Function1<UInt, R>): UInt?
+inline fun <R : Comparable<R>> UIntArray.maxOf(selector: Function1<UInt, R>): R
+inline fun <R : Comparable<R>> UIntArray.maxOfOrNull(selector: Function1<UInt, R>): R?
+inline fun <R : Comparable<R>> UIntArray.minBy(selector: Function1<UInt, R>): UInt
+inline fun <R : Comparable<R>> UIntArray.minBy(selector: Function1<UInt, R>): UInt?
+inline fun <R : Comparable<R>> UIntArray.minByOrNull(selector: Function1<UInt, R>): UInt?
+inline fun <R : Comparable<R>> UIntArray.minOf(selector: Function1<UInt, R>): R
+inline fun <R : Comparable<R>> UIntArray.minOfOrNull(selector: Function1<UInt, R>): R?
+inline fun <R : Comparable<R>> ULongArray.maxBy(selector: Function1<ULong, R>): ULong
+inline fun <R : Comparable<R>> ULongArray.maxBy(selector: Function1<ULong, R>): ULong?
+inline fun <R : Comparable<R>> ULongArray.maxByOrNull(selector: Function1<ULong, R>): ULong?
+inline fun <R : Comparable<R>> ULongArray.maxOf(selector: Function1<ULong, R>): R
+inline fun <R : Comparable<R>> ULongArray.maxOfOrNull(selector: Function1<ULong, R>): R?
+inline fun <R : Comparable<R>> ULongArray.minBy(selector: Function1<ULong, R>): ULong
+inline fun <R : Comparable<R>> ULongArray.minBy(selector: Function1<ULong, R>): ULong?
+inline fun <R : Comparable<R>> ULongArray.minByOrNull(selector: Function1<ULong, R>): ULong?
+inline fun <R : Comparable<R>> ULongArray.minOf(selector: Function1<ULong, R>): R
+inline fun <R : Comparable<R>> ULongArray.minOfOrNull(selector: Function1<ULong, R>): R?
+inline fun <R : Comparable<R>> UShortArray.maxBy(selector: Function1<UShort, R>): UShort
+inline fun <R : Comparable<R>> UShortArray.maxBy(selector: Function1<UShort, R>): UShort?
+inline fun <R : Comparable<R>> UShortArray.maxByOrNull(selector: Function1<UShort, R>): UShort?
+inline fun <R : Comparable<R>> UShortArray.maxOf(selector: Function1<UShort, R>): R
+inline fun <R : Comparable<R>> UShortArray.maxOfOrNull(selector: Function1<UShort, R>): R?
+inline fun <R : Comparable<R>> UShortArray.minBy(selector: Function1<UShort, R>): UShort
+inline fun <R : Comparable<R>> UShortArray.minBy(selector: Function1<UShort, R>): UShort?
+inline fun <R : Comparable<R>> UShortArray.minByOrNull(selector: Function1<UShort, R>): UShort?
+inline fun <R : Comparable<R>> UShortArray.minOf(selector: Function1<UShort, R>): R
+inline fun <R : Comparable<R>> UShortArray.minOfOrNull(selector: Function1<UShort, R>): R?
+inline fun <R, C : MutableCollection<in R>> BooleanArray.flatMapIndexedTo(destination: C, transform: Function2<Int, Boolean, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> BooleanArray.flatMapTo(destination: C, transform: Function1<Boolean, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> BooleanArray.mapIndexedTo(destination: C, transform: Function2<Int, Boolean, R>): C
+inline fun <R, C : MutableCollection<in R>> BooleanArray.mapTo(destination: C, transform: Function1<Boolean, R>): C
+inline fun <R, C : MutableCollection<in R>> ByteArray.flatMapIndexedTo(destination: C, transform: Function2<Int, Byte, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> ByteArray.flatMapTo(destination: C, transform: Function1<Byte, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> ByteArray.mapIndexedTo(destination: C, transform: Function2<Int, Byte, R>): C
+inline fun <R, C : MutableCollection<in R>> ByteArray.mapTo(destination: C, transform: Function1<Byte, R>): C
+inline fun <R, C : MutableCollection<in R>> CharArray.flatMapIndexedTo(destination: C, transform: Function2<Int, Char, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> CharArray.flatMapTo(destination: C, transform: Function1<Char, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> CharArray.mapIndexedTo(destination: C, transform: Function2<Int, Char, R>): C
+inline fun <R, C : MutableCollection<in R>> CharArray.mapTo(destination: C, transform: Function1<Char, R>): C
+inline fun <R, C : MutableCollection<in R>> DoubleArray.flatMapIndexedTo(destination: C, transform: Function2<Int, Double, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> DoubleArray.flatMapTo(destination: C, transform: Function1<Double, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> DoubleArray.mapIndexedTo(destination: C, transform: Function2<Int, Double, R>): C
+inline fun <R, C : MutableCollection<in R>> DoubleArray.mapTo(destination: C, transform: Function1<Double, R>): C
+inline fun <R, C : MutableCollection<in R>> FloatArray.flatMapIndexedTo(destination: C, transform: Function2<Int, Float, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> FloatArray.flatMapTo(destination: C, transform: Function1<Float, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> FloatArray.mapIndexedTo(destination: C, transform: Function2<Int, Float, R>): C
+inline fun <R, C : MutableCollection<in R>> FloatArray.mapTo(destination: C, transform: Function1<Float, R>): C
+inline fun <R, C : MutableCollection<in R>> IntArray.flatMapIndexedTo(destination: C, transform: Function2<Int, Int, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> IntArray.flatMapTo(destination: C, transform: Function1<Int, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> IntArray.mapIndexedTo(destination: C, transform: Function2<Int, Int, R>): C
+inline fun <R, C : MutableCollection<in R>> IntArray.mapTo(destination: C, transform: Function1<Int, R>): C
+inline fun <R, C : MutableCollection<in R>> LongArray.flatMapIndexedTo(destination: C, transform: Function2<Int, Long, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> LongArray.flatMapTo(destination: C, transform: Function1<Long, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> LongArray.mapIndexedTo(destination: C, transform: Function2<Int, Long, R>): C
+inline fun <R, C : MutableCollection<in R>> LongArray.mapTo(destination: C, transform: Function1<Long, R>): C
+inline fun <R, C : MutableCollection<in R>> ShortArray.flatMapIndexedTo(destination: C, transform: Function2<Int, Short, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> ShortArray.flatMapTo(destination: C, transform: Function1<Short, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> ShortArray.mapIndexedTo(destination: C, transform: Function2<Int, Short, R>): C
+inline fun <R, C : MutableCollection<in R>> ShortArray.mapTo(destination: C, transform: Function1<Short, R>): C
+inline fun <R, C : MutableCollection<in R>> UByteArray.flatMapIndexedTo(destination: C, transform: Function2<Int, UByte, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> UByteArray.flatMapTo(destination: C, transform: Function1<UByte, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> UByteArray.mapIndexedTo(destination: C, transform: Function2<Int, UByte, R>): C
+inline fun <R, C : MutableCollection<in R>> UByteArray.mapTo(destination: C, transform: Function1<UByte, R>): C
+inline fun <R, C : MutableCollection<in R>> UIntArray.flatMapIndexedTo(destination: C, transform: Function2<Int, UInt, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> UIntArray.flatMapTo(destination: C, transform: Function1<UInt, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> UIntArray.mapIndexedTo(destination: C, transform: Function2<Int, UInt, R>): C
+inline fun <R, C : MutableCollection<in R>> UIntArray.mapTo(destination: C, transform: Function1<UInt, R>): C
+inline fun <R, C : MutableCollection<in R>> ULongArray.flatMapIndexedTo(destination: C, transform: Function2<Int, ULong, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> ULongArray.flatMapTo(destination: C, transform: Function1<ULong, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> ULongArray.mapIndexedTo(destination: C, transform: Function2<Int, ULong, R>): C
+inline fun <R, C : MutableCollection<in R>> ULongArray.mapTo(destination: C, transform: Function1<ULong, R>): C
+inline fun <R, C : MutableCollection<in R>> UShortArray.flatMapIndexedTo(destination: C, transform: Function2<Int, UShort, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> UShortArray.flatMapTo(destination: C, transform: Function1<UShort, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> UShortArray.mapIndexedTo(destination: C, transform: Function2<Int, UShort, R>): C
+inline fun <R, C : MutableCollection<in R>> UShortArray.mapTo(destination: C, transform: Function1<UShort, R>): C
+inline fun <R, V> BooleanArray.zip(other: Array<out R>, transform: Function2<Boolean, R, V>): List<V>
+inline fun <R, V> BooleanArray.zip(other: Iterable<R>, transform: Function2<Boolean, R, V>): List<V>
+inline fun <R, V> ByteArray.zip(other: Array<out R>, transform: Function2<Byte, R, V>): List<V>
+inline fun <R, V> ByteArray.zip(other: Iterable<R>, transform: Function2<Byte, R, V>): List<V>
+inline fun <R, V> CharArray.zip(other: Array<out R>, transform: Function2<Char, R, V>): List<V>
+inline fun <R, V> CharArray.zip(other: Iterable<R>, transform: Function2<Char, R, V>): List<V>
+inline fun <R, V> DoubleArray.zip(other: Array<out R>, transform: Function2<Double, R, V>): List<V>
+inline fun <R, V> DoubleArray.zip(other: Iterable<R>, transform: Function2<Double, R, V>): List<V>
+inline fun <R, V> FloatArray.zip(other: Array<out R>, transform: Function2<Float, R, V>): List<V>
+inline fun <R, V> FloatArray.zip(other: Iterable<R>, transform: Function2<Float, R, V>): List<V>
+inline fun <R, V> IntArray.zip(other: Array<out R>, transform: Function2<Int, R, V>): List<V>
+inline fun <R, V> IntArray.zip(other: Iterable<R>, transform: Function2<Int, R, V>): List<V>
+inline fun <R, V> LongArray.zip(other: Array<out R>, transform: Function2<Long, R, V>): List<V>
+inline fun <R, V> LongArray.zip(other: Iterable<R>, transform: Function2<Long, R, V>): List<V>
+inline fun <R, V> ShortArray.zip(other: Array<out R>, transform: Function2<Short, R, V>): List<V>
+inline fun <R, V> ShortArray.zip(other: Iterable<R>, transform: Function2<Short, R, V>): List<V>
+inline fun <R, V> UByteArray.zip(other: Array<out R>, transform: Function2<UByte, R, V>): List<V>
+inline fun <R, V> UByteArray.zip(other: Iterable<R>, transform: Function2<UByte, R, V>): List<V>
+inline fun <R, V> UIntArray.zip(other: Array<out R>, transform: Function2<UInt, R, V>): List<V>
+inline fun <R, V> UIntArray.zip(other: Iterable<R>, transform: Function2<UInt, R, V>): List<V>
+inline fun <R, V> ULongArray.zip(other: Array<out R>, transform: Function2<ULong, R, V>): List<V>
+inline fun <R, V> ULongArray.zip(other: Iterable<R>, transform: Function2<ULong, R, V>): List<V>
+inline fun <R, V> UShortArray.zip(other: Array<out R>, transform: Function2<UShort, R, V>): List<V>
+inline fun <R, V> UShortArray.zip(other: Iterable<R>, transform: Function2<UShort, R, V>): List<V>
+inline fun <R> BooleanArray.flatMap(transform: Function1<Boolean, Iterable<R>>): List<R>
+inline fun <R> BooleanArray.flatMapIndexed(transform: Function2<Int, Boolean, Iterable<R>>): List<R>
+inline fun <R> BooleanArray.fold(initial: R, operation: Function2<R, Boolean, R>): R
+inline fun <R> BooleanArray.foldIndexed(initial: R, operation: Function3<Int, R, Boolean, R>): R
+inline fun <R> BooleanArray.foldRight(initial: R, operation: Function2<Boolean, R, R>): R
+inline fun <R> BooleanArray.foldRightIndexed(initial: R, operation: Function3<Int, Boolean, R, R>): R
+inline fun <R> BooleanArray.map(transform: Function1<Boolean, R>): List<R>
+inline fun <R> BooleanArray.mapIndexed(transform: Function2<Int, Boolean, R>): List<R>
+inline fun <R> BooleanArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<Boolean, R>): R
+inline fun <R> BooleanArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Boolean, R>): R?
+inline fun <R> BooleanArray.minOfWith(comparator: Comparator<in R>, selector: Function1<Boolean, R>): R
+inline fun <R> BooleanArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Boolean, R>): R?
+inline fun <R> BooleanArray.runningFold(initial: R, operation: Function2<R, Boolean, R>): List<R>
+inline fun <R> BooleanArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, Boolean, R>): List<R>
+inline fun <R> BooleanArray.scan(initial: R, operation: Function2<R, Boolean, R>): List<R>
+inline fun <R> BooleanArray.scanIndexed(initial: R, operation: Function3<Int, R, Boolean, R>): List<R>
+inline fun <R> ByteArray.flatMap(transform: Function1<Byte, Iterable<R>>): List<R>
+inline fun <R> ByteArray.flatMapIndexed(transform: Function2<Int, Byte, Iterable<R>>): List<R>
+inline fun <R> ByteArray.fold(initial: R, operation: Function2<R, Byte, R>): R
+inline fun <R> ByteArray.foldIndexed(initial: R, operation: Function3<Int, R, Byte, R>): R
+inline fun <R> ByteArray.foldRight(initial: R, operation: Function2<Byte, R, R>): R
+inline fun <R> ByteArray.foldRightIndexed(initial: R, operation: Function3<Int, Byte, R, R>): R
+inline fun <R> ByteArray.map(transform: Function1<Byte, R>): List<R>
+inline fun <R> ByteArray.mapIndexed(transform: Function2<Int, Byte, R>): List<R>
+inline fun <R> ByteArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<Byte, R>): R
+inline fun <R> ByteArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Byte, R>): R?
+inline fun <R> ByteArray.minOfWith(comparator: Comparator<in R>, selector: Function1<Byte, R>): R
+inline fun <R> ByteArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Byte, R>): R?
+inline fun <R> ByteArray.runningFold(initial: R, operation: Function2<R, Byte, R>): List<R>
+inline fun <R> ByteArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, Byte, R>): List<R>
+inline fun <R> ByteArray.scan(initial: R, operation: Function2<R, Byte, R>): List<R>
+inline fun <R> ByteArray.scanIndexed(initial: R, operation: Function3<Int, R, Byte, R>): List<R>
+inline fun <R> CharArray.flatMap(transform: Function1<Char, Iterable<R>>): List<R>
+inline fun <R> CharArray.flatMapIndexed(transform: Function2<Int, Char, Iterable<R>>): List<R>
+inline fun <R> CharArray.fold(initial: R, operation: Function2<R, Char, R>): R
+inline fun <R> CharArray.foldIndexed(initial: R, operation: Function3<Int, R, Char, R>): R
+inline fun <R> CharArray.foldRight(initial: R, operation: Function2<Char, R, R>): R
+inline fun <R> CharArray.foldRightIndexed(initial: R, operation: Function3<Int, Char, R, R>): R
+inline fun <R> CharArray.map(transform: Function1<Char, R>): List<R>
+inline fun <R> CharArray.mapIndexed(transform: Function2<Int, Char, R>): List<R>
+inline fun <R> CharArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<Char, R>): R
+inline fun <R> CharArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Char, R>): R?
+inline fun <R> CharArray.minOfWith(comparator: Comparator<in R>, selector: Function1<Char, R>): R
+inline fun <R> CharArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Char, R>): R?
+inline fun <R> CharArray.runningFold(initial: R, operation: Function2<R, Char, R>): List<R>
+inline fun <R> CharArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, Char, R>): List<R>
+inline fun <R> CharArray.scan(initial: R, operation: Function2<R, Char, R>): List<R>
+inline fun <R> CharArray.scanIndexed(initial: R, operation: Function3<Int, R, Char, R>): List<R>
+inline fun <R> DoubleArray.flatMap(transform: Function1<Double, Iterable<R>>): List<R>
+inline fun <R> DoubleArray.flatMapIndexed(transform: Function2<Int, Double, Iterable<R>>): List<R>
+inline fun <R> DoubleArray.fold(initial: R, operation: Function2<R, Double, R>): R
+inline fun <R> DoubleArray.foldIndexed(initial: R, operation: Function3<Int, R, Double, R>): R
+inline fun <R> DoubleArray.foldRight(initial: R, operation: Function2<Double, R, R>): R
+inline fun <R> DoubleArray.foldRightIndexed(initial: R, operation: Function3<Int, Double, R, R>): R
+inline fun <R> DoubleArray.map(transform: Function1<Double, R>): List<R>
+inline fun <R> DoubleArray.mapIndexed(transform: Function2<Int, Double, R>): List<R>
+inline fun <R> DoubleArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<Double, R>): R
+inline fun <R> DoubleArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Double, R>): R?
+inline fun <R> DoubleArray.minOfWith(comparator: Comparator<in R>, selector: Function1<Double, R>): R
+inline fun <R> DoubleArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Double, R>): R?
+inline fun <R> DoubleArray.runningFold(initial: R, operation: Function2<R, Double, R>): List<R>
+inline fun <R> DoubleArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, Double, R>): List<R>
+inline fun <R> DoubleArray.scan(initial: R, operation: Function2<R, Double, R>): List<R>
+inline fun <R> DoubleArray.scanIndexed(initial: R, operation: Function3<Int, R, Double, R>): List<R>
+inline fun <R> FloatArray.flatMap(transform: Function1<Float, Iterable<R>>): List<R>
+inline fun <R> FloatArray.flatMapIndexed(transform: Function2<Int, Float, Iterable<R>>): List<R>
+inline fun <R> FloatArray.fold(initial: R, operation: Function2<R, Float, R>): R
+inline fun <R> FloatArray.foldIndexed(initial: R, operation: Function3<Int, R, Float, R>): R
+inline fun <R> FloatArray.foldRight(initial: R, operation: Function2<Float, R, R>): R
+inline fun <R> FloatArray.foldRightIndexed(initial: R, operation: Function3<Int, Float, R, R>): R
+inline fun <R> FloatArray.map(transform: Function1<Float, R>): List<R>
+inline fun <R> FloatArray.mapIndexed(transform: Function2<Int, Float, R>): List<R>
+inline fun <R> FloatArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<Float, R>): R
+inline fun <R> FloatArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Float, R>): R?
+inline fun <R> FloatArray.minOfWith(comparator: Comparator<in R>, selector: Function1<Float, R>): R
+inline fun <R> FloatArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Float, R>): R?
+inline fun <R> FloatArray.runningFold(initial: R, operation: Function2<R, Float, R>): List<R>
+inline fun <R> FloatArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, Float, R>): List<R>
+inline fun <R> FloatArray.scan(initial: R, operation: Function2<R, Float, R>): List<R>
+inline fun <R> FloatArray.scanIndexed(initial: R, operation: Function3<Int, R, Float, R>): List<R>
+inline fun <R> IntArray.flatMap(transform: Function1<Int, Iterable<R>>): List<R>
+inline fun <R> IntArray.flatMapIndexed(transform: Function2<Int, Int, Iterable<R>>): List<R>
+inline fun <R> IntArray.fold(initial: R, operation: Function2<R, Int, R>): R
+inline fun <R> IntArray.foldIndexed(initial: R, operation: Function3<Int, R, Int, R>): R
+inline fun <R> IntArray.foldRight(initial: R, operation: Function2<Int, R, R>): R
+inline fun <R> IntArray.foldRightIndexed(initial: R, operation: Function3<Int, Int, R, R>): R
+inline fun <R> IntArray.map(transform: Function1<Int, R>): List<R>
+inline fun <R> IntArray.mapIndexed(transform: Function2<Int, Int, R>): List<R>
+inline fun <R> IntArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<Int, R>): R
+inline fun <R> IntArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Int, R>): R?
+inline fun <R> IntArray.minOfWith(comparator: Comparator<in R>, selector: Function1<Int, R>): R
+inline fun <R> IntArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Int, R>): R?
+inline fun <R> IntArray.runningFold(initial: R, operation: Function2<R, Int, R>): List<R>
+inline fun <R> IntArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, Int, R>): List<R>
+inline fun <R> IntArray.scan(initial: R, operation: Function2<R, Int, R>): List<R>
+inline fun <R> IntArray.scanIndexed(initial: R, operation: Function3<Int, R, Int, R>): List<R>
+inline fun <R> LongArray.flatMap(transform: Function1<Long, Iterable<R>>): List<R>
+inline fun <R> LongArray.flatMapIndexed(transform: Function2<Int, Long, Iterable<R>>): List<R>
+inline fun <R> LongArray.fold(initial: R, operation: Function2<R, Long, R>): R
+inline fun <R> LongArray.foldIndexed(initial: R, operation: Function3<Int, R, Long, R>): R
+inline fun <R> LongArray.foldRight(initial: R, operation: Function2<Long, R, R>): R
+inline fun <R> LongArray.foldRightIndexed(initial: R, operation: Function3<Int, Long, R, R>): R
+inline fun <R> LongArray.map(transform: Function1<Long, R>): List<R>
+inline fun <R> LongArray.mapIndexed(transform: Function2<Int, Long, R>): List<R>
+inline fun <R> LongArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<Long, R>): R
+inline fun <R> LongArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Long, R>): R?
+inline fun <R> LongArray.minOfWith(comparator: Comparator<in R>, selector: Function1<Long, R>): R
+inline fun <R> LongArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Long, R>): R?
+inline fun <R> LongArray.runningFold(initial: R, operation: Function2<R, Long, R>): List<R>
+inline fun <R> LongArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, Long, R>): List<R>
+inline fun <R> LongArray.scan(initial: R, operation: Function2<R, Long, R>): List<R>
+inline fun <R> LongArray.scanIndexed(initial: R, operation: Function3<Int, R, Long, R>): List<R>
+inline fun <R> ShortArray.flatMap(transform: Function1<Short, Iterable<R>>): List<R>
+inline fun <R> ShortArray.flatMapIndexed(transform: Function2<Int, Short, Iterable<R>>): List<R>
+inline fun <R> ShortArray.fold(initial: R, operation: Function2<R, Short, R>): R
+inline fun <R> ShortArray.foldIndexed(initial: R, operation: Function3<Int, R, Short, R>): R
+inline fun <R> ShortArray.foldRight(initial: R, operation: Function2<Short, R, R>): R
+inline fun <R> ShortArray.foldRightIndexed(initial: R, operation: Function3<Int, Short, R, R>): R
+inline fun <R> ShortArray.map(transform: Function1<Short, R>): List<R>
+inline fun <R> ShortArray.mapIndexed(transform: Function2<Int, Short, R>): List<R>
+inline fun <R> ShortArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<Short, R>): R
+inline fun <R> ShortArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Short, R>): R?
+inline fun <R> ShortArray.minOfWith(comparator: Comparator<in R>, selector: Function1<Short, R>): R
+inline fun <R> ShortArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Short, R>): R?
+inline fun <R> ShortArray.runningFold(initial: R, operation: Function2<R, Short, R>): List<R>
+inline fun <R> ShortArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, Short, R>): List<R>
+inline fun <R> ShortArray.scan(initial: R, operation: Function2<R, Short, R>): List<R>
+inline fun <R> ShortArray.scanIndexed(initial: R, operation: Function3<Int, R, Short, R>): List<R>
+inline fun <R> UByteArray.flatMap(transform: Function1<UByte, Iterable<R>>): List<R>
+inline fun <R> UByteArray.flatMapIndexed(transform: Function2<Int, UByte, Iterable<R>>): List<R>
+inline fun <R> UByteArray.fold(initial: R, operation: Function2<R, UByte, R>): R
+inline fun <R> UByteArray.foldIndexed(initial: R, operation: Function3<Int, R, UByte, R>): R
+inline fun <R> UByteArray.foldRight(initial: R, operation: Function2<UByte, R, R>): R
+inline fun <R> UByteArray.foldRightIndexed(initial: R, operation: Function3<Int, UByte, R, R>): R
+inline fun <R> UByteArray.map(transform: Function1<UByte, R>): List<R>
+inline fun <R> UByteArray.mapIndexed(transform: Function2<Int, UByte, R>): List<R>
+inline fun <R> UByteArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<UByte, R>): R
+inline fun <R> UByteArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<UByte, R>): R?
+inline fun <R> UByteArray.minOfWith(comparator: Comparator<in R>, selector: Function1<UByte, R>): R
+inline fun <R> UByteArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<UByte, R>): R?
+inline fun <R> UByteArray.runningFold(initial: R, operation: Function2<R, UByte, R>): List<R>
+inline fun <R> UByteArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, UByte, R>): List<R>
+inline fun <R> UByteArray.scan(initial: R, operation: Function2<R, UByte, R>): List<R>
+inline fun <R> UByteArray.scanIndexed(initial: R, operation: Function3<Int, R, UByte, R>): List<R>
+inline fun <R> UIntArray.flatMap(transform: Function1<UInt, Iterable<R>>): List<R>
+inline fun <R> UIntArray.flatMapIndexed(transform: Function2<Int, UInt, Iterable<R>>): List<R>
+inline fun <R> UIntArray.fold(initial: R, operation: Function2<R, UInt, R>): R
+inline fun <R> UIntArray.foldIndexed(initial: R, operation: Function3<Int, R, UInt, R>): R
+inline fun <R> UIntArray.foldRight(initial: R, operation: Function2<UInt, R, R>): R
+inline fun <R> UIntArray.foldRightIndexed(initial: R, operation: Function3<Int, UInt, R, R>): R
+inline fun <R> UIntArray.map(transform: Function1<UInt, R>): List<R>
+inline fun <R> UIntArray.mapIndexed(transform: Function2<Int, UInt, R>): List<R>
+inline fun <R> UIntArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<UInt, R>): R
+inline fun <R> UIntArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<UInt, R>): R?
+inline fun <R> UIntArray.minOfWith(comparator: Comparator<in R>, selector: Function1<UInt, R>): R
+inline fun <R> UIntArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<UInt, R>): R?
+inline fun <R> UIntArray.runningFold(initial: R, operation: Function2<R, UInt, R>): List<R>
+inline fun <R> UIntArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, UInt, R>): List<R>
+inline fun <R> UIntArray.scan(initial: R, operation: Function2<R, UInt, R>): List<R>
+inline fun <R> UIntArray.scanIndexed(initial: R, operation: Function3<Int, R, UInt, R>): List<R>
+inline fun <R> ULongArray.flatMap(transform: Function1<ULong, Iterable<R>>): List<R>
+inline fun <R> ULongArray.flatMapIndexed(transform: Function2<Int, ULong, Iterable<R>>): List<R>
+inline fun <R> ULongArray.fold(initial: R, operation: Function2<R, ULong, R>): R
+inline fun <R> ULongArray.foldIndexed(initial: R, operation: Function3<Int, R, ULong, R>): R
+inline fun <R> ULongArray.foldRight(initial: R, operation: Function2<ULong, R, R>): R
+inline fun <R> ULongArray.foldRightIndexed(initial: R, operation: Function3<Int, ULong, R, R>): R
+inline fun <R> ULongArray.map(transform: Function1<ULong, R>): List<R>
+inline fun <R> ULongArray.mapIndexed(transform: Function2<Int, ULong, R>): List<R>
+inline fun <R> ULongArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<ULong, R>): R
+inline fun <R> ULongArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<ULong, R>): R?
+inline fun <R> ULongArray.minOfWith(comparator: Comparator<in R>, selector: Function1<ULong, R>): R
+inline fun <R> ULongArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<ULong, R>): R?
+inline fun <R> ULongArray.runningFold(initial: R, operation: Function2<R, ULong, R>): List<R>
+inline fun <R> ULongArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, ULong, R>): List<R>
+inline fun <R> ULongArray.scan(initial: R, operation: Function2<R, ULong, R>): List<R>
+inline fun <R> ULongArray.scanIndexed(initial: R, operation: Function3<Int, R, ULong, R>): List<R>
+inline fun <R> UShortArray.flatMap(transform: Function1<UShort, Iterable<R>>): List<R>
+inline fun <R> UShortArray.flatMapIndexed(transform: Function2<Int, UShort, Iterable<R>>): List<R>
+inline fun <R> UShortArray.fold(initial: R, operation: Function2<R, UShort, R>): R
+inline fun <R> UShortArray.foldIndexed(initial: R, operation: Function3<Int, R, UShort, R>): R
+inline fun <R> UShortArray.foldRight(initial: R, operation: Function2<UShort, R, R>): R
+inline fun <R> UShortArray.foldRightIndexed(initial: R, operation: Function3<Int, UShort, R, R>): R
+inline fun <R> UShortArray.map(transform: Function1<UShort, R>): List<R>
+inline fun <R> UShortArray.mapIndexed(transform: Function2<Int, UShort, R>): List<R>
+inline fun <R> UShortArray.maxOfWith(comparator: Comparator<in R>, selector: Function1<UShort, R>): R
+inline fun <R> UShortArray.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<UShort, R>): R?
+inline fun <R> UShortArray.minOfWith(comparator: Comparator<in R>, selector: Function1<UShort, R>): R
+inline fun <R> UShortArray.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<UShort, R>): R?
+inline fun <R> UShortArray.runningFold(initial: R, operation: Function2<R, UShort, R>): List<R>
+inline fun <R> UShortArray.runningFoldIndexed(initial: R, operation: Function3<Int, R, UShort, R>): List<R>
+inline fun <R> UShortArray.scan(initial: R, operation: Function2<R, UShort, R>): List<R>
+inline fun <R> UShortArray.scanIndexed(initial: R, operation: Function3<Int, R, UShort, R>): List<R>
+inline fun <S, T : S, K, M : MutableMap<in K, S>> Grouping<T, K>.reduceTo(destination: M, operation: Function3<K, S, T, S>): M
+inline fun <S, T : S, K> Grouping<T, K>.reduce(operation: Function3<K, S, T, S>): Map<K, S>
+inline fun <S, T : S> Array<out T>.reduce(operation: Function2<S, T, S>): S
+inline fun <S, T : S> Array<out T>.reduceIndexed(operation: Function3<Int, S, T, S>): S
+inline fun <S, T : S> Array<out T>.reduceIndexedOrNull(operation: Function3<Int, S, T, S>): S?
+inline fun <S, T : S> Array<out T>.reduceOrNull(operation: Function2<S, T, S>): S?
+inline fun <S, T : S> Array<out T>.reduceRight(operation: Function2<T, S, S>): S
+inline fun <S, T : S> Array<out T>.reduceRightIndexed(operation: Function3<Int, T, S, S>): S
+inline fun <S, T : S> Array<out T>.reduceRightIndexedOrNull(operation: Function3<Int, T, S, S>): S?
+inline fun <S, T : S> Array<out T>.reduceRightOrNull(operation: Function2<T, S, S>): S?
+inline fun <S, T : S> Array<out T>.runningReduce(operation: Function2<S, T, S>): List<S>
+inline fun <S, T : S> Array<out T>.runningReduceIndexed(operation: Function3<Int, S, T, S>): List<S>
+inline fun <S, T : S> Iterable<T>.reduce(operation: Function2<S, T, S>): S
+inline fun <S, T : S> Iterable<T>.reduceIndexed(operation: Function3<Int, S, T, S>): S
+inline fun <S, T : S> Iterable<T>.reduceIndexedOrNull(operation: Function3<Int, S, T, S>): S?
+inline fun <S, T : S> Iterable<T>.reduceOrNull(operation: Function2<S, T, S>): S?
+inline fun <S, T : S> Iterable<T>.runningReduce(operation: Function2<S, T, S>): List<S>
+inline fun <S, T : S> Iterable<T>.runningReduceIndexed(operation: Function3<Int, S, T, S>): List<S>
+inline fun <S, T : S> List<T>.reduceRight(operation: Function2<T, S, S>): S
+inline fun <S, T : S> List<T>.reduceRightIndexed(operation: Function3<Int, T, S, S>): S
+inline fun <S, T : S> List<T>.reduceRightIndexedOrNull(operation: Function3<Int, T, S, S>): S?
+inline fun <S, T : S> List<T>.reduceRightOrNull(operation: Function2<T, S, S>): S?
+inline fun <T : Comparable<T>> Array<out T>.sort(): Unit
+inline fun <T, C : Iterable<T>> C.onEach(action: Function1<T, Unit>): C
+inline fun <T, C : Iterable<T>> C.onEachIndexed(action: Function2<Int, T, Unit>): C
+inline fun <T, C : MutableCollection<in T>> Array<out T>.filterIndexedTo(destination: C, predicate: Function2<Int, T, Boolean>): C
+inline fun <T, C : MutableCollection<in T>> Array<out T>.filterNotTo(destination: C, predicate: Function1<T, Boolean>): C
+inline fun <T, C : MutableCollection<in T>> Array<out T>.filterTo(destination: C, predicate: Function1<T, Boolean>): C
+inline fun <T, C : MutableCollection<in T>> Iterable<T>.filterIndexedTo(destination: C, predicate: Function2<Int, T, Boolean>): C
+inline fun <T, C : MutableCollection<in T>> Iterable<T>.filterNotTo(destination: C, predicate: Function1<T, Boolean>): C
+inline fun <T, C : MutableCollection<in T>> Iterable<T>.filterTo(destination: C, predicate: Function1<T, Boolean>): C
+inline fun <T, K : Comparable<K>> List<T>.binarySearchBy(key: K?, fromIndex: Int = ..., toIndex: Int = ..., selector: Function1<T, K?>): Int
+inline fun <T, K, M : MutableMap<in K, MutableList<T>>> Array<out T>.groupByTo(destination: M, keySelector: Function1<T, K>): M
+inline fun <T, K, M : MutableMap<in K, MutableList<T>>> Iterable<T>.groupByTo(destination: M, keySelector: Function1<T, K>): M
+inline fun <T, K, M : MutableMap<in K, in T>> Array<out T>.associateByTo(destination: M, keySelector: Function1<T, K>): M
+inline fun <T, K, M : MutableMap<in K, in T>> Iterable<T>.associateByTo(destination: M, keySelector: Function1<T, K>): M
+inline fun <T, K, R, M : MutableMap<in K, R>> Grouping<T, K>.aggregateTo(destination: M, operation: Function4<K, R?, T, Boolean, R>): M
+inline fun <T, K, R, M : MutableMap<in K, R>> Grouping<T, K>.foldTo(destination: M, initialValue: R, operation: Function2<R, T, R>): M
+inline fun <T, K, R, M : MutableMap<in K, R>> Grouping<T, K>.foldTo(destination: M, initialValueSelector: Function2<K, T, R>, operation: Function3<K, R, T, R>): M
+inline fun <T, K, R> Grouping<T, K>.aggregate(operation: Function4<K, R?, T, Boolean, R>): Map<K, R>
+inline fun <T, K, R> Grouping<T, K>.fold(initialValue: R, operation: Function2<R, T, R>): Map<K, R>
+inline fun <T, K, R> Grouping<T, K>.fold(initialValueSelector: Function2<K, T, R>, operation: Function3<K, R, T, R>): Map<K, R>
+inline fun <T, K, V, M : MutableMap<in K, MutableList<V>>> Array<out T>.groupByTo(destination: M, keySelector: Function1<T, K>, valueTransform: Function1<T, V>): M
+inline fun <T, K, V, M : MutableMap<in K, MutableList<V>>> Iterable<T>.groupByTo(destination: M, keySelector: Function1<T, K>, valueTransform: Function1<T, V>): M
+inline fun <T, K, V, M : MutableMap<in K, in V>> Array<out T>.associateByTo(destination: M, keySelector: Function1<T, K>, valueTransform: Function1<T, V>): M
+inline fun <T, K, V, M : MutableMap<in K, in V>> Array<out T>.associateTo(destination: M, transform: Function1<T, Pair<K, V>>): M
+inline fun <T, K, V, M : MutableMap<in K, in V>> Iterable<T>.associateByTo(destination: M, keySelector: Function1<T, K>, valueTransform: Function1<T, V>): M
+inline fun <T, K, V, M : MutableMap<in K, in V>> Iterable<T>.associateTo(destination: M, transform: Function1<T, Pair<K, V>>): M
+inline fun <T, K, V> Array<out T>.associate(transform: Function1<T, Pair<K, V>>): Map<K, V>
+inline fun <T, K, V> Array<out T>.associateBy(keySelector: Function1<T, K>, valueTransform: Function1<T, V>): Map<K, V>
+inline fun <T, K, V> Array<out T>.groupBy(keySelector: Function1<T, K>, valueTransform: Function1<T, V>): Map<K, List<V>>
+inline fun <T, K, V> Iterable<T>.associate(transform: Function1<T, Pair<K, V>>): Map<K, V>
+inline fun <T, K, V> Iterable<T>.associateBy(keySelector: Function1<T, K>, valueTransform: Function1<T, V>): Map<K, V>
+inline fun <T, K, V> Iterable<T>.groupBy(keySelector: Function1<T, K>, valueTransform: Function1<T, V>): Map<K, List<V>>
+inline fun <T, K> Array<out T>.associateBy(keySelector: Function1<T, K>): Map<K, T>
+inline fun <T, K> Array<out T>.distinctBy(selector: Function1<T, K>): List<T>
+inline fun <T, K> Array<out T>.groupBy(keySelector: Function1<T, K>): Map<K, List<T>>
+inline fun <T, K> Array<out T>.groupingBy(keySelector: Function1<T, K>): Grouping<T, K>
+inline fun <T, K> Iterable<T>.associateBy(keySelector: Function1<T, K>): Map<K, T>
+inline fun <T, K> Iterable<T>.distinctBy(selector: Function1<T, K>): List<T>
+inline fun <T, K> Iterable<T>.groupBy(keySelector: Function1<T, K>): Map<K, List<T>>
+inline fun <T, K> Iterable<T>.groupingBy(keySelector: Function1<T, K>): Grouping<T, K>
+inline fun <T, R : Any, C : MutableCollection<in R>> Array<out T>.mapIndexedNotNullTo(destination: C, transform: Function2<Int, T, R?>): C
+inline fun <T, R : Any, C : MutableCollection<in R>> Array<out T>.mapNotNullTo(destination: C, transform: Function1<T, R?>): C
+inline fun <T, R : Any, C : MutableCollection<in R>> Iterable<T>.mapIndexedNotNullTo(destination: C, transform: Function2<Int, T, R?>): C
+inline fun <T, R : Any, C : MutableCollection<in R>> Iterable<T>.mapNotNullTo(destination: C, transform: Function1<T, R?>): C
+inline fun <T, R : Any> Array<out T>.firstNotNullOf(transform: Function1<T, R?>): R
+inline fun <T, R : Any> Array<out T>.firstNotNullOfOrNull(transform: Function1<T, R?>): R?
+inline fun <T, R : Any> Array<out T>.mapIndexedNotNull(transform: Function2<Int, T, R?>): List<R>
+inline fun <T, R : Any> Array<out T>.mapNotNull(transform: Function1<T, R?>): List<R>
+inline fun <T, R : Any> Iterable<T>.firstNotNullOf(transform: Function1<T, R?>): R
+inline fun <T, R : Any> Iterable<T>.firstNotNullOfOrNull(transform: Function1<T, R?>): R?
+inline fun <T, R : Any> Iterable<T>.mapIndexedNotNull(transform: Function2<Int, T, R?>): List<R>
+inline fun <T, R : Any> Iterable<T>.mapNotNull(transform: Function1<T, R?>): List<R>
+inline fun <T, R : Comparable<R>> Array<out T>.maxBy(selector: Function1<T, R>): T
+inline fun <T, R : Comparable<R>> Array<out T>.maxBy(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Array<out T>.maxByOrNull(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Array<out T>.maxOf(selector: Function1<T, R>): R
+inline fun <T, R : Comparable<R>> Array<out T>.maxOfOrNull(selector: Function1<T, R>): R?
+inline fun <T, R : Comparable<R>> Array<out T>.minBy(selector: Function1<T, R>): T
+inline fun <T, R : Comparable<R>> Array<out T>.minBy(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Array<out T>.minByOrNull(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Array<out T>.minOf(selector: Function1<T, R>): R
+inline fun <T, R : Comparable<R>> Array<out T>.minOfOrNull(selector: Function1<T, R>): R?
+inline fun <T, R : Comparable<R>> Array<out T>.sortBy(selector: Function1<T, R?>): Unit
+inline fun <T, R : Comparable<R>> Array<out T>.sortByDescending(selector: Function1<T, R?>): Unit
+inline fun <T, R : Comparable<R>> Array<out T>.sortedBy(selector: Function1<T, R?>): List<T>
+inline fun <T, R : Comparable<R>> Array<out T>.sortedByDescending(selector: Function1<T, R?>): List<T>
+inline fun <T, R : Comparable<R>> Iterable<T>.maxBy(selector: Function1<T, R>): T
+inline fun <T, R : Comparable<R>> Iterable<T>.maxBy(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Iterable<T>.maxByOrNull(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Iterable<T>.maxOf(selector: Function1<T, R>): R
+inline fun <T, R : Comparable<R>> Iterable<T>.maxOfOrNull(selector: Function1<T, R>): R?
+inline fun <T, R : Comparable<R>> Iterable<T>.minBy(selector: Function1<T, R>): T
+inline fun <T, R : Comparable<R>> Iterable<T>.minBy(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Iterable<T>.minByOrNull(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Iterable<T>.minOf(selector: Function1<T, R>): R
+inline fun <T, R : Comparable<R>> Iterable<T>.minOfOrNull(selector: Function1<T, R>): R?
+inline fun <T, R : Comparable<R>> Iterable<T>.sortedBy(selector: Function1<T, R?>): List<T>
+inline fun <T, R : Comparable<R>> Iterable<T>.sortedByDescending(selector: Function1<T, R?>): List<T>
+inline fun <T, R : Comparable<R>> MutableList<T>.sortBy(selector: Function1<T, R?>): Unit
+inline fun <T, R : Comparable<R>> MutableList<T>.sortByDescending(selector: Function1<T, R?>): Unit
+inline fun <T, R, C : MutableCollection<in R>> Array<out T>.flatMapIndexedTo(destination: C, transform: Function2<Int, T, Iterable<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Array<out T>.flatMapIndexedTo(destination: C, transform: Function2<Int, T, Sequence<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Array<out T>.flatMapTo(destination: C, transform: Function1<T, Iterable<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Array<out T>.flatMapTo(destination: C, transform: Function1<T, Sequence<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Array<out T>.mapIndexedTo(destination: C, transform: Function2<Int, T, R>): C
+inline fun <T, R, C : MutableCollection<in R>> Array<out T>.mapTo(destination: C, transform: Function1<T, R>): C
+inline fun <T, R, C : MutableCollection<in R>> Iterable<T>.flatMapIndexedTo(destination: C, transform: Function2<Int, T, Iterable<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Iterable<T>.flatMapIndexedTo(destination: C, transform: Function2<Int, T, Sequence<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Iterable<T>.flatMapTo(destination: C, transform: Function1<T, Iterable<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Iterable<T>.flatMapTo(destination: C, transform: Function1<T, Sequence<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Iterable<T>.mapIndexedTo(destination: C, transform: Function2<Int, T, R>): C
+inline fun <T, R, C : MutableCollection<in R>> Iterable<T>.mapTo(destination: C, transform: Function1<T, R>): C
+inline fun <T, R, V> Array<out T>.zip(other: Array<out R>, transform: Function2<T, R, V>): List<V>
+inline fun <T, R, V> Array<out T>.zip(other: Iterable<R>, transform: Function2<T, R, V>): List<V>
+inline fun <T, R, V> Iterable<T>.zip(other: Array<out R>, transform: Function2<T, R, V>): List<V>
+inline fun <T, R, V> Iterable<T>.zip(other: Iterable<R>, transform: Function2<T, R, V>): List<V>
+inline fun <T, R> Array<out T>.flatMap(transform: Function1<T, Iterable<R>>): List<R>
+inline fun <T, R> Array<out T>.flatMap(transform: Function1<T, Sequence<R>>): List<R>
+inline fun <T, R> Array<out T>.flatMapIndexed(transform: Function2<Int, T, Iterable<R>>): List<R>
+inline fun <T, R> Array<out T>.flatMapIndexed(transform: Function2<Int, T, Sequence<R>>): List<R>
+inline fun <T, R> Array<out T>.fold(initial: R, operation: Function2<R, T, R>): R
+inline fun <T, R> Array<out T>.foldIndexed(initial: R, operation: Function3<Int, R, T, R>): R
+inline fun <T, R> Array<out T>.foldRight(initial: R, operation: Function2<T, R, R>): R
+inline fun <T, R> Array<out T>.foldRightIndexed(initial: R, operation: Function3<Int, T, R, R>): R
+inline fun <T, R> Array<out T>.map(transform: Function1<T, R>): List<R>
+inline fun <T, R> Array<out T>.mapIndexed(transform: Function2<Int, T, R>): List<R>
+inline fun <T, R> Array<out T>.maxOfWith(comparator: Comparator<in R>, selector: Function1<T, R>): R
+inline fun <T, R> Array<out T>.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<T, R>): R?
+inline fun <T, R> Array<out T>.minOfWith(comparator: Comparator<in R>, selector: Function1<T, R>): R
+inline fun <T, R> Array<out T>.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<T, R>): R?
+inline fun <T, R> Array<out T>.runningFold(initial: R, operation: Function2<R, T, R>): List<R>
+inline fun <T, R> Array<out T>.runningFoldIndexed(initial: R, operation: Function3<Int, R, T, R>): List<R>
+inline fun <T, R> Array<out T>.scan(initial: R, operation: Function2<R, T, R>): List<R>
+inline fun <T, R> Array<out T>.scanIndexed(initial: R, operation: Function3<Int, R, T, R>): List<R>
+inline fun <T, R> Iterable<T>.flatMap(transform: Function1<T, Iterable<R>>): List<R>
+inline fun <T, R> Iterable<T>.flatMap(transform: Function1<T, Sequence<R>>): List<R>
+inline fun <T, R> Iterable<T>.flatMapIndexed(transform: Function2<Int, T, Iterable<R>>): List<R>
+inline fun <T, R> Iterable<T>.flatMapIndexed(transform: Function2<Int, T, Sequence<R>>): List<R>
+inline fun <T, R> Iterable<T>.fold(initial: R, operation: Function2<R, T, R>): R
+inline fun <T, R> Iterable<T>.foldIndexed(initial: R, operation: Function3<Int, R, T, R>): R
+inline fun <T, R> Iterable<T>.map(transform: Function1<T, R>): List<R>
+inline fun <T, R> Iterable<T>.mapIndexed(transform: Function2<Int, T, R>): List<R>
+inline fun <T, R> Iterable<T>.maxOfWith(comparator: Comparator<in R>, selector: Function1<T, R>): R
+inline fun <T, R> Iterable<T>.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<T, R>): R?
+inline fun <T, R> Iterable<T>.minOfWith(comparator: Comparator<in R>, selector: Function1<T, R>): R
+inline fun <T, R> Iterable<T>.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<T, R>): R?
+inline fun <T, R> Iterable<T>.runningFold(initial: R, operation: Function2<R, T, R>): List<R>
+inline fun <T, R> Iterable<T>.runningFoldIndexed(initial: R, operation: Function3<Int, R, T, R>): List<R>
+inline fun <T, R> Iterable<T>.scan(initial: R, operation: Function2<R, T, R>): List<R>
+inline fun <T, R> Iterable<T>.scanIndexed(initial: R, operation: Function3<Int, R, T, R>): List<R>
+inline fun <T, R> Iterable<T>.zipWithNext(transform: Function2<T, T, R>): List<R>
+inline fun <T, R> List<T>.foldRight(initial: R, operation: Function2<T, R, R>): R
+inline fun <T, R> List<T>.foldRightIndexed(initial: R, operation: Function3<Int, T, R, R>): R
+inline fun <T> Array<T>.copyOf(): Array<T>
+inline fun <T> Array<T>.copyOf(newSize: Int): Array<T?>
+inline fun <T> Array<T>.copyOfRange(fromIndex: Int, toIndex: Int): Array<T>
+inline fun <T> Array<T>.plusElement(element: T): Array<T>
+inline fun <T> Array<out T>.all(predicate: Function1<T, Boolean>): Boolean
+inline fun <T> Array<out T>.any(predicate: Function1<T, Boolean>): Boolean
+inline fun <T> Array<out T>.contentDeepHashCode(): Int
+inline fun <T> Array<out T>.contentDeepToString(): String
+inline fun <T> Array<out T>.contentHashCode(): Int
+inline fun <T> Array<out T>.contentToString(): String
+inline fun <T> Array<out T>.count(): Int
+inline fun <T> Array<out T>.count(predicate: Function1<T, Boolean>): Int
+inline fun <T> Array<out T>.dropLastWhile(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> Array<out T>.dropWhile(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> Array<out T>.elementAt(index: Int): T
+inline fun <T> Array<out T>.elementAtOrElse(index: Int, defaultValue: Function1<Int, T>): T
+inline fun <T> Array<out T>.elementAtOrNull(index: Int): T?
+inline fun <T> Array<out T>.filter(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> Array<out T>.filterIndexed(predicate: Function2<Int, T, Boolean>): List<T>
+inline fun <T> Array<out T>.filterNot(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> Array<out T>.find(predicate: Function1<T, Boolean>): T?
+inline fun <T> Array<out T>.findLast(predicate: Function1<T, Boolean>): T?
+inline fun <T> Array<out T>.first(predicate: Function1<T, Boolean>): T
+inline fun <T> Array<out T>.firstOrNull(predicate: Function1<T, Boolean>): T?
+inline fun <T> Array<out T>.forEach(action: Function1<T, Unit>): Unit
+inline fun <T> Array<out T>.forEachIndexed(action: Function2<Int, T, Unit>): Unit
+inline fun <T> Array<out T>.getOrElse(index: Int, defaultValue: Function1<Int, T>): T
+inline fun <T> Array<out T>.indexOfFirst(predicate: Function1<T, Boolean>): Int
+inline fun <T> Array<out T>.indexOfLast(predicate: Function1<T, Boolean>): Int
+inline fun <T> Array<out T>.isEmpty(): Boolean
+inline fun <T> Array<out T>.isNotEmpty(): Boolean
+inline fun <T> Array<out T>.last(predicate: Function1<T, Boolean>): T
+inline fun <T> Array<out T>.lastOrNull(predicate: Function1<T, Boolean>): T?
+inline fun <T> Array<out T>.maxOf(selector: Function1<T, Double>): Double
+inline fun <T> Array<out T>.maxOf(selector: Function1<T, Float>): Float
+inline fun <T> Array<out T>.maxOfOrNull(selector: Function1<T, Double>): Double?
+inline fun <T> Array<out T>.maxOfOrNull(selector: Function1<T, Float>): Float?
+inline fun <T> Array<out T>.minOf(selector: Function1<T, Double>): Double
+inline fun <T> Array<out T>.minOf(selector: Function1<T, Float>): Float
+inline fun <T> Array<out T>.minOfOrNull(selector: Function1<T, Double>): Double?
+inline fun <T> Array<out T>.minOfOrNull(selector: Function1<T, Float>): Float?
+inline fun <T> Array<out T>.none(predicate: Function1<T, Boolean>): Boolean
+inline fun <T> Array<out T>.onEach(action: Function1<T, Unit>): Array<out T>
+inline fun <T> Array<out T>.onEachIndexed(action: Function2<Int, T, Unit>): Array<out T>
+inline fun <T> Array<out T>.partition(predicate: Function1<T, Boolean>): Pair<List<T>, List<T>>
+inline fun <T> Array<out T>.random(): T
+inline fun <T> Array<out T>.randomOrNull(): T?
+inline fun <T> Array<out T>.single(predicate: Function1<T, Boolean>): T
+inline fun <T> Array<out T>.singleOrNull(predicate: Function1<T, Boolean>): T?
+inline fun <T> Array<out T>.sumBy(selector: Function1<T, Int>): Int
+inline fun <T> Array<out T>.sumByDouble(selector: Function1<T, Double>): Double
+inline fun <T> Array<out T>.sumOf(selector: Function1<T, BigDecimal>): BigDecimal
+inline fun <T> Array<out T>.sumOf(selector: Function1<T, BigInteger>): BigInteger
+inline fun <T> Array<out T>.sumOf(selector: Function1<T, Double>): Double
+inline fun <T> Array<out T>.sumOf(selector: Function1<T, Int>): Int
+inline fun <T> Array<out T>.sumOf(selector: Function1<T, Long>): Long
+inline fun <T> Array<out T>.sumOf(selector: Function1<T, UInt>): UInt
+inline fun <T> Array<out T>.sumOf(selector: Function1<T, ULong>): ULong
+inline fun <T> Array<out T>.takeLastWhile(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> Array<out T>.takeWhile(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> Array<out T>?.contentDeepHashCode(): Int
+inline fun <T> Array<out T>?.contentDeepToString(): String
+inline fun <T> Array<out T>?.contentHashCode(): Int
+inline fun <T> Array<out T>?.contentToString(): String
+inline fun <T> Collection<T>.containsAll(elements: Collection<T>): Boolean
+inline fun <T> Collection<T>.count(): Int
+inline fun <T> Collection<T>.isNotEmpty(): Boolean
+inline fun <T> Collection<T>.plusElement(element: T): List<T>
+inline fun <T> Collection<T>.random(): T
+inline fun <T> Collection<T>.randomOrNull(): T?
+inline fun <T> Collection<T>?.isNullOrEmpty(): Boolean
+inline fun <T> Collection<T>?.orEmpty(): Collection<T>
+inline fun <T> Enumeration<T>.toList(): List<T>
+inline fun <T> Iterable(iterator: Function0<Iterator<T>>): Iterable<T>
+inline fun <T> Iterable<T>.all(predicate: Function1<T, Boolean>): Boolean
+inline fun <T> Iterable<T>.any(predicate: Function1<T, Boolean>): Boolean
+inline fun <T> Iterable<T>.asIterable(): Iterable<T>
+inline fun <T> Iterable<T>.count(predicate: Function1<T, Boolean>): Int
+inline fun <T> Iterable<T>.dropWhile(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> Iterable<T>.filter(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> Iterable<T>.filterIndexed(predicate: Function2<Int, T, Boolean>): List<T>
+inline fun <T> Iterable<T>.filterNot(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> Iterable<T>.find(predicate: Function1<T, Boolean>): T?
+inline fun <T> Iterable<T>.findLast(predicate: Function1<T, Boolean>): T?
+inline fun <T> Iterable<T>.first(predicate: Function1<T, Boolean>): T
+inline fun <T> Iterable<T>.firstOrNull(predicate: Function1<T, Boolean>): T?
+inline fun <T> Iterable<T>.forEach(action: Function1<T, Unit>): Unit
+inline fun <T> Iterable<T>.forEachIndexed(action: Function2<Int, T, Unit>): Unit
+inline fun <T> Iterable<T>.indexOfFirst(predicate: Function1<T, Boolean>): Int
+inline fun <T> Iterable<T>.indexOfLast(predicate: Function1<T, Boolean>): Int
+inline fun <T> Iterable<T>.last(predicate: Function1<T, Boolean>): T
+inline fun <T> Iterable<T>.lastOrNull(predicate: Function1<T, Boolean>): T?
+inline fun <T> Iterable<T>.maxOf(selector: Function1<T, Double>): Double
+inline fun <T> Iterable<T>.maxOf(selector: Function1<T, Float>): Float
+inline fun <T> Iterable<T>.maxOfOrNull(selector: Function1<T, Double>): Double?
+inline fun <T> Iterable<T>.maxOfOrNull(selector: Function1<T, Float>): Float?
+inline fun <T> Iterable<T>.minOf(selector: Function1<T, Double>): Double
+inline fun <T> Iterable<T>.minOf(selector: Function1<T, Float>): Float
+inline fun <T> Iterable<T>.minOfOrNull(selector: Function1<T, Double>): Double?
+inline fun <T> Iterable<T>.minOfOrNull(selector: Function1<T, Float>): Float?
+inline fun <T> Iterable<T>.minusElement(element: T): List<T>
+inline fun <T> Iterable<T>.none(predicate: Function1<T, Boolean>): Boolean
+inline fun <T> Iterable<T>.partition(predicate: Function1<T, Boolean>): Pair<List<T>, List<T>>
+inline fun <T> Iterable<T>.plusElement(element: T): List<T>
+inline fun <T> Iterable<T>.single(predicate: Function1<T, Boolean>): T
+inline fun <T> Iterable<T>.singleOrNull(predicate: Function1<T, Boolean>): T?
+inline fun <T> Iterable<T>.sumBy(selector: Function1<T, Int>): Int
+inline fun <T> Iterable<T>.sumByDouble(selector: Function1<T, Double>): Double
+inline fun <T> Iterable<T>.sumOf(selector: Function1<T, BigDecimal>): BigDecimal
+inline fun <T> Iterable<T>.sumOf(selector: Function1<T, BigInteger>): BigInteger
+inline fun <T> Iterable<T>.sumOf(selector: Function1<T, Double>): Double
+inline fun <T> Iterable<T>.sumOf(selector: Function1<T, Int>): Int
+inline fun <T> Iterable<T>.sumOf(selector: Function1<T, Long>): Long
+inline fun <T> Iterable<T>.sumOf(selector: Function1<T, UInt>): UInt
+inline fun <T> Iterable<T>.sumOf(selector: Function1<T, ULong>): ULong
+inline fun <T> Iterable<T>.takeWhile(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> Iterator<T>.forEach(operation: Function1<T, Unit>): Unit
+inline fun <T> List(size: Int, init: Function1<Int, T>): List<T>
+inline fun <T> List<T>.dropLastWhile(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> List<T>.elementAt(index: Int): T
+inline fun <T> List<T>.elementAtOrElse(index: Int, defaultValue: Function1<Int, T>): T
+inline fun <T> List<T>.elementAtOrNull(index: Int): T?
+inline fun <T> List<T>.findLast(predicate: Function1<T, Boolean>): T?
+inline fun <T> List<T>.getOrElse(index: Int, defaultValue: Function1<Int, T>): T
+inline fun <T> List<T>.indexOfFirst(predicate: Function1<T, Boolean>): Int
+inline fun <T> List<T>.indexOfLast(predicate: Function1<T, Boolean>): Int
+inline fun <T> List<T>.last(predicate: Function1<T, Boolean>): T
+inline fun <T> List<T>.lastOrNull(predicate: Function1<T, Boolean>): T?
+inline fun <T> List<T>.takeLastWhile(predicate: Function1<T, Boolean>): List<T>
+inline fun <T> List<T>?.orEmpty(): List<T>
+inline fun <T> MutableCollection<out T>.remove(element: T): Boolean
+inline fun <T> MutableCollection<out T>.removeAll(elements: Collection<T>): Boolean
+inline fun <T> MutableCollection<out T>.retainAll(elements: Collection<T>): Boolean
+inline fun <T> MutableList(size: Int, init: Function1<Int, T>): MutableList<T>
+inline fun <T> MutableList<T>.fill(value: T): Unit
+inline fun <T> MutableList<T>.remove(index: Int): T
+inline fun <T> MutableList<T>.shuffle(): Unit
+inline fun <T> MutableList<T>.shuffle(random: Random): Unit
+inline fun <T> MutableList<T>.sort(comparator: Comparator<in T>): Unit
+inline fun <T> MutableList<T>.sort(comparison: Function2<T, T, Int>): Unit
+inline fun <T> Set<T>.minusElement(element: T): Set<T>
+inline fun <T> Set<T>.plusElement(element: T): Set<T>
+inline fun <T> Set<T>?.orEmpty(): Set<T>
+inline fun <T> arrayListOf(): ArrayList<T>
+inline fun <T> hashSetOf(): HashSet<T>
+inline fun <T> linkedSetOf(): LinkedHashSet<T>
+inline fun <T> listOf(): List<T>
+inline fun <T> mutableListOf(): MutableList<T>
+inline fun <T> mutableSetOf(): MutableSet<T>
+inline fun <T> setOf(): Set<T>
+inline fun <V, M : MutableMap<in Boolean, in V>> BooleanArray.associateWithTo(destination: M, valueSelector: Function1<Boolean, V>): M
+inline fun <V, M : MutableMap<in Byte, in V>> ByteArray.associateWithTo(destination: M, valueSelector: Function1<Byte, V>): M
+inline fun <V, M : MutableMap<in Char, in V>> CharArray.associateWithTo(destination: M, valueSelector: Function1<Char, V>): M
+inline fun <V, M : MutableMap<in Double, in V>> DoubleArray.associateWithTo(destination: M, valueSelector: Function1<Double, V>): M
+inline fun <V, M : MutableMap<in Float, in V>> FloatArray.associateWithTo(destination: M, valueSelector: Function1<Float, V>): M
+inline fun <V, M : MutableMap<in Int, in V>> IntArray.associateWithTo(destination: M, valueSelector: Function1<Int, V>): M
+inline fun <V, M : MutableMap<in Long, in V>> LongArray.associateWithTo(destination: M, valueSelector: Function1<Long, V>): M
+inline fun <V, M : MutableMap<in Short, in V>> ShortArray.associateWithTo(destination: M, valueSelector: Function1<Short, V>): M
+inline fun <V, M : MutableMap<in UByte, in V>> UByteArray.associateWithTo(destination: M, valueSelector: Function1<UByte, V>): M
+inline fun <V, M : MutableMap<in UInt, in V>> UIntArray.associateWithTo(destination: M, valueSelector: Function1<UInt, V>): M
+inline fun <V, M : MutableMap<in ULong, in V>> ULongArray.associateWithTo(destination: M, valueSelector: Function1<ULong, V>): M
+inline fun <V, M : MutableMap<in UShort, in V>> UShortArray.associateWithTo(destination: M, valueSelector: Function1<UShort, V>): M
+inline fun <V> BooleanArray.associateWith(valueSelector: Function1<Boolean, V>): Map<Boolean, V>
+inline fun <V> BooleanArray.zip(other: BooleanArray, transform: Function2<Boolean, Boolean, V>): List<V>
+inline fun <V> ByteArray.associateWith(valueSelector: Function1<Byte, V>): Map<Byte, V>
+inline fun <V> ByteArray.zip(other: ByteArray, transform: Function2<Byte, Byte, V>): List<V>
+inline fun <V> CharArray.associateWith(valueSelector: Function1<Char, V>): Map<Char, V>
+inline fun <V> CharArray.zip(other: CharArray, transform: Function2<Char, Char, V>): List<V>
+inline fun <V> DoubleArray.associateWith(valueSelector: Function1<Double, V>): Map<Double, V>
+inline fun <V> DoubleArray.zip(other: DoubleArray, transform: Function2<Double, Double, V>): List<V>
+inline fun <V> FloatArray.associateWith(valueSelector: Function1<Float, V>): Map<Float, V>
+inline fun <V> FloatArray.zip(other: FloatArray, transform: Function2<Float, Float, V>): List<V>
+inline fun <V> IntArray.associateWith(valueSelector: Function1<Int, V>): Map<Int, V>
+inline fun <V> IntArray.zip(other: IntArray, transform: Function2<Int, Int, V>): List<V>
+inline fun <V> LongArray.associateWith(valueSelector: Function1<Long, V>): Map<Long, V>
+inline fun <V> LongArray.zip(other: LongArray, transform: Function2<Long, Long, V>): List<V>
+inline fun <V> ShortArray.associateWith(valueSelector: Function1<Short, V>): Map<Short, V>
+inline fun <V> ShortArray.zip(other: ShortArray, transform: Function2<Short, Short, V>): List<V>
+inline fun <V> UByteArray.associateWith(valueSelector: Function1<UByte, V>): Map<UByte, V>
+inline fun <V> UByteArray.zip(other: UByteArray, transform: Function2<UByte, UByte, V>): List<V>
+inline fun <V> UIntArray.associateWith(valueSelector: Function1<UInt, V>): Map<UInt, V>
+inline fun <V> UIntArray.zip(other: UIntArray, transform: Function2<UInt, UInt, V>): List<V>
+inline fun <V> ULongArray.associateWith(valueSelector: Function1<ULong, V>): Map<ULong, V>
+inline fun <V> ULongArray.zip(other: ULongArray, transform: Function2<ULong, ULong, V>): List<V>
+inline fun <V> UShortArray.associateWith(valueSelector: Function1<UShort, V>): Map<UShort, V>
+inline fun <V> UShortArray.zip(other: UShortArray, transform: Function2<UShort, UShort, V>): List<V>
+inline fun <reified R, C : MutableCollection<in R>> Array<*>.filterIsInstanceTo(destination: C): C
+inline fun <reified R, C : MutableCollection<in R>> Iterable<*>.filterIsInstanceTo(destination: C): C
+inline fun <reified R> Array<*>.filterIsInstance(): List<R>
+inline fun <reified R> Iterable<*>.filterIsInstance(): List<R>
+inline fun <reified T> Array<out T>?.orEmpty(): Array<out T>
+inline fun <reified T> Collection<T>.toTypedArray(): Array<T>
+inline fun Array<*>?.isNullOrEmpty(): Boolean
+inline fun BooleanArray.all(predicate: Function1<Boolean, Boolean>): Boolean
+inline fun BooleanArray.any(predicate: Function1<Boolean, Boolean>): Boolean
+inline fun BooleanArray.contentHashCode(): Int
+inline fun BooleanArray.contentToString(): String
+inline fun BooleanArray.copyOf(): BooleanArray
+inline fun BooleanArray.copyOf(newSize: Int): BooleanArray
+inline fun BooleanArray.copyOfRange(fromIndex: Int, toIndex: Int): BooleanArray
+inline fun BooleanArray.count(): Int
+inline fun BooleanArray.count(predicate: Function1<Boolean, Boolean>): Int
+inline fun BooleanArray.dropLastWhile(predicate: Function1<Boolean, Boolean>): List<Boolean>
+inline fun BooleanArray.dropWhile(predicate: Function1<Boolean, Boolean>): List<Boolean>
+inline fun BooleanArray.elementAt(index: Int): Boolean
+inline fun BooleanArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, Boolean>): Boolean
+inline fun BooleanArray.elementAtOrNull(index: Int): Boolean?
+inline fun BooleanArray.filter(predicate: Function1<Boolean, Boolean>): List<Boolean>
+inline fun BooleanArray.filterIndexed(predicate: Function2<Int, Boolean, Boolean>): List<Boolean>
+inline fun BooleanArray.filterNot(predicate: Function1<Boolean, Boolean>): List<Boolean>
+inline fun BooleanArray.find(predicate: Function1<Boolean, Boolean>): Boolean?
+inline fun BooleanArray.findLast(predicate: Function1<Boolean, Boolean>): Boolean?
+inline fun BooleanArray.first(predicate: Function1<Boolean, Boolean>): Boolean
+inline fun BooleanArray.firstOrNull(predicate: Function1<Boolean, Boolean>): Boolean?
+inline fun BooleanArray.forEach(action: Function1<Boolean, Unit>): Unit
+inline fun BooleanArray.forEachIndexed(action: Function2<Int, Boolean, Unit>): Unit
+inline fun BooleanArray.getOrElse(index: Int, defaultValue: Function1<Int, Boolean>): Boolean
+inline fun BooleanArray.indexOfFirst(predicate: Function1<Boolean, Boolean>): Int
+inline fun BooleanArray.indexOfLast(predicate: Function1<Boolean, Boolean>): Int
+inline fun BooleanArray.isEmpty(): Boolean
+inline fun BooleanArray.isNotEmpty(): Boolean
+inline fun BooleanArray.last(predicate: Function1<Boolean, Boolean>): Boolean
+inline fun BooleanArray.lastOrNull(predicate: Function1<Boolean, Boolean>): Boolean?
+inline fun BooleanArray.maxOf(selector: Function1<Boolean, Double>): Double
+inline fun BooleanArray.maxOf(selector: Function1<Boolean, Float>): Float
+inline fun BooleanArray.maxOfOrNull(selector: Function1<Boolean, Double>): Double?
+inline fun BooleanArray.maxOfOrNull(selector: Function1<Boolean, Float>): Float?
+inline fun BooleanArray.minOf(selector: Function1<Boolean, Double>): Double
+inline fun BooleanArray.minOf(selector: Function1<Boolean, Float>): Float
+inline fun BooleanArray.minOfOrNull(selector: Function1<Boolean, Double>): Double?
+inline fun BooleanArray.minOfOrNull(selector: Function1<Boolean, Float>): Float?
+inline fun BooleanArray.none(predicate: Function1<Boolean, Boolean>): Boolean
+inline fun BooleanArray.onEach(action: Function1<Boolean, Unit>): BooleanArray
+inline fun BooleanArray.onEachIndexed(action: Function2<Int, Boolean, Unit>): BooleanArray
+inline fun BooleanArray.partition(predicate: Function1<Boolean, Boolean>): Pair<List<Boolean>, List<Boolean>>
+inline fun BooleanArray.random(): Boolean
+inline fun BooleanArray.randomOrNull(): Boolean?
+inline fun BooleanArray.reduce(operation: Function2<Boolean, Boolean, Boolean>): Boolean
+inline fun BooleanArray.reduceIndexed(operation: Function3<Int, Boolean, Boolean, Boolean>): Boolean
+inline fun BooleanArray.reduceIndexedOrNull(operation: Function3<Int, Boolean, Boolean, Boolean>): Boolean?
+inline fun BooleanArray.reduceOrNull(operation: Function2<Boolean, Boolean, Boolean>): Boolean?
+inline fun BooleanArray.reduceRight(operation: Function2<Boolean, Boolean, Boolean>): Boolean
+inline fun BooleanArray.reduceRightIndexed(operation: Function3<Int, Boolean, Boolean, Boolean>): Boolean
+inline fun BooleanArray.reduceRightIndexedOrNull(operation: Function3<Int, Boolean, Boolean, Boolean>): Boolean?
+inline fun BooleanArray.reduceRightOrNull(operation: Function2<Boolean, Boolean, Boolean>): Boolean?
+inline fun BooleanArray.runningReduce(operation: Function2<Boolean, Boolean, Boolean>): List<Boolean>
+inline fun BooleanArray.runningReduceIndexed(operation: Function3<Int, Boolean, Boolean, Boolean>): List<Boolean>
+inline fun BooleanArray.single(predicate: Function1<Boolean, Boolean>): Boolean
+inline fun BooleanArray.singleOrNull(predicate: Function1<Boolean, Boolean>): Boolean?
+inline fun BooleanArray.sumBy(selector: Function1<Boolean, Int>): Int
+inline fun BooleanArray.sumByDouble(selector: Function1<Boolean, Double>): Double
+inline fun BooleanArray.sumOf(selector: Function1<Boolean, BigDecimal>): BigDecimal
+inline fun BooleanArray.sumOf(selector: Function1<Boolean, BigInteger>): BigInteger
+inline fun BooleanArray.sumOf(selector: Function1<Boolean, Double>): Double
+inline fun BooleanArray.sumOf(selector: Function1<Boolean, Int>): Int
+inline fun BooleanArray.sumOf(selector: Function1<Boolean, Long>): Long
+inline fun BooleanArray.sumOf(selector: Function1<Boolean, UInt>): UInt
+inline fun BooleanArray.sumOf(selector: Function1<Boolean, ULong>): ULong
+inline fun BooleanArray.takeLastWhile(predicate: Function1<Boolean, Boolean>): List<Boolean>
+inline fun BooleanArray.takeWhile(predicate: Function1<Boolean, Boolean>): List<Boolean>
+inline fun BooleanArray?.contentHashCode(): Int
+inline fun BooleanArray?.contentToString(): String
+inline fun ByteArray.all(predicate: Function1<Byte, Boolean>): Boolean
+inline fun ByteArray.any(predicate: Function1<Byte, Boolean>): Boolean
+inline fun ByteArray.asUByteArray(): UByteArray
+inline fun ByteArray.contentHashCode(): Int
+inline fun ByteArray.contentToString(): String
+inline fun ByteArray.copyOf(): ByteArray
+inline fun ByteArray.copyOf(newSize: Int): ByteArray
+inline fun ByteArray.copyOfRange(fromIndex: Int, toIndex: Int): ByteArray
+inline fun ByteArray.count(): Int
+inline fun ByteArray.count(predicate: Function1<Byte, Boolean>): Int
+inline fun ByteArray.dropLastWhile(predicate: Function1<Byte, Boolean>): List<Byte>
+inline fun ByteArray.dropWhile(predicate: Function1<Byte, Boolean>): List<Byte>
+inline fun ByteArray.elementAt(index: Int): Byte
+inline fun ByteArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, Byte>): Byte
+inline fun ByteArray.elementAtOrNull(index: Int): Byte?
+inline fun ByteArray.filter(predicate: Function1<Byte, Boolean>): List<Byte>
+inline fun ByteArray.filterIndexed(predicate: Function2<Int, Byte, Boolean>): List<Byte>
+inline fun ByteArray.filterNot(predicate: Function1<Byte, Boolean>): List<Byte>
+inline fun ByteArray.find(predicate: Function1<Byte, Boolean>): Byte?
+inline fun ByteArray.findLast(predicate: Function1<Byte, Boolean>): Byte?
+inline fun ByteArray.first(predicate: Function1<Byte, Boolean>): Byte
+inline fun ByteArray.firstOrNull(predicate: Function1<Byte, Boolean>): Byte?
+inline fun ByteArray.forEach(action: Function1<Byte, Unit>): Unit
+inline fun ByteArray.forEachIndexed(action: Function2<Int, Byte, Unit>): Unit
+inline fun ByteArray.getOrElse(index: Int, defaultValue: Function1<Int, Byte>): Byte
+inline fun ByteArray.indexOfFirst(predicate: Function1<Byte, Boolean>): Int
+inline fun ByteArray.indexOfLast(predicate: Function1<Byte, Boolean>): Int
+inline fun ByteArray.isEmpty(): Boolean
+inline fun ByteArray.isNotEmpty(): Boolean
+inline fun ByteArray.last(predicate: Function1<Byte, Boolean>): Byte
+inline fun ByteArray.lastOrNull(predicate: Function1<Byte, Boolean>): Byte?
+inline fun ByteArray.maxOf(selector: Function1<Byte, Double>): Double
+inline fun ByteArray.maxOf(selector: Function1<Byte, Float>): Float
+inline fun ByteArray.maxOfOrNull(selector: Function1<Byte, Double>): Double?
+inline fun ByteArray.maxOfOrNull(selector: Function1<Byte, Float>): Float?
+inline fun ByteArray.minOf(selector: Function1<Byte, Double>): Double
+inline fun ByteArray.minOf(selector: Function1<Byte, Float>): Float
+inline fun ByteArray.minOfOrNull(selector: Function1<Byte, Double>): Double?
+inline fun ByteArray.minOfOrNull(selector: Function1<Byte, Float>): Float?
+inline fun ByteArray.none(predicate: Function1<Byte, Boolean>): Boolean
+inline fun ByteArray.onEach(action: Function1<Byte, Unit>): ByteArray
+inline fun ByteArray.onEachIndexed(action: Function2<Int, Byte, Unit>): ByteArray
+inline fun ByteArray.partition(predicate: Function1<Byte, Boolean>): Pair<List<Byte>, List<Byte>>
+inline fun ByteArray.random(): Byte
+inline fun ByteArray.randomOrNull(): Byte?
+inline fun ByteArray.reduce(operation: Function2<Byte, Byte, Byte>): Byte
+inline fun ByteArray.reduceIndexed(operation: Function3<Int, Byte, Byte, Byte>): Byte
+inline fun ByteArray.reduceIndexedOrNull(operation: Function3<Int, Byte, Byte, Byte>): Byte?
+inline fun ByteArray.reduceOrNull(operation: Function2<Byte, Byte, Byte>): Byte?
+inline fun ByteArray.reduceRight(operation: Function2<Byte, Byte, Byte>): Byte
+inline fun ByteArray.reduceRightIndexed(operation: Function3<Int, Byte, Byte, Byte>): Byte
+inline fun ByteArray.reduceRightIndexedOrNull(operation: Function3<Int, Byte, Byte, Byte>): Byte?
+inline fun ByteArray.reduceRightOrNull(operation: Function2<Byte, Byte, Byte>): Byte?
+inline fun ByteArray.runningReduce(operation: Function2<Byte, Byte, Byte>): List<Byte>
+inline fun ByteArray.runningReduceIndexed(operation: Function3<Int, Byte, Byte, Byte>): List<Byte>
+inline fun ByteArray.single(predicate: Function1<Byte, Boolean>): Byte
+inline fun ByteArray.singleOrNull(predicate: Function1<Byte, Boolean>): Byte?
+inline fun ByteArray.sumBy(selector: Function1<Byte, Int>): Int
+inline fun ByteArray.sumByDouble(selector: Function1<Byte, Double>): Double
+inline fun ByteArray.sumOf(selector: Function1<Byte, BigDecimal>): BigDecimal
+inline fun ByteArray.sumOf(selector: Function1<Byte, BigInteger>): BigInteger
+inline fun ByteArray.sumOf(selector: Function1<Byte, Double>): Double
+inline fun ByteArray.sumOf(selector: Function1<Byte, Int>): Int
+inline fun ByteArray.sumOf(selector: Function1<Byte, Long>): Long
+inline fun ByteArray.sumOf(selector: Function1<Byte, UInt>): UInt
+inline fun ByteArray.sumOf(selector: Function1<Byte, ULong>): ULong
+inline fun ByteArray.takeLastWhile(predicate: Function1<Byte, Boolean>): List<Byte>
+inline fun ByteArray.takeWhile(predicate: Function1<Byte, Boolean>): List<Byte>
+inline fun ByteArray.toString(charset: Charset): String
+inline fun ByteArray.toUByteArray(): UByteArray
+inline fun ByteArray?.contentHashCode(): Int
+inline fun ByteArray?.contentToString(): String
+inline fun CharArray.all(predicate: Function1<Char, Boolean>): Boolean
+inline fun CharArray.any(predicate: Function1<Char, Boolean>): Boolean
+inline fun CharArray.contentHashCode(): Int
+inline fun CharArray.contentToString(): String
+inline fun CharArray.copyOf(): CharArray
+inline fun CharArray.copyOf(newSize: Int): CharArray
+inline fun CharArray.copyOfRange(fromIndex: Int, toIndex: Int): CharArray
+inline fun CharArray.count(): Int
+inline fun CharArray.count(predicate: Function1<Char, Boolean>): Int
+inline fun CharArray.dropLastWhile(predicate: Function1<Char, Boolean>): List<Char>
+inline fun CharArray.dropWhile(predicate: Function1<Char, Boolean>): List<Char>
+inline fun CharArray.elementAt(index: Int): Char
+inline fun CharArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, Char>): Char
+inline fun CharArray.elementAtOrNull(index: Int): Char?
+inline fun CharArray.filter(predicate: Function1<Char, Boolean>): List<Char>
+inline fun CharArray.filterIndexed(predicate: Function2<Int, Char, Boolean>): List<Char>
+inline fun CharArray.filterNot(predicate: Function1<Char, Boolean>): List<Char>
+inline fun CharArray.find(predicate: Function1<Char, Boolean>): Char?
+inline fun CharArray.findLast(predicate: Function1<Char, Boolean>): Char?
+inline fun CharArray.first(predicate: Function1<Char, Boolean>): Char
+inline fun CharArray.firstOrNull(predicate: Function1<Char, Boolean>): Char?
+inline fun CharArray.forEach(action: Function1<Char, Unit>): Unit
+inline fun CharArray.forEachIndexed(action: Function2<Int, Char, Unit>): Unit
+inline fun CharArray.getOrElse(index: Int, defaultValue: Function1<Int, Char>): Char
+inline fun CharArray.indexOfFirst(predicate: Function1<Char, Boolean>): Int
+inline fun CharArray.indexOfLast(predicate: Function1<Char, Boolean>): Int
+inline fun CharArray.isEmpty(): Boolean
+inline fun CharArray.isNotEmpty(): Boolean
+inline fun CharArray.last(predicate: Function1<Char, Boolean>): Char
+inline fun CharArray.lastOrNull(predicate: Function1<Char, Boolean>): Char?
+inline fun CharArray.maxOf(selector: Function1<Char, Double>): Double
+inline fun CharArray.maxOf(selector: Function1<Char, Float>): Float
+inline fun CharArray.maxOfOrNull(selector: Function1<Char, Double>): Double?
+inline fun CharArray.maxOfOrNull(selector: Function1<Char, Float>): Float?
+inline fun CharArray.minOf(selector: Function1<Char, Double>): Double
+inline fun CharArray.minOf(selector: Function1<Char, Float>): Float
+inline fun CharArray.minOfOrNull(selector: Function1<Char, Double>): Double?
+inline fun CharArray.minOfOrNull(selector: Function1<Char, Float>): Float?
+inline fun CharArray.none(predicate: Function1<Char, Boolean>): Boolean
+inline fun CharArray.onEach(action: Function1<Char, Unit>): CharArray
+inline fun CharArray.onEachIndexed(action: Function2<Int, Char, Unit>): CharArray
+inline fun CharArray.partition(predicate: Function1<Char, Boolean>): Pair<List<Char>, List<Char>>
+inline fun CharArray.random(): Char
+inline fun CharArray.randomOrNull(): Char?
+inline fun CharArray.reduce(operation: Function2<Char, Char, Char>): Char
+inline fun CharArray.reduceIndexed(operation: Function3<Int, Char, Char, Char>): Char
+inline fun CharArray.reduceIndexedOrNull(operation: Function3<Int, Char, Char, Char>): Char?
+inline fun CharArray.reduceOrNull(operation: Function2<Char, Char, Char>): Char?
+inline fun CharArray.reduceRight(operation: Function2<Char, Char, Char>): Char
+inline fun CharArray.reduceRightIndexed(operation: Function3<Int, Char, Char, Char>): Char
+inline fun CharArray.reduceRightIndexedOrNull(operation: Function3<Int, Char, Char, Char>): Char?
+inline fun CharArray.reduceRightOrNull(operation: Function2<Char, Char, Char>): Char?
+inline fun CharArray.runningReduce(operation: Function2<Char, Char, Char>): List<Char>
+inline fun CharArray.runningReduceIndexed(operation: Function3<Int, Char, Char, Char>): List<Char>
+inline fun CharArray.single(predicate: Function1<Char, Boolean>): Char
+inline fun CharArray.singleOrNull(predicate: Function1<Char, Boolean>): Char?
+inline fun CharArray.sumBy(selector: Function1<Char, Int>): Int
+inline fun CharArray.sumByDouble(selector: Function1<Char, Double>): Double
+inline fun CharArray.sumOf(selector: Function1<Char, BigDecimal>): BigDecimal
+inline fun CharArray.sumOf(selector: Function1<Char, BigInteger>): BigInteger
+inline fun CharArray.sumOf(selector: Function1<Char, Double>): Double
+inline fun CharArray.sumOf(selector: Function1<Char, Int>): Int
+inline fun CharArray.sumOf(selector: Function1<Char, Long>): Long
+inline fun CharArray.sumOf(selector: Function1<Char, UInt>): UInt
+inline fun CharArray.sumOf(selector: Function1<Char, ULong>): ULong
+inline fun CharArray.takeLastWhile(predicate: Function1<Char, Boolean>): List<Char>
+inline fun CharArray.takeWhile(predicate: Function1<Char, Boolean>): List<Char>
+inline fun CharArray?.contentHashCode(): Int
+inline fun CharArray?.contentToString(): String
+inline fun DoubleArray.all(predicate: Function1<Double, Boolean>): Boolean
+inline fun DoubleArray.any(predicate: Function1<Double, Boolean>): Boolean
+inline fun DoubleArray.contentHashCode(): Int
+inline fun DoubleArray.contentToString(): String
+inline fun DoubleArray.copyOf(): DoubleArray
+inline fun DoubleArray.copyOf(newSize: Int): DoubleArray
+inline fun DoubleArray.copyOfRange(fromIndex: Int, toIndex: Int): DoubleArray
+inline fun DoubleArray.count(): Int
+inline fun DoubleArray.count(predicate: Function1<Double, Boolean>): Int
+inline fun DoubleArray.dropLastWhile(predicate: Function1<Double, Boolean>): List<Double>
+inline fun DoubleArray.dropWhile(predicate: Function1<Double, Boolean>): List<Double>
+inline fun DoubleArray.elementAt(index: Int): Double
+inline fun DoubleArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, Double>): Double
+inline fun DoubleArray.elementAtOrNull(index: Int): Double?
+inline fun DoubleArray.filter(predicate: Function1<Double, Boolean>): List<Double>
+inline fun DoubleArray.filterIndexed(predicate: Function2<Int, Double, Boolean>): List<Double>
+inline fun DoubleArray.filterNot(predicate: Function1<Double, Boolean>): List<Double>
+inline fun DoubleArray.find(predicate: Function1<Double, Boolean>): Double?
+inline fun DoubleArray.findLast(predicate: Function1<Double, Boolean>): Double?
+inline fun DoubleArray.first(predicate: Function1<Double, Boolean>): Double
+inline fun DoubleArray.firstOrNull(predicate: Function1<Double, Boolean>): Double?
+inline fun DoubleArray.forEach(action: Function1<Double, Unit>): Unit
+inline fun DoubleArray.forEachIndexed(action: Function2<Int, Double, Unit>): Unit
+inline fun DoubleArray.getOrElse(index: Int, defaultValue: Function1<Int, Double>): Double
+inline fun DoubleArray.indexOfFirst(predicate: Function1<Double, Boolean>): Int
+inline fun DoubleArray.indexOfLast(predicate: Function1<Double, Boolean>): Int
+inline fun DoubleArray.isEmpty(): Boolean
+inline fun DoubleArray.isNotEmpty(): Boolean
+inline fun DoubleArray.last(predicate: Function1<Double, Boolean>): Double
+inline fun DoubleArray.lastOrNull(predicate: Function1<Double, Boolean>): Double?
+inline fun DoubleArray.maxOf(selector: Function1<Double, Double>): Double
+inline fun DoubleArray.maxOf(selector: Function1<Double, Float>): Float
+inline fun DoubleArray.maxOfOrNull(selector: Function1<Double, Double>): Double?
+inline fun DoubleArray.maxOfOrNull(selector: Function1<Double, Float>): Float?
+inline fun DoubleArray.minOf(selector: Function1<Double, Double>): Double
+inline fun DoubleArray.minOf(selector: Function1<Double, Float>): Float
+inline fun DoubleArray.minOfOrNull(selector: Function1<Double, Double>): Double?
+inline fun DoubleArray.minOfOrNull(selector: Function1<Double, Float>): Float?
+inline fun DoubleArray.none(predicate: Function1<Double, Boolean>): Boolean
+inline fun DoubleArray.onEach(action: Function1<Double, Unit>): DoubleArray
+inline fun DoubleArray.onEachIndexed(action: Function2<Int, Double, Unit>): DoubleArray
+inline fun DoubleArray.partition(predicate: Function1<Double, Boolean>): Pair<List<Double>, List<Double>>
+inline fun DoubleArray.random(): Double
+inline fun DoubleArray.randomOrNull(): Double?
+inline fun DoubleArray.reduce(operation: Function2<Double, Double, Double>): Double
+inline fun DoubleArray.reduceIndexed(operation: Function3<Int, Double, Double, Double>): Double
+inline fun DoubleArray.reduceIndexedOrNull(operation: Function3<Int, Double, Double, Double>): Double?
+inline fun DoubleArray.reduceOrNull(operation: Function2<Double, Double, Double>): Double?
+inline fun DoubleArray.reduceRight(operation: Function2<Double, Double, Double>): Double
+inline fun DoubleArray.reduceRightIndexed(operation: Function3<Int, Double, Double, Double>): Double
+inline fun DoubleArray.reduceRightIndexedOrNull(operation: Function3<Int, Double, Double, Double>): Double?
+inline fun DoubleArray.reduceRightOrNull(operation: Function2<Double, Double, Double>): Double?
+inline fun DoubleArray.runningReduce(operation: Function2<Double, Double, Double>): List<Double>
+inline fun DoubleArray.runningReduceIndexed(operation: Function3<Int, Double, Double, Double>): List<Double>
+inline fun DoubleArray.single(predicate: Function1<Double, Boolean>): Double
+inline fun DoubleArray.singleOrNull(predicate: Function1<Double, Boolean>): Double?
+inline fun DoubleArray.sumBy(selector: Function1<Double, Int>): Int
+inline fun DoubleArray.sumByDouble(selector: Function1<Double, Double>): Double
+inline fun DoubleArray.sumOf(selector: Function1<Double, BigDecimal>): BigDecimal
+inline fun DoubleArray.sumOf(selector: Function1<Double, BigInteger>): BigInteger
+inline fun DoubleArray.sumOf(selector: Function1<Double, Double>): Double
+inline fun DoubleArray.sumOf(selector: Function1<Double, Int>): Int
+inline fun DoubleArray.sumOf(selector: Function1<Double, Long>): Long
+inline fun DoubleArray.sumOf(selector: Function1<Double, UInt>): UInt
+inline fun DoubleArray.sumOf(selector: Function1<Double, ULong>): ULong
+inline fun DoubleArray.takeLastWhile(predicate: Function1<Double, Boolean>): List<Double>
+inline fun DoubleArray.takeWhile(predicate: Function1<Double, Boolean>): List<Double>
+inline fun DoubleArray?.contentHashCode(): Int
+inline fun DoubleArray?.contentToString(): String
+inline fun FloatArray.all(predicate: Function1<Float, Boolean>): Boolean
+inline fun FloatArray.any(predicate: Function1<Float, Boolean>): Boolean
+inline fun FloatArray.contentHashCode(): Int
+inline fun FloatArray.contentToString(): String
+inline fun FloatArray.copyOf(): FloatArray
+inline fun FloatArray.copyOf(newSize: Int): FloatArray
+inline fun FloatArray.copyOfRange(fromIndex: Int, toIndex: Int): FloatArray
+inline fun FloatArray.count(): Int
+inline fun FloatArray.count(predicate: Function1<Float, Boolean>): Int
+inline fun FloatArray.dropLastWhile(predicate: Function1<Float, Boolean>): List<Float>
+inline fun FloatArray.dropWhile(predicate: Function1<Float, Boolean>): List<Float>
+inline fun FloatArray.elementAt(index: Int): Float
+inline fun FloatArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, Float>): Float
+inline fun FloatArray.elementAtOrNull(index: Int): Float?
+inline fun FloatArray.filter(predicate: Function1<Float, Boolean>): List<Float>
+inline fun FloatArray.filterIndexed(predicate: Function2<Int, Float, Boolean>): List<Float>
+inline fun FloatArray.filterNot(predicate: Function1<Float, Boolean>): List<Float>
+inline fun FloatArray.find(predicate: Function1<Float, Boolean>): Float?
+inline fun FloatArray.findLast(predicate: Function1<Float, Boolean>): Float?
+inline fun FloatArray.first(predicate: Function1<Float, Boolean>): Float
+inline fun FloatArray.firstOrNull(predicate: Function1<Float, Boolean>): Float?
+inline fun FloatArray.forEach(action: Function1<Float, Unit>): Unit
+inline fun FloatArray.forEachIndexed(action: Function2<Int, Float, Unit>): Unit
+inline fun FloatArray.getOrElse(index: Int, defaultValue: Function1<Int, Float>): Float
+inline fun FloatArray.indexOfFirst(predicate: Function1<Float, Boolean>): Int
+inline fun FloatArray.indexOfLast(predicate: Function1<Float, Boolean>): Int
+inline fun FloatArray.isEmpty(): Boolean
+inline fun FloatArray.isNotEmpty(): Boolean
+inline fun FloatArray.last(predicate: Function1<Float, Boolean>): Float
+inline fun FloatArray.lastOrNull(predicate: Function1<Float, Boolean>): Float?
+inline fun FloatArray.maxOf(selector: Function1<Float, Double>): Double
+inline fun FloatArray.maxOf(selector: Function1<Float, Float>): Float
+inline fun FloatArray.maxOfOrNull(selector: Function1<Float, Double>): Double?
+inline fun FloatArray.maxOfOrNull(selector: Function1<Float, Float>): Float?
+inline fun FloatArray.minOf(selector: Function1<Float, Double>): Double
+inline fun FloatArray.minOf(selector: Function1<Float, Float>): Float
+inline fun FloatArray.minOfOrNull(selector: Function1<Float, Double>): Double?
+inline fun FloatArray.minOfOrNull(selector: Function1<Float, Float>): Float?
+inline fun FloatArray.none(predicate: Function1<Float, Boolean>): Boolean
+inline fun FloatArray.onEach(action: Function1<Float, Unit>): FloatArray
+inline fun FloatArray.onEachIndexed(action: Function2<Int, Float, Unit>): FloatArray
+inline fun FloatArray.partition(predicate: Function1<Float, Boolean>): Pair<List<Float>, List<Float>>
+inline fun FloatArray.random(): Float
+inline fun FloatArray.randomOrNull(): Float?
+inline fun FloatArray.reduce(operation: Function2<Float, Float, Float>): Float
+inline fun FloatArray.reduceIndexed(operation: Function3<Int, Float, Float, Float>): Float
+inline fun FloatArray.reduceIndexedOrNull(operation: Function3<Int, Float, Float, Float>): Float?
+inline fun FloatArray.reduceOrNull(operation: Function2<Float, Float, Float>): Float?
+inline fun FloatArray.reduceRight(operation: Function2<Float, Float, Float>): Float
+inline fun FloatArray.reduceRightIndexed(operation: Function3<Int, Float, Float, Float>): Float
+inline fun FloatArray.reduceRightIndexedOrNull(operation: Function3<Int, Float, Float, Float>): Float?
+inline fun FloatArray.reduceRightOrNull(operation: Function2<Float, Float, Float>): Float?
+inline fun FloatArray.runningReduce(operation: Function2<Float, Float, Float>): List<Float>
+inline fun FloatArray.runningReduceIndexed(operation: Function3<Int, Float, Float, Float>): List<Float>
+inline fun FloatArray.single(predicate: Function1<Float, Boolean>): Float
+inline fun FloatArray.singleOrNull(predicate: Function1<Float, Boolean>): Float?
+inline fun FloatArray.sumBy(selector: Function1<Float, Int>): Int
+inline fun FloatArray.sumByDouble(selector: Function1<Float, Double>): Double
+inline fun FloatArray.sumOf(selector: Function1<Float, BigDecimal>): BigDecimal
+inline fun FloatArray.sumOf(selector: Function1<Float, BigInteger>): BigInteger
+inline fun FloatArray.sumOf(selector: Function1<Float, Double>): Double
+inline fun FloatArray.sumOf(selector: Function1<Float, Int>): Int
+inline fun FloatArray.sumOf(selector: Function1<Float, Long>): Long
+inline fun FloatArray.sumOf(selector: Function1<Float, UInt>): UInt
+inline fun FloatArray.sumOf(selector: Function1<Float, ULong>): ULong
+inline fun FloatArray.takeLastWhile(predicate: Function1<Float, Boolean>): List<Float>
+inline fun FloatArray.takeWhile(predicate: Function1<Float, Boolean>): List<Float>
+inline fun FloatArray?.contentHashCode(): Int
+inline fun FloatArray?.contentToString(): String
+inline fun IntArray.all(predicate: Function1<Int, Boolean>): Boolean
+inline fun IntArray.any(predicate: Function1<Int, Boolean>): Boolean
+inline fun IntArray.asUIntArray(): UIntArray
+inline fun IntArray.contentHashCode(): Int
+inline fun IntArray.contentToString(): String
+inline fun IntArray.copyOf(): IntArray
+inline fun IntArray.copyOf(newSize: Int): IntArray
+inline fun IntArray.copyOfRange(fromIndex: Int, toIndex: Int): IntArray
+inline fun IntArray.count(): Int
+inline fun IntArray.count(predicate: Function1<Int, Boolean>): Int
+inline fun IntArray.dropLastWhile(predicate: Function1<Int, Boolean>): List<Int>
+inline fun IntArray.dropWhile(predicate: Function1<Int, Boolean>): List<Int>
+inline fun IntArray.elementAt(index: Int): Int
+inline fun IntArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, Int>): Int
+inline fun IntArray.elementAtOrNull(index: Int): Int?
+inline fun IntArray.filter(predicate: Function1<Int, Boolean>): List<Int>
+inline fun IntArray.filterIndexed(predicate: Function2<Int, Int, Boolean>): List<Int>
+inline fun IntArray.filterNot(predicate: Function1<Int, Boolean>): List<Int>
+inline fun IntArray.find(predicate: Function1<Int, Boolean>): Int?
+inline fun IntArray.findLast(predicate: Function1<Int, Boolean>): Int?
+inline fun IntArray.first(predicate: Function1<Int, Boolean>): Int
+inline fun IntArray.firstOrNull(predicate: Function1<Int, Boolean>): Int?
+inline fun IntArray.forEach(action: Function1<Int, Unit>): Unit
+inline fun IntArray.forEachIndexed(action: Function2<Int, Int, Unit>): Unit
+inline fun IntArray.getOrElse(index: Int, defaultValue: Function1<Int, Int>): Int
+inline fun IntArray.indexOfFirst(predicate: Function1<Int, Boolean>): Int
+inline fun IntArray.indexOfLast(predicate: Function1<Int, Boolean>): Int
+inline fun IntArray.isEmpty(): Boolean
+inline fun IntArray.isNotEmpty(): Boolean
+inline fun IntArray.last(predicate: Function1<Int, Boolean>): Int
+inline fun IntArray.lastOrNull(predicate: Function1<Int, Boolean>): Int?
+inline fun IntArray.maxOf(selector: Function1<Int, Double>): Double
+inline fun IntArray.maxOf(selector: Function1<Int, Float>): Float
+inline fun IntArray.maxOfOrNull(selector: Function1<Int, Double>): Double?
+inline fun IntArray.maxOfOrNull(selector: Function1<Int, Float>): Float?
+inline fun IntArray.minOf(selector: Function1<Int, Double>): Double
+inline fun IntArray.minOf(selector: Function1<Int, Float>): Float
+inline fun IntArray.minOfOrNull(selector: Function1<Int, Double>): Double?
+inline fun IntArray.minOfOrNull(selector: Function1<Int, Float>): Float?
+inline fun IntArray.none(predicate: Function1<Int, Boolean>): Boolean
+inline fun IntArray.onEach(action: Function1<Int, Unit>): IntArray
+inline fun IntArray.onEachIndexed(action: Function2<Int, Int, Unit>): IntArray
+inline fun IntArray.partition(predicate: Function1<Int, Boolean>): Pair<List<Int>, List<Int>>
+inline fun IntArray.random(): Int
+inline fun IntArray.randomOrNull(): Int?
+inline fun IntArray.reduce(operation: Function2<Int, Int, Int>): Int
+inline fun IntArray.reduceIndexed(operation: Function3<Int, Int, Int, Int>): Int
+inline fun IntArray.reduceIndexedOrNull(operation: Function3<Int, Int, Int, Int>): Int?
+inline fun IntArray.reduceOrNull(operation: Function2<Int, Int, Int>): Int?
+inline fun IntArray.reduceRight(operation: Function2<Int, Int, Int>): Int
+inline fun IntArray.reduceRightIndexed(operation: Function3<Int, Int, Int, Int>): Int
+inline fun IntArray.reduceRightIndexedOrNull(operation: Function3<Int, Int, Int, Int>): Int?
+inline fun IntArray.reduceRightOrNull(operation: Function2<Int, Int, Int>): Int?
+inline fun IntArray.runningReduce(operation: Function2<Int, Int, Int>): List<Int>
+inline fun IntArray.runningReduceIndexed(operation: Function3<Int, Int, Int, Int>): List<Int>
+inline fun IntArray.single(predicate: Function1<Int, Boolean>): Int
+inline fun IntArray.singleOrNull(predicate: Function1<Int, Boolean>): Int?
+inline fun IntArray.sumBy(selector: Function1<Int, Int>): Int
+inline fun IntArray.sumByDouble(selector: Function1<Int, Double>): Double
+inline fun IntArray.sumOf(selector: Function1<Int, BigDecimal>): BigDecimal
+inline fun IntArray.sumOf(selector: Function1<Int, BigInteger>): BigInteger
+inline fun IntArray.sumOf(selector: Function1<Int, Double>): Double
+inline fun IntArray.sumOf(selector: Function1<Int, Int>): Int
+inline fun IntArray.sumOf(selector: Function1<Int, Long>): Long
+inline fun IntArray.sumOf(selector: Function1<Int, UInt>): UInt
+inline fun IntArray.sumOf(selector: Function1<Int, ULong>): ULong
+inline fun IntArray.takeLastWhile(predicate: Function1<Int, Boolean>): List<Int>
+inline fun IntArray.takeWhile(predicate: Function1<Int, Boolean>): List<Int>
+inline fun IntArray.toUIntArray(): UIntArray
+inline fun IntArray?.contentHashCode(): Int
+inline fun IntArray?.contentToString(): String
+inline fun LongArray.all(predicate: Function1<Long, Boolean>): Boolean
+inline fun LongArray.any(predicate: Function1<Long, Boolean>): Boolean
+inline fun LongArray.asULongArray(): ULongArray
+inline fun LongArray.contentHashCode(): Int
+inline fun LongArray.contentToString(): String
+inline fun LongArray.copyOf(): LongArray
+inline fun LongArray.copyOf(newSize: Int): LongArray
+inline fun LongArray.copyOfRange(fromIndex: Int, toIndex: Int): LongArray
+inline fun LongArray.count(): Int
+inline fun LongArray.count(predicate: Function1<Long, Boolean>): Int
+inline fun LongArray.dropLastWhile(predicate: Function1<Long, Boolean>): List<Long>
+inline fun LongArray.dropWhile(predicate: Function1<Long, Boolean>): List<Long>
+inline fun LongArray.elementAt(index: Int): Long
+inline fun LongArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, Long>): Long
+inline fun LongArray.elementAtOrNull(index: Int): Long?
+inline fun LongArray.filter(predicate: Function1<Long, Boolean>): List<Long>
+inline fun LongArray.filterIndexed(predicate: Function2<Int, Long, Boolean>): List<Long>
+inline fun LongArray.filterNot(predicate: Function1<Long, Boolean>): List<Long>
+inline fun LongArray.find(predicate: Function1<Long, Boolean>): Long?
+inline fun LongArray.findLast(predicate: Function1<Long, Boolean>): Long?
+inline fun LongArray.first(predicate: Function1<Long, Boolean>): Long
+inline fun LongArray.firstOrNull(predicate: Function1<Long, Boolean>): Long?
+inline fun LongArray.forEach(action: Function1<Long, Unit>): Unit
+inline fun LongArray.forEachIndexed(action: Function2<Int, Long, Unit>): Unit
+inline fun LongArray.getOrElse(index: Int, defaultValue: Function1<Int, Long>): Long
+inline fun LongArray.indexOfFirst(predicate: Function1<Long, Boolean>): Int
+inline fun LongArray.indexOfLast(predicate: Function1<Long, Boolean>): Int
+inline fun LongArray.isEmpty(): Boolean
+inline fun LongArray.isNotEmpty(): Boolean
+inline fun LongArray.last(predicate: Function1<Long, Boolean>): Long
+inline fun LongArray.lastOrNull(predicate: Function1<Long, Boolean>): Long?
+inline fun LongArray.maxOf(selector: Function1<Long, Double>): Double
+inline fun LongArray.maxOf(selector: Function1<Long, Float>): Float
+inline fun LongArray.maxOfOrNull(selector: Function1<Long, Double>): Double?
+inline fun LongArray.maxOfOrNull(selector: Function1<Long, Float>): Float?
+inline fun LongArray.minOf(selector: Function1<Long, Double>): Double
+inline fun LongArray.minOf(selector: Function1<Long, Float>): Float
+inline fun LongArray.minOfOrNull(selector: Function1<Long, Double>): Double?
+inline fun LongArray.minOfOrNull(selector: Function1<Long, Float>): Float?
+inline fun LongArray.none(predicate: Function1<Long, Boolean>): Boolean
+inline fun LongArray.onEach(action: Function1<Long, Unit>): LongArray
+inline fun LongArray.onEachIndexed(action: Function2<Int, Long, Unit>): LongArray
+inline fun LongArray.partition(predicate: Function1<Long, Boolean>): Pair<List<Long>, List<Long>>
+inline fun LongArray.random(): Long
+inline fun LongArray.randomOrNull(): Long?
+inline fun LongArray.reduce(operation: Function2<Long, Long, Long>): Long
+inline fun LongArray.reduceIndexed(operation: Function3<Int, Long, Long, Long>): Long
+inline fun LongArray.reduceIndexedOrNull(operation: Function3<Int, Long, Long, Long>): Long?
+inline fun LongArray.reduceOrNull(operation: Function2<Long, Long, Long>): Long?
+inline fun LongArray.reduceRight(operation: Function2<Long, Long, Long>): Long
+inline fun LongArray.reduceRightIndexed(operation: Function3<Int, Long, Long, Long>): Long
+inline fun LongArray.reduceRightIndexedOrNull(operation: Function3<Int, Long, Long, Long>): Long?
+inline fun LongArray.reduceRightOrNull(operation: Function2<Long, Long, Long>): Long?
+inline fun LongArray.runningReduce(operation: Function2<Long, Long, Long>): List<Long>
+inline fun LongArray.runningReduceIndexed(operation: Function3<Int, Long, Long, Long>): List<Long>
+inline fun LongArray.single(predicate: Function1<Long, Boolean>): Long
+inline fun LongArray.singleOrNull(predicate: Function1<Long, Boolean>): Long?
+inline fun LongArray.sumBy(selector: Function1<Long, Int>): Int
+inline fun LongArray.sumByDouble(selector: Function1<Long, Double>): Double
+inline fun LongArray.sumOf(selector: Function1<Long, BigDecimal>): BigDecimal
+inline fun LongArray.sumOf(selector: Function1<Long, BigInteger>): BigInteger
+inline fun LongArray.sumOf(selector: Function1<Long, Double>): Double
+inline fun LongArray.sumOf(selector: Function1<Long, Int>): Int
+inline fun LongArray.sumOf(selector: Function1<Long, Long>): Long
+inline fun LongArray.sumOf(selector: Function1<Long, UInt>): UInt
+inline fun LongArray.sumOf(selector: Function1<Long, ULong>): ULong
+inline fun LongArray.takeLastWhile(predicate: Function1<Long, Boolean>): List<Long>
+inline fun LongArray.takeWhile(predicate: Function1<Long, Boolean>): List<Long>
+inline fun LongArray.toULongArray(): ULongArray
+inline fun LongArray?.contentHashCode(): Int
+inline fun LongArray?.contentToString(): String
+inline fun Map<String, String>.toProperties(): Properties
+inline fun ShortArray.all(predicate: Function1<Short, Boolean>): Boolean
+inline fun ShortArray.any(predicate: Function1<Short, Boolean>): Boolean
+inline fun ShortArray.asUShortArray(): UShortArray
+inline fun ShortArray.contentHashCode(): Int
+inline fun ShortArray.contentToString(): String
+inline fun ShortArray.copyOf(): ShortArray
+inline fun ShortArray.copyOf(newSize: Int): ShortArray
+inline fun ShortArray.copyOfRange(fromIndex: Int, toIndex: Int): ShortArray
+inline fun ShortArray.count(): Int
+inline fun ShortArray.count(predicate: Function1<Short, Boolean>): Int
+inline fun ShortArray.dropLastWhile(predicate: Function1<Short, Boolean>): List<Short>
+inline fun ShortArray.dropWhile(predicate: Function1<Short, Boolean>): List<Short>
+inline fun ShortArray.elementAt(index: Int): Short
+inline fun ShortArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, Short>): Short
+inline fun ShortArray.elementAtOrNull(index: Int): Short?
+inline fun ShortArray.filter(predicate: Function1<Short, Boolean>): List<Short>
+inline fun ShortArray.filterIndexed(predicate: Function2<Int, Short, Boolean>): List<Short>
+inline fun ShortArray.filterNot(predicate: Function1<Short, Boolean>): List<Short>
+inline fun ShortArray.find(predicate: Function1<Short, Boolean>): Short?
+inline fun ShortArray.findLast(predicate: Function1<Short, Boolean>): Short?
+inline fun ShortArray.first(predicate: Function1<Short, Boolean>): Short
+inline fun ShortArray.firstOrNull(predicate: Function1<Short, Boolean>): Short?
+inline fun ShortArray.forEach(action: Function1<Short, Unit>): Unit
+inline fun ShortArray.forEachIndexed(action: Function2<Int, Short, Unit>): Unit
+inline fun ShortArray.getOrElse(index: Int, defaultValue: Function1<Int, Short>): Short
+inline fun ShortArray.indexOfFirst(predicate: Function1<Short, Boolean>): Int
+inline fun ShortArray.indexOfLast(predicate: Function1<Short, Boolean>): Int
+inline fun ShortArray.isEmpty(): Boolean
+inline fun ShortArray.isNotEmpty(): Boolean
+inline fun ShortArray.last(predicate: Function1<Short, Boolean>): Short
+inline fun ShortArray.lastOrNull(predicate: Function1<Short, Boolean>): Short?
+inline fun ShortArray.maxOf(selector: Function1<Short, Double>): Double
+inline fun ShortArray.maxOf(selector: Function1<Short, Float>): Float
+inline fun ShortArray.maxOfOrNull(selector: Function1<Short, Double>): Double?
+inline fun ShortArray.maxOfOrNull(selector: Function1<Short, Float>): Float?
+inline fun ShortArray.minOf(selector: Function1<Short, Double>): Double
+inline fun ShortArray.minOf(selector: Function1<Short, Float>): Float
+inline fun ShortArray.minOfOrNull(selector: Function1<Short, Double>): Double?
+inline fun ShortArray.minOfOrNull(selector: Function1<Short, Float>): Float?
+inline fun ShortArray.none(predicate: Function1<Short, Boolean>): Boolean
+inline fun ShortArray.onEach(action: Function1<Short, Unit>): ShortArray
+inline fun ShortArray.onEachIndexed(action: Function2<Int, Short, Unit>): ShortArray
+inline fun ShortArray.partition(predicate: Function1<Short, Boolean>): Pair<List<Short>, List<Short>>
+inline fun ShortArray.random(): Short
+inline fun ShortArray.randomOrNull(): Short?
+inline fun ShortArray.reduce(operation: Function2<Short, Short, Short>): Short
+inline fun ShortArray.reduceIndexed(operation: Function3<Int, Short, Short, Short>): Short
+inline fun ShortArray.reduceIndexedOrNull(operation: Function3<Int, Short, Short, Short>): Short?
+inline fun ShortArray.reduceOrNull(operation: Function2<Short, Short, Short>): Short?
+inline fun ShortArray.reduceRight(operation: Function2<Short, Short, Short>): Short
+inline fun ShortArray.reduceRightIndexed(operation: Function3<Int, Short, Short, Short>): Short
+inline fun ShortArray.reduceRightIndexedOrNull(operation: Function3<Int, Short, Short, Short>): Short?
+inline fun ShortArray.reduceRightOrNull(operation: Function2<Short, Short, Short>): Short?
+inline fun ShortArray.runningReduce(operation: Function2<Short, Short, Short>): List<Short>
+inline fun ShortArray.runningReduceIndexed(operation: Function3<Int, Short, Short, Short>): List<Short>
+inline fun ShortArray.single(predicate: Function1<Short, Boolean>): Short
+inline fun ShortArray.singleOrNull(predicate: Function1<Short, Boolean>): Short?
+inline fun ShortArray.sumBy(selector: Function1<Short, Int>): Int
+inline fun ShortArray.sumByDouble(selector: Function1<Short, Double>): Double
+inline fun ShortArray.sumOf(selector: Function1<Short, BigDecimal>): BigDecimal
+inline fun ShortArray.sumOf(selector: Function1<Short, BigInteger>): BigInteger
+inline fun ShortArray.sumOf(selector: Function1<Short, Double>): Double
+inline fun ShortArray.sumOf(selector: Function1<Short, Int>): Int
+inline fun ShortArray.sumOf(selector: Function1<Short, Long>): Long
+inline fun ShortArray.sumOf(selector: Function1<Short, UInt>): UInt
+inline fun ShortArray.sumOf(selector: Function1<Short, ULong>): ULong
+inline fun ShortArray.takeLastWhile(predicate: Function1<Short, Boolean>): List<Short>
+inline fun ShortArray.takeWhile(predicate: Function1<Short, Boolean>): List<Short>
+inline fun ShortArray.toUShortArray(): UShortArray
+inline fun ShortArray?.contentHashCode(): Int
+inline fun ShortArray?.contentToString(): String
+inline fun UByteArray.all(predicate: Function1<UByte, Boolean>): Boolean
+inline fun UByteArray.any(): Boolean
+inline fun UByteArray.any(predicate: Function1<UByte, Boolean>): Boolean
+inline fun UByteArray.asByteArray(): ByteArray
+inline fun UByteArray.copyInto(destination: UByteArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): UByteArray
+inline fun UByteArray.copyOf(): UByteArray
+inline fun UByteArray.copyOf(newSize: Int): UByteArray
+inline fun UByteArray.copyOfRange(fromIndex: Int, toIndex: Int): UByteArray
+inline fun UByteArray.count(predicate: Function1<UByte, Boolean>): Int
+inline fun UByteArray.dropLastWhile(predicate: Function1<UByte, Boolean>): List<UByte>
+inline fun UByteArray.dropWhile(predicate: Function1<UByte, Boolean>): List<UByte>
+inline fun UByteArray.elementAt(index: Int): UByte
+inline fun UByteArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, UByte>): UByte
+inline fun UByteArray.elementAtOrNull(index: Int): UByte?
+inline fun UByteArray.filter(predicate: Function1<UByte, Boolean>): List<UByte>
+inline fun UByteArray.filterIndexed(predicate: Function2<Int, UByte, Boolean>): List<UByte>
+inline fun UByteArray.filterNot(predicate: Function1<UByte, Boolean>): List<UByte>
+inline fun UByteArray.find(predicate: Function1<UByte, Boolean>): UByte?
+inline fun UByteArray.findLast(predicate: Function1<UByte, Boolean>): UByte?
+inline fun UByteArray.first(): UByte
+inline fun UByteArray.first(predicate: Function1<UByte, Boolean>): UByte
+inline fun UByteArray.firstOrNull(predicate: Function1<UByte, Boolean>): UByte?
+inline fun UByteArray.forEach(action: Function1<UByte, Unit>): Unit
+inline fun UByteArray.forEachIndexed(action: Function2<Int, UByte, Unit>): Unit
+inline fun UByteArray.getOrElse(index: Int, defaultValue: Function1<Int, UByte>): UByte
+inline fun UByteArray.indexOf(element: UByte): Int
+inline fun UByteArray.indexOfFirst(predicate: Function1<UByte, Boolean>): Int
+inline fun UByteArray.indexOfLast(predicate: Function1<UByte, Boolean>): Int
+inline fun UByteArray.last(): UByte
+inline fun UByteArray.last(predicate: Function1<UByte, Boolean>): UByte
+inline fun UByteArray.lastIndexOf(element: UByte): Int
+inline fun UByteArray.lastOrNull(predicate: Function1<UByte, Boolean>): UByte?
+inline fun UByteArray.maxOf(selector: Function1<UByte, Double>): Double
+inline fun UByteArray.maxOf(selector: Function1<UByte, Float>): Float
+inline fun UByteArray.maxOfOrNull(selector: Function1<UByte, Double>): Double?
+inline fun UByteArray.maxOfOrNull(selector: Function1<UByte, Float>): Float?
+inline fun UByteArray.minOf(selector: Function1<UByte, Double>): Double
+inline fun UByteArray.minOf(selector: Function1<UByte, Float>): Float
+inline fun UByteArray.minOfOrNull(selector: Function1<UByte, Double>): Double?
+inline fun UByteArray.minOfOrNull(selector: Function1<UByte, Float>): Float?
+inline fun UByteArray.none(): Boolean
+inline fun UByteArray.none(predicate: Function1<UByte, Boolean>): Boolean
+inline fun UByteArray.onEach(action: Function1<UByte, Unit>): UByteArray
+inline fun UByteArray.onEachIndexed(action: Function2<Int, UByte, Unit>): UByteArray
+inline fun UByteArray.random(): UByte
+inline fun UByteArray.randomOrNull(): UByte?
+inline fun UByteArray.reduce(operation: Function2<UByte, UByte, UByte>): UByte
+inline fun UByteArray.reduceIndexed(operation: Function3<Int, UByte, UByte, UByte>): UByte
+inline fun UByteArray.reduceIndexedOrNull(operation: Function3<Int, UByte, UByte, UByte>): UByte?
+inline fun UByteArray.reduceOrNull(operation: Function2<UByte, UByte, UByte>): UByte?
+inline fun UByteArray.reduceRight(operation: Function2<UByte, UByte, UByte>): UByte
+inline fun UByteArray.reduceRightIndexed(operation: Function3<Int, UByte, UByte, UByte>): UByte
+inline fun UByteArray.reduceRightIndexedOrNull(operation: Function3<Int, UByte, UByte, UByte>): UByte?
+inline fun UByteArray.reduceRightOrNull(operation: Function2<UByte, UByte, UByte>): UByte?
+inline fun UByteArray.reverse(): Unit
+inline fun UByteArray.reverse(fromIndex: Int, toIndex: Int): Unit
+inline fun UByteArray.reversedArray(): UByteArray
+inline fun UByteArray.runningReduce(operation: Function2<UByte, UByte, UByte>): List<UByte>
+inline fun UByteArray.runningReduceIndexed(operation: Function3<Int, UByte, UByte, UByte>): List<UByte>
+inline fun UByteArray.single(): UByte
+inline fun UByteArray.single(predicate: Function1<UByte, Boolean>): UByte
+inline fun UByteArray.singleOrNull(predicate: Function1<UByte, Boolean>): UByte?
+inline fun UByteArray.sum(): UInt
+inline fun UByteArray.sumBy(selector: Function1<UByte, UInt>): UInt
+inline fun UByteArray.sumByDouble(selector: Function1<UByte, Double>): Double
+inline fun UByteArray.sumOf(selector: Function1<UByte, BigDecimal>): BigDecimal
+inline fun UByteArray.sumOf(selector: Function1<UByte, BigInteger>): BigInteger
+inline fun UByteArray.sumOf(selector: Function1<UByte, Double>): Double
+inline fun UByteArray.sumOf(selector: Function1<UByte, Int>): Int
+inline fun UByteArray.sumOf(selector: Function1<UByte, Long>): Long
+inline fun UByteArray.sumOf(selector: Function1<UByte, UInt>): UInt
+inline fun UByteArray.sumOf(selector: Function1<UByte, ULong>): ULong
+inline fun UByteArray.takeLastWhile(predicate: Function1<UByte, Boolean>): List<UByte>
+inline fun UByteArray.takeWhile(predicate: Function1<UByte, Boolean>): List<UByte>
+inline fun UByteArray.toByteArray(): ByteArray
+inline fun UIntArray.all(predicate: Function1<UInt, Boolean>): Boolean
+inline fun UIntArray.any(): Boolean
+inline fun UIntArray.any(predicate: Function1<UInt, Boolean>): Boolean
+inline fun UIntArray.asIntArray(): IntArray
+inline fun UIntArray.copyInto(destination: UIntArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): UIntArray
+inline fun UIntArray.copyOf(): UIntArray
+inline fun UIntArray.copyOf(newSize: Int): UIntArray
+inline fun UIntArray.copyOfRange(fromIndex: Int, toIndex: Int): UIntArray
+inline fun UIntArray.count(predicate: Function1<UInt, Boolean>): Int
+inline fun UIntArray.dropLastWhile(predicate: Function1<UInt, Boolean>): List<UInt>
+inline fun UIntArray.dropWhile(predicate: Function1<UInt, Boolean>): List<UInt>
+inline fun UIntArray.elementAt(index: Int): UInt
+inline fun UIntArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, UInt>): UInt
+inline fun UIntArray.elementAtOrNull(index: Int): UInt?
+inline fun UIntArray.filter(predicate: Function1<UInt, Boolean>): List<UInt>
+inline fun UIntArray.filterIndexed(predicate: Function2<Int, UInt, Boolean>): List<UInt>
+inline fun UIntArray.filterNot(predicate: Function1<UInt, Boolean>): List<UInt>
+inline fun UIntArray.find(predicate: Function1<UInt, Boolean>): UInt?
+inline fun UIntArray.findLast(predicate: Function1<UInt, Boolean>): UInt?
+inline fun UIntArray.first(): UInt
+inline fun UIntArray.first(predicate: Function1<UInt, Boolean>): UInt
+inline fun UIntArray.firstOrNull(predicate: Function1<UInt, Boolean>): UInt?
+inline fun UIntArray.forEach(action: Function1<UInt, Unit>): Unit
+inline fun UIntArray.forEachIndexed(action: Function2<Int, UInt, Unit>): Unit
+inline fun UIntArray.getOrElse(index: Int, defaultValue: Function1<Int, UInt>): UInt
+inline fun UIntArray.indexOf(element: UInt): Int
+inline fun UIntArray.indexOfFirst(predicate: Function1<UInt, Boolean>): Int
+inline fun UIntArray.indexOfLast(predicate: Function1<UInt, Boolean>): Int
+inline fun UIntArray.last(): UInt
+inline fun UIntArray.last(predicate: Function1<UInt, Boolean>): UInt
+inline fun UIntArray.lastIndexOf(element: UInt): Int
+inline fun UIntArray.lastOrNull(predicate: Function1<UInt, Boolean>): UInt?
+inline fun UIntArray.maxOf(selector: Function1<UInt, Double>): Double
+inline fun UIntArray.maxOf(selector: Function1<UInt, Float>): Float
+inline fun UIntArray.maxOfOrNull(selector: Function1<UInt, Double>): Double?
+inline fun UIntArray.maxOfOrNull(selector: Function1<UInt, Float>): Float?
+inline fun UIntArray.minOf(selector: Function1<UInt, Double>): Double
+inline fun UIntArray.minOf(selector: Function1<UInt, Float>): Float
+inline fun UIntArray.minOfOrNull(selector: Function1<UInt, Double>): Double?
+inline fun UIntArray.minOfOrNull(selector: Function1<UInt, Float>): Float?
+inline fun UIntArray.none(): Boolean
+inline fun UIntArray.none(predicate: Function1<UInt, Boolean>): Boolean
+inline fun UIntArray.onEach(action: Function1<UInt, Unit>): UIntArray
+inline fun UIntArray.onEachIndexed(action: Function2<Int, UInt, Unit>): UIntArray
+inline fun UIntArray.random(): UInt
+inline fun UIntArray.randomOrNull(): UInt?
+inline fun UIntArray.reduce(operation: Function2<UInt, UInt, UInt>): UInt
+inline fun UIntArray.reduceIndexed(operation: Function3<Int, UInt, UInt, UInt>): UInt
+inline fun UIntArray.reduceIndexedOrNull(operation: Function3<Int, UInt, UInt, UInt>): UInt?
+inline fun UIntArray.reduceOrNull(operation: Function2<UInt, UInt, UInt>): UInt?
+inline fun UIntArray.reduceRight(operation: Function2<UInt, UInt, UInt>): UInt
+inline fun UIntArray.reduceRightIndexed(operation: Function3<Int, UInt, UInt, UInt>): UInt
+inline fun UIntArray.reduceRightIndexedOrNull(operation: Function3<Int, UInt, UInt, UInt>): UInt?
+inline fun UIntArray.reduceRightOrNull(operation: Function2<UInt, UInt, UInt>): UInt?
+inline fun UIntArray.reverse(): Unit
+inline fun UIntArray.reverse(fromIndex: Int, toIndex: Int): Unit
+inline fun UIntArray.reversedArray(): UIntArray
+inline fun UIntArray.runningReduce(operation: Function2<UInt, UInt, UInt>): List<UInt>
+inline fun UIntArray.runningReduceIndexed(operation: Function3<Int, UInt, UInt, UInt>): List<UInt>
+inline fun UIntArray.single(): UInt
+inline fun UIntArray.single(predicate: Function1<UInt, Boolean>): UInt
+inline fun UIntArray.singleOrNull(predicate: Function1<UInt, Boolean>): UInt?
+inline fun UIntArray.sum(): UInt
+inline fun UIntArray.sumBy(selector: Function1<UInt, UInt>): UInt
+inline fun UIntArray.sumByDouble(selector: Function1<UInt, Double>): Double
+inline fun UIntArray.sumOf(selector: Function1<UInt, BigDecimal>): BigDecimal
+inline fun UIntArray.sumOf(selector: Function1<UInt, BigInteger>): BigInteger
+inline fun UIntArray.sumOf(selector: Function1<UInt, Double>): Double
+inline fun UIntArray.sumOf(selector: Function1<UInt, Int>): Int
+inline fun UIntArray.sumOf(selector: Function1<UInt, Long>): Long
+inline fun UIntArray.sumOf(selector: Function1<UInt, UInt>): UInt
+inline fun UIntArray.sumOf(selector: Function1<UInt, ULong>): ULong
+inline fun UIntArray.takeLastWhile(predicate: Function1<UInt, Boolean>): List<UInt>
+inline fun UIntArray.takeWhile(predicate: Function1<UInt, Boolean>): List<UInt>
+inline fun UIntArray.toIntArray(): IntArray
+inline fun ULongArray.all(predicate: Function1<ULong, Boolean>): Boolean
+inline fun ULongArray.any(): Boolean
+inline fun ULongArray.any(predicate: Function1<ULong, Boolean>): Boolean
+inline fun ULongArray.asLongArray(): LongArray
+inline fun ULongArray.copyInto(destination: ULongArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): ULongArray
+inline fun ULongArray.copyOf(): ULongArray
+inline fun ULongArray.copyOf(newSize: Int): ULongArray
+inline fun ULongArray.copyOfRange(fromIndex: Int, toIndex: Int): ULongArray
+inline fun ULongArray.count(predicate: Function1<ULong, Boolean>): Int
+inline fun ULongArray.dropLastWhile(predicate: Function1<ULong, Boolean>): List<ULong>
+inline fun ULongArray.dropWhile(predicate: Function1<ULong, Boolean>): List<ULong>
+inline fun ULongArray.elementAt(index: Int): ULong
+inline fun ULongArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, ULong>): ULong
+inline fun ULongArray.elementAtOrNull(index: Int): ULong?
+inline fun ULongArray.filter(predicate: Function1<ULong, Boolean>): List<ULong>
+inline fun ULongArray.filterIndexed(predicate: Function2<Int, ULong, Boolean>): List<ULong>
+inline fun ULongArray.filterNot(predicate: Function1<ULong, Boolean>): List<ULong>
+inline fun ULongArray.find(predicate: Function1<ULong, Boolean>): ULong?
+inline fun ULongArray.findLast(predicate: Function1<ULong, Boolean>): ULong?
+inline fun ULongArray.first(): ULong
+inline fun ULongArray.first(predicate: Function1<ULong, Boolean>): ULong
+inline fun ULongArray.firstOrNull(predicate: Function1<ULong, Boolean>): ULong?
+inline fun ULongArray.forEach(action: Function1<ULong, Unit>): Unit
+inline fun ULongArray.forEachIndexed(action: Function2<Int, ULong, Unit>): Unit
+inline fun ULongArray.getOrElse(index: Int, defaultValue: Function1<Int, ULong>): ULong
+inline fun ULongArray.indexOf(element: ULong): Int
+inline fun ULongArray.indexOfFirst(predicate: Function1<ULong, Boolean>): Int
+inline fun ULongArray.indexOfLast(predicate: Function1<ULong, Boolean>): Int
+inline fun ULongArray.last(): ULong
+inline fun ULongArray.last(predicate: Function1<ULong, Boolean>): ULong
+inline fun ULongArray.lastIndexOf(element: ULong): Int
+inline fun ULongArray.lastOrNull(predicate: Function1<ULong, Boolean>): ULong?
+inline fun ULongArray.maxOf(selector: Function1<ULong, Double>): Double
+inline fun ULongArray.maxOf(selector: Function1<ULong, Float>): Float
+inline fun ULongArray.maxOfOrNull(selector: Function1<ULong, Double>): Double?
+inline fun ULongArray.maxOfOrNull(selector: Function1<ULong, Float>): Float?
+inline fun ULongArray.minOf(selector: Function1<ULong, Double>): Double
+inline fun ULongArray.minOf(selector: Function1<ULong, Float>): Float
+inline fun ULongArray.minOfOrNull(selector: Function1<ULong, Double>): Double?
+inline fun ULongArray.minOfOrNull(selector: Function1<ULong, Float>): Float?
+inline fun ULongArray.none(): Boolean
+inline fun ULongArray.none(predicate: Function1<ULong, Boolean>): Boolean
+inline fun ULongArray.onEach(action: Function1<ULong, Unit>): ULongArray
+inline fun ULongArray.onEachIndexed(action: Function2<Int, ULong, Unit>): ULongArray
+inline fun ULongArray.random(): ULong
+inline fun ULongArray.randomOrNull(): ULong?
+inline fun ULongArray.reduce(operation: Function2<ULong, ULong, ULong>): ULong
+inline fun ULongArray.reduceIndexed(operation: Function3<Int, ULong, ULong, ULong>): ULong
+inline fun ULongArray.reduceIndexedOrNull(operation: Function3<Int, ULong, ULong, ULong>): ULong?
+inline fun ULongArray.reduceOrNull(operation: Function2<ULong, ULong, ULong>): ULong?
+inline fun ULongArray.reduceRight(operation: Function2<ULong, ULong, ULong>): ULong
+inline fun ULongArray.reduceRightIndexed(operation: Function3<Int, ULong, ULong, ULong>): ULong
+inline fun ULongArray.reduceRightIndexedOrNull(operation: Function3<Int, ULong, ULong, ULong>): ULong?
+inline fun ULongArray.reduceRightOrNull(operation: Function2<ULong, ULong, ULong>): ULong?
+inline fun ULongArray.reverse(): Unit
+inline fun ULongArray.reverse(fromIndex: Int, toIndex: Int): Unit
+inline fun ULongArray.reversedArray(): ULongArray
+inline fun ULongArray.runningReduce(operation: Function2<ULong, ULong, ULong>): List<ULong>
+inline fun ULongArray.runningReduceIndexed(operation: Function3<Int, ULong, ULong, ULong>): List<ULong>
+inline fun ULongArray.single(): ULong
+inline fun ULongArray.single(predicate: Function1<ULong, Boolean>): ULong
+inline fun ULongArray.singleOrNull(predicate: Function1<ULong, Boolean>): ULong?
+inline fun ULongArray.sum(): ULong
+inline fun ULongArray.sumBy(selector: Function1<ULong, UInt>): UInt
+inline fun ULongArray.sumByDouble(selector: Function1<ULong, Double>): Double
+inline fun ULongArray.sumOf(selector: Function1<ULong, BigDecimal>): BigDecimal
+inline fun ULongArray.sumOf(selector: Function1<ULong, BigInteger>): BigInteger
+inline fun ULongArray.sumOf(selector: Function1<ULong, Double>): Double
+inline fun ULongArray.sumOf(selector: Function1<ULong, Int>): Int
+inline fun ULongArray.sumOf(selector: Function1<ULong, Long>): Long
+inline fun ULongArray.sumOf(selector: Function1<ULong, UInt>): UInt
+inline fun ULongArray.sumOf(selector: Function1<ULong, ULong>): ULong
+inline fun ULongArray.takeLastWhile(predicate: Function1<ULong, Boolean>): List<ULong>
+inline fun ULongArray.takeWhile(predicate: Function1<ULong, Boolean>): List<ULong>
+inline fun ULongArray.toLongArray(): LongArray
+inline fun UShortArray.all(predicate: Function1<UShort, Boolean>): Boolean
+inline fun UShortArray.any(): Boolean
+inline fun UShortArray.any(predicate: Function1<UShort, Boolean>): Boolean
+inline fun UShortArray.asShortArray(): ShortArray
+inline fun UShortArray.copyInto(destination: UShortArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): UShortArray
+inline fun UShortArray.copyOf(): UShortArray
+inline fun UShortArray.copyOf(newSize: Int): UShortArray
+inline fun UShortArray.copyOfRange(fromIndex: Int, toIndex: Int): UShortArray
+inline fun UShortArray.count(predicate: Function1<UShort, Boolean>): Int
+inline fun UShortArray.dropLastWhile(predicate: Function1<UShort, Boolean>): List<UShort>
+inline fun UShortArray.dropWhile(predicate: Function1<UShort, Boolean>): List<UShort>
+inline fun UShortArray.elementAt(index: Int): UShort
+inline fun UShortArray.elementAtOrElse(index: Int, defaultValue: Function1<Int, UShort>): UShort
+inline fun UShortArray.elementAtOrNull(index: Int): UShort?
+inline fun UShortArray.filter(predicate: Function1<UShort, Boolean>): List<UShort>
+inline fun UShortArray.filterIndexed(predicate: Function2<Int, UShort, Boolean>): List<UShort>
+inline fun UShortArray.filterNot(predicate: Function1<UShort, Boolean>): List<UShort>
+inline fun UShortArray.find(predicate: Function1<UShort, Boolean>): UShort?
+inline fun UShortArray.findLast(predicate: Function1<UShort, Boolean>): UShort?
+inline fun UShortArray.first(): UShort
+inline fun UShortArray.first(predicate: Function1<UShort, Boolean>): UShort
+inline fun UShortArray.firstOrNull(predicate: Function1<UShort, Boolean>): UShort?
+inline fun UShortArray.forEach(action: Function1<UShort, Unit>): Unit
+inline fun UShortArray.forEachIndexed(action: Function2<Int, UShort, Unit>): Unit
+inline fun UShortArray.getOrElse(index: Int, defaultValue: Function1<Int, UShort>): UShort
+inline fun UShortArray.indexOf(element: UShort): Int
+inline fun UShortArray.indexOfFirst(predicate: Function1<UShort, Boolean>): Int
+inline fun UShortArray.indexOfLast(predicate: Function1<UShort, Boolean>): Int
+inline fun UShortArray.last(): UShort
+inline fun UShortArray.last(predicate: Function1<UShort, Boolean>): UShort
+inline fun UShortArray.lastIndexOf(element: UShort): Int
+inline fun UShortArray.lastOrNull(predicate: Function1<UShort, Boolean>): UShort?
+inline fun UShortArray.maxOf(selector: Function1<UShort, Double>): Double
+inline fun UShortArray.maxOf(selector: Function1<UShort, Float>): Float
+inline fun UShortArray.maxOfOrNull(selector: Function1<UShort, Double>): Double?
+inline fun UShortArray.maxOfOrNull(selector: Function1<UShort, Float>): Float?
+inline fun UShortArray.minOf(selector: Function1<UShort, Double>): Double
+inline fun UShortArray.minOf(selector: Function1<UShort, Float>): Float
+inline fun UShortArray.minOfOrNull(selector: Function1<UShort, Double>): Double?
+inline fun UShortArray.minOfOrNull(selector: Function1<UShort, Float>): Float?
+inline fun UShortArray.none(): Boolean
+inline fun UShortArray.none(predicate: Function1<UShort, Boolean>): Boolean
+inline fun UShortArray.onEach(action: Function1<UShort, Unit>): UShortArray
+inline fun UShortArray.onEachIndexed(action: Function2<Int, UShort, Unit>): UShortArray
+inline fun UShortArray.random(): UShort
+inline fun UShortArray.randomOrNull(): UShort?
+inline fun UShortArray.reduce(operation: Function2<UShort, UShort, UShort>): UShort
+inline fun UShortArray.reduceIndexed(operation: Function3<Int, UShort, UShort, UShort>): UShort
+inline fun UShortArray.reduceIndexedOrNull(operation: Function3<Int, UShort, UShort, UShort>): UShort?
+inline fun UShortArray.reduceOrNull(operation: Function2<UShort, UShort, UShort>): UShort?
+inline fun UShortArray.reduceRight(operation: Function2<UShort, UShort, UShort>): UShort
+inline fun UShortArray.reduceRightIndexed(operation: Function3<Int, UShort, UShort, UShort>): UShort
+inline fun UShortArray.reduceRightIndexedOrNull(operation: Function3<Int, UShort, UShort, UShort>): UShort?
+inline fun UShortArray.reduceRightOrNull(operation: Function2<UShort, UShort, UShort>): UShort?
+inline fun UShortArray.reverse(): Unit
+inline fun UShortArray.reverse(fromIndex: Int, toIndex: Int): Unit
+inline fun UShortArray.reversedArray(): UShortArray
+inline fun UShortArray.runningReduce(operation: Function2<UShort, UShort, UShort>): List<UShort>
+inline fun UShortArray.runningReduceIndexed(operation: Function3<Int, UShort, UShort, UShort>): List<UShort>
+inline fun UShortArray.single(): UShort
+inline fun UShortArray.single(predicate: Function1<UShort, Boolean>): UShort
+inline fun UShortArray.singleOrNull(predicate: Function1<UShort, Boolean>): UShort?
+inline fun UShortArray.sum(): UInt
+inline fun UShortArray.sumBy(selector: Function1<UShort, UInt>): UInt
+inline fun UShortArray.sumByDouble(selector: Function1<UShort, Double>): Double
+inline fun UShortArray.sumOf(selector: Function1<UShort, BigDecimal>): BigDecimal
+inline fun UShortArray.sumOf(selector: Function1<UShort, BigInteger>): BigInteger
+inline fun UShortArray.sumOf(selector: Function1<UShort, Double>): Double
+inline fun UShortArray.sumOf(selector: Function1<UShort, Int>): Int
+inline fun UShortArray.sumOf(selector: Function1<UShort, Long>): Long
+inline fun UShortArray.sumOf(selector: Function1<UShort, UInt>): UInt
+inline fun UShortArray.sumOf(selector: Function1<UShort, ULong>): ULong
+inline fun UShortArray.takeLastWhile(predicate: Function1<UShort, Boolean>): List<UShort>
+inline fun UShortArray.takeWhile(predicate: Function1<UShort, Boolean>): List<UShort>
+inline fun UShortArray.toShortArray(): ShortArray
+inline infix fun <T> Array<out T>.contentDeepEquals(other: Array<out T>): Boolean
+inline infix fun <T> Array<out T>.contentEquals(other: Array<out T>): Boolean
+inline infix fun <T> Array<out T>?.contentDeepEquals(other: Array<out T>?): Boolean
+inline infix fun <T> Array<out T>?.contentEquals(other: Array<out T>?): Boolean
+inline infix fun BooleanArray.contentEquals(other: BooleanArray): Boolean
+inline infix fun BooleanArray?.contentEquals(other: BooleanArray?): Boolean
+inline infix fun ByteArray.contentEquals(other: ByteArray): Boolean
+inline infix fun ByteArray?.contentEquals(other: ByteArray?): Boolean
+inline infix fun CharArray.contentEquals(other: CharArray): Boolean
+inline infix fun CharArray?.contentEquals(other: CharArray?): Boolean
+inline infix fun DoubleArray.contentEquals(other: DoubleArray): Boolean
+inline infix fun DoubleArray?.contentEquals(other: DoubleArray?): Boolean
+inline infix fun FloatArray.contentEquals(other: FloatArray): Boolean
+inline infix fun FloatArray?.contentEquals(other: FloatArray?): Boolean
+inline infix fun IntArray.contentEquals(other: IntArray): Boolean
+inline infix fun IntArray?.contentEquals(other: IntArray?): Boolean
+inline infix fun LongArray.contentEquals(other: LongArray): Boolean
+inline infix fun LongArray?.contentEquals(other: LongArray?): Boolean
+inline infix fun ShortArray.contentEquals(other: ShortArray): Boolean
+inline infix fun ShortArray?.contentEquals(other: ShortArray?): Boolean
+inline operator fun <K, V> Map.Entry<K, V>.component1(): K
+inline operator fun <K, V> Map.Entry<K, V>.component2(): V
+inline operator fun <K, V> Map<out K, V>.contains(key: K): Boolean
+inline operator fun <K, V> Map<out K, V>.get(key: K): V?
+inline operator fun <K, V> Map<out K, V>.iterator(): Iterator<Map.Entry<K, V>>
+inline operator fun <K, V> MutableMap<K, V>.iterator(): MutableIterator<MutableMap.MutableEntry<K, V>>
+inline operator fun <K, V> MutableMap<K, V>.minusAssign(key: K): Unit
+inline operator fun <K, V> MutableMap<K, V>.minusAssign(keys: Array<out K>): Unit
+inline operator fun <K, V> MutableMap<K, V>.minusAssign(keys: Iterable<K>): Unit
+inline operator fun <K, V> MutableMap<K, V>.minusAssign(keys: Sequence<K>): Unit
+inline operator fun <K, V> MutableMap<K, V>.set(key: K, value: V): Unit
+inline operator fun <K, V> MutableMap<in K, in V>.plusAssign(map: Map<K, V>): Unit
+inline operator fun <K, V> MutableMap<in K, in V>.plusAssign(pair: Pair<K, V>): Unit
+inline operator fun <K, V> MutableMap<in K, in V>.plusAssign(pairs: Array<out Pair<K, V>>): Unit
+inline operator fun <K, V> MutableMap<in K, in V>.plusAssign(pairs: Iterable<Pair<K, V>>): Unit
+inline operator fun <K, V> MutableMap<in K, in V>.plusAssign(pairs: Sequence<Pair<K, V>>): Unit
+inline operator fun <T> Array<out T>.component1(): T
+inline operator fun <T> Array<out T>.component2(): T
+inline operator fun <T> Array<out T>.component3(): T
+inline operator fun <T> Array<out T>.component4(): T
+inline operator fun <T> Array<out T>.component5(): T
+inline operator fun <T> Iterator<T>.iterator(): Iterator<T>
+inline operator fun <T> List<T>.component1(): T
+inline operator fun <T> List<T>.component2(): T
+inline operator fun <T> List<T>.component3(): T
+inline operator fun <T> List<T>.component4(): T
+inline operator fun <T> List<T>.component5(): T
+inline operator fun <T> MutableCollection<in T>.minusAssign(element: T): Unit
+inline operator fun <T> MutableCollection<in T>.minusAssign(elements: Array<T>): Unit
+inline operator fun <T> MutableCollection<in T>.minusAssign(elements: Iterable<T>): Unit
+inline operator fun <T> MutableCollection<in T>.minusAssign(elements: Sequence<T>): Unit
+inline operator fun <T> MutableCollection<in T>.plusAssign(element: T): Unit
+inline operator fun <T> MutableCollection<in T>.plusAssign(elements: Array<T>): Unit
+inline operator fun <T> MutableCollection<in T>.plusAssign(elements: Iterable<T>): Unit
+inline operator fun <T> MutableCollection<in T>.plusAssign(elements: Sequence<T>): Unit
+inline operator fun <V, V1 : V> Map<in String, V>.getValue(thisRef: Any?, property: KProperty<*>): V1
+inline operator fun <V, V1 : V> MutableMap<in String, out V>.getValue(thisRef: Any?, property: KProperty<*>): V1
+inline operator fun <V> MutableMap<in String, in V>.setValue(thisRef: Any?, property: KProperty<*>, value: V): Unit
+inline operator fun BooleanArray.component1(): Boolean
+inline operator fun BooleanArray.component2(): Boolean
+inline operator fun BooleanArray.component3(): Boolean
+inline operator fun BooleanArray.component4(): Boolean
+inline operator fun BooleanArray.component5(): Boolean
+inline operator fun ByteArray.component1(): Byte
+inline operator fun ByteArray.component2(): Byte
+inline operator fun ByteArray.component3(): Byte
+inline operator fun ByteArray.component4(): Byte
+inline operator fun ByteArray.component5(): Byte
+inline operator fun CharArray.component1(): Char
+inline operator fun CharArray.component2(): Char
+inline operator fun CharArray.component3(): Char
+inline operator fun CharArray.component4(): Char
+inline operator fun CharArray.component5(): Char
+inline operator fun DoubleArray.component1(): Double
+inline operator fun DoubleArray.component2(): Double
+inline operator fun DoubleArray.component3(): Double
+inline operator fun DoubleArray.component4(): Double
+inline operator fun DoubleArray.component5(): Double
+inline operator fun FloatArray.component1(): Float
+inline operator fun FloatArray.component2(): Float
+inline operator fun FloatArray.component3(): Float
+inline operator fun FloatArray.component4(): Float
+inline operator fun FloatArray.component5(): Float
+inline operator fun IntArray.component1(): Int
+inline operator fun IntArray.component2(): Int
+inline operator fun IntArray.component3(): Int
+inline operator fun IntArray.component4(): Int
+inline operator fun IntArray.component5(): Int
+inline operator fun LongArray.component1(): Long
+inline operator fun LongArray.component2(): Long
+inline operator fun LongArray.component3(): Long
+inline operator fun LongArray.component4(): Long
+inline operator fun LongArray.component5(): Long
+inline operator fun ShortArray.component1(): Short
+inline operator fun ShortArray.component2(): Short
+inline operator fun ShortArray.component3(): Short
+inline operator fun ShortArray.component4(): Short
+inline operator fun ShortArray.component5(): Short
+inline operator fun UByteArray.component1(): UByte
+inline operator fun UByteArray.component2(): UByte
+inline operator fun UByteArray.component3(): UByte
+inline operator fun UByteArray.component4(): UByte
+inline operator fun UByteArray.component5(): UByte
+inline operator fun UByteArray.plus(element: UByte): UByteArray
+inline operator fun UByteArray.plus(elements: UByteArray): UByteArray
+inline operator fun UIntArray.component1(): UInt
+inline operator fun UIntArray.component2(): UInt
+inline operator fun UIntArray.component3(): UInt
+inline operator fun UIntArray.component4(): UInt
+inline operator fun UIntArray.component5(): UInt
+inline operator fun UIntArray.plus(element: UInt): UIntArray
+inline operator fun UIntArray.plus(elements: UIntArray): UIntArray
+inline operator fun ULongArray.component1(): ULong
+inline operator fun ULongArray.component2(): ULong
+inline operator fun ULongArray.component3(): ULong
+inline operator fun ULongArray.component4(): ULong
+inline operator fun ULongArray.component5(): ULong
+inline operator fun ULongArray.plus(element: ULong): ULongArray
+inline operator fun ULongArray.plus(elements: ULongArray): ULongArray
+inline operator fun UShortArray.component1(): UShort
+inline operator fun UShortArray.component2(): UShort
+inline operator fun UShortArray.component3(): UShort
+inline operator fun UShortArray.component4(): UShort
+inline operator fun UShortArray.component5(): UShort
+inline operator fun UShortArray.plus(element: UShort): UShortArray
+inline operator fun UShortArray.plus(elements: UShortArray): UShortArray
+operator fun <K, V> Map<out K, V>.minus(key: K): Map<K, V>
+operator fun <K, V> Map<out K, V>.minus(keys: Array<out K>): Map<K, V>
+operator fun <K, V> Map<out K, V>.minus(keys: Iterable<K>): Map<K, V>
+operator fun <K, V> Map<out K, V>.minus(keys: Sequence<K>): Map<K, V>
+operator fun <K, V> Map<out K, V>.plus(map: Map<out K, V>): Map<K, V>
+operator fun <K, V> Map<out K, V>.plus(pair: Pair<K, V>): Map<K, V>
+operator fun <K, V> Map<out K, V>.plus(pairs: Array<out Pair<K, V>>): Map<K, V>
+operator fun <K, V> Map<out K, V>.plus(pairs: Iterable<Pair<K, V>>): Map<K, V>
+operator fun <K, V> Map<out K, V>.plus(pairs: Sequence<Pair<K, V>>): Map<K, V>
+operator fun <T> Array<T>.plus(element: T): Array<T>
+operator fun <T> Array<T>.plus(elements: Array<out T>): Array<T>
+operator fun <T> Array<T>.plus(elements: Collection<T>): Array<T>
+operator fun <T> Array<out T>.contains(element: T): Boolean
+operator fun <T> Collection<T>.plus(element: T): List<T>
+operator fun <T> Collection<T>.plus(elements: Array<out T>): List<T>
+operator fun <T> Collection<T>.plus(elements: Iterable<T>): List<T>
+operator fun <T> Collection<T>.plus(elements: Sequence<T>): List<T>
+operator fun <T> Enumeration<T>.iterator(): Iterator<T>
+operator fun <T> Iterable<T>.contains(element: T): Boolean
+operator fun <T> Iterable<T>.minus(element: T): List<T>
+operator fun <T> Iterable<T>.minus(elements: Array<out T>): List<T>
+operator fun <T> Iterable<T>.minus(elements: Iterable<T>): List<T>
+operator fun <T> Iterable<T>.minus(elements: Sequence<T>): List<T>
+operator fun <T> Iterable<T>.plus(element: T): List<T>
+operator fun <T> Iterable<T>.plus(elements: Array<out T>): List<T>
+operator fun <T> Iterable<T>.plus(elements: Iterable<T>): List<T>
+operator fun <T> Iterable<T>.plus(elements: Sequence<T>): List<T>
+operator fun <T> Set<T>.minus(element: T): Set<T>
+operator fun <T> Set<T>.minus(elements: Array<out T>): Set<T>
+operator fun <T> Set<T>.minus(elements: Iterable<T>): Set<T>
+operator fun <T> Set<T>.minus(elements: Sequence<T>): Set<T>
+operator fun <T> Set<T>.plus(element: T): Set<T>
+operator fun <T> Set<T>.plus(elements: Array<out T>): Set<T>
+operator fun <T> Set<T>.plus(elements: Iterable<T>): Set<T>
+operator fun <T> Set<T>.plus(elements: Sequence<T>): Set<T>
+operator fun BooleanArray.contains(element: Boolean): Boolean
+operator fun BooleanArray.plus(element: Boolean): BooleanArray
+operator fun BooleanArray.plus(elements: BooleanArray): BooleanArray
+operator fun BooleanArray.plus(elements: Collection<Boolean>): BooleanArray
+operator fun ByteArray.contains(element: Byte): Boolean
+operator fun ByteArray.plus(element: Byte): ByteArray
+operator fun ByteArray.plus(elements: ByteArray): ByteArray
+operator fun ByteArray.plus(elements: Collection<Byte>): ByteArray
+operator fun CharArray.contains(element: Char): Boolean
+operator fun CharArray.plus(element: Char): CharArray
+operator fun CharArray.plus(elements: CharArray): CharArray
+operator fun CharArray.plus(elements: Collection<Char>): CharArray
+operator fun DoubleArray.contains(element: Double): Boolean
+operator fun DoubleArray.plus(element: Double): DoubleArray
+operator fun DoubleArray.plus(elements: Collection<Double>): DoubleArray
+operator fun DoubleArray.plus(elements: DoubleArray): DoubleArray
+operator fun FloatArray.contains(element: Float): Boolean
+operator fun FloatArray.plus(element: Float): FloatArray
+operator fun FloatArray.plus(elements: Collection<Float>): FloatArray
+operator fun FloatArray.plus(elements: FloatArray): FloatArray
+operator fun IntArray.contains(element: Int): Boolean
+operator fun IntArray.plus(element: Int): IntArray
+operator fun IntArray.plus(elements: Collection<Int>): IntArray
+operator fun IntArray.plus(elements: IntArray): IntArray
+operator fun LongArray.contains(element: Long): Boolean
+operator fun LongArray.plus(element: Long): LongArray
+operator fun LongArray.plus(elements: Collection<Long>): LongArray
+operator fun LongArray.plus(elements: LongArray): LongArray
+operator fun ShortArray.contains(element: Short): Boolean
+operator fun ShortArray.plus(element: Short): ShortArray
+operator fun ShortArray.plus(elements: Collection<Short>): ShortArray
+operator fun ShortArray.plus(elements: ShortArray): ShortArray
+operator fun UByteArray.plus(elements: Collection<UByte>): UByteArray
+operator fun UIntArray.plus(elements: Collection<UInt>): UIntArray
+operator fun ULongArray.plus(elements: Collection<ULong>): ULongArray
+operator fun UShortArray.plus(elements: Collection<UShort>): UShortArray
+val <T> Array<out T>.indices: IntRange
+val <T> Array<out T>.lastIndex: Int
+val <T> List<T>.lastIndex: Int
+val BooleanArray.indices: IntRange
+val BooleanArray.lastIndex: Int
+val ByteArray.indices: IntRange
+val ByteArray.lastIndex: Int
+val CharArray.indices: IntRange
+val CharArray.lastIndex: Int
+val Collection<*>.indices: IntRange
+val DoubleArray.indices: IntRange
+val DoubleArray.lastIndex: Int
+val FloatArray.indices: IntRange
+val FloatArray.lastIndex: Int
+val IntArray.indices: IntRange
+val IntArray.lastIndex: Int
+val LongArray.indices: IntRange
+val LongArray.lastIndex: Int
+val ShortArray.indices: IntRange
+val ShortArray.lastIndex: Int
+val UByteArray.indices: IntRange
+val UByteArray.lastIndex: Int
+val UIntArray.indices: IntRange
+val UIntArray.lastIndex: Int
+val ULongArray.indices: IntRange
+val ULongArray.lastIndex: Int
+val UShortArray.indices: IntRange
+val UShortArray.lastIndex: Int
+class AbstractCollection<out E> : Collection<E> {
+    fun <T> toArray(array: Array<T>): Array<T>
+    fun containsAll(elements: Collection<E>): Boolean
+    fun isEmpty(): Boolean
+    fun toArray(): Array<Any?>
+    fun toString(): String
+    operator fun contains(element: E): Boolean
+    operator fun iterator(): Iterator<E>
+    val size: Int
+}
+class AbstractIterator<T> : Iterator<T> {
+    fun computeNext(): Unit
+    fun done(): Unit
+    fun setNext(value: T): Unit
+    operator fun hasNext(): Boolean
+    operator fun next(): T
+}
+class AbstractList<out E> : AbstractCollection<E>, List<E> {
+    fun hashCode(): Int
+    fun indexOf(element: E): Int
+    fun lastIndexOf(element: E): Int
+    fun listIterator(): ListIterator<E>
+    fun listIterator(index: Int): ListIterator<E>
+    fun subList(fromIndex: Int, toIndex: Int): List<E>
+    operator fun equals(other: Any?): Boolean
+    operator fun get(index: Int): E
+    operator fun iterator(): Iterator<E>
+    val size: Int
+}
+class AbstractMap<K, out V> : Map<K, V> {
+    fun containsKey(key: K): Boolean
+    fun containsValue(value: V): Boolean
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun equals(other: Any?): Boolean
+    operator fun get(key: K): V?
+    val keys: Set<K>
+    val size: Int
+    val values: Collection<V>
+}
+class AbstractMutableCollection<E> : MutableCollection<E>, AbstractCollection<E> {
+    fun add(element: E): Boolean
+}
+class AbstractMutableList<E> : MutableList<E>, AbstractList<E> {
+    fun add(index: Int, element: E): Unit
+    fun removeAt(index: Int): E
+    operator fun set(index: Int, element: E): E
+}
+class AbstractMutableMap<K, V> : MutableMap<K, V>, AbstractMap<K, V> {
+    fun put(key: K, value: V): V?
+}
+class AbstractMutableSet<E> : MutableSet<E>, AbstractSet<E> {
+    fun add(element: E): Boolean
+}
+class AbstractSet<out E> : AbstractCollection<E>, Set<E> {
+    fun hashCode(): Int
+    operator fun equals(other: Any?): Boolean
+}
+class ArrayDeque<E> : AbstractMutableList<E> {
+    fun <T> toArray(array: Array<T>): Array<T>
+    fun add(element: E): Boolean
+    fun add(index: Int, element: E): Unit
+    fun addAll(elements: Collection<E>): Boolean
+    fun addAll(index: Int, elements: Collection<E>): Boolean
+    fun addFirst(element: E): Unit
+    fun addLast(element: E): Unit
+    fun clear(): Unit
+    fun first(): E
+    fun firstOrNull(): E?
+    fun indexOf(element: E): Int
+    fun isEmpty(): Boolean
+    fun last(): E
+    fun lastIndexOf(element: E): Int
+    fun lastOrNull(): E?
+    fun remove(element: E): Boolean
+    fun removeAll(elements: Collection<E>): Boolean
+    fun removeAt(index: Int): E
+    fun removeFirst(): E
+    fun removeFirstOrNull(): E?
+    fun removeLast(): E
+    fun removeLastOrNull(): E?
+    fun retainAll(elements: Collection<E>): Boolean
+    fun toArray(): Array<Any?>
+    operator fun contains(element: E): Boolean
+    operator fun get(index: Int): E
+    operator fun set(index: Int, element: E): E
+    var size: Int
+}
+class BooleanIterator : Iterator<Boolean> {
+    fun nextBoolean(): Boolean
+    operator fun next(): Boolean
+}
+class ByteIterator : Iterator<Byte> {
+    fun nextByte(): Byte
+    operator fun next(): Byte
+}
+class CharIterator : Iterator<Char> {
+    fun nextChar(): Char
+    operator fun next(): Char
+}
+interface Collection<out E> : Iterable<E> {
+    fun containsAll(elements: Collection<E>): Boolean
+    fun isEmpty(): Boolean
+    operator fun contains(element: E): Boolean
+    operator fun iterator(): Iterator<E>
+    val size: Int
+}
+class DoubleIterator : Iterator<Double> {
+    fun nextDouble(): Double
+    operator fun next(): Double
+}
+class FloatIterator : Iterator<Float> {
+    fun nextFloat(): Float
+    operator fun next(): Float
+}
+interface Grouping<T, out K> : Any {
+    fun keyOf(element: T): K
+    fun sourceIterator(): Iterator<T>
+}
+data class IndexedValue<out T> : Any {
+    val index: Int
+    val value: T
+}
+class IntIterator : Iterator<Int> {
+    fun nextInt(): Int
+    operator fun next(): Int
+}
+interface Iterable<out T> : Any {
+    operator fun iterator(): Iterator<T>
+}
+interface Iterator<out T> : Any {
+    operator fun hasNext(): Boolean
+    operator fun next(): T
+}
+interface List<out E> : Collection<E> {
+    fun containsAll(elements: Collection<E>): Boolean
+    fun indexOf(element: E): Int
+    fun isEmpty(): Boolean
+    fun lastIndexOf(element: E): Int
+    fun listIterator(): ListIterator<E>
+    fun listIterator(index: Int): ListIterator<E>
+    fun subList(fromIndex: Int, toIndex: Int): List<E>
+    operator fun contains(element: E): Boolean
+    operator fun get(index: Int): E
+    operator fun iterator(): Iterator<E>
+    val size: Int
+}
+interface ListIterator<out T> : Iterator<T> {
+    fun hasPrevious(): Boolean
+    fun nextIndex(): Int
+    fun previous(): T
+    fun previousIndex(): Int
+    operator fun hasNext(): Boolean
+    operator fun next(): T
+}
+class LongIterator : Iterator<Long> {
+    fun nextLong(): Long
+    operator fun next(): Long
+}
+interface Map<K, out V> : Any {
+    fun containsKey(key: K): Boolean
+    fun containsValue(value: V): Boolean
+    fun getOrDefault(key: K, defaultValue: V): V
+    fun isEmpty(): Boolean
+    operator fun get(key: K): V?
+    val entries: Set<Map.Entry<K, V>>
+    val keys: Set<K>
+    val size: Int
+    val values: Collection<V>
+    interface Entry<out K, out V> : Any {
+        val key: K
+        val value: V
+    }
+}
+interface MutableCollection<E> : Collection<E>, MutableIterable<E> {
+    fun add(element: E): Boolean
+    fun addAll(elements: Collection<E>): Boolean
+    fun clear(): Unit
+    fun remove(element: E): Boolean
+    fun removeAll(elements: Collection<E>): Boolean
+    fun retainAll(elements: Collection<E>): Boolean
+    operator fun iterator(): MutableIterator<E>
+}
+interface MutableIterable<out T> : Iterable<T> {
+    operator fun iterator(): MutableIterator<T>
+}
+interface MutableIterator<out T> : Iterator<T> {
+    fun remove(): Unit
+}
+interface MutableList<E> : List<E>, MutableCollection<E> {
+    fun add(element: E): Boolean
+    fun add(index: Int, element: E): Unit
+    fun addAll(elements: Collection<E>): Boolean
+    fun addAll(index: Int, elements: Collection<E>): Boolean
+    fun clear(): Unit
+    fun listIterator(): MutableListIterator<E>
+    fun listIterator(index: Int): MutableListIterator<E>
+    fun remove(element: E): Boolean
+    fun removeAll(elements: Collection<E>): Boolean
+    fun removeAt(index: Int): E
+    fun retainAll(elements: Collection<E>): Boolean
+    fun subList(fromIndex: Int, toIndex: Int): MutableList<E>
+    operator fun set(index: Int, element: E): E
+}
+interface MutableListIterator<T> : ListIterator<T>, MutableIterator<T> {
+    fun add(element: T): Unit
+    fun remove(): Unit
+    fun set(element: T): Unit
+    operator fun hasNext(): Boolean
+    operator fun next(): T
+}
+interface MutableMap<K, V> : Map<K, V> {
+    fun clear(): Unit
+    fun put(key: K, value: V): V?
+    fun putAll(from: Map<out K, V>): Unit
+    fun remove(key: K): V?
+    fun remove(key: K, value: V): Boolean
+    val entries: MutableSet<MutableMap.MutableEntry<K, V>>
+    val keys: MutableSet<K>
+    val values: MutableCollection<V>
+    interface MutableEntry<K, V> : Map.Entry<K, V> {
+        fun setValue(newValue: V): V
+    }
+}
+interface MutableSet<E> : Set<E>, MutableCollection<E> {
+    fun add(element: E): Boolean
+    fun addAll(elements: Collection<E>): Boolean
+    fun clear(): Unit
+    fun remove(element: E): Boolean
+    fun removeAll(elements: Collection<E>): Boolean
+    fun retainAll(elements: Collection<E>): Boolean
+    operator fun iterator(): MutableIterator<E>
+}
+interface Set<out E> : Collection<E> {
+    fun containsAll(elements: Collection<E>): Boolean
+    fun isEmpty(): Boolean
+    operator fun contains(element: E): Boolean
+    operator fun iterator(): Iterator<E>
+    val size: Int
+}
+class ShortIterator : Iterator<Short> {
+    fun nextShort(): Short
+    operator fun next(): Short
+}
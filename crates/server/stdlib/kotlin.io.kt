@@ -0,0 +1,114 @@
+package kotlin.io
+
+// Generated from cloud-pubsub-emulator-0.8.20-all.jar by `just generate-stdlib-index`
+
+const val DEFAULT_BUFFER_SIZE: Int
+fun BufferedReader.lineSequence(): Sequence<String>
+fun File.appendBytes(array: ByteArray): Unit
+fun File.appendText(text: String, charset: Charset = ...): Unit
+fun File.copyRecursively(target: File, overwrite: Boolean = ..., onError: Function2<File, IOException, OnErrorAction> = ...): Boolean
+fun File.copyTo(target: File, overwrite: Boolean = ..., bufferSize: Int = ...): File
+fun File.deleteRecursively(): Boolean
+fun File.endsWith(other: File): Boolean
+fun File.endsWith(other: String): Boolean
+fun File.forEachBlock(action: Function2<ByteArray, Int, Unit>): Unit
+fun File.forEachBlock(blockSize: Int, action: Function2<ByteArray, Int, Unit>): Unit
+fun File.forEachLine(charset: Charset = ..., action: Function1<String, Unit>): Unit
+fun File.normalize(): File
+fun File.readBytes(): ByteArray
+fun File.readLines(charset: Charset = ...): List<String>
+fun File.readText(charset: Charset = ...): String
+fun File.relativeTo(base: File): File
+fun File.relativeToOrNull(base: File): File?
+fun File.relativeToOrSelf(base: File): File
+fun File.resolve(relative: File): File
+fun File.resolve(relative: String): File
+fun File.resolveSibling(relative: File): File
+fun File.resolveSibling(relative: String): File
+fun File.startsWith(other: File): Boolean
+fun File.startsWith(other: String): Boolean
+fun File.toRelativeString(base: File): String
+fun File.walk(direction: FileWalkDirection = ...): FileTreeWalk
+fun File.walkBottomUp(): FileTreeWalk
+fun File.walkTopDown(): FileTreeWalk
+fun File.writeBytes(array: ByteArray): Unit
+fun File.writeText(text: String, charset: Charset = ...): Unit
+fun InputStream.copyTo(out: OutputStream, bufferSize: Int = ...): Long
+fun InputStream.readBytes(): ByteArray
+fun InputStream.readBytes(estimatedSize: Int = ...): ByteArray
+fun Reader.copyTo(out: Writer, bufferSize: Int = ...): Long
+fun Reader.forEachLine(action: Function1<String, Unit>): Unit
+fun Reader.readLines(): List<String>
+fun Reader.readText(): String
+fun URL.readBytes(): ByteArray
+fun createTempDir(prefix: String = ..., suffix: String? = ..., directory: File? = ...): File
+fun createTempFile(prefix: String = ..., suffix: String? = ..., directory: File? = ...): File
+fun readLine(): String?
+fun readln(): String
+fun readlnOrNull(): String?
+inline fun <T : Closeable?, R> T.use(block: Function1<T, R>): R
+inline fun <T> File.useLines(charset: Charset = ..., block: Function1<Sequence<String>, T>): T
+inline fun <T> Reader.useLines(block: Function1<Sequence<String>, T>): T
+inline fun ByteArray.inputStream(): ByteArrayInputStream
+inline fun ByteArray.inputStream(offset: Int, length: Int): ByteArrayInputStream
+inline fun File.bufferedReader(charset: Charset = ..., bufferSize: Int = ...): BufferedReader
+inline fun File.bufferedWriter(charset: Charset = ..., bufferSize: Int = ...): BufferedWriter
+inline fun File.inputStream(): FileInputStream
+inline fun File.outputStream(): FileOutputStream
+inline fun File.printWriter(charset: Charset = ...): PrintWriter
+inline fun File.reader(charset: Charset = ...): InputStreamReader
+inline fun File.writer(charset: Charset = ...): OutputStreamWriter
+inline fun InputStream.buffered(bufferSize: Int = ...): BufferedInputStream
+inline fun InputStream.bufferedReader(charset: Charset = ...): BufferedReader
+inline fun InputStream.reader(charset: Charset = ...): InputStreamReader
+inline fun OutputStream.buffered(bufferSize: Int = ...): BufferedOutputStream
+inline fun OutputStream.bufferedWriter(charset: Charset = ...): BufferedWriter
+inline fun OutputStream.writer(charset: Charset = ...): OutputStreamWriter
+inline fun Reader.buffered(bufferSize: Int = ...): BufferedReader
+inline fun String.byteInputStream(charset: Charset = ...): ByteArrayInputStream
+inline fun String.reader(): StringReader
+inline fun URL.readText(charset: Charset = ...): String
+inline fun Writer.buffered(bufferSize: Int = ...): BufferedWriter
+inline fun print(message: Any?): Unit
+inline fun print(message: Boolean): Unit
+inline fun print(message: Byte): Unit
+inline fun print(message: Char): Unit
+inline fun print(message: CharArray): Unit
+inline fun print(message: Double): Unit
+inline fun print(message: Float): Unit
+inline fun print(message: Int): Unit
+inline fun print(message: Long): Unit
+inline fun print(message: Short): Unit
+inline fun println(): Unit
+inline fun println(message: Any?): Unit
+inline fun println(message: Boolean): Unit
+inline fun println(message: Byte): Unit
+inline fun println(message: Char): Unit
+inline fun println(message: CharArray): Unit
+inline fun println(message: Double): Unit
+inline fun println(message: Float): Unit
+inline fun println(message: Int): Unit
+inline fun println(message: Long): Unit
+inline fun println(message: Short): Unit
+operator fun BufferedInputStream.iterator(): ByteIterator
+val File.extension: String
+val File.invariantSeparatorsPath: String
+val File.isRooted: Boolean
+val File.nameWithoutExtension: String
+class AccessDeniedException : FileSystemException
+class FileAlreadyExistsException : FileSystemException
+class FileSystemException : IOException {
+    val file: File
+    val other: File?
+    val reason: String?
+}
+class FileTreeWalk : Sequence<File> {
+    fun maxDepth(depth: Int): FileTreeWalk
+    fun onEnter(function: Function1<File, Boolean>): FileTreeWalk
+    fun onFail(function: Function2<File, IOException, Unit>): FileTreeWalk
+    fun onLeave(function: Function1<File, Unit>): FileTreeWalk
+    operator fun iterator(): Iterator<File>
+}
+enum class FileWalkDirection : Enum<FileWalkDirection>
+class NoSuchFileException : FileSystemException
+enum class OnErrorAction : Enum<OnErrorAction>
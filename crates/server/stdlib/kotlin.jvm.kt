@@ -0,0 +1,42 @@
+package kotlin.jvm
+
+// Generated from cloud-pubsub-emulator-0.8.20-all.jar by `just generate-stdlib-index`
+
+fun <reified T : Any> Array<*>.isArrayOf(): Boolean
+val <E : Enum<E>> Enum<E>.declaringJavaClass: Class<E>
+val <T : Annotation> T.annotationClass: KClass<out T>
+val <T : Any> Class<T>.kotlin: KClass<T>
+val <T : Any> KClass<T>.javaClass: Class<KClass<T>>
+val <T : Any> KClass<T>.javaObjectType: Class<T>
+val <T : Any> KClass<T>.javaPrimitiveType: Class<T>?
+val <T : Any> T.javaClass: Class<T>
+val <T> KClass<T>.java: Class<T>
+annotation class JvmDefault : Annotation
+annotation class JvmDefaultWithCompatibility : Annotation
+annotation class JvmDefaultWithoutCompatibility : Annotation
+annotation class JvmField : Annotation
+annotation class JvmInline : Annotation
+annotation class JvmMultifileClass : Annotation
+annotation class JvmName : Annotation {
+    val name: String
+}
+annotation class JvmOverloads : Annotation
+annotation class JvmRecord : Annotation
+annotation class JvmSerializableLambda : Annotation
+annotation class JvmStatic : Annotation
+annotation class JvmSuppressWildcards : Annotation {
+    val suppress: Boolean
+}
+annotation class JvmSynthetic : Annotation
+annotation class JvmWildcard : Annotation
+class KotlinReflectionNotSupportedError : Error
+annotation class PurelyImplements : Annotation {
+    val value: String
+}
+annotation class Strictfp : Annotation
+annotation class Synchronized : Annotation
+annotation class Throws : Annotation {
+    val exceptionClasses: Array<out KClass<out Throwable>>
+}
+annotation class Transient : Annotation
+annotation class Volatile : Annotation
@@ -0,0 +1,1181 @@
+package kotlin
+
+// Generated from cloud-pubsub-emulator-0.8.20-all.jar by `just generate-stdlib-index`
+
+fun <T> Pair<T, T>.toList(): List<T>
+fun <T> Triple<T, T, T>.toList(): List<T>
+fun <T> lazy(initializer: Function0<T>): Lazy<T>
+fun <T> lazy(lock: Any?, initializer: Function0<T>): Lazy<T>
+fun <T> lazy(mode: LazyThreadSafetyMode, initializer: Function0<T>): Lazy<T>
+fun <T> lazyOf(value: T): Lazy<T>
+fun <reified T> arrayOfNulls(size: Int): Array<T?>
+fun Any?.toString(): String
+fun Byte.rotateLeft(bitCount: Int): Byte
+fun Byte.rotateRight(bitCount: Int): Byte
+fun Short.rotateLeft(bitCount: Int): Short
+fun Short.rotateRight(bitCount: Int): Short
+fun Throwable.addSuppressed(exception: Throwable): Unit
+fun Throwable.stackTraceToString(): String
+fun booleanArrayOf(vararg elements: Boolean): BooleanArray
+fun byteArrayOf(vararg elements: Byte): ByteArray
+fun charArrayOf(vararg elements: Char): CharArray
+fun doubleArrayOf(vararg elements: Double): DoubleArray
+fun floatArrayOf(vararg elements: Float): FloatArray
+fun intArrayOf(vararg elements: Int): IntArray
+fun longArrayOf(vararg elements: Long): LongArray
+fun shortArrayOf(vararg elements: Short): ShortArray
+infix fun <A, B> A.to(that: B): Pair<A, B>
+inline fun <R, T : R> Result<T>.getOrDefault(defaultValue: R): R
+inline fun <R, T : R> Result<T>.getOrElse(onFailure: Function1<Throwable, R>): R
+inline fun <R, T : R> Result<T>.recover(transform: Function1<Throwable, R>): Result<R>
+inline fun <R, T : R> Result<T>.recoverCatching(transform: Function1<Throwable, R>): Result<R>
+inline fun <R, T> Result<T>.fold(onSuccess: Function1<T, R>, onFailure: Function1<Throwable, R>): R
+inline fun <R, T> Result<T>.map(transform: Function1<T, R>): Result<R>
+inline fun <R, T> Result<T>.mapCatching(transform: Function1<T, R>): Result<R>
+inline fun <R> run(block: Function0<R>): R
+inline fun <R> runCatching(block: Function0<R>): Result<R>
+inline fun <R> suspend(block: Function1<Continuation<R>, Any?>): Function1<Continuation<R>, Any?>
+inline fun <R> synchronized(lock: Any, block: Function0<R>): R
+inline fun <T : Any> checkNotNull(value: T?): T
+inline fun <T : Any> checkNotNull(value: T?, lazyMessage: Function0<Any>): T
+inline fun <T : Any> requireNotNull(value: T?): T
+inline fun <T : Any> requireNotNull(value: T?, lazyMessage: Function0<Any>): T
+inline fun <T : AutoCloseable?, R> T.use(block: Function1<T, R>): R
+inline fun <T, R> T.let(block: Function1<T, R>): R
+inline fun <T, R> T.run(block: Function1<T, R>): R
+inline fun <T, R> T.runCatching(block: Function1<T, R>): Result<R>
+inline fun <T, R> with(receiver: T, block: Function1<T, R>): R
+inline fun <T> Result<T>.getOrThrow(): T
+inline fun <T> Result<T>.onFailure(action: Function1<Throwable, Unit>): Result<T>
+inline fun <T> Result<T>.onSuccess(action: Function1<T, Unit>): Result<T>
+inline fun <T> T.also(block: Function1<T, Unit>): T
+inline fun <T> T.apply(block: Function1<T, Unit>): T
+inline fun <T> T.takeIf(predicate: Function1<T, Boolean>): T?
+inline fun <T> T.takeUnless(predicate: Function1<T, Boolean>): T?
+inline fun <reified T : Enum<T>> enumValueOf(name: String): T
+inline fun <reified T : Enum<T>> enumValues(): Array<T>
+inline fun <reified T> arrayOf(vararg elements: T): Array<T>
+inline fun <reified T> emptyArray(): Array<T>
+inline fun Any?.hashCode(): Int
+inline fun BigInteger.inv(): BigInteger
+inline fun BigInteger.toBigDecimal(): BigDecimal
+inline fun BigInteger.toBigDecimal(scale: Int = ..., mathContext: MathContext = ...): BigDecimal
+inline fun Byte.countLeadingZeroBits(): Int
+inline fun Byte.countOneBits(): Int
+inline fun Byte.countTrailingZeroBits(): Int
+inline fun Byte.floorDiv(other: Byte): Int
+inline fun Byte.floorDiv(other: Int): Int
+inline fun Byte.floorDiv(other: Long): Long
+inline fun Byte.floorDiv(other: Short): Int
+inline fun Byte.mod(other: Byte): Byte
+inline fun Byte.mod(other: Int): Int
+inline fun Byte.mod(other: Long): Long
+inline fun Byte.mod(other: Short): Short
+inline fun Byte.takeHighestOneBit(): Byte
+inline fun Byte.takeLowestOneBit(): Byte
+inline fun Byte.toUByte(): UByte
+inline fun Byte.toUInt(): UInt
+inline fun Byte.toULong(): ULong
+inline fun Byte.toUShort(): UShort
+inline fun Char(code: Int): Char
+inline fun Char(code: UShort): Char
+inline fun Double.Companion.fromBits(bits: Long): Double
+inline fun Double.isFinite(): Boolean
+inline fun Double.isInfinite(): Boolean
+inline fun Double.isNaN(): Boolean
+inline fun Double.mod(other: Double): Double
+inline fun Double.mod(other: Float): Double
+inline fun Double.toBigDecimal(): BigDecimal
+inline fun Double.toBigDecimal(mathContext: MathContext): BigDecimal
+inline fun Double.toBits(): Long
+inline fun Double.toRawBits(): Long
+inline fun Double.toUInt(): UInt
+inline fun Double.toULong(): ULong
+inline fun Float.Companion.fromBits(bits: Int): Float
+inline fun Float.isFinite(): Boolean
+inline fun Float.isInfinite(): Boolean
+inline fun Float.isNaN(): Boolean
+inline fun Float.mod(other: Double): Double
+inline fun Float.mod(other: Float): Float
+inline fun Float.toBigDecimal(): BigDecimal
+inline fun Float.toBigDecimal(mathContext: MathContext): BigDecimal
+inline fun Float.toBits(): Int
+inline fun Float.toRawBits(): Int
+inline fun Float.toUInt(): UInt
+inline fun Float.toULong(): ULong
+inline fun Int.countLeadingZeroBits(): Int
+inline fun Int.countOneBits(): Int
+inline fun Int.countTrailingZeroBits(): Int
+inline fun Int.floorDiv(other: Byte): Int
+inline fun Int.floorDiv(other: Int): Int
+inline fun Int.floorDiv(other: Long): Long
+inline fun Int.floorDiv(other: Short): Int
+inline fun Int.mod(other: Byte): Byte
+inline fun Int.mod(other: Int): Int
+inline fun Int.mod(other: Long): Long
+inline fun Int.mod(other: Short): Short
+inline fun Int.rotateLeft(bitCount: Int): Int
+inline fun Int.rotateRight(bitCount: Int): Int
+inline fun Int.takeHighestOneBit(): Int
+inline fun Int.takeLowestOneBit(): Int
+inline fun Int.toBigDecimal(): BigDecimal
+inline fun Int.toBigDecimal(mathContext: MathContext): BigDecimal
+inline fun Int.toBigInteger(): BigInteger
+inline fun Int.toUByte(): UByte
+inline fun Int.toUInt(): UInt
+inline fun Int.toULong(): ULong
+inline fun Int.toUShort(): UShort
+inline fun Long.countLeadingZeroBits(): Int
+inline fun Long.countOneBits(): Int
+inline fun Long.countTrailingZeroBits(): Int
+inline fun Long.floorDiv(other: Byte): Long
+inline fun Long.floorDiv(other: Int): Long
+inline fun Long.floorDiv(other: Long): Long
+inline fun Long.floorDiv(other: Short): Long
+inline fun Long.mod(other: Byte): Byte
+inline fun Long.mod(other: Int): Int
+inline fun Long.mod(other: Long): Long
+inline fun Long.mod(other: Short): Short
+inline fun Long.rotateLeft(bitCount: Int): Long
+inline fun Long.rotateRight(bitCount: Int): Long
+inline fun Long.takeHighestOneBit(): Long
+inline fun Long.takeLowestOneBit(): Long
+inline fun Long.toBigDecimal(): BigDecimal
+inline fun Long.toBigDecimal(mathContext: MathContext): BigDecimal
+inline fun Long.toBigInteger(): BigInteger
+inline fun Long.toUByte(): UByte
+inline fun Long.toUInt(): UInt
+inline fun Long.toULong(): ULong
+inline fun Long.toUShort(): UShort
+inline fun Short.countLeadingZeroBits(): Int
+inline fun Short.countOneBits(): Int
+inline fun Short.countTrailingZeroBits(): Int
+inline fun Short.floorDiv(other: Byte): Int
+inline fun Short.floorDiv(other: Int): Int
+inline fun Short.floorDiv(other: Long): Long
+inline fun Short.floorDiv(other: Short): Int
+inline fun Short.mod(other: Byte): Byte
+inline fun Short.mod(other: Int): Int
+inline fun Short.mod(other: Long): Long
+inline fun Short.mod(other: Short): Short
+inline fun Short.takeHighestOneBit(): Short
+inline fun Short.takeLowestOneBit(): Short
+inline fun Short.toUByte(): UByte
+inline fun Short.toUInt(): UInt
+inline fun Short.toULong(): ULong
+inline fun Short.toUShort(): UShort
+inline fun TODO(): Nothing
+inline fun TODO(reason: String): Nothing
+inline fun Throwable.printStackTrace(): Unit
+inline fun Throwable.printStackTrace(stream: PrintStream): Unit
+inline fun Throwable.printStackTrace(writer: PrintWriter): Unit
+inline fun UByte.countLeadingZeroBits(): Int
+inline fun UByte.countOneBits(): Int
+inline fun UByte.countTrailingZeroBits(): Int
+inline fun UByte.rotateLeft(bitCount: Int): UByte
+inline fun UByte.rotateRight(bitCount: Int): UByte
+inline fun UByte.takeHighestOneBit(): UByte
+inline fun UByte.takeLowestOneBit(): UByte
+inline fun UByteArray(size: Int, init: Function1<Int, UByte>): UByteArray
+inline fun UInt.countLeadingZeroBits(): Int
+inline fun UInt.countOneBits(): Int
+inline fun UInt.countTrailingZeroBits(): Int
+inline fun UInt.rotateLeft(bitCount: Int): UInt
+inline fun UInt.rotateRight(bitCount: Int): UInt
+inline fun UInt.takeHighestOneBit(): UInt
+inline fun UInt.takeLowestOneBit(): UInt
+inline fun UIntArray(size: Int, init: Function1<Int, UInt>): UIntArray
+inline fun ULong.countLeadingZeroBits(): Int
+inline fun ULong.countOneBits(): Int
+inline fun ULong.countTrailingZeroBits(): Int
+inline fun ULong.rotateLeft(bitCount: Int): ULong
+inline fun ULong.rotateRight(bitCount: Int): ULong
+inline fun ULong.takeHighestOneBit(): ULong
+inline fun ULong.takeLowestOneBit(): ULong
+inline fun ULongArray(size: Int, init: Function1<Int, ULong>): ULongArray
+inline fun UShort.countLeadingZeroBits(): Int
+inline fun UShort.countOneBits(): Int
+inline fun UShort.countTrailingZeroBits(): Int
+inline fun UShort.rotateLeft(bitCount: Int): UShort
+inline fun UShort.rotateRight(bitCount: Int): UShort
+inline fun UShort.takeHighestOneBit(): UShort
+inline fun UShort.takeLowestOneBit(): UShort
+inline fun UShortArray(size: Int, init: Function1<Int, UShort>): UShortArray
+inline fun assert(value: Boolean): Unit
+inline fun assert(value: Boolean, lazyMessage: Function0<Any>): Unit
+inline fun check(value: Boolean): Unit
+inline fun check(value: Boolean, lazyMessage: Function0<Any>): Unit
+inline fun error(message: Any): Nothing
+inline fun repeat(times: Int, action: Function1<Int, Unit>): Unit
+inline fun require(value: Boolean): Unit
+inline fun require(value: Boolean, lazyMessage: Function0<Any>): Unit
+inline fun ubyteArrayOf(vararg elements: UByte): UByteArray
+inline fun uintArrayOf(vararg elements: UInt): UIntArray
+inline fun ulongArrayOf(vararg elements: ULong): ULongArray
+inline fun ushortArrayOf(vararg elements: UShort): UShortArray
+inline infix fun <T> Comparable<T>.compareTo(other: T): Int
+inline infix fun BigInteger.and(other: BigInteger): BigInteger
+inline infix fun BigInteger.or(other: BigInteger): BigInteger
+inline infix fun BigInteger.shl(n: Int): BigInteger
+inline infix fun BigInteger.shr(n: Int): BigInteger
+inline infix fun BigInteger.xor(other: BigInteger): BigInteger
+inline operator fun <T, V> KMutableProperty1<T, V>.setValue(thisRef: T, property: KProperty<*>, value: V): Unit
+inline operator fun <T, V> KProperty1<T, V>.getValue(thisRef: T, property: KProperty<*>): V
+inline operator fun <T> Lazy<T>.getValue(thisRef: Any?, property: KProperty<*>): T
+inline operator fun <V> KMutableProperty0<V>.setValue(thisRef: Any?, property: KProperty<*>, value: V): Unit
+inline operator fun <V> KProperty0<V>.getValue(thisRef: Any?, property: KProperty<*>): V
+inline operator fun BigDecimal.dec(): BigDecimal
+inline operator fun BigDecimal.div(other: BigDecimal): BigDecimal
+inline operator fun BigDecimal.inc(): BigDecimal
+inline operator fun BigDecimal.minus(other: BigDecimal): BigDecimal
+inline operator fun BigDecimal.plus(other: BigDecimal): BigDecimal
+inline operator fun BigDecimal.rem(other: BigDecimal): BigDecimal
+inline operator fun BigDecimal.times(other: BigDecimal): BigDecimal
+inline operator fun BigDecimal.unaryMinus(): BigDecimal
+inline operator fun BigInteger.dec(): BigInteger
+inline operator fun BigInteger.div(other: BigInteger): BigInteger
+inline operator fun BigInteger.inc(): BigInteger
+inline operator fun BigInteger.minus(other: BigInteger): BigInteger
+inline operator fun BigInteger.plus(other: BigInteger): BigInteger
+inline operator fun BigInteger.rem(other: BigInteger): BigInteger
+inline operator fun BigInteger.times(other: BigInteger): BigInteger
+inline operator fun BigInteger.unaryMinus(): BigInteger
+operator fun <T, R> DeepRecursiveFunction<T, R>.invoke(value: T): R
+operator fun String?.plus(other: Any?): String
+val Char.code: Int
+val KProperty0<*>.isInitialized: Boolean
+val Throwable.stackTrace: Array<StackTraceElement>
+val Throwable.suppressedExceptions: List<Throwable>
+interface Annotation : Any
+class Any {
+    fun hashCode(): Int
+    fun toString(): String
+    operator fun equals(other: Any?): Boolean
+}
+class Array<T> : Any {
+    operator fun get(index: Int): T
+    operator fun iterator(): Iterator<T>
+    operator fun set(index: Int, value: T): Unit
+    val size: Int
+}
+class Boolean : Comparable<Boolean> {
+    fun toString(): String
+    infix fun and(other: Boolean): Boolean
+    infix fun or(other: Boolean): Boolean
+    infix fun xor(other: Boolean): Boolean
+    operator fun compareTo(other: Boolean): Int
+    operator fun equals(other: Any?): Boolean
+    operator fun not(): Boolean
+    companion object Companion : Any
+}
+class BooleanArray : Any {
+    operator fun get(index: Int): Boolean
+    operator fun iterator(): BooleanIterator
+    operator fun set(index: Int, value: Boolean): Unit
+    val size: Int
+}
+annotation class BuilderInference : Annotation
+class Byte : Number, Comparable<Byte> {
+    fun toByte(): Byte
+    fun toChar(): Char
+    fun toDouble(): Double
+    fun toFloat(): Float
+    fun toInt(): Int
+    fun toLong(): Long
+    fun toShort(): Short
+    fun toString(): String
+    operator fun compareTo(other: Byte): Int
+    operator fun compareTo(other: Double): Int
+    operator fun compareTo(other: Float): Int
+    operator fun compareTo(other: Int): Int
+    operator fun compareTo(other: Long): Int
+    operator fun compareTo(other: Short): Int
+    operator fun dec(): Byte
+    operator fun div(other: Byte): Int
+    operator fun div(other: Double): Double
+    operator fun div(other: Float): Float
+    operator fun div(other: Int): Int
+    operator fun div(other: Long): Long
+    operator fun div(other: Short): Int
+    operator fun equals(other: Any?): Boolean
+    operator fun inc(): Byte
+    operator fun minus(other: Byte): Int
+    operator fun minus(other: Double): Double
+    operator fun minus(other: Float): Float
+    operator fun minus(other: Int): Int
+    operator fun minus(other: Long): Long
+    operator fun minus(other: Short): Int
+    operator fun plus(other: Byte): Int
+    operator fun plus(other: Double): Double
+    operator fun plus(other: Float): Float
+    operator fun plus(other: Int): Int
+    operator fun plus(other: Long): Long
+    operator fun plus(other: Short): Int
+    operator fun rangeTo(other: Byte): IntRange
+    operator fun rangeTo(other: Int): IntRange
+    operator fun rangeTo(other: Long): LongRange
+    operator fun rangeTo(other: Short): IntRange
+    operator fun rangeUntil(other: Byte): IntRange
+    operator fun rangeUntil(other: Int): IntRange
+    operator fun rangeUntil(other: Long): LongRange
+    operator fun rangeUntil(other: Short): IntRange
+    operator fun rem(other: Byte): Int
+    operator fun rem(other: Double): Double
+    operator fun rem(other: Float): Float
+    operator fun rem(other: Int): Int
+    operator fun rem(other: Long): Long
+    operator fun rem(other: Short): Int
+    operator fun times(other: Byte): Int
+    operator fun times(other: Double): Double
+    operator fun times(other: Float): Float
+    operator fun times(other: Int): Int
+    operator fun times(other: Long): Long
+    operator fun times(other: Short): Int
+    operator fun unaryMinus(): Int
+    operator fun unaryPlus(): Int
+    companion object Companion : Any {
+        const val MAX_VALUE: Byte
+        const val MIN_VALUE: Byte
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class ByteArray : Any {
+    operator fun get(index: Int): Byte
+    operator fun iterator(): ByteIterator
+    operator fun set(index: Int, value: Byte): Unit
+    val size: Int
+}
+class Char : Comparable<Char> {
+    fun toByte(): Byte
+    fun toChar(): Char
+    fun toDouble(): Double
+    fun toFloat(): Float
+    fun toInt(): Int
+    fun toLong(): Long
+    fun toShort(): Short
+    fun toString(): String
+    operator fun compareTo(other: Char): Int
+    operator fun dec(): Char
+    operator fun equals(other: Any?): Boolean
+    operator fun inc(): Char
+    operator fun minus(other: Char): Int
+    operator fun minus(other: Int): Char
+    operator fun plus(other: Int): Char
+    operator fun rangeTo(other: Char): CharRange
+    operator fun rangeUntil(other: Char): CharRange
+    companion object Companion : Any {
+        const val MAX_HIGH_SURROGATE: Char
+        const val MAX_LOW_SURROGATE: Char
+        const val MAX_SURROGATE: Char
+        const val MAX_VALUE: Char
+        const val MIN_HIGH_SURROGATE: Char
+        const val MIN_LOW_SURROGATE: Char
+        const val MIN_SURROGATE: Char
+        const val MIN_VALUE: Char
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class CharArray : Any {
+    operator fun get(index: Int): Char
+    operator fun iterator(): CharIterator
+    operator fun set(index: Int, value: Char): Unit
+    val size: Int
+}
+interface CharSequence : Any {
+    fun subSequence(startIndex: Int, endIndex: Int): CharSequence
+    operator fun get(index: Int): Char
+    val length: Int
+}
+interface Cloneable : Any {
+    fun clone(): Any
+}
+interface Comparable<in T> : Any {
+    operator fun compareTo(other: T): Int
+}
+annotation class ContextFunctionTypeParams : Annotation {
+    val count: Int
+}
+class DeepRecursiveFunction<T, R> : Any {
+}
+class DeepRecursiveScope<T, R> : Any {
+    operator fun DeepRecursiveFunction<*, *>.invoke(value: Any?): Nothing
+    suspend fun <U, S> DeepRecursiveFunction<U, S>.callRecursive(value: U): S
+    suspend fun callRecursive(value: T): R
+}
+annotation class Deprecated : Annotation {
+    val level: DeprecationLevel
+    val message: String
+    val replaceWith: ReplaceWith
+}
+annotation class DeprecatedSinceKotlin : Annotation {
+    val errorSince: String
+    val hiddenSince: String
+    val warningSince: String
+}
+enum class DeprecationLevel : Enum<DeprecationLevel>
+class Double : Number, Comparable<Double> {
+    fun toByte(): Byte
+    fun toChar(): Char
+    fun toDouble(): Double
+    fun toFloat(): Float
+    fun toInt(): Int
+    fun toLong(): Long
+    fun toShort(): Short
+    fun toString(): String
+    operator fun compareTo(other: Byte): Int
+    operator fun compareTo(other: Double): Int
+    operator fun compareTo(other: Float): Int
+    operator fun compareTo(other: Int): Int
+    operator fun compareTo(other: Long): Int
+    operator fun compareTo(other: Short): Int
+    operator fun dec(): Double
+    operator fun div(other: Byte): Double
+    operator fun div(other: Double): Double
+    operator fun div(other: Float): Double
+    operator fun div(other: Int): Double
+    operator fun div(other: Long): Double
+    operator fun div(other: Short): Double
+    operator fun equals(other: Any?): Boolean
+    operator fun inc(): Double
+    operator fun minus(other: Byte): Double
+    operator fun minus(other: Double): Double
+    operator fun minus(other: Float): Double
+    operator fun minus(other: Int): Double
+    operator fun minus(other: Long): Double
+    operator fun minus(other: Short): Double
+    operator fun plus(other: Byte): Double
+    operator fun plus(other: Double): Double
+    operator fun plus(other: Float): Double
+    operator fun plus(other: Int): Double
+    operator fun plus(other: Long): Double
+    operator fun plus(other: Short): Double
+    operator fun rem(other: Byte): Double
+    operator fun rem(other: Double): Double
+    operator fun rem(other: Float): Double
+    operator fun rem(other: Int): Double
+    operator fun rem(other: Long): Double
+    operator fun rem(other: Short): Double
+    operator fun times(other: Byte): Double
+    operator fun times(other: Double): Double
+    operator fun times(other: Float): Double
+    operator fun times(other: Int): Double
+    operator fun times(other: Long): Double
+    operator fun times(other: Short): Double
+    operator fun unaryMinus(): Double
+    operator fun unaryPlus(): Double
+    companion object Companion : Any {
+        const val MAX_VALUE: Double
+        const val MIN_VALUE: Double
+        const val NEGATIVE_INFINITY: Double
+        const val NaN: Double
+        const val POSITIVE_INFINITY: Double
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class DoubleArray : Any {
+    operator fun get(index: Int): Double
+    operator fun iterator(): DoubleIterator
+    operator fun set(index: Int, value: Double): Unit
+    val size: Int
+}
+annotation class DslMarker : Annotation
+class Enum<E : Enum<E>> : Comparable<E> {
+    fun clone(): Any
+    fun hashCode(): Int
+    fun toString(): String
+    operator fun compareTo(other: E): Int
+    operator fun equals(other: Any?): Boolean
+    val name: String
+    val ordinal: Int
+    companion object Companion : Any
+}
+annotation class ExperimentalMultiplatform : Annotation
+annotation class ExperimentalStdlibApi : Annotation
+annotation class ExperimentalSubclassOptIn : Annotation
+annotation class ExperimentalUnsignedTypes : Annotation
+annotation class ExtensionFunctionType : Annotation
+class Float : Number, Comparable<Float> {
+    fun toByte(): Byte
+    fun toChar(): Char
+    fun toDouble(): Double
+    fun toFloat(): Float
+    fun toInt(): Int
+    fun toLong(): Long
+    fun toShort(): Short
+    fun toString(): String
+    operator fun compareTo(other: Byte): Int
+    operator fun compareTo(other: Double): Int
+    operator fun compareTo(other: Float): Int
+    operator fun compareTo(other: Int): Int
+    operator fun compareTo(other: Long): Int
+    operator fun compareTo(other: Short): Int
+    operator fun dec(): Float
+    operator fun div(other: Byte): Float
+    operator fun div(other: Double): Double
+    operator fun div(other: Float): Float
+    operator fun div(other: Int): Float
+    operator fun div(other: Long): Float
+    operator fun div(other: Short): Float
+    operator fun equals(other: Any?): Boolean
+    operator fun inc(): Float
+    operator fun minus(other: Byte): Float
+    operator fun minus(other: Double): Double
+    operator fun minus(other: Float): Float
+    operator fun minus(other: Int): Float
+    operator fun minus(other: Long): Float
+    operator fun minus(other: Short): Float
+    operator fun plus(other: Byte): Float
+    operator fun plus(other: Double): Double
+    operator fun plus(other: Float): Float
+    operator fun plus(other: Int): Float
+    operator fun plus(other: Long): Float
+    operator fun plus(other: Short): Float
+    operator fun rem(other: Byte): Float
+    operator fun rem(other: Double): Double
+    operator fun rem(other: Float): Float
+    operator fun rem(other: Int): Float
+    operator fun rem(other: Long): Float
+    operator fun rem(other: Short): Float
+    operator fun times(other: Byte): Float
+    operator fun times(other: Double): Double
+    operator fun times(other: Float): Float
+    operator fun times(other: Int): Float
+    operator fun times(other: Long): Float
+    operator fun times(other: Short): Float
+    operator fun unaryMinus(): Float
+    operator fun unaryPlus(): Float
+    companion object Companion : Any {
+        const val MAX_VALUE: Float
+        const val MIN_VALUE: Float
+        const val NEGATIVE_INFINITY: Float
+        const val NaN: Float
+        const val POSITIVE_INFINITY: Float
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class FloatArray : Any {
+    operator fun get(index: Int): Float
+    operator fun iterator(): FloatIterator
+    operator fun set(index: Int, value: Float): Unit
+    val size: Int
+}
+interface Function<out R> : Any
+class Int : Number, Comparable<Int> {
+    fun inv(): Int
+    fun toByte(): Byte
+    fun toChar(): Char
+    fun toDouble(): Double
+    fun toFloat(): Float
+    fun toInt(): Int
+    fun toLong(): Long
+    fun toShort(): Short
+    fun toString(): String
+    infix fun and(other: Int): Int
+    infix fun or(other: Int): Int
+    infix fun shl(bitCount: Int): Int
+    infix fun shr(bitCount: Int): Int
+    infix fun ushr(bitCount: Int): Int
+    infix fun xor(other: Int): Int
+    operator fun compareTo(other: Byte): Int
+    operator fun compareTo(other: Double): Int
+    operator fun compareTo(other: Float): Int
+    operator fun compareTo(other: Int): Int
+    operator fun compareTo(other: Long): Int
+    operator fun compareTo(other: Short): Int
+    operator fun dec(): Int
+    operator fun div(other: Byte): Int
+    operator fun div(other: Double): Double
+    operator fun div(other: Float): Float
+    operator fun div(other: Int): Int
+    operator fun div(other: Long): Long
+    operator fun div(other: Short): Int
+    operator fun equals(other: Any?): Boolean
+    operator fun inc(): Int
+    operator fun minus(other: Byte): Int
+    operator fun minus(other: Double): Double
+    operator fun minus(other: Float): Float
+    operator fun minus(other: Int): Int
+    operator fun minus(other: Long): Long
+    operator fun minus(other: Short): Int
+    operator fun plus(other: Byte): Int
+    operator fun plus(other: Double): Double
+    operator fun plus(other: Float): Float
+    operator fun plus(other: Int): Int
+    operator fun plus(other: Long): Long
+    operator fun plus(other: Short): Int
+    operator fun rangeTo(other: Byte): IntRange
+    operator fun rangeTo(other: Int): IntRange
+    operator fun rangeTo(other: Long): LongRange
+    operator fun rangeTo(other: Short): IntRange
+    operator fun rangeUntil(other: Byte): IntRange
+    operator fun rangeUntil(other: Int): IntRange
+    operator fun rangeUntil(other: Long): LongRange
+    operator fun rangeUntil(other: Short): IntRange
+    operator fun rem(other: Byte): Int
+    operator fun rem(other: Double): Double
+    operator fun rem(other: Float): Float
+    operator fun rem(other: Int): Int
+    operator fun rem(other: Long): Long
+    operator fun rem(other: Short): Int
+    operator fun times(other: Byte): Int
+    operator fun times(other: Double): Double
+    operator fun times(other: Float): Float
+    operator fun times(other: Int): Int
+    operator fun times(other: Long): Long
+    operator fun times(other: Short): Int
+    operator fun unaryMinus(): Int
+    operator fun unaryPlus(): Int
+    companion object Companion : Any {
+        const val MAX_VALUE: Int
+        const val MIN_VALUE: Int
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class IntArray : Any {
+    operator fun get(index: Int): Int
+    operator fun iterator(): IntIterator
+    operator fun set(index: Int, value: Int): Unit
+    val size: Int
+}
+class KotlinNullPointerException : NullPointerException
+class KotlinVersion : Comparable<KotlinVersion> {
+    fun hashCode(): Int
+    fun isAtLeast(major: Int, minor: Int): Boolean
+    fun isAtLeast(major: Int, minor: Int, patch: Int): Boolean
+    fun toString(): String
+    operator fun compareTo(other: KotlinVersion): Int
+    operator fun equals(other: Any?): Boolean
+    val major: Int
+    val minor: Int
+    val patch: Int
+    companion object Companion : Any {
+        const val MAX_COMPONENT_VALUE: Int
+        val CURRENT: KotlinVersion
+    }
+}
+interface Lazy<out T> : Any {
+    fun isInitialized(): Boolean
+    val value: T
+}
+enum class LazyThreadSafetyMode : Enum<LazyThreadSafetyMode>
+class Long : Number, Comparable<Long> {
+    fun inv(): Long
+    fun toByte(): Byte
+    fun toChar(): Char
+    fun toDouble(): Double
+    fun toFloat(): Float
+    fun toInt(): Int
+    fun toLong(): Long
+    fun toShort(): Short
+    fun toString(): String
+    infix fun and(other: Long): Long
+    infix fun or(other: Long): Long
+    infix fun shl(bitCount: Int): Long
+    infix fun shr(bitCount: Int): Long
+    infix fun ushr(bitCount: Int): Long
+    infix fun xor(other: Long): Long
+    operator fun compareTo(other: Byte): Int
+    operator fun compareTo(other: Double): Int
+    operator fun compareTo(other: Float): Int
+    operator fun compareTo(other: Int): Int
+    operator fun compareTo(other: Long): Int
+    operator fun compareTo(other: Short): Int
+    operator fun dec(): Long
+    operator fun div(other: Byte): Long
+    operator fun div(other: Double): Double
+    operator fun div(other: Float): Float
+    operator fun div(other: Int): Long
+    operator fun div(other: Long): Long
+    operator fun div(other: Short): Long
+    operator fun equals(other: Any?): Boolean
+    operator fun inc(): Long
+    operator fun minus(other: Byte): Long
+    operator fun minus(other: Double): Double
+    operator fun minus(other: Float): Float
+    operator fun minus(other: Int): Long
+    operator fun minus(other: Long): Long
+    operator fun minus(other: Short): Long
+    operator fun plus(other: Byte): Long
+    operator fun plus(other: Double): Double
+    operator fun plus(other: Float): Float
+    operator fun plus(other: Int): Long
+    operator fun plus(other: Long): Long
+    operator fun plus(other: Short): Long
+    operator fun rangeTo(other: Byte): LongRange
+    operator fun rangeTo(other: Int): LongRange
+    operator fun rangeTo(other: Long): LongRange
+    operator fun rangeTo(other: Short): LongRange
+    operator fun rangeUntil(other: Byte): LongRange
+    operator fun rangeUntil(other: Int): LongRange
+    operator fun rangeUntil(other: Long): LongRange
+    operator fun rangeUntil(other: Short): LongRange
+    operator fun rem(other: Byte): Long
+    operator fun rem(other: Double): Double
+    operator fun rem(other: Float): Float
+    operator fun rem(other: Int): Long
+    operator fun rem(other: Long): Long
+    operator fun rem(other: Short): Long
+    operator fun times(other: Byte): Long
+    operator fun times(other: Double): Double
+    operator fun times(other: Float): Float
+    operator fun times(other: Int): Long
+    operator fun times(other: Long): Long
+    operator fun times(other: Short): Long
+    operator fun unaryMinus(): Long
+    operator fun unaryPlus(): Long
+    companion object Companion : Any {
+        const val MAX_VALUE: Long
+        const val MIN_VALUE: Long
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class LongArray : Any {
+    operator fun get(index: Int): Long
+    operator fun iterator(): LongIterator
+    operator fun set(index: Int, value: Long): Unit
+    val size: Int
+}
+annotation class Metadata : Annotation {
+    val bytecodeVersion: IntArray
+    val data1: Array<String>
+    val data2: Array<String>
+    val extraInt: Int
+    val extraString: String
+    val kind: Int
+    val metadataVersion: IntArray
+    val packageName: String
+}
+class NoWhenBranchMatchedException : RuntimeException
+class NotImplementedError : Error
+class Nothing
+class Number : Any {
+    fun toByte(): Byte
+    fun toChar(): Char
+    fun toDouble(): Double
+    fun toFloat(): Float
+    fun toInt(): Int
+    fun toLong(): Long
+    fun toShort(): Short
+}
+annotation class OptIn : Annotation {
+    val markerClass: Array<out KClass<out Annotation>>
+}
+annotation class OptionalExpectation : Annotation
+annotation class OverloadResolutionByLambdaReturnType : Annotation
+data class Pair<out A, out B> : Serializable {
+    fun toString(): String
+    val first: A
+    val second: B
+}
+annotation class ParameterName : Annotation {
+    val name: String
+}
+annotation class PublishedApi : Annotation
+annotation class ReplaceWith : Annotation {
+    val expression: String
+    val imports: Array<out String>
+}
+annotation class RequiresOptIn : Annotation {
+    val level: RequiresOptIn.Level
+    val message: String
+    enum class Level : Enum<RequiresOptIn.Level>
+}
+class Result<out T> : Serializable {
+    fun exceptionOrNull(): Throwable?
+    fun toString(): String
+    inline fun getOrNull(): T?
+    val isFailure: Boolean
+    val isSuccess: Boolean
+    companion object Companion : Any {
+        inline fun <T> failure(exception: Throwable): Result<T>
+        inline fun <T> success(value: T): Result<T>
+    }
+}
+class Short : Number, Comparable<Short> {
+    fun toByte(): Byte
+    fun toChar(): Char
+    fun toDouble(): Double
+    fun toFloat(): Float
+    fun toInt(): Int
+    fun toLong(): Long
+    fun toShort(): Short
+    fun toString(): String
+    operator fun compareTo(other: Byte): Int
+    operator fun compareTo(other: Double): Int
+    operator fun compareTo(other: Float): Int
+    operator fun compareTo(other: Int): Int
+    operator fun compareTo(other: Long): Int
+    operator fun compareTo(other: Short): Int
+    operator fun dec(): Short
+    operator fun div(other: Byte): Int
+    operator fun div(other: Double): Double
+    operator fun div(other: Float): Float
+    operator fun div(other: Int): Int
+    operator fun div(other: Long): Long
+    operator fun div(other: Short): Int
+    operator fun equals(other: Any?): Boolean
+    operator fun inc(): Short
+    operator fun minus(other: Byte): Int
+    operator fun minus(other: Double): Double
+    operator fun minus(other: Float): Float
+    operator fun minus(other: Int): Int
+    operator fun minus(other: Long): Long
+    operator fun minus(other: Short): Int
+    operator fun plus(other: Byte): Int
+    operator fun plus(other: Double): Double
+    operator fun plus(other: Float): Float
+    operator fun plus(other: Int): Int
+    operator fun plus(other: Long): Long
+    operator fun plus(other: Short): Int
+    operator fun rangeTo(other: Byte): IntRange
+    operator fun rangeTo(other: Int): IntRange
+    operator fun rangeTo(other: Long): LongRange
+    operator fun rangeTo(other: Short): IntRange
+    operator fun rangeUntil(other: Byte): IntRange
+    operator fun rangeUntil(other: Int): IntRange
+    operator fun rangeUntil(other: Long): LongRange
+    operator fun rangeUntil(other: Short): IntRange
+    operator fun rem(other: Byte): Int
+    operator fun rem(other: Double): Double
+    operator fun rem(other: Float): Float
+    operator fun rem(other: Int): Int
+    operator fun rem(other: Long): Long
+    operator fun rem(other: Short): Int
+    operator fun times(other: Byte): Int
+    operator fun times(other: Double): Double
+    operator fun times(other: Float): Float
+    operator fun times(other: Int): Int
+    operator fun times(other: Long): Long
+    operator fun times(other: Short): Int
+    operator fun unaryMinus(): Int
+    operator fun unaryPlus(): Int
+    companion object Companion : Any {
+        const val MAX_VALUE: Short
+        const val MIN_VALUE: Short
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class ShortArray : Any {
+    operator fun get(index: Int): Short
+    operator fun iterator(): ShortIterator
+    operator fun set(index: Int, value: Short): Unit
+    val size: Int
+}
+annotation class SinceKotlin : Annotation {
+    val version: String
+}
+class String : Comparable<String>, CharSequence {
+    fun subSequence(startIndex: Int, endIndex: Int): CharSequence
+    fun toString(): String
+    operator fun compareTo(other: String): Int
+    operator fun equals(other: Any?): Boolean
+    operator fun get(index: Int): Char
+    operator fun plus(other: Any?): String
+    val length: Int
+    companion object Companion : Any
+}
+annotation class SubclassOptInRequired : Annotation {
+    val markerClass: KClass<out Annotation>
+}
+annotation class Suppress : Annotation {
+    val names: Array<out String>
+}
+class Throwable : Any {
+    val cause: Throwable?
+    val message: String?
+}
+data class Triple<out A, out B, out C> : Serializable {
+    fun toString(): String
+    val first: A
+    val second: B
+    val third: C
+}
+class TypeCastException : ClassCastException
+class UByte : Comparable<UByte> {
+    fun toString(): String
+    inline fun floorDiv(other: UByte): UInt
+    inline fun floorDiv(other: UInt): UInt
+    inline fun floorDiv(other: ULong): ULong
+    inline fun floorDiv(other: UShort): UInt
+    inline fun inv(): UByte
+    inline fun mod(other: UByte): UByte
+    inline fun mod(other: UInt): UInt
+    inline fun mod(other: ULong): ULong
+    inline fun mod(other: UShort): UShort
+    inline fun toByte(): Byte
+    inline fun toDouble(): Double
+    inline fun toFloat(): Float
+    inline fun toInt(): Int
+    inline fun toLong(): Long
+    inline fun toShort(): Short
+    inline fun toUByte(): UByte
+    inline fun toUInt(): UInt
+    inline fun toULong(): ULong
+    inline fun toUShort(): UShort
+    inline infix fun and(other: UByte): UByte
+    inline infix fun or(other: UByte): UByte
+    inline infix fun xor(other: UByte): UByte
+    inline operator fun compareTo(other: UByte): Int
+    inline operator fun compareTo(other: UInt): Int
+    inline operator fun compareTo(other: ULong): Int
+    inline operator fun compareTo(other: UShort): Int
+    inline operator fun dec(): UByte
+    inline operator fun div(other: UByte): UInt
+    inline operator fun div(other: UInt): UInt
+    inline operator fun div(other: ULong): ULong
+    inline operator fun div(other: UShort): UInt
+    inline operator fun inc(): UByte
+    inline operator fun minus(other: UByte): UInt
+    inline operator fun minus(other: UInt): UInt
+    inline operator fun minus(other: ULong): ULong
+    inline operator fun minus(other: UShort): UInt
+    inline operator fun plus(other: UByte): UInt
+    inline operator fun plus(other: UInt): UInt
+    inline operator fun plus(other: ULong): ULong
+    inline operator fun plus(other: UShort): UInt
+    inline operator fun rangeTo(other: UByte): UIntRange
+    inline operator fun rangeUntil(other: UByte): UIntRange
+    inline operator fun rem(other: UByte): UInt
+    inline operator fun rem(other: UInt): UInt
+    inline operator fun rem(other: ULong): ULong
+    inline operator fun rem(other: UShort): UInt
+    inline operator fun times(other: UByte): UInt
+    inline operator fun times(other: UInt): UInt
+    inline operator fun times(other: ULong): ULong
+    inline operator fun times(other: UShort): UInt
+    companion object Companion : Any {
+        const val MAX_VALUE: UByte
+        const val MIN_VALUE: UByte
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class UByteArray : Collection<UByte> {
+    fun containsAll(elements: Collection<UByte>): Boolean
+    fun isEmpty(): Boolean
+    operator fun contains(element: UByte): Boolean
+    operator fun get(index: Int): UByte
+    operator fun iterator(): Iterator<UByte>
+    operator fun set(index: Int, value: UByte): Unit
+    val size: Int
+}
+class UInt : Comparable<UInt> {
+    fun toString(): String
+    inline fun floorDiv(other: UByte): UInt
+    inline fun floorDiv(other: UInt): UInt
+    inline fun floorDiv(other: ULong): ULong
+    inline fun floorDiv(other: UShort): UInt
+    inline fun inv(): UInt
+    inline fun mod(other: UByte): UByte
+    inline fun mod(other: UInt): UInt
+    inline fun mod(other: ULong): ULong
+    inline fun mod(other: UShort): UShort
+    inline fun toByte(): Byte
+    inline fun toDouble(): Double
+    inline fun toFloat(): Float
+    inline fun toInt(): Int
+    inline fun toLong(): Long
+    inline fun toShort(): Short
+    inline fun toUByte(): UByte
+    inline fun toUInt(): UInt
+    inline fun toULong(): ULong
+    inline fun toUShort(): UShort
+    inline infix fun and(other: UInt): UInt
+    inline infix fun or(other: UInt): UInt
+    inline infix fun shl(bitCount: Int): UInt
+    inline infix fun shr(bitCount: Int): UInt
+    inline infix fun xor(other: UInt): UInt
+    inline operator fun compareTo(other: UByte): Int
+    inline operator fun compareTo(other: UInt): Int
+    inline operator fun compareTo(other: ULong): Int
+    inline operator fun compareTo(other: UShort): Int
+    inline operator fun dec(): UInt
+    inline operator fun div(other: UByte): UInt
+    inline operator fun div(other: UInt): UInt
+    inline operator fun div(other: ULong): ULong
+    inline operator fun div(other: UShort): UInt
+    inline operator fun inc(): UInt
+    inline operator fun minus(other: UByte): UInt
+    inline operator fun minus(other: UInt): UInt
+    inline operator fun minus(other: ULong): ULong
+    inline operator fun minus(other: UShort): UInt
+    inline operator fun plus(other: UByte): UInt
+    inline operator fun plus(other: UInt): UInt
+    inline operator fun plus(other: ULong): ULong
+    inline operator fun plus(other: UShort): UInt
+    inline operator fun rangeTo(other: UInt): UIntRange
+    inline operator fun rangeUntil(other: UInt): UIntRange
+    inline operator fun rem(other: UByte): UInt
+    inline operator fun rem(other: UInt): UInt
+    inline operator fun rem(other: ULong): ULong
+    inline operator fun rem(other: UShort): UInt
+    inline operator fun times(other: UByte): UInt
+    inline operator fun times(other: UInt): UInt
+    inline operator fun times(other: ULong): ULong
+    inline operator fun times(other: UShort): UInt
+    companion object Companion : Any {
+        const val MAX_VALUE: UInt
+        const val MIN_VALUE: UInt
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class UIntArray : Collection<UInt> {
+    fun containsAll(elements: Collection<UInt>): Boolean
+    fun isEmpty(): Boolean
+    operator fun contains(element: UInt): Boolean
+    operator fun get(index: Int): UInt
+    operator fun iterator(): Iterator<UInt>
+    operator fun set(index: Int, value: UInt): Unit
+    val size: Int
+}
+class ULong : Comparable<ULong> {
+    fun toString(): String
+    inline fun floorDiv(other: UByte): ULong
+    inline fun floorDiv(other: UInt): ULong
+    inline fun floorDiv(other: ULong): ULong
+    inline fun floorDiv(other: UShort): ULong
+    inline fun inv(): ULong
+    inline fun mod(other: UByte): UByte
+    inline fun mod(other: UInt): UInt
+    inline fun mod(other: ULong): ULong
+    inline fun mod(other: UShort): UShort
+    inline fun toByte(): Byte
+    inline fun toDouble(): Double
+    inline fun toFloat(): Float
+    inline fun toInt(): Int
+    inline fun toLong(): Long
+    inline fun toShort(): Short
+    inline fun toUByte(): UByte
+    inline fun toUInt(): UInt
+    inline fun toULong(): ULong
+    inline fun toUShort(): UShort
+    inline infix fun and(other: ULong): ULong
+    inline infix fun or(other: ULong): ULong
+    inline infix fun shl(bitCount: Int): ULong
+    inline infix fun shr(bitCount: Int): ULong
+    inline infix fun xor(other: ULong): ULong
+    inline operator fun compareTo(other: UByte): Int
+    inline operator fun compareTo(other: UInt): Int
+    inline operator fun compareTo(other: ULong): Int
+    inline operator fun compareTo(other: UShort): Int
+    inline operator fun dec(): ULong
+    inline operator fun div(other: UByte): ULong
+    inline operator fun div(other: UInt): ULong
+    inline operator fun div(other: ULong): ULong
+    inline operator fun div(other: UShort): ULong
+    inline operator fun inc(): ULong
+    inline operator fun minus(other: UByte): ULong
+    inline operator fun minus(other: UInt): ULong
+    inline operator fun minus(other: ULong): ULong
+    inline operator fun minus(other: UShort): ULong
+    inline operator fun plus(other: UByte): ULong
+    inline operator fun plus(other: UInt): ULong
+    inline operator fun plus(other: ULong): ULong
+    inline operator fun plus(other: UShort): ULong
+    inline operator fun rangeTo(other: ULong): ULongRange
+    inline operator fun rangeUntil(other: ULong): ULongRange
+    inline operator fun rem(other: UByte): ULong
+    inline operator fun rem(other: UInt): ULong
+    inline operator fun rem(other: ULong): ULong
+    inline operator fun rem(other: UShort): ULong
+    inline operator fun times(other: UByte): ULong
+    inline operator fun times(other: UInt): ULong
+    inline operator fun times(other: ULong): ULong
+    inline operator fun times(other: UShort): ULong
+    companion object Companion : Any {
+        const val MAX_VALUE: ULong
+        const val MIN_VALUE: ULong
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class ULongArray : Collection<ULong> {
+    fun containsAll(elements: Collection<ULong>): Boolean
+    fun isEmpty(): Boolean
+    operator fun contains(element: ULong): Boolean
+    operator fun get(index: Int): ULong
+    operator fun iterator(): Iterator<ULong>
+    operator fun set(index: Int, value: ULong): Unit
+    val size: Int
+}
+class UShort : Comparable<UShort> {
+    fun toString(): String
+    inline fun floorDiv(other: UByte): UInt
+    inline fun floorDiv(other: UInt): UInt
+    inline fun floorDiv(other: ULong): ULong
+    inline fun floorDiv(other: UShort): UInt
+    inline fun inv(): UShort
+    inline fun mod(other: UByte): UByte
+    inline fun mod(other: UInt): UInt
+    inline fun mod(other: ULong): ULong
+    inline fun mod(other: UShort): UShort
+    inline fun toByte(): Byte
+    inline fun toDouble(): Double
+    inline fun toFloat(): Float
+    inline fun toInt(): Int
+    inline fun toLong(): Long
+    inline fun toShort(): Short
+    inline fun toUByte(): UByte
+    inline fun toUInt(): UInt
+    inline fun toULong(): ULong
+    inline fun toUShort(): UShort
+    inline infix fun and(other: UShort): UShort
+    inline infix fun or(other: UShort): UShort
+    inline infix fun xor(other: UShort): UShort
+    inline operator fun compareTo(other: UByte): Int
+    inline operator fun compareTo(other: UInt): Int
+    inline operator fun compareTo(other: ULong): Int
+    inline operator fun compareTo(other: UShort): Int
+    inline operator fun dec(): UShort
+    inline operator fun div(other: UByte): UInt
+    inline operator fun div(other: UInt): UInt
+    inline operator fun div(other: ULong): ULong
+    inline operator fun div(other: UShort): UInt
+    inline operator fun inc(): UShort
+    inline operator fun minus(other: UByte): UInt
+    inline operator fun minus(other: UInt): UInt
+    inline operator fun minus(other: ULong): ULong
+    inline operator fun minus(other: UShort): UInt
+    inline operator fun plus(other: UByte): UInt
+    inline operator fun plus(other: UInt): UInt
+    inline operator fun plus(other: ULong): ULong
+    inline operator fun plus(other: UShort): UInt
+    inline operator fun rangeTo(other: UShort): UIntRange
+    inline operator fun rangeUntil(other: UShort): UIntRange
+    inline operator fun rem(other: UByte): UInt
+    inline operator fun rem(other: UInt): UInt
+    inline operator fun rem(other: ULong): ULong
+    inline operator fun rem(other: UShort): UInt
+    inline operator fun times(other: UByte): UInt
+    inline operator fun times(other: UInt): UInt
+    inline operator fun times(other: ULong): ULong
+    inline operator fun times(other: UShort): UInt
+    companion object Companion : Any {
+        const val MAX_VALUE: UShort
+        const val MIN_VALUE: UShort
+        const val SIZE_BITS: Int
+        const val SIZE_BYTES: Int
+    }
+}
+class UShortArray : Collection<UShort> {
+    fun containsAll(elements: Collection<UShort>): Boolean
+    fun isEmpty(): Boolean
+    operator fun contains(element: UShort): Boolean
+    operator fun get(index: Int): UShort
+    operator fun iterator(): Iterator<UShort>
+    operator fun set(index: Int, value: UShort): Unit
+    val size: Int
+}
+class UninitializedPropertyAccessException : RuntimeException
+object Unit : Any {
+    fun toString(): String
+}
+annotation class UnsafeVariance : Annotation
@@ -0,0 +1,349 @@
+package kotlin.ranges
+
+// Generated from cloud-pubsub-emulator-0.8.20-all.jar by `just generate-stdlib-index`
+
+fun <T : Comparable<T>> T.coerceAtLeast(minimumValue: T): T
+fun <T : Comparable<T>> T.coerceAtMost(maximumValue: T): T
+fun <T : Comparable<T>> T.coerceIn(minimumValue: T?, maximumValue: T?): T
+fun <T : Comparable<T>> T.coerceIn(range: ClosedFloatingPointRange<T>): T
+fun <T : Comparable<T>> T.coerceIn(range: ClosedRange<T>): T
+fun Byte.coerceAtLeast(minimumValue: Byte): Byte
+fun Byte.coerceAtMost(maximumValue: Byte): Byte
+fun Byte.coerceIn(minimumValue: Byte, maximumValue: Byte): Byte
+fun CharProgression.first(): Char
+fun CharProgression.firstOrNull(): Char?
+fun CharProgression.last(): Char
+fun CharProgression.lastOrNull(): Char?
+fun CharProgression.reversed(): CharProgression
+fun CharRange.random(random: Random): Char
+fun CharRange.randomOrNull(random: Random): Char?
+fun Double.coerceAtLeast(minimumValue: Double): Double
+fun Double.coerceAtMost(maximumValue: Double): Double
+fun Double.coerceIn(minimumValue: Double, maximumValue: Double): Double
+fun Float.coerceAtLeast(minimumValue: Float): Float
+fun Float.coerceAtMost(maximumValue: Float): Float
+fun Float.coerceIn(minimumValue: Float, maximumValue: Float): Float
+fun Int.coerceAtLeast(minimumValue: Int): Int
+fun Int.coerceAtMost(maximumValue: Int): Int
+fun Int.coerceIn(minimumValue: Int, maximumValue: Int): Int
+fun Int.coerceIn(range: ClosedRange<Int>): Int
+fun IntProgression.first(): Int
+fun IntProgression.firstOrNull(): Int?
+fun IntProgression.last(): Int
+fun IntProgression.lastOrNull(): Int?
+fun IntProgression.reversed(): IntProgression
+fun IntRange.random(random: Random): Int
+fun IntRange.randomOrNull(random: Random): Int?
+fun Long.coerceAtLeast(minimumValue: Long): Long
+fun Long.coerceAtMost(maximumValue: Long): Long
+fun Long.coerceIn(minimumValue: Long, maximumValue: Long): Long
+fun Long.coerceIn(range: ClosedRange<Long>): Long
+fun LongProgression.first(): Long
+fun LongProgression.firstOrNull(): Long?
+fun LongProgression.last(): Long
+fun LongProgression.lastOrNull(): Long?
+fun LongProgression.reversed(): LongProgression
+fun LongRange.random(random: Random): Long
+fun LongRange.randomOrNull(random: Random): Long?
+fun Short.coerceAtLeast(minimumValue: Short): Short
+fun Short.coerceAtMost(maximumValue: Short): Short
+fun Short.coerceIn(minimumValue: Short, maximumValue: Short): Short
+fun UByte.coerceAtLeast(minimumValue: UByte): UByte
+fun UByte.coerceAtMost(maximumValue: UByte): UByte
+fun UByte.coerceIn(minimumValue: UByte, maximumValue: UByte): UByte
+fun UInt.coerceAtLeast(minimumValue: UInt): UInt
+fun UInt.coerceAtMost(maximumValue: UInt): UInt
+fun UInt.coerceIn(minimumValue: UInt, maximumValue: UInt): UInt
+fun UInt.coerceIn(range: ClosedRange<UInt>): UInt
+fun UIntProgression.first(): UInt
+fun UIntProgression.firstOrNull(): UInt?
+fun UIntProgression.last(): UInt
+fun UIntProgression.lastOrNull(): UInt?
+fun UIntProgression.reversed(): UIntProgression
+fun UIntRange.random(random: Random): UInt
+fun UIntRange.randomOrNull(random: Random): UInt?
+fun ULong.coerceAtLeast(minimumValue: ULong): ULong
+fun ULong.coerceAtMost(maximumValue: ULong): ULong
+fun ULong.coerceIn(minimumValue: ULong, maximumValue: ULong): ULong
+fun ULong.coerceIn(range: ClosedRange<ULong>): ULong
+fun ULongProgression.first(): ULong
+fun ULongProgression.firstOrNull(): ULong?
+fun ULongProgression.last(): ULong
+fun ULongProgression.lastOrNull(): ULong?
+fun ULongProgression.reversed(): ULongProgression
+fun ULongRange.random(random: Random): ULong
+fun ULongRange.randomOrNull(random: Random): ULong?
+fun UShort.coerceAtLeast(minimumValue: UShort): UShort
+fun UShort.coerceAtMost(maximumValue: UShort): UShort
+fun UShort.coerceIn(minimumValue: UShort, maximumValue: UShort): UShort
+infix fun Byte.downTo(to: Byte): IntProgression
+infix fun Byte.downTo(to: Int): IntProgression
+infix fun Byte.downTo(to: Long): LongProgression
+infix fun Byte.downTo(to: Short): IntProgression
+infix fun Byte.until(to: Byte): IntRange
+infix fun Byte.until(to: Int): IntRange
+infix fun Byte.until(to: Long): LongRange
+infix fun Byte.until(to: Short): IntRange
+infix fun Char.downTo(to: Char): CharProgression
+infix fun Char.until(to: Char): CharRange
+infix fun CharProgression.step(step: Int): CharProgression
+infix fun Int.downTo(to: Byte): IntProgression
+infix fun Int.downTo(to: Int): IntProgression
+infix fun Int.downTo(to: Long): LongProgression
+infix fun Int.downTo(to: Short): IntProgression
+infix fun Int.until(to: Byte): IntRange
+infix fun Int.until(to: Int): IntRange
+infix fun Int.until(to: Long): LongRange
+infix fun Int.until(to: Short): IntRange
+infix fun IntProgression.step(step: Int): IntProgression
+infix fun Long.downTo(to: Byte): LongProgression
+infix fun Long.downTo(to: Int): LongProgression
+infix fun Long.downTo(to: Long): LongProgression
+infix fun Long.downTo(to: Short): LongProgression
+infix fun Long.until(to: Byte): LongRange
+infix fun Long.until(to: Int): LongRange
+infix fun Long.until(to: Long): LongRange
+infix fun Long.until(to: Short): LongRange
+infix fun LongProgression.step(step: Long): LongProgression
+infix fun Short.downTo(to: Byte): IntProgression
+infix fun Short.downTo(to: Int): IntProgression
+infix fun Short.downTo(to: Long): LongProgression
+infix fun Short.downTo(to: Short): IntProgression
+infix fun Short.until(to: Byte): IntRange
+infix fun Short.until(to: Int): IntRange
+infix fun Short.until(to: Long): LongRange
+infix fun Short.until(to: Short): IntRange
+infix fun UByte.downTo(to: UByte): UIntProgression
+infix fun UByte.until(to: UByte): UIntRange
+infix fun UInt.downTo(to: UInt): UIntProgression
+infix fun UInt.until(to: UInt): UIntRange
+infix fun UIntProgression.step(step: Int): UIntProgression
+infix fun ULong.downTo(to: ULong): ULongProgression
+infix fun ULong.until(to: ULong): ULongRange
+infix fun ULongProgression.step(step: Long): ULongProgression
+infix fun UShort.downTo(to: UShort): UIntProgression
+infix fun UShort.until(to: UShort): UIntRange
+inline fun CharRange.random(): Char
+inline fun CharRange.randomOrNull(): Char?
+inline fun IntRange.random(): Int
+inline fun IntRange.randomOrNull(): Int?
+inline fun LongRange.random(): Long
+inline fun LongRange.randomOrNull(): Long?
+inline fun UIntRange.random(): UInt
+inline fun UIntRange.randomOrNull(): UInt?
+inline fun ULongRange.random(): ULong
+inline fun ULongRange.randomOrNull(): ULong?
+inline operator fun <T : Any, R> R.contains(element: T?): Boolean
+inline operator fun CharRange.contains(element: Char?): Boolean
+inline operator fun IntRange.contains(element: Int?): Boolean
+inline operator fun IntRange.contains(value: Byte): Boolean
+inline operator fun IntRange.contains(value: Long): Boolean
+inline operator fun IntRange.contains(value: Short): Boolean
+inline operator fun LongRange.contains(element: Long?): Boolean
+inline operator fun LongRange.contains(value: Byte): Boolean
+inline operator fun LongRange.contains(value: Int): Boolean
+inline operator fun LongRange.contains(value: Short): Boolean
+inline operator fun UIntRange.contains(element: UInt?): Boolean
+inline operator fun ULongRange.contains(element: ULong?): Boolean
+operator fun <T : Comparable<T>> T.rangeTo(that: T): ClosedRange<T>
+operator fun <T : Comparable<T>> T.rangeUntil(that: T): OpenEndRange<T>
+operator fun ClosedRange<Byte>.contains(value: Double): Boolean
+operator fun ClosedRange<Byte>.contains(value: Float): Boolean
+operator fun ClosedRange<Byte>.contains(value: Int): Boolean
+operator fun ClosedRange<Byte>.contains(value: Long): Boolean
+operator fun ClosedRange<Byte>.contains(value: Short): Boolean
+operator fun ClosedRange<Double>.contains(value: Byte): Boolean
+operator fun ClosedRange<Double>.contains(value: Float): Boolean
+operator fun ClosedRange<Double>.contains(value: Int): Boolean
+operator fun ClosedRange<Double>.contains(value: Long): Boolean
+operator fun ClosedRange<Double>.contains(value: Short): Boolean
+operator fun ClosedRange<Float>.contains(value: Byte): Boolean
+operator fun ClosedRange<Float>.contains(value: Double): Boolean
+operator fun ClosedRange<Float>.contains(value: Int): Boolean
+operator fun ClosedRange<Float>.contains(value: Long): Boolean
+operator fun ClosedRange<Float>.contains(value: Short): Boolean
+operator fun ClosedRange<Int>.contains(value: Byte): Boolean
+operator fun ClosedRange<Int>.contains(value: Double): Boolean
+operator fun ClosedRange<Int>.contains(value: Float): Boolean
+operator fun ClosedRange<Int>.contains(value: Long): Boolean
+operator fun ClosedRange<Int>.contains(value: Short): Boolean
+operator fun ClosedRange<Long>.contains(value: Byte): Boolean
+operator fun ClosedRange<Long>.contains(value: Double): Boolean
+operator fun ClosedRange<Long>.contains(value: Float): Boolean
+operator fun ClosedRange<Long>.contains(value: Int): Boolean
+operator fun ClosedRange<Long>.contains(value: Short): Boolean
+operator fun ClosedRange<Short>.contains(value: Byte): Boolean
+operator fun ClosedRange<Short>.contains(value: Double): Boolean
+operator fun ClosedRange<Short>.contains(value: Float): Boolean
+operator fun ClosedRange<Short>.contains(value: Int): Boolean
+operator fun ClosedRange<Short>.contains(value: Long): Boolean
+operator fun Double.rangeTo(that: Double): ClosedFloatingPointRange<Double>
+operator fun Double.rangeUntil(that: Double): OpenEndRange<Double>
+operator fun Float.rangeTo(that: Float): ClosedFloatingPointRange<Float>
+operator fun Float.rangeUntil(that: Float): OpenEndRange<Float>
+operator fun OpenEndRange<Byte>.contains(value: Int): Boolean
+operator fun OpenEndRange<Byte>.contains(value: Long): Boolean
+operator fun OpenEndRange<Byte>.contains(value: Short): Boolean
+operator fun OpenEndRange<Double>.contains(value: Float): Boolean
+operator fun OpenEndRange<Int>.contains(value: Byte): Boolean
+operator fun OpenEndRange<Int>.contains(value: Long): Boolean
+operator fun OpenEndRange<Int>.contains(value: Short): Boolean
+operator fun OpenEndRange<Long>.contains(value: Byte): Boolean
+operator fun OpenEndRange<Long>.contains(value: Int): Boolean
+operator fun OpenEndRange<Long>.contains(value: Short): Boolean
+operator fun OpenEndRange<Short>.contains(value: Byte): Boolean
+operator fun OpenEndRange<Short>.contains(value: Int): Boolean
+operator fun OpenEndRange<Short>.contains(value: Long): Boolean
+operator fun UIntRange.contains(value: UByte): Boolean
+operator fun UIntRange.contains(value: ULong): Boolean
+operator fun UIntRange.contains(value: UShort): Boolean
+operator fun ULongRange.contains(value: UByte): Boolean
+operator fun ULongRange.contains(value: UInt): Boolean
+operator fun ULongRange.contains(value: UShort): Boolean
+class CharProgression : Iterable<Char> {
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun equals(other: Any?): Boolean
+    operator fun iterator(): CharIterator
+    val first: Char
+    val last: Char
+    val step: Int
+    companion object Companion : Any {
+        fun fromClosedRange(rangeStart: Char, rangeEnd: Char, step: Int): CharProgression
+    }
+}
+class CharRange : CharProgression, ClosedRange<Char>, OpenEndRange<Char> {
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun contains(value: Char): Boolean
+    operator fun equals(other: Any?): Boolean
+    val endExclusive: Char
+    val endInclusive: Char
+    val start: Char
+    companion object Companion : Any {
+        val EMPTY: CharRange
+    }
+}
+interface ClosedFloatingPointRange<T : Comparable<T>> : ClosedRange<T> {
+    fun isEmpty(): Boolean
+    fun lessThanOrEquals(a: T, b: T): Boolean
+    operator fun contains(value: T): Boolean
+}
+interface ClosedRange<T : Comparable<T>> : Any {
+    fun isEmpty(): Boolean
+    operator fun contains(value: T): Boolean
+    val endInclusive: T
+    val start: T
+}
+class IntProgression : Iterable<Int> {
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun equals(other: Any?): Boolean
+    operator fun iterator(): IntIterator
+    val first: Int
+    val last: Int
+    val step: Int
+    companion object Companion : Any {
+        fun fromClosedRange(rangeStart: Int, rangeEnd: Int, step: Int): IntProgression
+    }
+}
+class IntRange : IntProgression, ClosedRange<Int>, OpenEndRange<Int> {
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun contains(value: Int): Boolean
+    operator fun equals(other: Any?): Boolean
+    val endExclusive: Int
+    val endInclusive: Int
+    val start: Int
+    companion object Companion : Any {
+        val EMPTY: IntRange
+    }
+}
+class LongProgression : Iterable<Long> {
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun equals(other: Any?): Boolean
+    operator fun iterator(): LongIterator
+    val first: Long
+    val last: Long
+    val step: Long
+    companion object Companion : Any {
+        fun fromClosedRange(rangeStart: Long, rangeEnd: Long, step: Long): LongProgression
+    }
+}
+class LongRange : LongProgression, ClosedRange<Long>, OpenEndRange<Long> {
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun contains(value: Long): Boolean
+    operator fun equals(other: Any?): Boolean
+    val endExclusive: Long
+    val endInclusive: Long
+    val start: Long
+    companion object Companion : Any {
+        val EMPTY: LongRange
+    }
+}
+interface OpenEndRange<T : Comparable<T>> : Any {
+    fun isEmpty(): Boolean
+    operator fun contains(value: T): Boolean
+    val endExclusive: T
+    val start: T
+}
+class UIntProgression : Iterable<UInt> {
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun equals(other: Any?): Boolean
+    operator fun iterator(): Iterator<UInt>
+    val first: UInt
+    val last: UInt
+    val step: Int
+    companion object Companion : Any {
+        fun fromClosedRange(rangeStart: UInt, rangeEnd: UInt, step: Int): UIntProgression
+    }
+}
+class UIntRange : UIntProgression, ClosedRange<UInt>, OpenEndRange<UInt> {
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun contains(value: UInt): Boolean
+    operator fun equals(other: Any?): Boolean
+    val endExclusive: UInt
+    val endInclusive: UInt
+    val start: UInt
+    companion object Companion : Any {
+        val EMPTY: UIntRange
+    }
+}
+class ULongProgression : Iterable<ULong> {
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun equals(other: Any?): Boolean
+    operator fun iterator(): Iterator<ULong>
+    val first: ULong
+    val last: ULong
+    val step: Long
+    companion object Companion : Any {
+        fun fromClosedRange(rangeStart: ULong, rangeEnd: ULong, step: Long): ULongProgression
+    }
+}
+class ULongRange : ULongProgression, ClosedRange<ULong>, OpenEndRange<ULong> {
+    fun hashCode(): Int
+    fun isEmpty(): Boolean
+    fun toString(): String
+    operator fun contains(value: ULong): Boolean
+    operator fun equals(other: Any?): Boolean
+    val endExclusive: ULong
+    val endInclusive: ULong
+    val start: ULong
+    companion object Companion : Any {
+        val EMPTY: ULongRange
+    }
+}
@@ -0,0 +1,234 @@
+package kotlin.sequences
+
+// Generated from cloud-pubsub-emulator-0.8.20-all.jar by `just generate-stdlib-index`
+
+fun <C : MutableCollection<in R>, R> Sequence<*>.filterIsInstanceTo(destination: C, klass: Class<R>): C
+fun <C : MutableCollection<in T>, T : Any> Sequence<T?>.filterNotNullTo(destination: C): C
+fun <R> Sequence<*>.filterIsInstance(klass: Class<R>): Sequence<R>
+fun <S, T : S> Sequence<T>.runningReduce(operation: Function2<S, T, S>): Sequence<S>
+fun <S, T : S> Sequence<T>.runningReduceIndexed(operation: Function3<Int, S, T, S>): Sequence<S>
+fun <T : Any> Sequence<T?>.filterNotNull(): Sequence<T>
+fun <T : Any> Sequence<T?>.requireNoNulls(): Sequence<T>
+fun <T : Any> generateSequence(nextFunction: Function0<T?>): Sequence<T>
+fun <T : Any> generateSequence(seed: T?, nextFunction: Function1<T, T?>): Sequence<T>
+fun <T : Any> generateSequence(seedFunction: Function0<T?>, nextFunction: Function1<T, T?>): Sequence<T>
+fun <T : Comparable<T>> Sequence<T>.max(): T
+fun <T : Comparable<T>> Sequence<T>.max(): T?
+fun <T : Comparable<T>> Sequence<T>.maxOrNull(): T?
+fun <T : Comparable<T>> Sequence<T>.min(): T
+fun <T : Comparable<T>> Sequence<T>.min(): T?
+fun <T : Comparable<T>> Sequence<T>.minOrNull(): T?
+fun <T : Comparable<T>> Sequence<T>.sorted(): Sequence<T>
+fun <T : Comparable<T>> Sequence<T>.sortedDescending(): Sequence<T>
+fun <T : Comparable<T>> Sequence<T>.toSortedSet(): SortedSet<T>
+fun <T, A : Appendable> Sequence<T>.joinTo(buffer: A, separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<T, CharSequence>? = ...): A
+fun <T, C : MutableCollection<in T>> Sequence<T>.toCollection(destination: C): C
+fun <T, K> Sequence<T>.distinctBy(selector: Function1<T, K>): Sequence<T>
+fun <T, R : Any> Sequence<T>.mapIndexedNotNull(transform: Function2<Int, T, R?>): Sequence<R>
+fun <T, R : Any> Sequence<T>.mapNotNull(transform: Function1<T, R?>): Sequence<R>
+fun <T, R, V> Sequence<T>.zip(other: Sequence<R>, transform: Function2<T, R, V>): Sequence<V>
+fun <T, R> Sequence<Pair<T, R>>.unzip(): Pair<List<T>, List<R>>
+fun <T, R> Sequence<T>.chunked(size: Int, transform: Function1<List<T>, R>): Sequence<R>
+fun <T, R> Sequence<T>.flatMap(transform: Function1<T, Iterable<R>>): Sequence<R>
+fun <T, R> Sequence<T>.flatMap(transform: Function1<T, Sequence<R>>): Sequence<R>
+fun <T, R> Sequence<T>.flatMapIndexed(transform: Function2<Int, T, Iterable<R>>): Sequence<R>
+fun <T, R> Sequence<T>.flatMapIndexed(transform: Function2<Int, T, Sequence<R>>): Sequence<R>
+fun <T, R> Sequence<T>.map(transform: Function1<T, R>): Sequence<R>
+fun <T, R> Sequence<T>.mapIndexed(transform: Function2<Int, T, R>): Sequence<R>
+fun <T, R> Sequence<T>.runningFold(initial: R, operation: Function2<R, T, R>): Sequence<R>
+fun <T, R> Sequence<T>.runningFoldIndexed(initial: R, operation: Function3<Int, R, T, R>): Sequence<R>
+fun <T, R> Sequence<T>.scan(initial: R, operation: Function2<R, T, R>): Sequence<R>
+fun <T, R> Sequence<T>.scanIndexed(initial: R, operation: Function3<Int, R, T, R>): Sequence<R>
+fun <T, R> Sequence<T>.windowed(size: Int, step: Int = ..., partialWindows: Boolean = ..., transform: Function1<List<T>, R>): Sequence<R>
+fun <T, R> Sequence<T>.zipWithNext(transform: Function2<T, T, R>): Sequence<R>
+fun <T> Iterator<T>.asSequence(): Sequence<T>
+fun <T> Sequence<Iterable<T>>.flatten(): Sequence<T>
+fun <T> Sequence<Sequence<T>>.flatten(): Sequence<T>
+fun <T> Sequence<T>.any(): Boolean
+fun <T> Sequence<T>.asIterable(): Iterable<T>
+fun <T> Sequence<T>.chunked(size: Int): Sequence<List<T>>
+fun <T> Sequence<T>.constrainOnce(): Sequence<T>
+fun <T> Sequence<T>.count(): Int
+fun <T> Sequence<T>.distinct(): Sequence<T>
+fun <T> Sequence<T>.drop(n: Int): Sequence<T>
+fun <T> Sequence<T>.dropWhile(predicate: Function1<T, Boolean>): Sequence<T>
+fun <T> Sequence<T>.elementAt(index: Int): T
+fun <T> Sequence<T>.elementAtOrElse(index: Int, defaultValue: Function1<Int, T>): T
+fun <T> Sequence<T>.elementAtOrNull(index: Int): T?
+fun <T> Sequence<T>.filter(predicate: Function1<T, Boolean>): Sequence<T>
+fun <T> Sequence<T>.filterIndexed(predicate: Function2<Int, T, Boolean>): Sequence<T>
+fun <T> Sequence<T>.filterNot(predicate: Function1<T, Boolean>): Sequence<T>
+fun <T> Sequence<T>.first(): T
+fun <T> Sequence<T>.firstOrNull(): T?
+fun <T> Sequence<T>.ifEmpty(defaultValue: Function0<Sequence<T>>): Sequence<T>
+fun <T> Sequence<T>.indexOf(element: T): Int
+fun <T> Sequence<T>.joinToString(separator: CharSequence = ..., prefix: CharSequence = ..., postfix: CharSequence = ..., limit: Int = ..., truncated: CharSequence = ..., transform: Function1<T, CharSequence>? = ...): String
+fun <T> Sequence<T>.last(): T
+fun <T> Sequence<T>.lastIndexOf(element: T): Int
+fun <T> Sequence<T>.lastOrNull(): T?
+fun <T> Sequence<T>.maxWith(comparator: Comparator<in T>): T
+fun <T> Sequence<T>.maxWith(comparator: Comparator<in T>): T?
+fun <T> Sequence<T>.maxWithOrNull(comparator: Comparator<in T>): T?
+fun <T> Sequence<T>.minWith(comparator: Comparator<in T>): T
+fun <T> Sequence<T>.minWith(comparator: Comparator<in T>): T?
+fun <T> Sequence<T>.minWithOrNull(comparator: Comparator<in T>): T?
+fun <T> Sequence<T>.none(): Boolean
+fun <T> Sequence<T>.onEach(action: Function1<T, Unit>): Sequence<T>
+fun <T> Sequence<T>.onEachIndexed(action: Function2<Int, T, Unit>): Sequence<T>
+fun <T> Sequence<T>.shuffled(): Sequence<T>
+fun <T> Sequence<T>.shuffled(random: Random): Sequence<T>
+fun <T> Sequence<T>.single(): T
+fun <T> Sequence<T>.singleOrNull(): T?
+fun <T> Sequence<T>.sortedWith(comparator: Comparator<in T>): Sequence<T>
+fun <T> Sequence<T>.take(n: Int): Sequence<T>
+fun <T> Sequence<T>.takeWhile(predicate: Function1<T, Boolean>): Sequence<T>
+fun <T> Sequence<T>.toHashSet(): HashSet<T>
+fun <T> Sequence<T>.toList(): List<T>
+fun <T> Sequence<T>.toMutableList(): MutableList<T>
+fun <T> Sequence<T>.toMutableSet(): MutableSet<T>
+fun <T> Sequence<T>.toSet(): Set<T>
+fun <T> Sequence<T>.toSortedSet(comparator: Comparator<in T>): SortedSet<T>
+fun <T> Sequence<T>.windowed(size: Int, step: Int = ..., partialWindows: Boolean = ...): Sequence<List<T>>
+fun <T> Sequence<T>.withIndex(): Sequence<IndexedValue<T>>
+fun <T> Sequence<T>.zipWithNext(): Sequence<Pair<T, T>>
+fun <T> emptySequence(): Sequence<T>
+fun <T> iterator(block: Function2<SequenceScope<T>, Continuation<Unit>, Any?>): Iterator<T>
+fun <T> sequence(block: Function2<SequenceScope<T>, Continuation<Unit>, Any?>): Sequence<T>
+fun <T> sequenceOf(vararg elements: T): Sequence<T>
+fun Sequence<Byte>.average(): Double
+fun Sequence<Byte>.sum(): Int
+fun Sequence<Double>.average(): Double
+fun Sequence<Double>.max(): Double
+fun Sequence<Double>.max(): Double?
+fun Sequence<Double>.maxOrNull(): Double?
+fun Sequence<Double>.min(): Double
+fun Sequence<Double>.min(): Double?
+fun Sequence<Double>.minOrNull(): Double?
+fun Sequence<Double>.sum(): Double
+fun Sequence<Float>.average(): Double
+fun Sequence<Float>.max(): Float
+fun Sequence<Float>.max(): Float?
+fun Sequence<Float>.maxOrNull(): Float?
+fun Sequence<Float>.min(): Float
+fun Sequence<Float>.min(): Float?
+fun Sequence<Float>.minOrNull(): Float?
+fun Sequence<Float>.sum(): Float
+fun Sequence<Int>.average(): Double
+fun Sequence<Int>.sum(): Int
+fun Sequence<Long>.average(): Double
+fun Sequence<Long>.sum(): Long
+fun Sequence<Short>.average(): Double
+fun Sequence<Short>.sum(): Int
+fun Sequence<UByte>.sum(): UInt
+fun Sequence<UInt>.sum(): UInt
+fun Sequence<ULong>.sum(): ULong
+fun Sequence<UShort>.sum(): UInt
+infix fun <T, R> Sequence<T>.zip(other: Sequence<R>): Sequence<Pair<T, R>>
+inline fun <K, V, M : MutableMap<in K, in V>> Sequence<K>.associateWithTo(destination: M, valueSelector: Function1<K, V>): M
+inline fun <K, V> Sequence<K>.associateWith(valueSelector: Function1<K, V>): Map<K, V>
+inline fun <S, T : S> Sequence<T>.reduce(operation: Function2<S, T, S>): S
+inline fun <S, T : S> Sequence<T>.reduceIndexed(operation: Function3<Int, S, T, S>): S
+inline fun <S, T : S> Sequence<T>.reduceIndexedOrNull(operation: Function3<Int, S, T, S>): S?
+inline fun <S, T : S> Sequence<T>.reduceOrNull(operation: Function2<S, T, S>): S?
+inline fun <T, C : MutableCollection<in T>> Sequence<T>.filterIndexedTo(destination: C, predicate: Function2<Int, T, Boolean>): C
+inline fun <T, C : MutableCollection<in T>> Sequence<T>.filterNotTo(destination: C, predicate: Function1<T, Boolean>): C
+inline fun <T, C : MutableCollection<in T>> Sequence<T>.filterTo(destination: C, predicate: Function1<T, Boolean>): C
+inline fun <T, K, M : MutableMap<in K, MutableList<T>>> Sequence<T>.groupByTo(destination: M, keySelector: Function1<T, K>): M
+inline fun <T, K, M : MutableMap<in K, in T>> Sequence<T>.associateByTo(destination: M, keySelector: Function1<T, K>): M
+inline fun <T, K, V, M : MutableMap<in K, MutableList<V>>> Sequence<T>.groupByTo(destination: M, keySelector: Function1<T, K>, valueTransform: Function1<T, V>): M
+inline fun <T, K, V, M : MutableMap<in K, in V>> Sequence<T>.associateByTo(destination: M, keySelector: Function1<T, K>, valueTransform: Function1<T, V>): M
+inline fun <T, K, V, M : MutableMap<in K, in V>> Sequence<T>.associateTo(destination: M, transform: Function1<T, Pair<K, V>>): M
+inline fun <T, K, V> Sequence<T>.associate(transform: Function1<T, Pair<K, V>>): Map<K, V>
+inline fun <T, K, V> Sequence<T>.associateBy(keySelector: Function1<T, K>, valueTransform: Function1<T, V>): Map<K, V>
+inline fun <T, K, V> Sequence<T>.groupBy(keySelector: Function1<T, K>, valueTransform: Function1<T, V>): Map<K, List<V>>
+inline fun <T, K> Sequence<T>.associateBy(keySelector: Function1<T, K>): Map<K, T>
+inline fun <T, K> Sequence<T>.groupBy(keySelector: Function1<T, K>): Map<K, List<T>>
+inline fun <T, K> Sequence<T>.groupingBy(keySelector: Function1<T, K>): Grouping<T, K>
+inline fun <T, R : Any, C : MutableCollection<in R>> Sequence<T>.mapIndexedNotNullTo(destination: C, transform: Function2<Int, T, R?>): C
+inline fun <T, R : Any, C : MutableCollection<in R>> Sequence<T>.mapNotNullTo(destination: C, transform: Function1<T, R?>): C
+inline fun <T, R : Any> Sequence<T>.firstNotNullOf(transform: Function1<T, R?>): R
+inline fun <T, R : Any> Sequence<T>.firstNotNullOfOrNull(transform: Function1<T, R?>): R?
+inline fun <T, R : Comparable<R>> Sequence<T>.maxBy(selector: Function1<T, R>): T
+inline fun <T, R : Comparable<R>> Sequence<T>.maxBy(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Sequence<T>.maxByOrNull(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Sequence<T>.maxOf(selector: Function1<T, R>): R
+inline fun <T, R : Comparable<R>> Sequence<T>.maxOfOrNull(selector: Function1<T, R>): R?
+inline fun <T, R : Comparable<R>> Sequence<T>.minBy(selector: Function1<T, R>): T
+inline fun <T, R : Comparable<R>> Sequence<T>.minBy(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Sequence<T>.minByOrNull(selector: Function1<T, R>): T?
+inline fun <T, R : Comparable<R>> Sequence<T>.minOf(selector: Function1<T, R>): R
+inline fun <T, R : Comparable<R>> Sequence<T>.minOfOrNull(selector: Function1<T, R>): R?
+inline fun <T, R : Comparable<R>> Sequence<T>.sortedBy(selector: Function1<T, R?>): Sequence<T>
+inline fun <T, R : Comparable<R>> Sequence<T>.sortedByDescending(selector: Function1<T, R?>): Sequence<T>
+inline fun <T, R, C : MutableCollection<in R>> Sequence<T>.flatMapIndexedTo(destination: C, transform: Function2<Int, T, Iterable<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Sequence<T>.flatMapIndexedTo(destination: C, transform: Function2<Int, T, Sequence<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Sequence<T>.flatMapTo(destination: C, transform: Function1<T, Iterable<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Sequence<T>.flatMapTo(destination: C, transform: Function1<T, Sequence<R>>): C
+inline fun <T, R, C : MutableCollection<in R>> Sequence<T>.mapIndexedTo(destination: C, transform: Function2<Int, T, R>): C
+inline fun <T, R, C : MutableCollection<in R>> Sequence<T>.mapTo(destination: C, transform: Function1<T, R>): C
+inline fun <T, R> Sequence<T>.fold(initial: R, operation: Function2<R, T, R>): R
+inline fun <T, R> Sequence<T>.foldIndexed(initial: R, operation: Function3<Int, R, T, R>): R
+inline fun <T, R> Sequence<T>.maxOfWith(comparator: Comparator<in R>, selector: Function1<T, R>): R
+inline fun <T, R> Sequence<T>.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<T, R>): R?
+inline fun <T, R> Sequence<T>.minOfWith(comparator: Comparator<in R>, selector: Function1<T, R>): R
+inline fun <T, R> Sequence<T>.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<T, R>): R?
+inline fun <T> Enumeration<T>.asSequence(): Sequence<T>
+inline fun <T> Sequence(iterator: Function0<Iterator<T>>): Sequence<T>
+inline fun <T> Sequence<T>.all(predicate: Function1<T, Boolean>): Boolean
+inline fun <T> Sequence<T>.any(predicate: Function1<T, Boolean>): Boolean
+inline fun <T> Sequence<T>.asSequence(): Sequence<T>
+inline fun <T> Sequence<T>.count(predicate: Function1<T, Boolean>): Int
+inline fun <T> Sequence<T>.find(predicate: Function1<T, Boolean>): T?
+inline fun <T> Sequence<T>.findLast(predicate: Function1<T, Boolean>): T?
+inline fun <T> Sequence<T>.first(predicate: Function1<T, Boolean>): T
+inline fun <T> Sequence<T>.firstOrNull(predicate: Function1<T, Boolean>): T?
+inline fun <T> Sequence<T>.forEach(action: Function1<T, Unit>): Unit
+inline fun <T> Sequence<T>.forEachIndexed(action: Function2<Int, T, Unit>): Unit
+inline fun <T> Sequence<T>.indexOfFirst(predicate: Function1<T, Boolean>): Int
+inline fun <T> Sequence<T>.indexOfLast(predicate: Function1<T, Boolean>): Int
+inline fun <T> Sequence<T>.last(predicate: Function1<T, Boolean>): T
+inline fun <T> Sequence<T>.lastOrNull(predicate: Function1<T, Boolean>): T?
+inline fun <T> Sequence<T>.maxOf(selector: Function1<T, Double>): Double
+inline fun <T> Sequence<T>.maxOf(selector: Function1<T, Float>): Float
+inline fun <T> Sequence<T>.maxOfOrNull(selector: Function1<T, Double>): Double?
+inline fun <T> Sequence<T>.maxOfOrNull(selector: Function1<T, Float>): Float?
+inline fun <T> Sequence<T>.minOf(selector: Function1<T, Double>): Double
+inline fun <T> Sequence<T>.minOf(selector: Function1<T, Float>): Float
+inline fun <T> Sequence<T>.minOfOrNull(selector: Function1<T, Double>): Double?
+inline fun <T> Sequence<T>.minOfOrNull(selector: Function1<T, Float>): Float?
+inline fun <T> Sequence<T>.minusElement(element: T): Sequence<T>
+inline fun <T> Sequence<T>.none(predicate: Function1<T, Boolean>): Boolean
+inline fun <T> Sequence<T>.partition(predicate: Function1<T, Boolean>): Pair<List<T>, List<T>>
+inline fun <T> Sequence<T>.plusElement(element: T): Sequence<T>
+inline fun <T> Sequence<T>.single(predicate: Function1<T, Boolean>): T
+inline fun <T> Sequence<T>.singleOrNull(predicate: Function1<T, Boolean>): T?
+inline fun <T> Sequence<T>.sumBy(selector: Function1<T, Int>): Int
+inline fun <T> Sequence<T>.sumByDouble(selector: Function1<T, Double>): Double
+inline fun <T> Sequence<T>.sumOf(selector: Function1<T, BigDecimal>): BigDecimal
+inline fun <T> Sequence<T>.sumOf(selector: Function1<T, BigInteger>): BigInteger
+inline fun <T> Sequence<T>.sumOf(selector: Function1<T, Double>): Double
+inline fun <T> Sequence<T>.sumOf(selector: Function1<T, Int>): Int
+inline fun <T> Sequence<T>.sumOf(selector: Function1<T, Long>): Long
+inline fun <T> Sequence<T>.sumOf(selector: Function1<T, UInt>): UInt
+inline fun <T> Sequence<T>.sumOf(selector: Function1<T, ULong>): ULong
+inline fun <T> Sequence<T>?.orEmpty(): Sequence<T>
+inline fun <reified R, C : MutableCollection<in R>> Sequence<*>.filterIsInstanceTo(destination: C): C
+inline fun <reified R> Sequence<*>.filterIsInstance(): Sequence<R>
+operator fun <T> Sequence<T>.contains(element: T): Boolean
+operator fun <T> Sequence<T>.minus(element: T): Sequence<T>
+operator fun <T> Sequence<T>.minus(elements: Array<out T>): Sequence<T>
+operator fun <T> Sequence<T>.minus(elements: Iterable<T>): Sequence<T>
+operator fun <T> Sequence<T>.minus(elements: Sequence<T>): Sequence<T>
+operator fun <T> Sequence<T>.plus(element: T): Sequence<T>
+operator fun <T> Sequence<T>.plus(elements: Array<out T>): Sequence<T>
+operator fun <T> Sequence<T>.plus(elements: Iterable<T>): Sequence<T>
+operator fun <T> Sequence<T>.plus(elements: Sequence<T>): Sequence<T>
+interface Sequence<out T> : Any {
+    operator fun iterator(): Iterator<T>
+}
+class SequenceScope<in T> : Any {
+    suspend fun yield(value: T): Unit
+    suspend fun yieldAll(elements: Iterable<T>): Unit
+    suspend fun yieldAll(iterator: Iterator<T>): Unit
+    suspend fun yieldAll(sequence: Sequence<T>): Unit
+}
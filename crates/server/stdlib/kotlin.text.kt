@@ -0,0 +1,636 @@
+package kotlin.text
+
+// Generated from cloud-pubsub-emulator-0.8.20-all.jar by `just generate-stdlib-index`
+
+fun <C : MutableCollection<in Char>> CharSequence.toCollection(destination: C): C
+fun <R> CharSequence.chunked(size: Int, transform: Function1<CharSequence, R>): List<R>
+fun <R> CharSequence.chunkedSequence(size: Int, transform: Function1<CharSequence, R>): Sequence<R>
+fun <R> CharSequence.windowed(size: Int, step: Int = ..., partialWindows: Boolean = ..., transform: Function1<CharSequence, R>): List<R>
+fun <R> CharSequence.windowedSequence(size: Int, step: Int = ..., partialWindows: Boolean = ..., transform: Function1<CharSequence, R>): Sequence<R>
+fun <T : Appendable> T.append(vararg value: CharSequence?): T
+fun <T : Appendable> T.appendRange(value: CharSequence, startIndex: Int, endIndex: Int): T
+fun Appendable.appendln(): Appendable
+fun ByteArray.decodeToString(): String
+fun ByteArray.decodeToString(startIndex: Int = ..., endIndex: Int = ..., throwOnInvalidSequence: Boolean = ...): String
+fun Char.digitToInt(): Int
+fun Char.digitToInt(radix: Int): Int
+fun Char.digitToIntOrNull(): Int?
+fun Char.digitToIntOrNull(radix: Int): Int?
+fun Char.equals(other: Char, ignoreCase: Boolean = ...): Boolean
+fun Char.isSurrogate(): Boolean
+fun Char.isWhitespace(): Boolean
+fun Char.lowercase(locale: Locale): String
+fun Char.titlecase(): String
+fun Char.titlecase(locale: Locale): String
+fun Char.uppercase(locale: Locale): String
+fun CharArray.concatToString(): String
+fun CharArray.concatToString(startIndex: Int = ..., endIndex: Int = ...): String
+fun CharSequence.any(): Boolean
+fun CharSequence.asIterable(): Iterable<Char>
+fun CharSequence.asSequence(): Sequence<Char>
+fun CharSequence.chunked(size: Int): List<String>
+fun CharSequence.chunkedSequence(size: Int): Sequence<String>
+fun CharSequence.commonPrefixWith(other: CharSequence, ignoreCase: Boolean = ...): String
+fun CharSequence.commonSuffixWith(other: CharSequence, ignoreCase: Boolean = ...): String
+fun CharSequence.drop(n: Int): CharSequence
+fun CharSequence.dropLast(n: Int): CharSequence
+fun CharSequence.endsWith(char: Char, ignoreCase: Boolean = ...): Boolean
+fun CharSequence.endsWith(suffix: CharSequence, ignoreCase: Boolean = ...): Boolean
+fun CharSequence.findAnyOf(strings: Collection<String>, startIndex: Int = ..., ignoreCase: Boolean = ...): Pair<Int, String>?
+fun CharSequence.findLastAnyOf(strings: Collection<String>, startIndex: Int = ..., ignoreCase: Boolean = ...): Pair<Int, String>?
+fun CharSequence.first(): Char
+fun CharSequence.firstOrNull(): Char?
+fun CharSequence.getOrNull(index: Int): Char?
+fun CharSequence.hasSurrogatePairAt(index: Int): Boolean
+fun CharSequence.indexOf(char: Char, startIndex: Int = ..., ignoreCase: Boolean = ...): Int
+fun CharSequence.indexOf(string: String, startIndex: Int = ..., ignoreCase: Boolean = ...): Int
+fun CharSequence.indexOfAny(chars: CharArray, startIndex: Int = ..., ignoreCase: Boolean = ...): Int
+fun CharSequence.indexOfAny(strings: Collection<String>, startIndex: Int = ..., ignoreCase: Boolean = ...): Int
+fun CharSequence.isBlank(): Boolean
+fun CharSequence.last(): Char
+fun CharSequence.lastIndexOf(char: Char, startIndex: Int = ..., ignoreCase: Boolean = ...): Int
+fun CharSequence.lastIndexOf(string: String, startIndex: Int = ..., ignoreCase: Boolean = ...): Int
+fun CharSequence.lastIndexOfAny(chars: CharArray, startIndex: Int = ..., ignoreCase: Boolean = ...): Int
+fun CharSequence.lastIndexOfAny(strings: Collection<String>, startIndex: Int = ..., ignoreCase: Boolean = ...): Int
+fun CharSequence.lastOrNull(): Char?
+fun CharSequence.lineSequence(): Sequence<String>
+fun CharSequence.lines(): List<String>
+fun CharSequence.max(): Char
+fun CharSequence.max(): Char?
+fun CharSequence.maxOrNull(): Char?
+fun CharSequence.maxWith(comparator: Comparator<in Char>): Char
+fun CharSequence.maxWith(comparator: Comparator<in Char>): Char?
+fun CharSequence.maxWithOrNull(comparator: Comparator<in Char>): Char?
+fun CharSequence.min(): Char
+fun CharSequence.min(): Char?
+fun CharSequence.minOrNull(): Char?
+fun CharSequence.minWith(comparator: Comparator<in Char>): Char
+fun CharSequence.minWith(comparator: Comparator<in Char>): Char?
+fun CharSequence.minWithOrNull(comparator: Comparator<in Char>): Char?
+fun CharSequence.none(): Boolean
+fun CharSequence.padEnd(length: Int, padChar: Char = ...): CharSequence
+fun CharSequence.padStart(length: Int, padChar: Char = ...): CharSequence
+fun CharSequence.random(random: Random): Char
+fun CharSequence.randomOrNull(random: Random): Char?
+fun CharSequence.regionMatches(thisOffset: Int, other: CharSequence, otherOffset: Int, length: Int, ignoreCase: Boolean = ...): Boolean
+fun CharSequence.removePrefix(prefix: CharSequence): CharSequence
+fun CharSequence.removeRange(range: IntRange): CharSequence
+fun CharSequence.removeRange(startIndex: Int, endIndex: Int): CharSequence
+fun CharSequence.removeSuffix(suffix: CharSequence): CharSequence
+fun CharSequence.removeSurrounding(delimiter: CharSequence): CharSequence
+fun CharSequence.removeSurrounding(prefix: CharSequence, suffix: CharSequence): CharSequence
+fun CharSequence.repeat(n: Int): String
+fun CharSequence.replaceRange(range: IntRange, replacement: CharSequence): CharSequence
+fun CharSequence.replaceRange(startIndex: Int, endIndex: Int, replacement: CharSequence): CharSequence
+fun CharSequence.reversed(): CharSequence
+fun CharSequence.single(): Char
+fun CharSequence.singleOrNull(): Char?
+fun CharSequence.slice(indices: IntRange): CharSequence
+fun CharSequence.slice(indices: Iterable<Int>): CharSequence
+fun CharSequence.split(regex: Pattern, limit: Int = ...): List<String>
+fun CharSequence.split(vararg delimiters: Char, ignoreCase: Boolean = ..., limit: Int = ...): List<String>
+fun CharSequence.split(vararg delimiters: String, ignoreCase: Boolean = ..., limit: Int = ...): List<String>
+fun CharSequence.splitToSequence(vararg delimiters: Char, ignoreCase: Boolean = ..., limit: Int = ...): Sequence<String>
+fun CharSequence.splitToSequence(vararg delimiters: String, ignoreCase: Boolean = ..., limit: Int = ...): Sequence<String>
+fun CharSequence.startsWith(char: Char, ignoreCase: Boolean = ...): Boolean
+fun CharSequence.startsWith(prefix: CharSequence, ignoreCase: Boolean = ...): Boolean
+fun CharSequence.startsWith(prefix: CharSequence, startIndex: Int, ignoreCase: Boolean = ...): Boolean
+fun CharSequence.subSequence(range: IntRange): CharSequence
+fun CharSequence.substring(range: IntRange): String
+fun CharSequence.take(n: Int): CharSequence
+fun CharSequence.takeLast(n: Int): CharSequence
+fun CharSequence.toHashSet(): HashSet<Char>
+fun CharSequence.toList(): List<Char>
+fun CharSequence.toMutableList(): MutableList<Char>
+fun CharSequence.toSet(): Set<Char>
+fun CharSequence.toSortedSet(): SortedSet<Char>
+fun CharSequence.trim(): CharSequence
+fun CharSequence.trim(vararg chars: Char): CharSequence
+fun CharSequence.trimEnd(): CharSequence
+fun CharSequence.trimEnd(vararg chars: Char): CharSequence
+fun CharSequence.trimStart(): CharSequence
+fun CharSequence.trimStart(vararg chars: Char): CharSequence
+fun CharSequence.windowed(size: Int, step: Int = ..., partialWindows: Boolean = ...): List<String>
+fun CharSequence.windowedSequence(size: Int, step: Int = ..., partialWindows: Boolean = ...): Sequence<String>
+fun CharSequence.withIndex(): Iterable<IndexedValue<Char>>
+fun CharSequence.zipWithNext(): List<Pair<Char, Char>>
+fun CharSequence?.contentEquals(other: CharSequence?, ignoreCase: Boolean): Boolean
+fun Int.digitToChar(): Char
+fun Int.digitToChar(radix: Int): Char
+fun String.capitalize(): String
+fun String.capitalize(locale: Locale): String
+fun String.compareTo(other: String, ignoreCase: Boolean = ...): Int
+fun String.decapitalize(): String
+fun String.decapitalize(locale: Locale): String
+fun String.drop(n: Int): String
+fun String.dropLast(n: Int): String
+fun String.encodeToByteArray(): ByteArray
+fun String.encodeToByteArray(startIndex: Int = ..., endIndex: Int = ..., throwOnInvalidSequence: Boolean = ...): ByteArray
+fun String.endsWith(suffix: String, ignoreCase: Boolean = ...): Boolean
+fun String.padEnd(length: Int, padChar: Char = ...): String
+fun String.padStart(length: Int, padChar: Char = ...): String
+fun String.prependIndent(indent: String = ...): String
+fun String.regionMatches(thisOffset: Int, other: String, otherOffset: Int, length: Int, ignoreCase: Boolean = ...): Boolean
+fun String.removePrefix(prefix: CharSequence): String
+fun String.removeSuffix(suffix: CharSequence): String
+fun String.removeSurrounding(delimiter: CharSequence): String
+fun String.removeSurrounding(prefix: CharSequence, suffix: CharSequence): String
+fun String.replace(oldChar: Char, newChar: Char, ignoreCase: Boolean = ...): String
+fun String.replace(oldValue: String, newValue: String, ignoreCase: Boolean = ...): String
+fun String.replaceAfter(delimiter: Char, replacement: String, missingDelimiterValue: String = ...): String
+fun String.replaceAfter(delimiter: String, replacement: String, missingDelimiterValue: String = ...): String
+fun String.replaceAfterLast(delimiter: Char, replacement: String, missingDelimiterValue: String = ...): String
+fun String.replaceAfterLast(delimiter: String, replacement: String, missingDelimiterValue: String = ...): String
+fun String.replaceBefore(delimiter: Char, replacement: String, missingDelimiterValue: String = ...): String
+fun String.replaceBefore(delimiter: String, replacement: String, missingDelimiterValue: String = ...): String
+fun String.replaceBeforeLast(delimiter: Char, replacement: String, missingDelimiterValue: String = ...): String
+fun String.replaceBeforeLast(delimiter: String, replacement: String, missingDelimiterValue: String = ...): String
+fun String.replaceFirst(oldChar: Char, newChar: Char, ignoreCase: Boolean = ...): String
+fun String.replaceFirst(oldValue: String, newValue: String, ignoreCase: Boolean = ...): String
+fun String.replaceIndent(newIndent: String = ...): String
+fun String.replaceIndentByMargin(newIndent: String = ..., marginPrefix: String = ...): String
+fun String.slice(indices: IntRange): String
+fun String.startsWith(prefix: String, ignoreCase: Boolean = ...): Boolean
+fun String.startsWith(prefix: String, startIndex: Int, ignoreCase: Boolean = ...): Boolean
+fun String.substring(range: IntRange): String
+fun String.substringAfter(delimiter: Char, missingDelimiterValue: String = ...): String
+fun String.substringAfter(delimiter: String, missingDelimiterValue: String = ...): String
+fun String.substringAfterLast(delimiter: Char, missingDelimiterValue: String = ...): String
+fun String.substringAfterLast(delimiter: String, missingDelimiterValue: String = ...): String
+fun String.substringBefore(delimiter: Char, missingDelimiterValue: String = ...): String
+fun String.substringBefore(delimiter: String, missingDelimiterValue: String = ...): String
+fun String.substringBeforeLast(delimiter: Char, missingDelimiterValue: String = ...): String
+fun String.substringBeforeLast(delimiter: String, missingDelimiterValue: String = ...): String
+fun String.take(n: Int): String
+fun String.takeLast(n: Int): String
+fun String.toBigDecimalOrNull(): BigDecimal?
+fun String.toBigDecimalOrNull(mathContext: MathContext): BigDecimal?
+fun String.toBigIntegerOrNull(): BigInteger?
+fun String.toBigIntegerOrNull(radix: Int): BigInteger?
+fun String.toBooleanStrict(): Boolean
+fun String.toBooleanStrictOrNull(): Boolean?
+fun String.toByteOrNull(): Byte?
+fun String.toByteOrNull(radix: Int): Byte?
+fun String.toCharArray(startIndex: Int = ..., endIndex: Int = ...): CharArray
+fun String.toDoubleOrNull(): Double?
+fun String.toFloatOrNull(): Float?
+fun String.toIntOrNull(): Int?
+fun String.toIntOrNull(radix: Int): Int?
+fun String.toLongOrNull(): Long?
+fun String.toLongOrNull(radix: Int): Long?
+fun String.toShortOrNull(): Short?
+fun String.toShortOrNull(radix: Int): Short?
+fun String.toUByte(): UByte
+fun String.toUByte(radix: Int): UByte
+fun String.toUByteOrNull(): UByte?
+fun String.toUByteOrNull(radix: Int): UByte?
+fun String.toUInt(): UInt
+fun String.toUInt(radix: Int): UInt
+fun String.toUIntOrNull(): UInt?
+fun String.toUIntOrNull(radix: Int): UInt?
+fun String.toULong(): ULong
+fun String.toULong(radix: Int): ULong
+fun String.toULongOrNull(): ULong?
+fun String.toULongOrNull(radix: Int): ULong?
+fun String.toUShort(): UShort
+fun String.toUShort(radix: Int): UShort
+fun String.toUShortOrNull(): UShort?
+fun String.toUShortOrNull(radix: Int): UShort?
+fun String.trim(vararg chars: Char): String
+fun String.trimEnd(vararg chars: Char): String
+fun String.trimIndent(): String
+fun String.trimMargin(marginPrefix: String = ...): String
+fun String.trimStart(vararg chars: Char): String
+fun String?.equals(other: String?, ignoreCase: Boolean = ...): Boolean
+fun StringBuilder.append(vararg value: Any?): StringBuilder
+fun StringBuilder.append(vararg value: String?): StringBuilder
+fun StringBuilder.appendln(): StringBuilder
+fun StringBuilder.clear(): StringBuilder
+fun UByte.toString(radix: Int): String
+fun UInt.toString(radix: Int): String
+fun ULong.toString(radix: Int): String
+fun UShort.toString(radix: Int): String
+infix fun CharSequence.zip(other: CharSequence): List<Pair<Char, Char>>
+infix fun CharSequence?.contentEquals(other: CharSequence?): Boolean
+inline fun <C : Appendable> CharSequence.filterIndexedTo(destination: C, predicate: Function2<Int, Char, Boolean>): C
+inline fun <C : Appendable> CharSequence.filterNotTo(destination: C, predicate: Function1<Char, Boolean>): C
+inline fun <C : Appendable> CharSequence.filterTo(destination: C, predicate: Function1<Char, Boolean>): C
+inline fun <C, R> C.ifBlank(defaultValue: Function0<R>): R
+inline fun <C, R> C.ifEmpty(defaultValue: Function0<R>): R
+inline fun <K, M : MutableMap<in K, MutableList<Char>>> CharSequence.groupByTo(destination: M, keySelector: Function1<Char, K>): M
+inline fun <K, M : MutableMap<in K, in Char>> CharSequence.associateByTo(destination: M, keySelector: Function1<Char, K>): M
+inline fun <K, V, M : MutableMap<in K, MutableList<V>>> CharSequence.groupByTo(destination: M, keySelector: Function1<Char, K>, valueTransform: Function1<Char, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> CharSequence.associateByTo(destination: M, keySelector: Function1<Char, K>, valueTransform: Function1<Char, V>): M
+inline fun <K, V, M : MutableMap<in K, in V>> CharSequence.associateTo(destination: M, transform: Function1<Char, Pair<K, V>>): M
+inline fun <K, V> CharSequence.associate(transform: Function1<Char, Pair<K, V>>): Map<K, V>
+inline fun <K, V> CharSequence.associateBy(keySelector: Function1<Char, K>, valueTransform: Function1<Char, V>): Map<K, V>
+inline fun <K, V> CharSequence.groupBy(keySelector: Function1<Char, K>, valueTransform: Function1<Char, V>): Map<K, List<V>>
+inline fun <K> CharSequence.associateBy(keySelector: Function1<Char, K>): Map<K, Char>
+inline fun <K> CharSequence.groupBy(keySelector: Function1<Char, K>): Map<K, List<Char>>
+inline fun <K> CharSequence.groupingBy(keySelector: Function1<Char, K>): Grouping<Char, K>
+inline fun <R : Any, C : MutableCollection<in R>> CharSequence.mapIndexedNotNullTo(destination: C, transform: Function2<Int, Char, R?>): C
+inline fun <R : Any, C : MutableCollection<in R>> CharSequence.mapNotNullTo(destination: C, transform: Function1<Char, R?>): C
+inline fun <R : Any> CharSequence.firstNotNullOf(transform: Function1<Char, R?>): R
+inline fun <R : Any> CharSequence.firstNotNullOfOrNull(transform: Function1<Char, R?>): R?
+inline fun <R : Any> CharSequence.mapIndexedNotNull(transform: Function2<Int, Char, R?>): List<R>
+inline fun <R : Any> CharSequence.mapNotNull(transform: Function1<Char, R?>): List<R>
+inline fun <R : Comparable<R>> CharSequence.maxBy(selector: Function1<Char, R>): Char
+inline fun <R : Comparable<R>> CharSequence.maxBy(selector: Function1<Char, R>): Char?
+inline fun <R : Comparable<R>> CharSequence.maxByOrNull(selector: Function1<Char, R>): Char?
+inline fun <R : Comparable<R>> CharSequence.maxOf(selector: Function1<Char, R>): R
+inline fun <R : Comparable<R>> CharSequence.maxOfOrNull(selector: Function1<Char, R>): R?
+inline fun <R : Comparable<R>> CharSequence.minBy(selector: Function1<Char, R>): Char
+inline fun <R : Comparable<R>> CharSequence.minBy(selector: Function1<Char, R>): Char?
+inline fun <R : Comparable<R>> CharSequence.minByOrNull(selector: Function1<Char, R>): Char?
+inline fun <R : Comparable<R>> CharSequence.minOf(selector: Function1<Char, R>): R
+inline fun <R : Comparable<R>> CharSequence.minOfOrNull(selector: Function1<Char, R>): R?
+inline fun <R, C : MutableCollection<in R>> CharSequence.flatMapIndexedTo(destination: C, transform: Function2<Int, Char, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> CharSequence.flatMapTo(destination: C, transform: Function1<Char, Iterable<R>>): C
+inline fun <R, C : MutableCollection<in R>> CharSequence.mapIndexedTo(destination: C, transform: Function2<Int, Char, R>): C
+inline fun <R, C : MutableCollection<in R>> CharSequence.mapTo(destination: C, transform: Function1<Char, R>): C
+inline fun <R> CharSequence.flatMap(transform: Function1<Char, Iterable<R>>): List<R>
+inline fun <R> CharSequence.flatMapIndexed(transform: Function2<Int, Char, Iterable<R>>): List<R>
+inline fun <R> CharSequence.fold(initial: R, operation: Function2<R, Char, R>): R
+inline fun <R> CharSequence.foldIndexed(initial: R, operation: Function3<Int, R, Char, R>): R
+inline fun <R> CharSequence.foldRight(initial: R, operation: Function2<Char, R, R>): R
+inline fun <R> CharSequence.foldRightIndexed(initial: R, operation: Function3<Int, Char, R, R>): R
+inline fun <R> CharSequence.map(transform: Function1<Char, R>): List<R>
+inline fun <R> CharSequence.mapIndexed(transform: Function2<Int, Char, R>): List<R>
+inline fun <R> CharSequence.maxOfWith(comparator: Comparator<in R>, selector: Function1<Char, R>): R
+inline fun <R> CharSequence.maxOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Char, R>): R?
+inline fun <R> CharSequence.minOfWith(comparator: Comparator<in R>, selector: Function1<Char, R>): R
+inline fun <R> CharSequence.minOfWithOrNull(comparator: Comparator<in R>, selector: Function1<Char, R>): R?
+inline fun <R> CharSequence.runningFold(initial: R, operation: Function2<R, Char, R>): List<R>
+inline fun <R> CharSequence.runningFoldIndexed(initial: R, operation: Function3<Int, R, Char, R>): List<R>
+inline fun <R> CharSequence.scan(initial: R, operation: Function2<R, Char, R>): List<R>
+inline fun <R> CharSequence.scanIndexed(initial: R, operation: Function3<Int, R, Char, R>): List<R>
+inline fun <R> CharSequence.zipWithNext(transform: Function2<Char, Char, R>): List<R>
+inline fun <S : CharSequence> S.onEach(action: Function1<Char, Unit>): S
+inline fun <S : CharSequence> S.onEachIndexed(action: Function2<Int, Char, Unit>): S
+inline fun <V, M : MutableMap<in Char, in V>> CharSequence.associateWithTo(destination: M, valueSelector: Function1<Char, V>): M
+inline fun <V> CharSequence.associateWith(valueSelector: Function1<Char, V>): Map<Char, V>
+inline fun <V> CharSequence.zip(other: CharSequence, transform: Function2<Char, Char, V>): List<V>
+inline fun Appendable.appendLine(): Appendable
+inline fun Appendable.appendLine(value: Char): Appendable
+inline fun Appendable.appendLine(value: CharSequence?): Appendable
+inline fun Appendable.appendln(value: Char): Appendable
+inline fun Appendable.appendln(value: CharSequence?): Appendable
+inline fun Byte.toString(radix: Int): String
+inline fun Char.isDefined(): Boolean
+inline fun Char.isDigit(): Boolean
+inline fun Char.isHighSurrogate(): Boolean
+inline fun Char.isISOControl(): Boolean
+inline fun Char.isIdentifierIgnorable(): Boolean
+inline fun Char.isJavaIdentifierPart(): Boolean
+inline fun Char.isJavaIdentifierStart(): Boolean
+inline fun Char.isLetter(): Boolean
+inline fun Char.isLetterOrDigit(): Boolean
+inline fun Char.isLowSurrogate(): Boolean
+inline fun Char.isLowerCase(): Boolean
+inline fun Char.isTitleCase(): Boolean
+inline fun Char.isUpperCase(): Boolean
+inline fun Char.lowercase(): String
+inline fun Char.lowercaseChar(): Char
+inline fun Char.titlecaseChar(): Char
+inline fun Char.toLowerCase(): Char
+inline fun Char.toTitleCase(): Char
+inline fun Char.toUpperCase(): Char
+inline fun Char.uppercase(): String
+inline fun Char.uppercaseChar(): Char
+inline fun CharSequence.all(predicate: Function1<Char, Boolean>): Boolean
+inline fun CharSequence.any(predicate: Function1<Char, Boolean>): Boolean
+inline fun CharSequence.count(): Int
+inline fun CharSequence.count(predicate: Function1<Char, Boolean>): Int
+inline fun CharSequence.dropLastWhile(predicate: Function1<Char, Boolean>): CharSequence
+inline fun CharSequence.dropWhile(predicate: Function1<Char, Boolean>): CharSequence
+inline fun CharSequence.elementAt(index: Int): Char
+inline fun CharSequence.elementAtOrElse(index: Int, defaultValue: Function1<Int, Char>): Char
+inline fun CharSequence.elementAtOrNull(index: Int): Char?
+inline fun CharSequence.filter(predicate: Function1<Char, Boolean>): CharSequence
+inline fun CharSequence.filterIndexed(predicate: Function2<Int, Char, Boolean>): CharSequence
+inline fun CharSequence.filterNot(predicate: Function1<Char, Boolean>): CharSequence
+inline fun CharSequence.find(predicate: Function1<Char, Boolean>): Char?
+inline fun CharSequence.findLast(predicate: Function1<Char, Boolean>): Char?
+inline fun CharSequence.first(predicate: Function1<Char, Boolean>): Char
+inline fun CharSequence.firstOrNull(predicate: Function1<Char, Boolean>): Char?
+inline fun CharSequence.forEach(action: Function1<Char, Unit>): Unit
+inline fun CharSequence.forEachIndexed(action: Function2<Int, Char, Unit>): Unit
+inline fun CharSequence.getOrElse(index: Int, defaultValue: Function1<Int, Char>): Char
+inline fun CharSequence.indexOfFirst(predicate: Function1<Char, Boolean>): Int
+inline fun CharSequence.indexOfLast(predicate: Function1<Char, Boolean>): Int
+inline fun CharSequence.isEmpty(): Boolean
+inline fun CharSequence.isNotBlank(): Boolean
+inline fun CharSequence.isNotEmpty(): Boolean
+inline fun CharSequence.last(predicate: Function1<Char, Boolean>): Char
+inline fun CharSequence.lastOrNull(predicate: Function1<Char, Boolean>): Char?
+inline fun CharSequence.maxOf(selector: Function1<Char, Double>): Double
+inline fun CharSequence.maxOf(selector: Function1<Char, Float>): Float
+inline fun CharSequence.maxOfOrNull(selector: Function1<Char, Double>): Double?
+inline fun CharSequence.maxOfOrNull(selector: Function1<Char, Float>): Float?
+inline fun CharSequence.minOf(selector: Function1<Char, Double>): Double
+inline fun CharSequence.minOf(selector: Function1<Char, Float>): Float
+inline fun CharSequence.minOfOrNull(selector: Function1<Char, Double>): Double?
+inline fun CharSequence.minOfOrNull(selector: Function1<Char, Float>): Float?
+inline fun CharSequence.none(predicate: Function1<Char, Boolean>): Boolean
+inline fun CharSequence.partition(predicate: Function1<Char, Boolean>): Pair<CharSequence, CharSequence>
+inline fun CharSequence.random(): Char
+inline fun CharSequence.randomOrNull(): Char?
+inline fun CharSequence.reduce(operation: Function2<Char, Char, Char>): Char
+inline fun CharSequence.reduceIndexed(operation: Function3<Int, Char, Char, Char>): Char
+inline fun CharSequence.reduceIndexedOrNull(operation: Function3<Int, Char, Char, Char>): Char?
+inline fun CharSequence.reduceOrNull(operation: Function2<Char, Char, Char>): Char?
+inline fun CharSequence.reduceRight(operation: Function2<Char, Char, Char>): Char
+inline fun CharSequence.reduceRightIndexed(operation: Function3<Int, Char, Char, Char>): Char
+inline fun CharSequence.reduceRightIndexedOrNull(operation: Function3<Int, Char, Char, Char>): Char?
+inline fun CharSequence.reduceRightOrNull(operation: Function2<Char, Char, Char>): Char?
+inline fun CharSequence.replace(regex: Regex, replacement: String): String
+inline fun CharSequence.replace(regex: Regex, transform: Function1<MatchResult, CharSequence>): String
+inline fun CharSequence.replaceFirst(regex: Regex, replacement: String): String
+inline fun CharSequence.runningReduce(operation: Function2<Char, Char, Char>): List<Char>
+inline fun CharSequence.runningReduceIndexed(operation: Function3<Int, Char, Char, Char>): List<Char>
+inline fun CharSequence.single(predicate: Function1<Char, Boolean>): Char
+inline fun CharSequence.singleOrNull(predicate: Function1<Char, Boolean>): Char?
+inline fun CharSequence.split(regex: Regex, limit: Int = ...): List<String>
+inline fun CharSequence.splitToSequence(regex: Regex, limit: Int = ...): Sequence<String>
+inline fun CharSequence.substring(startIndex: Int, endIndex: Int = ...): String
+inline fun CharSequence.sumBy(selector: Function1<Char, Int>): Int
+inline fun CharSequence.sumByDouble(selector: Function1<Char, Double>): Double
+inline fun CharSequence.sumOf(selector: Function1<Char, BigDecimal>): BigDecimal
+inline fun CharSequence.sumOf(selector: Function1<Char, BigInteger>): BigInteger
+inline fun CharSequence.sumOf(selector: Function1<Char, Double>): Double
+inline fun CharSequence.sumOf(selector: Function1<Char, Int>): Int
+inline fun CharSequence.sumOf(selector: Function1<Char, Long>): Long
+inline fun CharSequence.sumOf(selector: Function1<Char, UInt>): UInt
+inline fun CharSequence.sumOf(selector: Function1<Char, ULong>): ULong
+inline fun CharSequence.takeLastWhile(predicate: Function1<Char, Boolean>): CharSequence
+inline fun CharSequence.takeWhile(predicate: Function1<Char, Boolean>): CharSequence
+inline fun CharSequence.trim(predicate: Function1<Char, Boolean>): CharSequence
+inline fun CharSequence.trimEnd(predicate: Function1<Char, Boolean>): CharSequence
+inline fun CharSequence.trimStart(predicate: Function1<Char, Boolean>): CharSequence
+inline fun CharSequence?.isNullOrBlank(): Boolean
+inline fun CharSequence?.isNullOrEmpty(): Boolean
+inline fun Int.toString(radix: Int): String
+inline fun Long.toString(radix: Int): String
+inline fun Pattern.toRegex(): Regex
+inline fun Short.toString(radix: Int): String
+inline fun String(bytes: ByteArray): String
+inline fun String(bytes: ByteArray, charset: Charset): String
+inline fun String(bytes: ByteArray, offset: Int, length: Int): String
+inline fun String(bytes: ByteArray, offset: Int, length: Int, charset: Charset): String
+inline fun String(chars: CharArray): String
+inline fun String(chars: CharArray, offset: Int, length: Int): String
+inline fun String(codePoints: IntArray, offset: Int, length: Int): String
+inline fun String(stringBuffer: StringBuffer): String
+inline fun String(stringBuilder: StringBuilder): String
+inline fun String.Companion.format(format: String, vararg args: Any?): String
+inline fun String.Companion.format(locale: Locale, format: String, vararg args: Any?): String
+inline fun String.Companion.format(locale: Locale?, format: String, vararg args: Any?): String
+inline fun String.codePointAt(index: Int): Int
+inline fun String.codePointBefore(index: Int): Int
+inline fun String.codePointCount(beginIndex: Int, endIndex: Int): Int
+inline fun String.contentEquals(charSequence: CharSequence): Boolean
+inline fun String.contentEquals(stringBuilder: StringBuffer): Boolean
+inline fun String.dropLastWhile(predicate: Function1<Char, Boolean>): String
+inline fun String.dropWhile(predicate: Function1<Char, Boolean>): String
+inline fun String.filter(predicate: Function1<Char, Boolean>): String
+inline fun String.filterIndexed(predicate: Function2<Int, Char, Boolean>): String
+inline fun String.filterNot(predicate: Function1<Char, Boolean>): String
+inline fun String.format(locale: Locale, vararg args: Any?): String
+inline fun String.format(locale: Locale?, vararg args: Any?): String
+inline fun String.format(vararg args: Any?): String
+inline fun String.intern(): String
+inline fun String.lowercase(): String
+inline fun String.lowercase(locale: Locale): String
+inline fun String.offsetByCodePoints(index: Int, codePointOffset: Int): Int
+inline fun String.partition(predicate: Function1<Char, Boolean>): Pair<String, String>
+inline fun String.removeRange(range: IntRange): String
+inline fun String.removeRange(startIndex: Int, endIndex: Int): String
+inline fun String.replaceFirstChar(transform: Function1<Char, Char>): String
+inline fun String.replaceFirstChar(transform: Function1<Char, CharSequence>): String
+inline fun String.replaceRange(range: IntRange, replacement: CharSequence): String
+inline fun String.replaceRange(startIndex: Int, endIndex: Int, replacement: CharSequence): String
+inline fun String.reversed(): String
+inline fun String.slice(indices: Iterable<Int>): String
+inline fun String.subSequence(start: Int, end: Int): CharSequence
+inline fun String.substring(startIndex: Int): String
+inline fun String.substring(startIndex: Int, endIndex: Int): String
+inline fun String.takeLastWhile(predicate: Function1<Char, Boolean>): String
+inline fun String.takeWhile(predicate: Function1<Char, Boolean>): String
+inline fun String.toBigDecimal(): BigDecimal
+inline fun String.toBigDecimal(mathContext: MathContext): BigDecimal
+inline fun String.toBigInteger(): BigInteger
+inline fun String.toBigInteger(radix: Int): BigInteger
+inline fun String.toBoolean(): Boolean
+inline fun String.toByte(): Byte
+inline fun String.toByte(radix: Int): Byte
+inline fun String.toByteArray(charset: Charset = ...): ByteArray
+inline fun String.toCharArray(): CharArray
+inline fun String.toCharArray(destination: CharArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): CharArray
+inline fun String.toDouble(): Double
+inline fun String.toFloat(): Float
+inline fun String.toInt(): Int
+inline fun String.toInt(radix: Int): Int
+inline fun String.toLong(): Long
+inline fun String.toLong(radix: Int): Long
+inline fun String.toLowerCase(): String
+inline fun String.toLowerCase(locale: Locale): String
+inline fun String.toPattern(flags: Int = ...): Pattern
+inline fun String.toRegex(): Regex
+inline fun String.toRegex(option: RegexOption): Regex
+inline fun String.toRegex(options: Set<RegexOption>): Regex
+inline fun String.toShort(): Short
+inline fun String.toShort(radix: Int): Short
+inline fun String.toUpperCase(): String
+inline fun String.toUpperCase(locale: Locale): String
+inline fun String.trim(): String
+inline fun String.trim(predicate: Function1<Char, Boolean>): String
+inline fun String.trimEnd(): String
+inline fun String.trimEnd(predicate: Function1<Char, Boolean>): String
+inline fun String.trimStart(): String
+inline fun String.trimStart(predicate: Function1<Char, Boolean>): String
+inline fun String.uppercase(): String
+inline fun String.uppercase(locale: Locale): String
+inline fun String?.orEmpty(): String
+inline fun String?.toBoolean(): Boolean
+inline fun StringBuilder.append(obj: Any?): StringBuilder
+inline fun StringBuilder.append(str: CharArray, offset: Int, len: Int): StringBuilder
+inline fun StringBuilder.appendLine(): StringBuilder
+inline fun StringBuilder.appendLine(value: Any?): StringBuilder
+inline fun StringBuilder.appendLine(value: Boolean): StringBuilder
+inline fun StringBuilder.appendLine(value: Byte): StringBuilder
+inline fun StringBuilder.appendLine(value: Char): StringBuilder
+inline fun StringBuilder.appendLine(value: CharArray): StringBuilder
+inline fun StringBuilder.appendLine(value: CharSequence?): StringBuilder
+inline fun StringBuilder.appendLine(value: Double): StringBuilder
+inline fun StringBuilder.appendLine(value: Float): StringBuilder
+inline fun StringBuilder.appendLine(value: Int): StringBuilder
+inline fun StringBuilder.appendLine(value: Long): StringBuilder
+inline fun StringBuilder.appendLine(value: Short): StringBuilder
+inline fun StringBuilder.appendLine(value: String?): StringBuilder
+inline fun StringBuilder.appendLine(value: StringBuffer?): StringBuilder
+inline fun StringBuilder.appendLine(value: StringBuilder?): StringBuilder
+inline fun StringBuilder.appendRange(value: CharArray, startIndex: Int, endIndex: Int): StringBuilder
+inline fun StringBuilder.appendRange(value: CharSequence, startIndex: Int, endIndex: Int): StringBuilder
+inline fun StringBuilder.appendln(value: Any?): StringBuilder
+inline fun StringBuilder.appendln(value: Boolean): StringBuilder
+inline fun StringBuilder.appendln(value: Byte): StringBuilder
+inline fun StringBuilder.appendln(value: Char): StringBuilder
+inline fun StringBuilder.appendln(value: CharArray): StringBuilder
+inline fun StringBuilder.appendln(value: CharSequence?): StringBuilder
+inline fun StringBuilder.appendln(value: Double): StringBuilder
+inline fun StringBuilder.appendln(value: Float): StringBuilder
+inline fun StringBuilder.appendln(value: Int): StringBuilder
+inline fun StringBuilder.appendln(value: Long): StringBuilder
+inline fun StringBuilder.appendln(value: Short): StringBuilder
+inline fun StringBuilder.appendln(value: String?): StringBuilder
+inline fun StringBuilder.appendln(value: StringBuffer?): StringBuilder
+inline fun StringBuilder.appendln(value: StringBuilder?): StringBuilder
+inline fun StringBuilder.deleteAt(index: Int): StringBuilder
+inline fun StringBuilder.deleteRange(startIndex: Int, endIndex: Int): StringBuilder
+inline fun StringBuilder.insertRange(index: Int, value: CharArray, startIndex: Int, endIndex: Int): StringBuilder
+inline fun StringBuilder.insertRange(index: Int, value: CharSequence, startIndex: Int, endIndex: Int): StringBuilder
+inline fun StringBuilder.setRange(startIndex: Int, endIndex: Int, value: String): StringBuilder
+inline fun StringBuilder.toCharArray(destination: CharArray, destinationOffset: Int = ..., startIndex: Int = ..., endIndex: Int = ...): Unit
+inline fun buildString(builderAction: Function1<StringBuilder, Unit>): String
+inline fun buildString(capacity: Int, builderAction: Function1<StringBuilder, Unit>): String
+inline fun charset(charsetName: String): Charset
+inline infix fun CharSequence.matches(regex: Regex): Boolean
+inline operator fun Char.plus(other: String): String
+inline operator fun CharSequence.contains(regex: Regex): Boolean
+inline operator fun StringBuilder.set(index: Int, value: Char): Unit
+operator fun CharSequence.contains(char: Char, ignoreCase: Boolean = ...): Boolean
+operator fun CharSequence.contains(other: CharSequence, ignoreCase: Boolean = ...): Boolean
+operator fun CharSequence.iterator(): CharIterator
+operator fun MatchGroupCollection.get(name: String): MatchGroup?
+val Char.category: CharCategory
+val Char.directionality: CharDirectionality
+val CharSequence.indices: IntRange
+val CharSequence.lastIndex: Int
+val String.Companion.CASE_INSENSITIVE_ORDER: Comparator<String>
+enum class CharCategory : Enum<CharCategory> {
+    operator fun contains(char: Char): Boolean
+    val code: String
+    val value: Int
+    companion object Companion : Any {
+        fun valueOf(category: Int): CharCategory
+    }
+}
+enum class CharDirectionality : Enum<CharDirectionality> {
+    val value: Int
+    companion object Companion : Any {
+        fun valueOf(directionality: Int): CharDirectionality
+    }
+}
+object Charsets : Any {
+    val ISO_8859_1: Charset
+    val US_ASCII: Charset
+    val UTF_16: Charset
+    val UTF_16BE: Charset
+    val UTF_16LE: Charset
+    val UTF_32: Charset
+    val UTF_32BE: Charset
+    val UTF_32LE: Charset
+    val UTF_8: Charset
+}
+data class MatchGroup : Any {
+    val range: IntRange
+    val value: String
+}
+interface MatchGroupCollection : Collection<MatchGroup?> {
+    operator fun get(index: Int): MatchGroup?
+}
+interface MatchNamedGroupCollection : MatchGroupCollection {
+    operator fun get(name: String): MatchGroup?
+}
+interface MatchResult : Any {
+    fun next(): MatchResult?
+    val destructured: MatchResult.Destructured
+    val groupValues: List<String>
+    val groups: MatchGroupCollection
+    val range: IntRange
+    val value: String
+    class Destructured : Any {
+        fun toList(): List<String>
+        inline operator fun component1(): String
+        inline operator fun component10(): String
+        inline operator fun component2(): String
+        inline operator fun component3(): String
+        inline operator fun component4(): String
+        inline operator fun component5(): String
+        inline operator fun component6(): String
+        inline operator fun component7(): String
+        inline operator fun component8(): String
+        inline operator fun component9(): String
+        val match: MatchResult
+    }
+}
+class Regex : Serializable {
+    fun containsMatchIn(input: CharSequence): Boolean
+    fun find(input: CharSequence, startIndex: Int = ...): MatchResult?
+    fun findAll(input: CharSequence, startIndex: Int = ...): Sequence<MatchResult>
+    fun matchAt(input: CharSequence, index: Int): MatchResult?
+    fun matchEntire(input: CharSequence): MatchResult?
+    fun matchesAt(input: CharSequence, index: Int): Boolean
+    fun replace(input: CharSequence, replacement: String): String
+    fun replace(input: CharSequence, transform: Function1<MatchResult, CharSequence>): String
+    fun replaceFirst(input: CharSequence, replacement: String): String
+    fun split(input: CharSequence, limit: Int = ...): List<String>
+    fun splitToSequence(input: CharSequence, limit: Int = ...): Sequence<String>
+    fun toPattern(): Pattern
+    fun toString(): String
+    infix fun matches(input: CharSequence): Boolean
+    val options: Set<RegexOption>
+    val pattern: String
+    companion object Companion : Any {
+        fun escape(literal: String): String
+        fun escapeReplacement(literal: String): String
+        fun fromLiteral(literal: String): Regex
+    }
+}
+enum class RegexOption : Enum<RegexOption>, FlagEnum {
+    val mask: Int
+    val value: Int
+}
+object Typography : Any {
+    const val almostEqual: Char
+    const val amp: Char
+    const val bullet: Char
+    const val cent: Char
+    const val copyright: Char
+    const val dagger: Char
+    const val degree: Char
+    const val dollar: Char
+    const val doubleDagger: Char
+    const val doublePrime: Char
+    const val ellipsis: Char
+    const val euro: Char
+    const val greater: Char
+    const val greaterOrEqual: Char
+    const val half: Char
+    const val leftDoubleQuote: Char
+    const val leftGuillemet: Char
+    const val leftGuillemete: Char
+    const val leftSingleQuote: Char
+    const val less: Char
+    const val lessOrEqual: Char
+    const val lowDoubleQuote: Char
+    const val lowSingleQuote: Char
+    const val mdash: Char
+    const val middleDot: Char
+    const val nbsp: Char
+    const val ndash: Char
+    const val notEqual: Char
+    const val paragraph: Char
+    const val plusMinus: Char
+    const val pound: Char
+    const val prime: Char
+    const val quote: Char
+    const val registered: Char
+    const val rightDoubleQuote: Char
+    const val rightGuillemet: Char
+    const val rightGuillemete: Char
+    const val rightSingleQuote: Char
+    const val section: Char
+    const val times: Char
+    const val tm: Char
+}
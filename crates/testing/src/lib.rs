@@ -1,6 +1,7 @@
 #[macro_use]
 extern crate derive_builder;
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -19,7 +20,10 @@ pub use workspace::*;
 pub use tower_lsp::lsp_types::*;
 pub use tower_lsp::LanguageServer;
 
-pub struct TestClientData {}
+pub struct TestClientData {
+    /// The last published diagnostics per file
+    pub diagnostics: HashMap<Url, Vec<Diagnostic>>,
+}
 
 #[derive(Clone)]
 pub struct TestClient {
@@ -28,7 +32,9 @@ pub struct TestClient {
 impl TestClient {
     pub fn new() -> Self {
         TestClient {
-            v: new_arc_lock(TestClientData {}),
+            v: new_arc_lock(TestClientData {
+                diagnostics: HashMap::new(),
+            }),
         }
     }
 }
@@ -38,6 +44,11 @@ impl ClientI for TestClient {
     async fn log_message(&self, ty: MessageType, msg: String) {
         info!("ClientLogging: {:?} {}", ty, msg);
     }
+
+    async fn publish_diagnostics(&self, uri: Url, diags: Vec<Diagnostic>, _version: Option<i32>) {
+        info!("ClientDiagnostics: {} {:?}", uri, diags);
+        self.v.lock().diagnostics.insert(uri, diags);
+    }
 }
 
 #[derive(Builder, Clone)]
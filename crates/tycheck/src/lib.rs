@@ -9,6 +9,7 @@ static TC_KEY_GENERATOR: AtomicI32 = AtomicI32::new(1);
 #[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
 pub struct TcKey(i32);
 
+#[derive(Eq, PartialEq, Clone, Debug)]
 pub enum Ty {
     Any,
 
@@ -35,6 +36,39 @@ pub enum Ty {
     Generic { satisfies: TcKey },
 }
 
+/// The builtin types and their fully qualified kotlin names
+const BUILTINS: &[(&str, Ty)] = &[
+    ("kotlin.Any", Ty::Any),
+    ("kotlin.Byte", Ty::Byte),
+    ("kotlin.Short", Ty::Short),
+    ("kotlin.Int", Ty::Int),
+    ("kotlin.Long", Ty::Long),
+    ("kotlin.Float", Ty::Float),
+    ("kotlin.Double", Ty::Double),
+    ("kotlin.UByte", Ty::UByte),
+    ("kotlin.UShort", Ty::UShort),
+    ("kotlin.UInt", Ty::UInt),
+    ("kotlin.ULong", Ty::ULong),
+    ("kotlin.Boolean", Ty::Boolean),
+    ("kotlin.Char", Ty::Char),
+    ("kotlin.String", Ty::String),
+];
+
+impl Ty {
+    /// Returns the builtin type declared as `fq_name` (e.G. `kotlin.Int`)
+    pub fn builtin(fq_name: &str) -> Option<Ty> {
+        BUILTINS
+            .iter()
+            .find(|(name, _)| *name == fq_name)
+            .map(|(_, ty)| ty.clone())
+    }
+
+    /// The fully qualified names of all builtin types
+    pub fn builtin_names() -> impl Iterator<Item = &'static str> {
+        BUILTINS.iter().map(|(name, _)| *name)
+    }
+}
+
 pub struct TyTable {
     pub constraints: HashMap<TcKey, Vec<Constraint>>,
     // name to key mapping for items visible _outside_ the own buffer
@@ -46,6 +80,12 @@ pub enum Constraint {
     Eq(TcKey),
 }
 
+impl Default for TyTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TyTable {
     pub fn new() -> Self {
         Self {
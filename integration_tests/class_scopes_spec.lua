@@ -0,0 +1,65 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local files = {
+    ["src/main/kotlin/example.kt"] = [[
+package example
+
+data class Person(val name: String, age: Int) : Named {
+    fun greet(): String = name
+
+    object Factory
+}
+
+interface Named
+]],
+}
+
+local print_scopes = function(client)
+    return client.print_scopes({ print_file_contents = false, print_scopes = true })
+end
+
+local contains = function(text, expected)
+    assert(text:find(expected, 1, true), "Expected to find " .. expected .. " in\n" .. text)
+end
+
+async.describe("Class scopes", function()
+    local test_name = "class_scopes__create_classes_with_members"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        local scopes = print_scopes(client)
+
+        contains(scopes, "data class Person(val name: String, age: Int) : Named")
+        contains(scopes, "fn greet() -> String  {...}")
+        contains(scopes, "object Factory")
+        contains(scopes, "interface Named")
+    end)
+
+    test_name = "class_scopes__update_members"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/greet<CR>cwwelcome<ESC>")
+        util.exec_keys("/Factory<CR>Ofun bye() = Unit<ESC>")
+        -- TODO, neovim is not sending the didChange notification without the write
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "fn welcome() -> String  {...}")
+        contains(scopes, "fn bye()")
+        assert.falsy(scopes:find("greet", 1, true))
+    end)
+
+    test_name = "class_scopes__update_class_header"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/age<CR>ciwvar age<ESC>")
+        util.exec_keys("/Named {<CR>ct{Named, Comparable<Person> <ESC>")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "data class Person(val name: String, var age: Int) : Named, Comparable")
+        contains(scopes, "fn greet() -> String  {...}")
+    end)
+end)
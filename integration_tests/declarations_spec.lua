@@ -0,0 +1,151 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local request_at_cursor = function(client, method)
+    local response = client.request_sync(method, vim.lsp.util.make_position_params(), 5000, 0)
+    assert(response ~= nil, "Request failed")
+    assert(response.err == nil, vim.inspect(response.err))
+    return response.result
+end
+
+local files = {
+    ["src/main/kotlin/example/declarations.kt"] = [[
+package example
+
+typealias Palette = Color
+
+enum class Color {
+    RED,
+    GREEN;
+
+    fun isWarm() = this == RED
+}
+
+class Factory {
+    companion object {
+        fun create(): Factory = Factory()
+    }
+}
+]],
+    ["src/main/kotlin/example/user.kt"] = [[
+package example
+
+data class User(val name: String, var age: Int = 0) {
+    override fun toString() = name
+}
+
+fun greet(user: User) {
+    val (name, age) = user
+    val copied = user.copy(age = 1)
+}
+]],
+    ["src/main/kotlin/example/main.kt"] = [[
+package example
+
+fun main() {
+    val red = Color.RED
+    val all = Palette.values()
+    val factory = Factory.create()
+}
+]],
+}
+
+local print_scopes = function(client)
+    return client.print_scopes({ print_file_contents = false, print_scopes = true })
+end
+
+local contains = function(text, expected)
+    assert(text:find(expected, 1, true), "Expected to find " .. expected .. " in\n" .. text)
+end
+
+async.describe("Declarations", function()
+    local test_name = "declarations__create_scopes"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        local scopes = print_scopes(client)
+
+        contains(scopes, "typealias Palette = Color")
+        contains(scopes, "enum entry RED")
+        contains(scopes, "enum entry GREEN")
+        contains(scopes, "companion object Companion")
+    end)
+
+    test_name = "declarations__goto_enum_entry"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        util.exec_keys("/RED<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.equal(5, locations[1].range.start.line)
+    end)
+
+    test_name = "declarations__hover_synthetic_enum_member_through_type_alias"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        util.exec_keys("/values<CR>")
+
+        local result = request_at_cursor(client, "textDocument/hover")
+        contains(result.contents.value, "fun values(): Array<Color>")
+    end)
+
+    test_name = "declarations__goto_companion_member_by_class_name"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        util.exec_keys("/create<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.equal(13, locations[1].range.start.line)
+    end)
+
+    test_name = "declarations__update_type_alias"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/declarations.kt")
+        util.exec_keys("/= Color<CR>wciwFactory<ESC>")
+        vim.cmd.write()
+
+        contains(print_scopes(client), "typealias Palette = Factory")
+    end)
+
+    test_name = "declarations__hover_destructured_component"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/user.kt")
+        util.exec_keys("/age) =<CR>")
+
+        local result = request_at_cursor(client, "textDocument/hover")
+        contains(result.contents.value, "operator fun component2(): Int")
+    end)
+
+    test_name = "declarations__goto_synthetic_copy_lands_on_primary_constructor"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/user.kt")
+        util.exec_keys("/copy<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.equal(2, locations[1].range.start.line)
+        assert.equal(15, locations[1].range.start.character)
+    end)
+
+    test_name = "declarations__complete_data_class_members"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/user.kt")
+        util.exec_keys("/user.copy<CR>f.l")
+
+        local items = request_at_cursor(client, "textDocument/completion")
+        local labels = vim.tbl_map(function(item) return item.label end, items)
+        assert.truthy(vim.tbl_contains(labels, "copy"))
+        assert.truthy(vim.tbl_contains(labels, "component1"))
+        assert.truthy(vim.tbl_contains(labels, "hashCode"))
+        -- Declared explicitly, so only the declaration is listed
+        assert.equal(1, #vim.tbl_filter(function(label) return label == "toString" end, labels))
+    end)
+end)
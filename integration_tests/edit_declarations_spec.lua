@@ -0,0 +1,145 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local files = {
+    ["src/main/kotlin/example.kt"] = [[
+package example
+
+fun first(a: Int): Int = a
+
+fun second(b: Int): Int = b
+
+class Holder {
+    fun third(c: Int): Int = c
+
+    fun fourth(d: Int): Int = d
+}
+
+fun params(a: Int, b: String, c: Long): Int = a
+]],
+}
+
+local print_scopes = function(client)
+    return client.print_scopes({ print_file_contents = false, print_scopes = true })
+end
+
+local contains = function(text, expected)
+    assert(text:find(expected, 1, true), "Expected to find " .. expected .. " in\n" .. text)
+end
+
+async.describe("Edits of multiple declarations", function()
+    local test_name = "edit_declarations__update_all_changed_declarations"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        vim.cmd("%s/Int/Long/g")
+        -- TODO, neovim is not sending the didChange notification without the write
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "fn first(a: Long,) -> Long")
+        contains(scopes, "fn second(b: Long,) -> Long")
+        contains(scopes, "fn third(c: Long,) -> Long")
+        contains(scopes, "fn fourth(d: Long,) -> Long")
+        assert.falsy(scopes:find("Int", 1, true))
+    end)
+
+    test_name = "edit_declarations__merge_declarations"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/first<CR>c/second(<CR>merged(<ESC>")
+        util.exec_keys("/third<CR>c/fourth(<CR>member(<ESC>")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "fn merged(b: Int,) -> Int")
+        contains(scopes, "fn member(d: Int,) -> Int")
+        assert.falsy(scopes:find("first", 1, true))
+        assert.falsy(scopes:find("second", 1, true))
+        assert.falsy(scopes:find("third", 1, true))
+        assert.falsy(scopes:find("fourth", 1, true))
+    end)
+
+    test_name = "edit_declarations__change_the_kind_of_declarations"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/fun second<CR>c/=<CR>val second <ESC>")
+        util.exec_keys("/fun third<CR>c/=<CR>val third <ESC>")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "val second")
+        contains(scopes, "val third")
+        contains(scopes, "fn first(a: Int,) -> Int")
+        contains(scopes, "fn fourth(d: Int,) -> Int")
+        assert.falsy(scopes:find("fn second", 1, true))
+        assert.falsy(scopes:find("fn third", 1, true))
+    end)
+
+    test_name = "edit_declarations__delete_and_insert_parameters"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/b: String<CR>d/c<CR>")
+        util.exec_keys("/c: Long<CR>f)i, d: Char<ESC>")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "fn params(a: Int,c: Long,d: Char,) -> Int")
+    end)
+
+    test_name = "edit_declarations__reorder_parameters"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/b: String<CR>d/c<CR>")
+        util.exec_keys("/c: Long<CR>f)i, b: String<ESC>")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "fn params(a: Int,c: Long,b: String,) -> Int")
+    end)
+
+    test_name = "edit_declarations__move_declarations"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        -- Swaps the members of Holder
+        util.exec_keys("/fun third<CR>dd/fun fourth<CR>p")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        local fourth = scopes:find("fn fourth", 1, true)
+        local third = scopes:find("fn third", 1, true)
+        assert(fourth and third and fourth < third, "Expected fourth before third in\n" .. scopes)
+    end)
+
+    test_name = "edit_declarations__replace_name_and_parameters_at_once"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        vim.cmd("%s/first(a: Int)/renamed(b: Long)/")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "fn renamed(b: Long,) -> Int")
+        assert.falsy(scopes:find("first", 1, true))
+    end)
+
+    test_name = "edit_declarations__delete_whole_declarations"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/fun second<CR>dd")
+        util.exec_keys("/fun fourth<CR>dd")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "fn first(a: Int,) -> Int")
+        contains(scopes, "fn third(c: Int,) -> Int")
+        assert.falsy(scopes:find("second", 1, true))
+        assert.falsy(scopes:find("fourth", 1, true))
+    end)
+end)
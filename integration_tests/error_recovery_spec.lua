@@ -0,0 +1,52 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local files = {
+    ["src/main/kotlin/example.kt"] = [[
+package example
+
+fun first(a: Int): Int = a
+
+class Holder {
+    fun second(b: Int): Int = b
+}
+]],
+}
+
+local print_scopes = function(client)
+    return client.print_scopes({ print_file_contents = false, print_scopes = true })
+end
+
+local contains = function(text, expected)
+    assert(text:find(expected, 1, true), "Expected to find " .. expected .. " in\n" .. text)
+end
+
+async.describe("Declarations, which do not parse", function()
+    local test_name = "error_recovery__unfinished_function"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/fun first<CR>Ofun half(value: Long<ESC>")
+        -- TODO, neovim is not sending the didChange notification without the write
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "fn half(value: Long,)")
+        contains(scopes, "(recovered)")
+        contains(scopes, "fn first(a: Int,) -> Int")
+    end)
+
+    test_name = "error_recovery__unclosed_class"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("Goclass Unclosed(val size: Int) : Holder {<CR>fun third(c: Int): Int = c<ESC>")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "(recovered)")
+        contains(scopes, "Unclosed")
+        contains(scopes, "fn third(c: Int,) -> Int")
+        contains(scopes, "fn second(b: Int,) -> Int")
+    end)
+end)
@@ -0,0 +1,43 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local files = {
+    ["src/main/kotlin/example.kt"] = [[
+package example
+
+internal suspend fun <T> List<T>.firstMatching(vararg names: String, limit: Int = 10): T? where T : Comparable<T> = null
+
+fun usage(items: List<String>) = items.firstMatching("a")
+]],
+}
+
+local contains = function(text, expected)
+    assert(text:find(expected, 1, true), "Expected to find " .. expected .. " in\n" .. text)
+end
+
+async.describe("Function signatures", function()
+    local test_name = "function_signatures__hover_full_signature"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/firstMatching(\"a<CR>")
+
+        local response = client.request_sync("textDocument/hover", vim.lsp.util.make_position_params(), 5000, 0)
+        assert(response ~= nil and response.err == nil, "Hover failed")
+        contains(
+            response.result.contents.value,
+            "internal suspend fun <T> List<T>.firstMatching(vararg names: String, limit: Int = 10): T? where T : Comparable<T>"
+        )
+    end)
+
+    test_name = "function_signatures__update_modifiers"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/internal<CR>ciwprivate<ESC>")
+        vim.cmd.write()
+
+        local scopes = client.print_scopes({ print_file_contents = false, print_scopes = true })
+        contains(scopes, "private suspend fn <T> List<T>.firstMatching(")
+    end)
+end)
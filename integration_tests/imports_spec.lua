@@ -0,0 +1,71 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local request_at_cursor = function(client, method)
+    local response = client.request_sync(method, vim.lsp.util.make_position_params(), 5000, 0)
+    assert(response ~= nil, "Request failed")
+    assert(response.err == nil, vim.inspect(response.err))
+    return response.result
+end
+
+local files = {
+    ["src/main/kotlin/lib/util.kt"] = "package lib.util\nfun helper(): Int = 1\n",
+    ["src/main/kotlin/example.kt"] = [[
+package example
+
+import lib.util.helper as aid
+import lib.util.*
+import lib.missing.Thing
+import java.util.UUID
+
+fun main() = aid()
+]],
+}
+
+async.describe("Imports", function()
+    local test_name = "imports__unresolved_import_diagnostic"
+    async.it(test_name, function()
+        require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        vim.wait(5000, function() return #vim.diagnostic.get(0) > 0 end, 100)
+
+        local diagnostics = vim.diagnostic.get(0)
+        assert.equal(1, #diagnostics)
+        assert.equal("Unresolved import lib.missing.Thing", diagnostics[1].message)
+        assert.equal(4, diagnostics[1].lnum)
+    end)
+
+    test_name = "imports__goto_aliased_import"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/aid()<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.truthy(locations[1].uri:find("lib/util.kt", 1, true))
+    end)
+
+    test_name = "imports__complete_by_alias"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/aid()<CR>l")
+
+        local items = request_at_cursor(client, "textDocument/completion")
+        local labels = vim.tbl_map(function(item) return item.label end, items)
+        assert.truthy(vim.tbl_contains(labels, "aid"))
+    end)
+
+    test_name = "imports__diagnostic_cleared_after_fix"
+    async.it(test_name, function()
+        require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        vim.wait(5000, function() return #vim.diagnostic.get(0) > 0 end, 100)
+        util.exec_keys("/lib.missing<CR>dd")
+        vim.cmd.write()
+        vim.wait(5000, function() return #vim.diagnostic.get(0) == 0 end, 100)
+
+        assert.equal(0, #vim.diagnostic.get(0))
+    end)
+end)
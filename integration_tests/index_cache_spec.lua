@@ -0,0 +1,33 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local files = {
+    ["src/main/kotlin/example/greeter.kt"] = "package example\n\nfun greet() = \"hi\"\n",
+    ["src/main/kotlin/example/main.kt"] = "package example\n\nfun main() = greet()\n",
+}
+
+local cache_files = function(root_dir)
+    return vim.fn.glob(root_dir .. "/kls-cache/*.json", false, true)
+end
+
+async.describe("Index cache", function()
+    local test_name = "index_cache__written_after_import"
+    async.it(test_name, function()
+        local client, test_dir = require "kserver".start(test_name, { files = files })
+        vim.wait(5000, function() return #cache_files(test_dir.root_dir) > 0 end, 100)
+
+        local caches = cache_files(test_dir.root_dir)
+        assert.equal(1, #caches)
+        local cache = vim.json.decode(table.concat(vim.fn.readfile(caches[1]), "\n"))
+        assert.equal(1, cache.version)
+        local paths = vim.tbl_keys(cache.files)
+        assert.equal(2, #paths, vim.inspect(paths))
+
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        util.exec_keys("/greet<CR>")
+        local response = client.request_sync("textDocument/definition", vim.lsp.util.make_position_params(), 5000, 0)
+        assert(response ~= nil and response.err == nil, vim.inspect(response))
+        assert.equal(1, #response.result)
+        assert.truthy(response.result[1].uri:find("greeter.kt", 1, true))
+    end)
+end)
@@ -0,0 +1,50 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local request_at_cursor = function(client, method, params)
+    local response = client.request_sync(method,
+        vim.tbl_extend('force', vim.lsp.util.make_position_params(), params or {}), 5000, 0)
+    assert(response ~= nil, "Request failed")
+    assert(response.err == nil, vim.inspect(response.err))
+    return response.result
+end
+
+local files = {
+    ["src/main/java/example/Bean.java"] = [[
+package example;
+
+public class Bean {
+    private String name;
+
+    public String getName() { return name; }
+    public void setName(String name) { this.name = name; }
+    public boolean isEnabled() { return true; }
+}
+]],
+    ["src/main/kotlin/example/main.kt"] = "package example\nfun bean(): Bean = Bean.na\n",
+}
+
+async.describe("Java interop", function()
+    local test_name = "java_interop__goto_java_class"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        util.exec_keys("/Bean<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.truthy(locations[1].uri:find("src/main/java/example/Bean.java", 1, true))
+        assert.equal(2, locations[1].range.start.line)
+    end)
+
+    test_name = "java_interop__complete_synthetic_properties"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        util.exec_keys("/\\.na<CR>3l")
+
+        local items = request_at_cursor(client, "textDocument/completion")
+        local labels = vim.tbl_map(function(item) return item.label end, items)
+        assert.truthy(vim.tbl_contains(labels, "name"))
+    end)
+end)
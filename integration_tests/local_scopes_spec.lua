@@ -0,0 +1,106 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local request_at_cursor = function(client, method)
+    local response = client.request_sync(method, vim.lsp.util.make_position_params(), 5000, 0)
+    assert(response ~= nil, "Request failed")
+    assert(response.err == nil, vim.inspect(response.err))
+    return response.result
+end
+
+local files = {
+    ["src/main/kotlin/example.kt"] = [[
+package example
+
+fun total(prices: List<Int>): Int {
+    val tax: Int = 2
+    var sum = 0
+    for (price in prices) {
+        val taxed = price + tax
+        sum += taxed
+    }
+    try {
+        check(sum > 0)
+    } catch (e: IllegalStateException) {
+        return 0
+    }
+    when (val rounded = sum / 10) {
+        0 -> return rounded
+    }
+    fun twice(n: Int) = n * 2
+    return prices.map { twice(it) }.sumOf { price -> price }
+}
+]],
+}
+
+local print_scopes = function(client)
+    return client.print_scopes({ print_file_contents = false, print_scopes = true })
+end
+
+local contains = function(text, expected)
+    assert(text:find(expected, 1, true), "Expected to find " .. expected .. " in\n" .. text)
+end
+
+async.describe("Local scopes", function()
+    local test_name = "local_scopes__create_locals"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        local scopes = print_scopes(client)
+
+        contains(scopes, "val tax: Int")
+        contains(scopes, "var sum")
+        contains(scopes, "for {...}")
+        contains(scopes, "val price")
+        contains(scopes, "val taxed")
+        contains(scopes, "val e: IllegalStateException")
+        contains(scopes, "val rounded")
+        contains(scopes, "fn twice(n: Int,)")
+        contains(scopes, "val it")
+    end)
+
+    test_name = "local_scopes__goto_local"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/+ tax<CR>w")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.equal(3, locations[1].range.start.line)
+    end)
+
+    test_name = "local_scopes__hover_lambda_parameter"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/-> price }<CR>w")
+
+        local result = request_at_cursor(client, "textDocument/hover")
+        contains(result.contents.value, "val price")
+    end)
+
+    test_name = "local_scopes__complete_locals"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/sum += taxed<CR>")
+
+        local items = request_at_cursor(client, "textDocument/completion")
+        local labels = vim.tbl_map(function(item) return item.label end, items)
+        assert.truthy(vim.tbl_contains(labels, "taxed"))
+        assert.truthy(vim.tbl_contains(labels, "prices"))
+        assert.falsy(vim.tbl_contains(labels, "rounded"))
+    end)
+
+    test_name = "local_scopes__update_local"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/val taxed<CR>wciwnet<ESC>")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "val net")
+        assert.falsy(scopes:find("taxed", 1, true))
+    end)
+end)
@@ -0,0 +1,176 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local kmp_source_sets = {
+    {
+        name = "commonMain",
+        src_dir = "src/commonMain/kotlin",
+        dependencies = {},
+    },
+    {
+        name = "jvmMain",
+        src_dir = "src/jvmMain/kotlin",
+        dependencies = {},
+        depends_on = { "commonMain" },
+        target = "jvm",
+    },
+    {
+        name = "iosMain",
+        src_dir = "src/iosMain/kotlin",
+        dependencies = {},
+        depends_on = { "commonMain" },
+        target = "ios",
+    },
+}
+
+local definitions_at_cursor = function(client)
+    local response = client.request_sync("textDocument/definition", vim.lsp.util.make_position_params(), 5000, 0)
+    assert(response ~= nil, "Request failed")
+    assert(response.err == nil, vim.inspect(response.err))
+    return response.result
+end
+
+async.describe("Kotlin multiplatform", function()
+    local test_name = "multiplatform__goto_actual_of_expect"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name,
+            {
+                source_sets = kmp_source_sets,
+                files = {
+                    ["src/commonMain/kotlin/platform.kt"] = "package example\nexpect fun platformName(): String\n",
+                    ["src/jvmMain/kotlin/platform.kt"] = "package example\nactual fun platformName(): String {}\n",
+                    ["src/iosMain/kotlin/platform.kt"] = "package example\nactual fun platformName(): String {}\n",
+                }
+            }
+        )
+        vim.cmd.edit("src/commonMain/kotlin/platform.kt")
+        util.exec_keys("/platformName<CR>")
+
+        local locations = definitions_at_cursor(client)
+        assert.equal(2, #locations)
+        local uris = vim.tbl_map(function(l) return l.uri end, locations)
+        table.sort(uris)
+        assert.truthy(uris[1]:find("src/iosMain/kotlin/platform.kt", 1, true))
+        assert.truthy(uris[2]:find("src/jvmMain/kotlin/platform.kt", 1, true))
+    end)
+
+    test_name = "multiplatform__goto_expect_of_actual"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name,
+            {
+                source_sets = kmp_source_sets,
+                files = {
+                    ["src/commonMain/kotlin/platform.kt"] = "package example\nexpect fun platformName(): String\n",
+                    ["src/jvmMain/kotlin/platform.kt"] = "package example\nactual fun platformName(): String {}\n",
+                }
+            }
+        )
+        vim.cmd.edit("src/jvmMain/kotlin/platform.kt")
+        util.exec_keys("/platformName<CR>")
+
+        local locations = definitions_at_cursor(client)
+        assert.equal(1, #locations)
+        assert.truthy(locations[1].uri:find("src/commonMain/kotlin/platform.kt", 1, true))
+        assert.equal(1, locations[1].range.start.line)
+    end)
+
+    test_name = "multiplatform__expect_without_actual_is_reported"
+    async.it(test_name, function()
+        require "kserver".start(test_name,
+            {
+                source_sets = kmp_source_sets,
+                files = {
+                    ["src/commonMain/kotlin/platform.kt"] = "package example\nexpect fun platformName(): String\n",
+                    ["src/jvmMain/kotlin/platform.kt"] = "package example\nactual fun platformName(): String {}\n",
+                }
+            }
+        )
+        vim.cmd.edit("src/commonMain/kotlin/platform.kt")
+        vim.wait(5000, function() return #vim.diagnostic.get(0) > 0 end, 100)
+
+        local diagnostics = vim.diagnostic.get(0)
+        assert.equal(1, #diagnostics)
+        assert.equal("expect platformName has no actual declaration for target ios", diagnostics[1].message)
+    end)
+
+    test_name = "multiplatform__goto_actual_of_expect_class"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name,
+            {
+                source_sets = kmp_source_sets,
+                files = {
+                    ["src/commonMain/kotlin/platform.kt"] = "package example\nexpect class Platform\n",
+                    ["src/jvmMain/kotlin/platform.kt"] = "package example\nactual class Platform\n",
+                    ["src/iosMain/kotlin/platform.kt"] = "package example\nactual class Platform\n",
+                }
+            }
+        )
+        vim.cmd.edit("src/commonMain/kotlin/platform.kt")
+        util.exec_keys("/Platform<CR>")
+
+        local locations = definitions_at_cursor(client)
+        assert.equal(2, #locations)
+    end)
+
+    test_name = "multiplatform__goto_expect_of_actual_property"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name,
+            {
+                source_sets = kmp_source_sets,
+                files = {
+                    ["src/commonMain/kotlin/platform.kt"] = "package example\nexpect val platformName: String\n",
+                    ["src/jvmMain/kotlin/platform.kt"] = "package example\nactual val platformName: String = \"jvm\"\n",
+                }
+            }
+        )
+        vim.cmd.edit("src/jvmMain/kotlin/platform.kt")
+        util.exec_keys("/platformName<CR>")
+
+        local locations = definitions_at_cursor(client)
+        assert.equal(1, #locations)
+        assert.truthy(locations[1].uri:find("src/commonMain/kotlin/platform.kt", 1, true))
+    end)
+
+    test_name = "multiplatform__expect_class_and_property_without_actual_are_reported"
+    async.it(test_name, function()
+        require "kserver".start(test_name,
+            {
+                source_sets = kmp_source_sets,
+                files = {
+                    ["src/commonMain/kotlin/platform.kt"] = "package example\nexpect class Platform\nexpect val platformName: String\n",
+                    ["src/jvmMain/kotlin/platform.kt"] = "package example\nactual class Platform\nactual val platformName: String = \"jvm\"\n",
+                }
+            }
+        )
+        vim.cmd.edit("src/commonMain/kotlin/platform.kt")
+        vim.wait(5000, function() return #vim.diagnostic.get(0) > 1 end, 100)
+
+        local messages = vim.tbl_map(function(d) return d.message end, vim.diagnostic.get(0))
+        assert.equal(2, #messages, vim.inspect(messages))
+        assert.truthy(vim.tbl_contains(messages, "expect Platform has no actual declaration for target ios"))
+        assert.truthy(vim.tbl_contains(messages, "expect platformName has no actual declaration for target ios"))
+    end)
+
+    test_name = "multiplatform__deleting_actual_reports_expect"
+    async.it(test_name, function()
+        require "kserver".start(test_name,
+            {
+                source_sets = kmp_source_sets,
+                files = {
+                    ["src/commonMain/kotlin/platform.kt"] = "package example\nexpect fun platformName(): String\n",
+                    ["src/jvmMain/kotlin/platform.kt"] = "package example\nactual fun platformName(): String {}\n",
+                    ["src/iosMain/kotlin/platform.kt"] = "package example\nactual fun platformName(): String {}\n",
+                }
+            }
+        )
+        vim.cmd.edit("src/commonMain/kotlin/platform.kt")
+        local common = vim.api.nvim_get_current_buf()
+        vim.cmd.edit("src/iosMain/kotlin/platform.kt")
+        util.exec_keys("/actual<CR>dd")
+        vim.wait(5000, function() return #vim.diagnostic.get(common) > 0 end, 100)
+
+        local diagnostics = vim.diagnostic.get(common)
+        assert.equal(1, #diagnostics)
+        assert.equal("expect platformName has no actual declaration for target ios", diagnostics[1].message)
+    end)
+end)
@@ -0,0 +1,65 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local request_at_cursor = function(client, method, params)
+    local response = client.request_sync(method, params or vim.lsp.util.make_position_params(), 5000, 0)
+    assert(response ~= nil, "Request failed")
+    assert(response.err == nil, vim.inspect(response.err))
+    return response.result
+end
+
+local files = {
+    ["src/main/kotlin/com/example/util/strings.kt"] = "package com.example.util\nfun shout(s: String) = s\n",
+    ["src/main/kotlin/com/example/ui/view.kt"] = "package com.example.model\nclass View\n",
+    ["src/main/kotlin/com/example/main.kt"] = [[
+package com.example
+
+import com.example.util.shout
+
+fun main() = shout("a")
+]],
+}
+
+async.describe("Packages", function()
+    local test_name = "packages__goto_package_segment"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/com/example/main.kt")
+        util.exec_keys("/util.shout<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.truthy(locations[1].uri:find("util/strings.kt", 1, true))
+        assert.equal(0, locations[1].range.start.line)
+    end)
+
+    test_name = "packages__complete_packages_in_import"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/com/example/main.kt")
+        util.exec_keys("/util.shout<CR>")
+
+        local items = request_at_cursor(client, "textDocument/completion")
+        local labels = vim.tbl_map(function(item) return item.label end, items)
+        assert.truthy(vim.tbl_contains(labels, "util"))
+        assert.truthy(vim.tbl_contains(labels, "model"))
+    end)
+
+    test_name = "packages__mismatch_diagnostic_and_quick_fix"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/com/example/ui/view.kt")
+        vim.wait(5000, function() return #vim.diagnostic.get(0) > 0 end, 100)
+
+        local diagnostics = vim.diagnostic.get(0)
+        assert.equal(1, #diagnostics)
+        assert.equal("Package 'com.example.model' does not match the directory. Expected 'com.example.ui'",
+            diagnostics[1].message)
+
+        local params = vim.lsp.util.make_range_params()
+        params.context = { diagnostics = vim.lsp.diagnostic.get_line_diagnostics(0, 0) }
+        local actions = request_at_cursor(client, "textDocument/codeAction", params)
+        assert.equal(1, #actions)
+        assert.equal("Change the package to 'com.example.ui'", actions[1].title)
+    end)
+end)
@@ -0,0 +1,70 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local files = {
+    ["src/main/kotlin/example.kt"] = [[
+package example
+
+const val MAX: Int = 3
+val names by lazy { listOf("a") }
+
+class Counter {
+    lateinit var label: String
+    var count: Int = 0
+        private set
+    val doubled: Int
+        get() = count * 2
+}
+]],
+}
+
+local print_scopes = function(client)
+    return client.print_scopes({ print_file_contents = false, print_scopes = true })
+end
+
+local contains = function(text, expected)
+    assert(text:find(expected, 1, true), "Expected to find " .. expected .. " in\n" .. text)
+end
+
+async.describe("Property scopes", function()
+    local test_name = "property_scopes__create_properties"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        local scopes = print_scopes(client)
+
+        contains(scopes, "const val MAX: Int")
+        contains(scopes, "val names by lazy")
+        contains(scopes, "lateinit var label: String")
+        contains(scopes, "var count: Int { set }")
+        contains(scopes, "val doubled: Int { get }")
+    end)
+
+    test_name = "property_scopes__update_property"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/var count<CR>ciwval<ESC>")
+        util.exec_keys("/MAX<CR>ciwLIMIT<ESC>")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "val count: Int { set }")
+        contains(scopes, "const val LIMIT: Int")
+        assert.falsy(scopes:find("MAX", 1, true))
+    end)
+
+    test_name = "property_scopes__hover_property"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, {
+            files = vim.tbl_extend("force", files, {
+                ["src/main/kotlin/usage.kt"] = "package example\nfun limit() = MAX\n",
+            })
+        })
+        vim.cmd.edit("src/main/kotlin/usage.kt")
+        util.exec_keys("/MAX<CR>")
+
+        local response = client.request_sync("textDocument/hover", vim.lsp.util.make_position_params(), 5000, 0)
+        assert(response ~= nil and response.err == nil, "Hover failed")
+        contains(response.result.contents.value, "const val MAX: Int")
+    end)
+end)
@@ -0,0 +1,75 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local request_at_cursor = function(client, method, params)
+    local response = client.request_sync(method,
+        vim.tbl_extend('force', vim.lsp.util.make_position_params(), params or {}), 5000, 0)
+    assert(response ~= nil, "Request failed")
+    assert(response.err == nil, vim.inspect(response.err))
+    return response.result
+end
+
+local files = {
+    ["build.gradle.kts"] = [[
+fun versionOf(name: String): String = name
+
+dependencies {
+    val version = versionOf("kotlin")
+}
+]],
+    ["tool.main.kts"] = "val greeting = greet()\n",
+    ["src/main/kotlin/example/Host.kt"] = [[
+package example
+
+class Host {
+    fun greet(): String = "hello"
+}
+]],
+}
+
+local script_definitions = {
+    { file_suffix = ".main.kts", implicit_receivers = { "example.Host" } },
+}
+
+local print_scopes = function(client)
+    return client.print_scopes({ print_file_contents = false, print_scopes = true })
+end
+
+local contains = function(text, expected)
+    assert(text:find(expected, 1, true), "Expected to find " .. expected .. " in\n" .. text)
+end
+
+async.describe("Kotlin scripts", function()
+    local test_name = "scripts__statements_and_declarations"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+
+        local scopes = print_scopes(client)
+        contains(scopes, "script {")
+        contains(scopes, "fn versionOf(name: String,) -> String")
+    end)
+
+    test_name = "scripts__goto_script_declaration"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("build.gradle.kts")
+        util.exec_keys("/versionOf(\"<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.truthy(locations[1].uri:find("build.gradle.kts", 1, true))
+        assert.equal(0, locations[1].range.start.line)
+    end)
+
+    test_name = "scripts__goto_implicit_receiver_member"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name,
+            { files = files, script_definitions = script_definitions })
+        vim.cmd.edit("tool.main.kts")
+        util.exec_keys("/greet(<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.truthy(locations[1].uri:find("src/main/kotlin/example/Host.kt", 1, true))
+    end)
+end)
@@ -0,0 +1,85 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local request_at_cursor = function(client, method, params)
+    local response = client.request_sync(method,
+        vim.tbl_extend('force', vim.lsp.util.make_position_params(), params or {}), 5000, 0)
+    assert(response ~= nil, "Request failed")
+    assert(response.err == nil, vim.inspect(response.err))
+    return response.result
+end
+
+local files = {
+    ["src/main/kotlin/example.kt"] = "package example\nfun strings(): List<String> = listOf(\"a\")\n",
+}
+
+async.describe("Bundled stdlib", function()
+    local test_name = "stdlib__goto_default_imported_function"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/listOf<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.equal("kls-stdlib:/kotlin.collections.kt", locations[1].uri)
+        assert.truthy(client.library_file_content(locations[1].uri):find("fun <T> listOf(element: T): List<T>", 1, true))
+    end)
+
+    test_name = "stdlib__goto_overload_by_signature"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, {
+            files = {
+                ["src/main/kotlin/example.kt"] = "package example\nfun strings(): List<String> = listOf(\"a\", \"b\")\n",
+            }
+        })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/listOf<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        local lines = vim.split(client.library_file_content(locations[1].uri), "\n")
+        local declarations = vim.tbl_map(function(location) return lines[location.range.start.line + 1] end, locations)
+        assert.truthy(vim.tbl_contains(declarations, "fun <T> listOf(vararg elements: T): List<T>"), vim.inspect(declarations))
+    end)
+
+    test_name = "stdlib__hover_builtin_class"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/String<CR>")
+
+        local hover = request_at_cursor(client, "textDocument/hover")
+        assert.truthy(hover.contents.value:find("class String : Comparable<String>, CharSequence", 1, true))
+        assert.truthy(hover.contents.value:find("`kotlin`", 1, true))
+    end)
+
+    test_name = "stdlib__complete_default_imported_functions"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/listOf<CR>4l")
+
+        local items = request_at_cursor(client, "textDocument/completion")
+        local labels = vim.tbl_map(function(item) return item.label end, items)
+        assert.truthy(vim.tbl_contains(labels, "listOf"))
+        assert.truthy(vim.tbl_contains(labels, "listOfNotNull"))
+        assert.falsy(vim.tbl_contains(labels, "setOf"))
+    end)
+
+    test_name = "stdlib__goto_local_in_library_source"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        local jar_dir = "/tmp/" .. test_name .. "/jar"
+        require "tfs".write(jar_dir .. "/lib/Util.kt",
+            "package lib\nfun twice(value: Int): Int {\n    val sum = value + value\n    return sum\n}\n")
+        vim.system({ "zip", "-r", "../lib-sources.jar", "lib" }, { cwd = jar_dir }):wait()
+        local uri = "kls-jar:/tmp/" .. test_name .. "/lib-sources.jar!/lib/Util.kt"
+        vim.cmd.edit(uri)
+        util.exec_keys("/return sum<CR>7l")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.equal(uri, locations[1].uri)
+        assert.equal(2, locations[1].range.start.line)
+    end)
+end)
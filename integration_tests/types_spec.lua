@@ -0,0 +1,45 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local files = {
+    ["src/main/kotlin/example.kt"] = [[
+package example
+
+val handlers: Map<String, suspend (Int) -> Unit>? = null
+val entries: List<out kotlin.collections.Map.Entry<*, in Number>> = listOf()
+
+fun String.parse(input: String?, onError: (String.(Throwable) -> Unit)?): Array<Int>? = null
+]],
+}
+
+local print_scopes = function(client)
+    return client.print_scopes({ print_file_contents = false, print_scopes = true })
+end
+
+local contains = function(text, expected)
+    assert(text:find(expected, 1, true), "Expected to find " .. expected .. " in\n" .. text)
+end
+
+async.describe("Types", function()
+    local test_name = "types__display_as_written"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        local scopes = print_scopes(client)
+
+        contains(scopes, "val handlers: Map<String, suspend (Int) -> Unit>?")
+        contains(scopes, "val entries: List<out kotlin.collections.Map.Entry<*, in Number>>")
+        contains(scopes, "fn parse(input: String?,onError: (String.(Throwable) -> Unit)?,) -> Array<Int>?")
+    end)
+
+    test_name = "types__update_parameter_type"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example.kt")
+        util.exec_keys("/input: String<CR>wciwInt<ESC>")
+        vim.cmd.write()
+
+        local scopes = print_scopes(client)
+        contains(scopes, "fn parse(input: Int?,")
+        contains(scopes, "-> Array<Int>?")
+    end)
+end)
@@ -0,0 +1,110 @@
+local async = require 'plenary.async.tests'
+local util = require 'util'
+
+local request_at_cursor = function(client, method)
+    local response = client.request_sync(method, vim.lsp.util.make_position_params(), 5000, 0)
+    assert(response ~= nil, "Request failed")
+    assert(response.err == nil, vim.inspect(response.err))
+    return response.result
+end
+
+local files = {
+    ["src/main/kotlin/example/secrets.kt"] = [[
+package example
+
+private fun secret(): Int = 42
+
+object Registry {
+    private val entries = 0
+    val size = entries
+}
+
+open class Base {
+    protected fun hook(): Int = 1
+}
+
+open class Middle : Base()
+
+fun shared(): Int = 2
+]],
+    ["src/main/kotlin/example/main.kt"] = [[
+package example
+
+class Derived : Base() {
+    fun run() = hook()
+}
+
+class Leaf : Middle() {
+    fun run() = hook()
+}
+
+fun main() {
+    secret()
+    Registry.entries
+    Registry.size
+    unknown().secret()
+    unknown().shared()
+}
+]],
+}
+
+local messages_of = function(diagnostics)
+    return vim.tbl_map(function(diagnostic) return diagnostic.message end, diagnostics)
+end
+
+async.describe("Visibility", function()
+    local test_name = "visibility__inaccessible_diagnostics"
+    async.it(test_name, function()
+        require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        vim.wait(5000, function() return #vim.diagnostic.get(0) > 0 end, 100)
+
+        local messages = messages_of(vim.diagnostic.get(0))
+        assert.equal(2, #messages, vim.inspect(messages))
+        assert.truthy(vim.tbl_contains(messages, "Cannot access 'secret': it is private in file secrets.kt"))
+        assert.truthy(vim.tbl_contains(messages, "Cannot access 'entries': it is private in 'example.Registry'"))
+    end)
+
+    test_name = "visibility__no_goto_to_private_declaration"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        util.exec_keys("/secret()<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(0, #(locations or {}))
+    end)
+
+    test_name = "visibility__goto_protected_member_in_subclass"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        util.exec_keys("/hook()<CR>")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(1, #locations)
+        assert.truthy(locations[1].uri:find("secrets.kt", 1, true))
+    end)
+
+    test_name = "visibility__complete_without_private_members"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        util.exec_keys("/Registry.size<CR>f.l")
+
+        local items = request_at_cursor(client, "textDocument/completion")
+        local labels = vim.tbl_map(function(item) return item.label end, items)
+        assert.truthy(vim.tbl_contains(labels, "size"))
+        assert.falsy(vim.tbl_contains(labels, "entries"))
+    end)
+
+    test_name = "visibility__no_goto_from_member_of_unknown_receiver"
+    async.it(test_name, function()
+        local client = require "kserver".start(test_name, { files = files })
+        vim.cmd.edit("src/main/kotlin/example/main.kt")
+        util.exec_keys("/unknown().shared<CR>f.l")
+
+        local locations = request_at_cursor(client, "textDocument/definition")
+        assert.equal(0, #(locations or {}))
+    end)
+end)
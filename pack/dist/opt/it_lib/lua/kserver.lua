@@ -5,6 +5,7 @@ local M = {}
 
 --- @class KlsClient : vim.lsp.Client
 --- @field print_scopes fun(params: table): any
+--- @field library_file_content fun(uri: string): string
 
 ---Start KLS
 ---@param test_case_name string: opts for starting the server
@@ -40,6 +41,8 @@ M.start = function(test_case_name, test_dir_cfg, client_config)
             "--start-new-log-file",
             "--log-timestamps=false",
         },
+        -- Keeps the index cache of test projects out of the user cache directory
+        cmd_env = { KLS_CACHE_DIR = test_dir.root_dir .. "/kls-cache" },
         root_dir = test_dir.root_dir,
         workspace_folders = test_dir.workspace_folders,
     })
@@ -78,6 +81,25 @@ M.start = function(test_case_name, test_dir_cfg, client_config)
         return kls_response.result
     end
 
+    client.library_file_content = function(uri)
+        local kls_response = client.request_sync("custom/libraryFileContent", { uri = uri }, nil, 0)
+        assert(kls_response ~= nil, "Request failed")
+        assert(kls_response.err == nil, vim.inspect(kls_response.err))
+        return kls_response.result
+    end
+
+    -- Library files are served by kls. Load them read only
+    vim.api.nvim_create_autocmd('BufReadCmd', {
+        pattern = { 'kls-jar:*', 'kls-stdlib:*' },
+        callback = function(args)
+            local content = client.library_file_content(args.match)
+            vim.api.nvim_buf_set_lines(args.buf, 0, -1, false, vim.split(content, "\n"))
+            vim.bo[args.buf].modifiable = false
+            vim.bo[args.buf].readonly = true
+            vim.bo[args.buf].filetype = 'kotlin'
+        end,
+    })
+
 
     return client, test_dir
 end
@@ -32,6 +32,7 @@ M.create = function(test_case_name, passed_args)
         name = args.name or "KLS Test",
         root_dir = dir,
         workspace_folders = { { name = dir, uri = "file://" .. dir } },
+        script_definitions = args.script_definitions,
         source_sets = args.source_sets or {
             {
                 name = "kotlin",
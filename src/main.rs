@@ -32,9 +32,17 @@ async fn main() {
     let stdin = tokio::io::stdin();
     let stdout = tokio::io::stdout();
 
-    let (service, socket) = LspService::build(|client| KServer::new(Arc::new(client)))
-        .custom_method("custom/printScopes", KServer::print_scopes)
-        .finish();
+    let verify_incremental = args.verify_incremental;
+    let (service, socket) = LspService::build(|client| {
+        let mut server = KServer::new(Arc::new(client));
+        if verify_incremental {
+            server.incremental_check = Some(Default::default());
+        }
+        server
+    })
+    .custom_method("custom/printScopes", KServer::print_scopes)
+    .custom_method("custom/libraryFileContent", KServer::library_file_content)
+    .finish();
 
     debug!("KLS starting");
     Server::new(stdin, stdout, socket).serve(service).await;
@@ -45,6 +53,8 @@ struct AppArgs {
     log_file: Option<String>,
     start_new_log_file: bool,
     log_timestamps: bool,
+    /// Compare incremental scope updates with a rebuild, see [server::incremental_check]
+    verify_incremental: bool,
 }
 impl AppArgs {
     fn from_env() -> Result<AppArgs, pico_args::Error> {
@@ -55,6 +65,7 @@ impl AppArgs {
             log_timestamps: pargs
                 .opt_value_from_str("--log-timestamps")?
                 .unwrap_or(true),
+            verify_incremental: pargs.contains("--verify-incremental"),
         })
     }
 }